@@ -76,7 +76,7 @@ impl ReadState {
         // Validate that all chunks have incrementing sequence numbers and valid chunk types
         let secure_channel = trace_read_lock_unwrap!(self.secure_channel);
         self.last_received_sequence_number = Chunker::validate_chunks(
-            self.last_received_sequence_number + 1,
+            self.last_received_sequence_number.wrapping_add(1),
             &secure_channel,
             chunks,
         )?;
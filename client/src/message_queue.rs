@@ -7,6 +7,7 @@ use std::collections::{HashMap, HashSet};
 use futures::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 use opcua_core::supported_message::SupportedMessage;
+use opcua_types::{status_code::StatusCode, ResponseHeader, ServiceFault};
 
 pub(crate) struct MessageQueue {
     /// The requests that are in-flight, defined by their request handle and an async flag. Basically,
@@ -81,6 +82,29 @@ impl MessageQueue {
         let _ = self.inflight_requests.remove(&(request_handle, true));
     }
 
+    /// Fails every in-flight request by storing a service fault with the supplied status code as
+    /// its response, e.g. when the secure channel the requests were sent over is closed.
+    pub(crate) fn fail_inflight_requests(&mut self, status_code: StatusCode) {
+        let inflight_requests = self.inflight_requests.drain().collect::<Vec<_>>();
+        inflight_requests
+            .into_iter()
+            .for_each(|(request_handle, is_async)| {
+                debug!(
+                    "Request {} has failed with status {}",
+                    request_handle, status_code
+                );
+                let response = ServiceFault {
+                    response_header: ResponseHeader {
+                        request_handle,
+                        service_result: status_code,
+                        ..ResponseHeader::null()
+                    },
+                };
+                self.responses
+                    .insert(request_handle, (response.into(), is_async));
+            });
+    }
+
     /// Called by the connection to store a response for the consumption of the session.
     pub(crate) fn store_response(&mut self, response: SupportedMessage) {
         // Remove corresponding request handle from inflight queue, add to responses
@@ -860,7 +860,8 @@ impl Session {
     }
 
     /// Sends a [`CloseSecureChannelRequest`] to the server which will cause the server to drop
-    /// the connection.
+    /// the connection. Requests that are still awaiting a response fail with
+    /// `BadSecureChannelClosed`.
     ///
     /// See OPC UA Part 4 - Services 5.5.3 for complete description of the service and error responses.
     ///
@@ -872,6 +873,11 @@ impl Session {
     /// [`CloseSecureChannelRequest`]: ./struct.CloseSecureChannelRequest.html
     ///
     pub fn close_secure_channel(&mut self) -> Result<(), StatusCode> {
+        // Requests still waiting on a response will not get one once the channel is gone
+        {
+            let mut message_queue = trace_write_lock_unwrap!(self.message_queue);
+            message_queue.fail_inflight_requests(StatusCode::BadSecureChannelClosed);
+        }
         let request = CloseSecureChannelRequest {
             request_header: self.make_request_header(),
         };
@@ -14,7 +14,9 @@ use std::{
 use chrono;
 
 use opcua_core::{
-    comms::secure_channel::SecureChannel, handle::Handle, supported_message::SupportedMessage,
+    comms::{secure_channel::SecureChannel, PROTOCOL_VERSION},
+    handle::Handle,
+    supported_message::SupportedMessage,
 };
 use opcua_crypto::SecurityPolicy;
 use opcua_types::{status_code::StatusCode, *};
@@ -405,7 +407,7 @@ impl SessionState {
         let requested_lifetime = REQUESTED_LIFETIME;
         let request = OpenSecureChannelRequest {
             request_header: self.make_request_header(),
-            client_protocol_version: 0,
+            client_protocol_version: PROTOCOL_VERSION,
             request_type,
             security_mode,
             client_nonce,
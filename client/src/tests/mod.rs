@@ -1,12 +1,13 @@
 use std::{self, collections::BTreeMap, path::PathBuf};
 
-use opcua_core::config::Config;
+use opcua_core::{config::Config, supported_message::SupportedMessage};
 use opcua_crypto::SecurityPolicy;
-use opcua_types::*;
+use opcua_types::{status_code::StatusCode, *};
 
 use crate::{
     builder::ClientBuilder,
     config::{ClientConfig, ClientEndpoint, ClientUserToken, ANONYMOUS_USER_TOKEN_ID},
+    message_queue::MessageQueue,
     session::Session,
 };
 
@@ -203,3 +204,49 @@ fn node_id_is_one_of() {
         &object_ids
     ));
 }
+
+#[test]
+fn inflight_requests_fail_when_channel_closes() {
+    let mut message_queue = MessageQueue::new();
+    let (_, _rx) = message_queue.make_request_channel();
+    let read_request = |request_handle| {
+        let mut request_header = RequestHeader::dummy();
+        request_header.request_handle = request_handle;
+        let request: SupportedMessage = ReadRequest {
+            request_header,
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Both,
+            nodes_to_read: None,
+        }
+        .into();
+        request
+    };
+    message_queue.add_request(read_request(1), false);
+    message_queue.add_request(read_request(2), true);
+
+    message_queue.fail_inflight_requests(StatusCode::BadSecureChannelClosed);
+    match message_queue.take_response(1) {
+        Some(SupportedMessage::ServiceFault(fault)) => {
+            assert_eq!(fault.response_header.request_handle, 1);
+            assert_eq!(
+                fault.response_header.service_result,
+                StatusCode::BadSecureChannelClosed
+            );
+        }
+        response => panic!("Expected a service fault, got {:?}", response),
+    }
+    let responses = message_queue.async_responses();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].request_handle(), 2);
+
+    // Nothing is left in flight so a late response is ignored
+    message_queue.store_response(
+        ReadResponse {
+            response_header: ResponseHeader::null(),
+            results: None,
+            diagnostic_infos: None,
+        }
+        .into(),
+    );
+    assert!(message_queue.take_response(0).is_none());
+}
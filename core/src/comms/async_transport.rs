@@ -0,0 +1,77 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains futures for reading and writing whole messages over a non-blocking stream. The bytes
+//! of a message are read into a buffer and from there they are parsed by the same
+//! `BinaryEncoder` implementations that the blocking code uses.
+
+use std::io::{self, Cursor};
+
+use futures::Future;
+use tokio_io::{io as async_io, AsyncRead, AsyncWrite};
+
+use opcua_types::{encoding::*, status_code::StatusCode};
+
+use crate::comms::tcp_types::{FrameReader, MessageHeader, MESSAGE_HEADER_LEN};
+
+fn io_error_to_status_code(err: io::Error) -> StatusCode {
+    if err.kind() == io::ErrorKind::UnexpectedEof {
+        debug!("Stream closed while reading or writing a message");
+        StatusCode::BadConnectionClosed
+    } else {
+        error!("Stream error while reading or writing a message, {:?}", err);
+        StatusCode::BadCommunicationError
+    }
+}
+
+/// Reads the header of a message and then its body from the stream. The header is validated
+/// against the decoding limits before any of the body is read. The future resolves to the
+/// stream, the header and the body bytes which follow the header.
+///
+/// The body buffer grows as bytes arrive rather than being sized from the header, so a header
+/// which claims a huge size cannot make it allocate more than the peer actually sends.
+pub fn read_message<S>(
+    stream: S,
+    decoding_limits: &DecodingLimits,
+) -> impl Future<Item = (S, MessageHeader, Vec<u8>), Error = StatusCode>
+where
+    S: AsyncRead,
+{
+    let decoding_limits = *decoding_limits;
+    async_io::read_exact(stream, [0u8; MESSAGE_HEADER_LEN])
+        .map_err(io_error_to_status_code)
+        .and_then(move |(stream, header_bytes)| {
+            let frame = FrameReader::new(Cursor::new(&header_bytes[..]), &decoding_limits)?;
+            Ok((stream, frame.header().clone()))
+        })
+        .and_then(|(stream, header)| {
+            let body_len = header.message_size as usize - MESSAGE_HEADER_LEN;
+            async_io::read_to_end(stream.take(body_len as u64), Vec::new())
+                .map_err(io_error_to_status_code)
+                .and_then(move |(stream, body)| {
+                    if body.len() < body_len {
+                        debug!(
+                            "Stream closed after {} bytes of a {} byte message body",
+                            body.len(),
+                            body_len
+                        );
+                        Err(StatusCode::BadConnectionClosed)
+                    } else {
+                        Ok((stream.into_inner(), header, body))
+                    }
+                })
+        })
+}
+
+/// Encodes the message and writes all of it to the stream. The future resolves to the stream
+/// once the message has been written.
+pub fn write_message<S, T>(stream: S, message: &T) -> impl Future<Item = S, Error = StatusCode>
+where
+    S: AsyncWrite,
+    T: BinaryEncoder<T>,
+{
+    async_io::write_all(stream, message.encode_to_vec())
+        .map_err(io_error_to_status_code)
+        .map(|(stream, _)| stream)
+}
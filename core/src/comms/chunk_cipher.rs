@@ -0,0 +1,140 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Encryption and decryption of message chunks.
+//!
+//! A `ChunkCipher` encrypts the part of a chunk that follows the security header - the sequence
+//! header, body, padding and signature - when the secure channel's security mode is
+//! `SignAndEncrypt`. The body is padded out to a multiple of the cipher's plain text block size
+//! before it is signed and encrypted, and the padding is checked and stripped after decryption.
+
+use std::ops::Range;
+
+use opcua_crypto::{aeskey::AesKey, SecurityPolicy};
+use opcua_types::status_code::StatusCode;
+
+/// Encrypts and decrypts the encrypted portion of a chunk.
+pub trait ChunkCipher {
+    /// The block size that the plain text must be padded to a multiple of before encryption.
+    fn plain_text_block_size(&self) -> usize;
+
+    /// Encrypts the source into the destination, returning the size of the cipher text.
+    fn encrypt(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode>;
+
+    /// Decrypts the source into the destination, returning the size of the plain text.
+    fn decrypt(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode>;
+}
+
+/// Encrypts with the symmetric algorithm of a security policy. Chunks are encrypted with the
+/// local key and decrypted with the remote key.
+pub struct PolicyCipher<'a> {
+    security_policy: SecurityPolicy,
+    encryption_keys: (&'a AesKey, &'a [u8]),
+    decryption_keys: (&'a AesKey, &'a [u8]),
+}
+
+impl<'a> PolicyCipher<'a> {
+    pub fn new(
+        security_policy: SecurityPolicy,
+        encryption_keys: (&'a AesKey, &'a [u8]),
+        decryption_keys: (&'a AesKey, &'a [u8]),
+    ) -> PolicyCipher<'a> {
+        PolicyCipher {
+            security_policy,
+            encryption_keys,
+            decryption_keys,
+        }
+    }
+}
+
+impl<'a> ChunkCipher for PolicyCipher<'a> {
+    fn plain_text_block_size(&self) -> usize {
+        self.security_policy.plain_block_size()
+    }
+
+    fn encrypt(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let (key, iv) = self.encryption_keys;
+        self.security_policy.symmetric_encrypt(key, iv, src, dst)
+    }
+
+    fn decrypt(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        let (key, iv) = self.decryption_keys;
+        self.security_policy.symmetric_decrypt(key, iv, src, dst)
+    }
+}
+
+/// Encrypts the `encrypted_range` of `src` into the same position of `dst`, copying the
+/// unencrypted headers in front of it. Returns the size of the chunk after encryption.
+pub fn encrypt_chunk(
+    cipher: &dyn ChunkCipher,
+    src: &[u8],
+    encrypted_range: Range<usize>,
+    dst: &mut [u8],
+) -> Result<usize, StatusCode> {
+    let plain_text_size = encrypted_range.end - encrypted_range.start;
+    let remainder = plain_text_size % cipher.plain_text_block_size();
+    if remainder > 0 {
+        error!(
+            "Plain text size {} is not a multiple of the block size {}",
+            plain_text_size,
+            cipher.plain_text_block_size()
+        );
+        return Err(StatusCode::BadSecurityChecksFailed);
+    }
+    dst[..encrypted_range.start].copy_from_slice(&src[..encrypted_range.start]);
+    let encrypted_size = cipher.encrypt(
+        &src[encrypted_range.clone()],
+        &mut dst[encrypted_range.start..],
+    )?;
+    Ok(encrypted_range.start + encrypted_size)
+}
+
+/// Decrypts the `encrypted_range` of `src` into the same position of `dst`, copying the
+/// unencrypted headers in front of it. Returns the size of the chunk after decryption.
+pub fn decrypt_chunk(
+    cipher: &dyn ChunkCipher,
+    src: &[u8],
+    encrypted_range: Range<usize>,
+    dst: &mut [u8],
+) -> Result<usize, StatusCode> {
+    dst[..encrypted_range.start].copy_from_slice(&src[..encrypted_range.start]);
+    let decrypted_size = cipher.decrypt(
+        &src[encrypted_range.clone()],
+        &mut dst[encrypted_range.start..],
+    )?;
+    Ok(encrypted_range.start + decrypted_size)
+}
+
+/// Checks the padding that ends at `padding_end` and returns the offset where it starts, i.e.
+/// the end of the body. The last padding byte holds the number of padding bytes in front of it
+/// and every one of those bytes must hold the same value.
+pub fn strip_padding(data: &[u8], padding_end: usize) -> Result<usize, StatusCode> {
+    if padding_end == 0 || padding_end > data.len() {
+        error!("Padding end {} is outside of the chunk", padding_end);
+        return Err(StatusCode::BadSecurityChecksFailed);
+    }
+    let padding_byte = data[padding_end - 1];
+    let padding_size = padding_byte as usize + 1;
+    if padding_size > padding_end {
+        error!(
+            "Padding size {} is larger than the chunk {}",
+            padding_size, padding_end
+        );
+        return Err(StatusCode::BadSecurityChecksFailed);
+    }
+    let padding_start = padding_end - padding_size;
+    if let Some(i) = data[padding_start..padding_end]
+        .iter()
+        .position(|b| *b != padding_byte)
+    {
+        error!(
+            "Expected padding byte {}, got {} at index {}",
+            padding_byte,
+            data[padding_start + i],
+            padding_start + i
+        );
+        return Err(StatusCode::BadSecurityChecksFailed);
+    }
+    Ok(padding_start)
+}
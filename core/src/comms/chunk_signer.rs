@@ -0,0 +1,120 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Signing and verification of message chunks.
+//!
+//! A `ChunkSigner` produces and checks the signature that trails a chunk when the secure channel's
+//! security mode is `Sign`. The secure channel picks the signer from its security mode so that
+//! a channel with no security passes chunks through untouched.
+
+use std::ops::Range;
+
+use opcua_crypto::SecurityPolicy;
+use opcua_types::status_code::StatusCode;
+
+/// Signs and verifies the signed portion of a chunk.
+pub trait ChunkSigner {
+    /// Produces the signature of the supplied data. An empty signature means the data is not signed.
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, StatusCode>;
+
+    /// Verifies that the signature matches the supplied data, returning `BadSecurityChecksFailed`
+    /// if it does not.
+    fn verify(&self, data: &[u8], signature: &[u8]) -> Result<(), StatusCode>;
+}
+
+/// The signer for security mode `None`. It produces no signature and accepts any data.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoSigner;
+
+impl ChunkSigner for NoSigner {
+    fn sign(&self, _data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        Ok(Vec::new())
+    }
+
+    fn verify(&self, _data: &[u8], _signature: &[u8]) -> Result<(), StatusCode> {
+        Ok(())
+    }
+}
+
+/// Signs with the symmetric algorithm of a security policy. Chunks are signed with the local
+/// key and verified with the remote key.
+pub struct PolicySigner<'a> {
+    security_policy: SecurityPolicy,
+    signing_key: &'a [u8],
+    verification_key: &'a [u8],
+}
+
+impl<'a> PolicySigner<'a> {
+    pub fn new(
+        security_policy: SecurityPolicy,
+        signing_key: &'a [u8],
+        verification_key: &'a [u8],
+    ) -> PolicySigner<'a> {
+        PolicySigner {
+            security_policy,
+            signing_key,
+            verification_key,
+        }
+    }
+}
+
+impl<'a> ChunkSigner for PolicySigner<'a> {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let mut signature = vec![0u8; self.security_policy.symmetric_signature_size()];
+        match self
+            .security_policy
+            .symmetric_sign(self.signing_key, data, &mut signature)
+        {
+            Ok(_) => Ok(signature),
+            Err(err) => {
+                error!("Cannot sign chunk, error = {}", err);
+                Err(err)
+            }
+        }
+    }
+
+    fn verify(&self, data: &[u8], signature: &[u8]) -> Result<(), StatusCode> {
+        self.security_policy
+            .symmetric_verify_signature(self.verification_key, data, signature)
+            .map(|_| ())
+            .map_err(|_| StatusCode::BadSecurityChecksFailed)
+    }
+}
+
+/// Signs the `signed_range` of `src`, copying it into `dst` followed by the signature. The
+/// signature must be exactly `signature_size` bytes. Returns the offset of the end of the
+/// signature in `dst`, or the error from the signer if the data could not be signed.
+pub fn sign_chunk(
+    signer: &dyn ChunkSigner,
+    src: &[u8],
+    signed_range: Range<usize>,
+    signature_size: usize,
+    dst: &mut [u8],
+) -> Result<usize, StatusCode> {
+    let signature = signer.sign(&src[signed_range.clone()])?;
+    if signature.len() != signature_size {
+        error!(
+            "Chunk signature is {} bytes, expected {} bytes",
+            signature.len(),
+            signature_size
+        );
+        return Err(StatusCode::BadSecurityChecksFailed);
+    }
+    let signature_range = signed_range.end..(signed_range.end + signature_size);
+    dst[signed_range.clone()].copy_from_slice(&src[signed_range]);
+    dst[signature_range.clone()].copy_from_slice(&signature);
+    Ok(signature_range.end)
+}
+
+/// Verifies the `signed_range` of `data` against the signature that immediately follows it and
+/// runs to the end of `data`.
+pub fn verify_chunk(
+    signer: &dyn ChunkSigner,
+    data: &[u8],
+    signed_range: Range<usize>,
+) -> Result<(), StatusCode> {
+    signer
+        .verify(&data[signed_range.clone()], &data[signed_range.end..])
+        .map_err(|_| StatusCode::BadSecurityChecksFailed)
+}
@@ -16,10 +16,17 @@ use crate::{
     comms::{
         message_chunk::{MessageChunk, MessageChunkType, MessageIsFinalType},
         secure_channel::SecureChannel,
+        tcp_types::CountingWriter,
     },
     supported_message::SupportedMessage,
 };
 
+/// Sequence numbers may only wrap around once they are greater than this value
+pub const SEQUENCE_NUMBER_WRAP_AROUND: u32 = u32::MAX - 1024;
+
+/// The first sequence number after a wrap around must be less than this value
+const MAX_SEQUENCE_NUMBER_AFTER_WRAP_AROUND: u32 = 1024;
+
 /// The Chunker is responsible for turning messages to chunks and chunks into messages.
 pub struct Chunker;
 
@@ -37,11 +44,15 @@ impl Chunker {
         }
     }
 
-    /// Ensure all of the supplied chunks have a valid secure channel id, and sequence numbers
-    /// greater than the input sequence number and the preceding chunk
+    /// Ensure all of the supplied chunks have a valid secure channel id, a size which agrees
+    /// with their header, and sequence numbers greater than the input sequence number and the
+    /// preceding chunk. Sequence numbers may wrap around to a value below 1024 once they exceed
+    /// `SEQUENCE_NUMBER_WRAP_AROUND`.
     ///
     /// The function returns the last sequence number in the series for success, or
-    /// `BadSequenceNumberInvalid` or `BadSecureChannelIdInvalid` for failure.
+    /// `BadSequenceNumberInvalid` if the message is older than the input sequence number,
+    /// `BadSecurityChecksFailed` if a chunk is malformed or out of order, or
+    /// `BadSecureChannelIdInvalid` for failure.
     pub fn validate_chunks(
         starting_sequence_number: u32,
         secure_channel: &SecureChannel,
@@ -51,7 +62,9 @@ impl Chunker {
             let chunk_info = chunks[0].chunk_info(secure_channel)?;
             chunk_info.sequence_header.sequence_number
         };
-        if first_sequence_number < starting_sequence_number {
+        let wrapped_around = starting_sequence_number > SEQUENCE_NUMBER_WRAP_AROUND
+            && first_sequence_number < MAX_SEQUENCE_NUMBER_AFTER_WRAP_AROUND;
+        if first_sequence_number < starting_sequence_number && !wrapped_around {
             error!(
                 "First sequence number of {} is less than last value {}",
                 first_sequence_number, starting_sequence_number
@@ -65,6 +78,17 @@ impl Chunker {
             for (i, chunk) in chunks.iter().enumerate() {
                 let chunk_info = chunk.chunk_info(secure_channel)?;
 
+                // Check the size in the header is the size of the chunk
+                if chunk_info.message_header.message_size as usize != chunk.data.len() {
+                    error!(
+                        "Chunk size {} does not match the size in its header {}, idx {}",
+                        chunk.data.len(),
+                        chunk_info.message_header.message_size,
+                        i
+                    );
+                    return Err(StatusCode::BadSecurityChecksFailed);
+                }
+
                 // Check the channel id of each chunk
                 if secure_channel_id != 0
                     && chunk_info.message_header.secure_channel_id != secure_channel_id
@@ -78,7 +102,7 @@ impl Chunker {
 
                 // Check the sequence id - should be larger than the last one decoded
                 let sequence_number = chunk_info.sequence_header.sequence_number;
-                let expected_sequence_number = first_sequence_number + i as u32;
+                let expected_sequence_number = first_sequence_number.wrapping_add(i as u32);
                 if sequence_number != expected_sequence_number {
                     error!(
                         "Chunk sequence number of {} is not the expected value of {}, idx {}",
@@ -95,7 +119,7 @@ impl Chunker {
                     return Err(StatusCode::BadSecurityChecksFailed);
                 }
             }
-            Ok(first_sequence_number + chunks.len() as u32 - 1)
+            Ok(first_sequence_number.wrapping_add(chunks.len() as u32 - 1))
         }
     }
 
@@ -136,12 +160,13 @@ impl Chunker {
             message_size += node_id.byte_len();
 
             let message_type = Chunker::message_type(supported_message);
-            let mut stream = Cursor::new(vec![0u8; message_size]);
+            let mut stream =
+                CountingWriter::new(Cursor::new(vec![0u8; message_size]), message_size);
 
             trace!("Encoding node id {:?}", node_id);
             let _ = node_id.encode(&mut stream);
             let _ = supported_message.encode(&mut stream)?;
-            let data = stream.into_inner();
+            let data = stream.finish()?.into_inner();
 
             let result = if max_chunk_size > 0 {
                 let max_body_per_chunk = MessageChunk::body_size_from_message_size(
@@ -169,7 +194,7 @@ impl Chunker {
                         MessageIsFinalType::Intermediate
                     };
                     let chunk = MessageChunk::new(
-                        sequence_number + i as u32,
+                        sequence_number.wrapping_add(i as u32),
                         request_id,
                         message_type,
                         is_final,
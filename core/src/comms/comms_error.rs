@@ -0,0 +1,76 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the error type returned when reading messages from a stream.
+
+use std::{error, fmt, io};
+
+use opcua_types::status_code::StatusCode;
+
+/// The ways in which reading a message from a stream can fail.
+#[derive(Debug)]
+pub enum CommsError {
+    /// The message could not be decoded
+    DecodeFailed,
+    /// The message is larger than the limits allow
+    MessageTooLarge,
+    /// The message type is not one that was expected or recognized
+    UnexpectedType,
+    /// The stream failed
+    Io(io::Error),
+}
+
+impl fmt::Display for CommsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommsError::DecodeFailed => write!(f, "message could not be decoded"),
+            CommsError::MessageTooLarge => write!(f, "message is too large"),
+            CommsError::UnexpectedType => write!(f, "message type is unexpected"),
+            CommsError::Io(err) => write!(f, "stream error, {}", err),
+        }
+    }
+}
+
+impl error::Error for CommsError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CommsError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CommsError {
+    fn from(err: io::Error) -> Self {
+        CommsError::Io(err)
+    }
+}
+
+impl From<StatusCode> for CommsError {
+    fn from(status_code: StatusCode) -> Self {
+        match status_code {
+            StatusCode::BadTcpMessageTooLarge | StatusCode::BadEncodingLimitsExceeded => {
+                CommsError::MessageTooLarge
+            }
+            StatusCode::BadTcpMessageTypeInvalid | StatusCode::BadNotSupported => {
+                CommsError::UnexpectedType
+            }
+            _ => CommsError::DecodeFailed,
+        }
+    }
+}
+
+impl From<CommsError> for StatusCode {
+    fn from(err: CommsError) -> Self {
+        match err {
+            CommsError::DecodeFailed => StatusCode::BadDecodingError,
+            CommsError::MessageTooLarge => StatusCode::BadTcpMessageTooLarge,
+            CommsError::UnexpectedType => StatusCode::BadTcpMessageTypeInvalid,
+            CommsError::Io(ref err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                StatusCode::BadConnectionClosed
+            }
+            CommsError::Io(_) => StatusCode::BadCommunicationError,
+        }
+    }
+}
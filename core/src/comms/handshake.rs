@@ -0,0 +1,186 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the state machine which sequences the HEL / ACK handshake that opens a connection
+//! before any secure channel messages may be exchanged.
+
+use std::io::{Cursor, Read};
+
+use opcua_types::{encoding::*, status_code::StatusCode};
+
+use crate::comms::tcp_types::{
+    AcknowledgeMessage, EndpointUrlPolicy, ErrorMessage, HelloMessage, MessageHeader, MessageType,
+    TransportLimits,
+};
+
+/// The outcome of processing a message through the handshake.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HandshakeEvent {
+    /// The hello was accepted and this acknowledge should be sent back to the client.
+    SendAcknowledge(AcknowledgeMessage),
+    /// The handshake is complete and the message should be processed as normal.
+    Message,
+    /// The message broke the protocol. The connection should be closed with an error
+    /// message containing the status code.
+    Reject(StatusCode),
+}
+
+/// Tracks the server side of the connection handshake. A connection starts by waiting for a
+/// hello, and once that has been acknowledged any further hello is a protocol error.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HandshakeState {
+    /// Waiting for the client to send a hello
+    WaitingHello,
+    /// The hello has been acknowledged and other messages may follow
+    Acknowledged,
+    /// The handshake failed and the connection should be closed
+    Failed,
+}
+
+impl Default for HandshakeState {
+    fn default() -> Self {
+        HandshakeState::WaitingHello
+    }
+}
+
+impl HandshakeState {
+    /// Processes a message received from the client, where the body is the part of the message
+    /// that follows the header. A hello is decoded and acknowledged as `respond_to_hello()`
+    /// describes. Messages which arrive out of order move the state to `Failed`.
+    pub fn process(
+        &mut self,
+        header: &MessageHeader,
+        body: &[u8],
+        server_limits: &TransportLimits,
+        endpoints: &[String],
+        endpoint_url_policy: EndpointUrlPolicy,
+    ) -> HandshakeEvent {
+        if header.message_type != MessageType::Hello {
+            self.process_message(header.message_type)
+        } else if let Err(status_code) = self.expect(MessageType::Hello) {
+            self.transition(Err(status_code))
+        } else {
+            let mut stream = Cursor::new(header.encode_to_vec()).chain(body);
+            match HelloMessage::decode(&mut stream, &DecodingLimits::default()) {
+                Ok(hello) => {
+                    self.process_hello(&hello, server_limits, endpoints, endpoint_url_policy)
+                }
+                Err(status_code) => self.transition(Err(status_code)),
+            }
+        }
+    }
+
+    /// Processes a hello which has already been decoded, e.g. by the `TcpCodec`.
+    pub fn process_hello(
+        &mut self,
+        hello: &HelloMessage,
+        server_limits: &TransportLimits,
+        endpoints: &[String],
+        endpoint_url_policy: EndpointUrlPolicy,
+    ) -> HandshakeEvent {
+        let result = self.expect(MessageType::Hello).and_then(|_| {
+            negotiate_hello(hello, server_limits, endpoints, endpoint_url_policy)
+                .map(HandshakeEvent::SendAcknowledge)
+        });
+        self.transition(result)
+    }
+
+    /// Processes a message other than a hello, whose body has no bearing on the handshake. A
+    /// hello must go through `process_hello()` so it can be acknowledged.
+    pub fn process_message(&mut self, message_type: MessageType) -> HandshakeEvent {
+        let result = self.expect(message_type).and_then(|_| {
+            if message_type == MessageType::Hello {
+                error!("A hello must be processed with its body");
+                Err(StatusCode::BadTcpInternalError)
+            } else {
+                Ok(HandshakeEvent::Message)
+            }
+        });
+        self.transition(result)
+    }
+
+    /// Tests if the hello has been acknowledged
+    pub fn is_acknowledged(&self) -> bool {
+        *self == HandshakeState::Acknowledged
+    }
+
+    /// Tests if a message of the type may arrive in the current state
+    fn expect(&self, message_type: MessageType) -> Result<(), StatusCode> {
+        match *self {
+            HandshakeState::WaitingHello if message_type == MessageType::Hello => Ok(()),
+            HandshakeState::WaitingHello => {
+                error!(
+                    "Expected a hello message but received {:?} instead",
+                    message_type
+                );
+                Err(StatusCode::BadTcpMessageTypeInvalid)
+            }
+            HandshakeState::Acknowledged if message_type == MessageType::Chunk => Ok(()),
+            HandshakeState::Acknowledged => {
+                error!(
+                    "Received {:?} message after the hello was acknowledged",
+                    message_type
+                );
+                Err(StatusCode::BadTcpMessageTypeInvalid)
+            }
+            HandshakeState::Failed => {
+                error!("Received a message after the handshake failed");
+                Err(StatusCode::BadInvalidState)
+            }
+        }
+    }
+
+    fn transition(&mut self, result: Result<HandshakeEvent, StatusCode>) -> HandshakeEvent {
+        match result {
+            Ok(event) => {
+                *self = HandshakeState::Acknowledged;
+                event
+            }
+            Err(status_code) => {
+                *self = HandshakeState::Failed;
+                HandshakeEvent::Reject(status_code)
+            }
+        }
+    }
+}
+
+/// Validates a hello against the server's limits and endpoints and produces the response to send
+/// back, either an acknowledge or an error message holding the reason the hello was refused.
+///
+/// The acknowledge's buffer sizes are the smaller of the server's and the client's, and its max
+/// message size and chunk count are the server's, i.e. what the server is prepared to receive.
+pub fn respond_to_hello(
+    hello: &HelloMessage,
+    server_limits: &TransportLimits,
+    endpoints: &[String],
+    endpoint_url_policy: EndpointUrlPolicy,
+) -> Result<AcknowledgeMessage, ErrorMessage> {
+    negotiate_hello(hello, server_limits, endpoints, endpoint_url_policy)
+        .map_err(ErrorMessage::from_status_code)
+}
+
+/// The hello validation and negotiation shared by `respond_to_hello()` and `HandshakeState`
+fn negotiate_hello(
+    hello: &HelloMessage,
+    server_limits: &TransportLimits,
+    endpoints: &[String],
+    endpoint_url_policy: EndpointUrlPolicy,
+) -> Result<AcknowledgeMessage, StatusCode> {
+    hello.validate_protocol_version()?;
+    hello.validate_endpoint_urls(endpoints, endpoint_url_policy)?;
+    hello.validate_buffer_sizes()?;
+    hello.validate_limits()?;
+    let receive_buffer_size = server_limits
+        .receive_buffer_size
+        .min(hello.send_buffer_size as usize);
+    let send_buffer_size = server_limits
+        .send_buffer_size
+        .min(hello.receive_buffer_size as usize);
+    Ok(AcknowledgeMessage::new(
+        receive_buffer_size as u32,
+        send_buffer_size as u32,
+        server_limits.max_message_size as u32,
+        server_limits.max_chunk_count as u32,
+    ))
+}
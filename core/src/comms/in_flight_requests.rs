@@ -0,0 +1,81 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the counter that limits how many requests a peer may have in flight at once.
+
+use std::collections::HashSet;
+
+use opcua_types::status_code::StatusCode;
+
+/// Tracks the requests that have been received on a channel but not yet responded to. The number
+/// of requests is capped so a peer that sends requests faster than they are serviced is turned
+/// away instead of queuing unbounded work.
+#[derive(Debug)]
+pub struct InFlightRequests {
+    /// The ids of the requests awaiting a response
+    request_ids: HashSet<u32>,
+    /// The maximum number of requests in flight, or 0 for no limit
+    max_requests: usize,
+}
+
+impl InFlightRequests {
+    /// Creates a tracker that permits at most `max_requests` requests in flight, or any number
+    /// if it is 0.
+    pub fn new(max_requests: usize) -> InFlightRequests {
+        InFlightRequests {
+            request_ids: HashSet::new(),
+            max_requests,
+        }
+    }
+
+    /// Returns the maximum number of requests in flight, or 0 for no limit
+    pub fn max_requests(&self) -> usize {
+        self.max_requests
+    }
+
+    /// Returns the number of requests in flight
+    pub fn len(&self) -> usize {
+        self.request_ids.len()
+    }
+
+    /// Tests if no requests are in flight
+    pub fn is_empty(&self) -> bool {
+        self.request_ids.is_empty()
+    }
+
+    /// Tests if the request id is in flight
+    pub fn contains(&self, request_id: u32) -> bool {
+        self.request_ids.contains(&request_id)
+    }
+
+    /// Admits a request, failing with `BadTooManyOperations` if the maximum number of requests
+    /// are already in flight.
+    pub fn begin(&mut self, request_id: u32) -> Result<(), StatusCode> {
+        if self.max_requests > 0
+            && !self.request_ids.contains(&request_id)
+            && self.request_ids.len() >= self.max_requests
+        {
+            warn!(
+                "Request id {} is rejected, there are already {} requests in flight",
+                request_id,
+                self.request_ids.len()
+            );
+            Err(StatusCode::BadTooManyOperations)
+        } else {
+            self.request_ids.insert(request_id);
+            Ok(())
+        }
+    }
+
+    /// Releases a request when its response is sent. Returns `false` if the request id was not
+    /// in flight.
+    pub fn end(&mut self, request_id: u32) -> bool {
+        self.request_ids.remove(&request_id)
+    }
+
+    /// Forgets every request in flight, e.g. when the channel is closed
+    pub fn clear(&mut self) {
+        self.request_ids.clear();
+    }
+}
@@ -16,10 +16,7 @@ use crate::comms::{
     security_header::{
         AsymmetricSecurityHeader, SecurityHeader, SequenceHeader, SymmetricSecurityHeader,
     },
-    tcp_types::{
-        CHUNK_FINAL, CHUNK_FINAL_ERROR, CHUNK_INTERMEDIATE, CHUNK_MESSAGE,
-        CLOSE_SECURE_CHANNEL_MESSAGE, MIN_CHUNK_SIZE, OPEN_SECURE_CHANNEL_MESSAGE,
-    },
+    tcp_types::{MessageTag, CHUNK_FINAL, CHUNK_FINAL_ERROR, CHUNK_INTERMEDIATE, MIN_CHUNK_SIZE},
 };
 
 /// The size of a chunk header, used by several places
@@ -66,11 +63,7 @@ impl BinaryEncoder<MessageChunkHeader> for MessageChunkHeader {
     }
 
     fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
-        let message_type = match self.message_type {
-            MessageChunkType::Message => CHUNK_MESSAGE,
-            MessageChunkType::OpenSecureChannel => OPEN_SECURE_CHANNEL_MESSAGE,
-            MessageChunkType::CloseSecureChannel => CLOSE_SECURE_CHANNEL_MESSAGE,
-        };
+        let message_type = MessageTag::from(self.message_type);
 
         let is_final = match self.is_final {
             MessageIsFinalType::Intermediate => CHUNK_INTERMEDIATE,
@@ -79,7 +72,7 @@ impl BinaryEncoder<MessageChunkHeader> for MessageChunkHeader {
         };
 
         let mut size = 0;
-        size += process_encode_io_result(stream.write(&message_type))?;
+        size += process_encode_io_result(stream.write(message_type.as_bytes()))?;
         size += write_u8(stream, is_final)?;
         size += write_u32(stream, self.message_size)?;
         size += write_u32(stream, self.secure_channel_id)?;
@@ -90,12 +83,9 @@ impl BinaryEncoder<MessageChunkHeader> for MessageChunkHeader {
     fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
         let mut message_type_code = [0u8; 3];
         process_decode_io_result(stream.read_exact(&mut message_type_code))?;
-        let message_type = if message_type_code == CHUNK_MESSAGE {
-            MessageChunkType::Message
-        } else if message_type_code == OPEN_SECURE_CHANNEL_MESSAGE {
-            MessageChunkType::OpenSecureChannel
-        } else if message_type_code == CLOSE_SECURE_CHANNEL_MESSAGE {
-            MessageChunkType::CloseSecureChannel
+        let message_type = if let Some(message_type) = MessageTag(message_type_code).as_chunk_type()
+        {
+            message_type
         } else {
             error!("Invalid message code");
             return Err(StatusCode::BadDecodingError);
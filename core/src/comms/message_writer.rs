@@ -50,7 +50,7 @@ impl MessageWriter {
         // Turn message to chunk(s)
         // TODO max message size and max chunk size
         let chunks = Chunker::encode(
-            self.last_sent_sequence_number + 1,
+            self.last_sent_sequence_number.wrapping_add(1),
             request_id,
             0,
             0,
@@ -58,8 +58,10 @@ impl MessageWriter {
             &message,
         )?;
 
-        // Sequence number monotonically increases per chunk
-        self.last_sent_sequence_number += chunks.len() as u32;
+        // Sequence number monotonically increases per chunk, wrapping around to 0 after u32::MAX
+        self.last_sent_sequence_number = self
+            .last_sent_sequence_number
+            .wrapping_add(chunks.len() as u32);
 
         // Send chunks
 
@@ -5,10 +5,18 @@
 //! Contains all code related to sending / receiving messages from a transport
 //! and turning those messages into and out of chunks.
 
+pub mod async_transport;
+pub mod chunk_cipher;
+pub mod chunk_signer;
 pub mod chunker;
+pub mod comms_error;
+pub mod handshake;
+pub mod in_flight_requests;
 pub mod message_chunk;
 pub mod message_chunk_info;
 pub mod message_writer;
+pub mod pending_chunks;
+pub mod pending_requests;
 pub mod secure_channel;
 pub mod security_header;
 pub mod tcp_codec;
@@ -16,13 +24,32 @@ pub mod tcp_types;
 pub mod url;
 pub mod wrapped_tcp_stream;
 
+/// The version of the UA TCP protocol implemented by this crate. It is sent in the HEL / ACK
+/// messages and in the open secure channel request / response.
+pub const PROTOCOL_VERSION: u32 = 0;
+
+/// Returns the protocol versions this crate can speak, so a peer's version can be checked during
+/// negotiation.
+pub fn supported_protocol_versions() -> &'static [u32] {
+    &[PROTOCOL_VERSION]
+}
+
 pub mod prelude {
+    pub use super::async_transport::*;
+    pub use super::chunk_cipher::*;
+    pub use super::chunk_signer::*;
     pub use super::chunker::*;
+    pub use super::comms_error::*;
+    pub use super::handshake::*;
+    pub use super::in_flight_requests::*;
     pub use super::message_chunk::*;
+    pub use super::pending_chunks::*;
+    pub use super::pending_requests::*;
     pub use super::secure_channel::*;
     pub use super::security_header::*;
     pub use super::tcp_codec::*;
     pub use super::tcp_types::*;
     pub use super::url::*;
     pub use super::wrapped_tcp_stream::WrappedTcpStream;
+    pub use super::{supported_protocol_versions, PROTOCOL_VERSION};
 }
@@ -0,0 +1,78 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the buffer that holds the chunks of a message while it is being reassembled.
+
+use opcua_types::status_code::StatusCode;
+
+use crate::comms::message_chunk::MessageChunk;
+
+/// Holds the chunks of a message until its final chunk arrives. The number of chunks held is
+/// capped by the negotiated max chunk count so a peer cannot exhaust memory by sending an endless
+/// stream of intermediate chunks.
+#[derive(Debug)]
+pub struct PendingChunks {
+    /// The chunks received so far
+    chunks: Vec<MessageChunk>,
+    /// The maximum number of chunks in a message, or 0 for no limit
+    max_chunk_count: usize,
+}
+
+impl PendingChunks {
+    /// Creates a buffer that holds at most `max_chunk_count` chunks, or any number if it is 0.
+    pub fn new(max_chunk_count: usize) -> PendingChunks {
+        PendingChunks {
+            chunks: Vec::with_capacity(2),
+            max_chunk_count,
+        }
+    }
+
+    /// Returns the maximum number of chunks in a message, or 0 for no limit
+    pub fn max_chunk_count(&self) -> usize {
+        self.max_chunk_count
+    }
+
+    /// Sets the maximum number of chunks in a message, e.g. after it has been negotiated
+    pub fn set_max_chunk_count(&mut self, max_chunk_count: usize) {
+        self.max_chunk_count = max_chunk_count;
+    }
+
+    /// Returns the number of chunks held
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Tests if no chunks are held
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Adds a chunk to the message. If the chunk would exceed the max chunk count then the
+    /// message is abandoned, the chunks held so far are freed and `BadTcpMessageTooLarge` is
+    /// returned.
+    pub fn push(&mut self, chunk: MessageChunk) -> Result<(), StatusCode> {
+        if self.max_chunk_count > 0 && self.chunks.len() >= self.max_chunk_count {
+            error!(
+                "Message exceeds the max chunk count {}, discarding {} pending chunks",
+                self.max_chunk_count,
+                self.chunks.len()
+            );
+            self.chunks = Vec::new();
+            Err(StatusCode::BadTcpMessageTooLarge)
+        } else {
+            self.chunks.push(chunk);
+            Ok(())
+        }
+    }
+
+    /// Discards the chunks held so far
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    /// Removes and returns the chunks held so far, e.g. when the final chunk has arrived
+    pub fn take(&mut self) -> Vec<MessageChunk> {
+        self.chunks.drain(..).collect()
+    }
+}
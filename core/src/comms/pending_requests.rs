@@ -0,0 +1,123 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the map which correlates outstanding requests with the responses that complete them.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use futures::sync::oneshot;
+
+use opcua_types::status_code::StatusCode;
+
+use crate::supported_message::SupportedMessage;
+
+/// The result delivered to whoever is waiting on a request, either the response or the status
+/// code the request failed with.
+pub type RequestResult = Result<SupportedMessage, StatusCode>;
+
+/// A request that has been sent and is awaiting its response.
+#[derive(Debug)]
+struct PendingRequest {
+    /// The slot the response is delivered through
+    sender: oneshot::Sender<RequestResult>,
+    /// The time after which the request is considered to have timed out
+    deadline: Instant,
+}
+
+/// Tracks the requests that have been sent but not yet answered, keyed by the request id that
+/// goes into the sequence header of each chunk. Each request has a deadline and the caller
+/// receives the outcome through a oneshot receiver.
+#[derive(Debug, Default)]
+pub struct PendingRequests {
+    requests: HashMap<u32, PendingRequest>,
+}
+
+impl PendingRequests {
+    pub fn new() -> PendingRequests {
+        PendingRequests {
+            requests: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of outstanding requests
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Tests if there are no outstanding requests
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Tests if the request id is outstanding
+    pub fn contains(&self, request_id: u32) -> bool {
+        self.requests.contains_key(&request_id)
+    }
+
+    /// Registers a request which must be completed within the timeout and returns the receiver
+    /// that the response or failure will be delivered to. Registering an id that is already
+    /// outstanding fails the earlier request with `BadRequestInterrupted`.
+    pub fn register(
+        &mut self,
+        request_id: u32,
+        timeout: Duration,
+    ) -> oneshot::Receiver<RequestResult> {
+        let (sender, receiver) = oneshot::channel();
+        let deadline = Instant::now() + timeout;
+        if let Some(previous) = self
+            .requests
+            .insert(request_id, PendingRequest { sender, deadline })
+        {
+            error!(
+                "Request id {} was registered while it was still outstanding",
+                request_id
+            );
+            let _ = previous.sender.send(Err(StatusCode::BadRequestInterrupted));
+        }
+        receiver
+    }
+
+    /// Completes the request with its response. Returns `true` if the response was delivered to
+    /// a waiter, or `false` if the request id is unknown (e.g. it already timed out) or the waiter
+    /// has gone away.
+    pub fn complete(&mut self, request_id: u32, response: SupportedMessage) -> bool {
+        if let Some(request) = self.requests.remove(&request_id) {
+            request.sender.send(Ok(response)).is_ok()
+        } else {
+            debug!(
+                "Response for request id {} has no outstanding request and will be ignored",
+                request_id
+            );
+            false
+        }
+    }
+
+    /// Fails every request whose deadline has passed with `BadTimeout` and returns their ids.
+    pub fn reap_expired(&mut self, now: Instant) -> Vec<u32> {
+        let expired = self
+            .requests
+            .iter()
+            .filter(|(_, request)| request.deadline <= now)
+            .map(|(request_id, _)| *request_id)
+            .collect::<Vec<_>>();
+        expired.iter().for_each(|request_id| {
+            if let Some(request) = self.requests.remove(request_id) {
+                info!("Request id {} has timed out", request_id);
+                let _ = request.sender.send(Err(StatusCode::BadTimeout));
+            }
+        });
+        expired
+    }
+
+    /// Fails every outstanding request with the supplied status code, e.g. when the connection
+    /// is closed.
+    pub fn fail_all(&mut self, status_code: StatusCode) {
+        self.requests.drain().for_each(|(_, request)| {
+            let _ = request.sender.send(Err(status_code));
+        });
+    }
+}
@@ -20,6 +20,8 @@ use opcua_types::status_code::StatusCode;
 use opcua_types::*;
 
 use crate::comms::{
+    chunk_cipher::{self, ChunkCipher, PolicyCipher},
+    chunk_signer::{self, ChunkSigner, NoSigner, PolicySigner},
     message_chunk::{MessageChunk, MessageChunkHeader, MessageChunkType},
     security_header::{AsymmetricSecurityHeader, SecurityHeader, SymmetricSecurityHeader},
 };
@@ -321,7 +323,7 @@ impl SecureChannel {
                     Ok(())
                 }
             } else {
-                error!("Remote nonce is invalid {:?}", remote_nonce);
+                error!("Remote nonce is invalid {}", remote_nonce);
                 Err(StatusCode::BadNonceInvalid)
             }
         } else {
@@ -652,7 +654,7 @@ impl SecureChannel {
                 message_size,
                 src.len()
             );
-            return Err(StatusCode::BadUnexpectedError);
+            return Err(StatusCode::BadSecurityChecksFailed);
         }
 
         // S - Message Header
@@ -719,7 +721,7 @@ impl SecureChannel {
 
             let verification_key = sender_certificate.public_key()?;
             let receiver_thumbprint = security_header.receiver_certificate_thumbprint;
-            trace!("Receiver thumbprint = {:?}", receiver_thumbprint);
+            trace!("Receiver thumbprint = {}", receiver_thumbprint);
 
             let mut decrypted_data = vec![0u8; message_size];
             let decrypted_size = self.asymmetric_decrypt_and_verify(
@@ -759,12 +761,13 @@ impl SecureChannel {
                 &mut decrypted_data,
             )?;
 
-            // Now we need to strip off signature
-            Self::update_message_size_and_truncate(
-                decrypted_data,
-                decrypted_size - signature_size,
-                &self.decoding_limits,
-            )?
+            // Now we need to strip off signature, and the padding if the body was encrypted
+            let body_end = if self.security_mode == MessageSecurityMode::SignAndEncrypt {
+                chunk_cipher::strip_padding(&decrypted_data, decrypted_size - signature_size)?
+            } else {
+                decrypted_size - signature_size
+            };
+            Self::update_message_size_and_truncate(decrypted_data, body_end, &self.decoding_limits)?
         } else {
             src.to_vec()
         };
@@ -1117,18 +1120,14 @@ impl SecureChannel {
                 // Sign the block
                 let _ = self.symmetric_sign(src, signed_range, &mut dst_tmp)?;
 
-                // Encrypt the sequence header, payload, signature
-                let (key, iv) = self.encryption_keys();
-                let encrypted_size = self.security_policy.symmetric_encrypt(
-                    key,
-                    iv,
-                    &dst_tmp[encrypted_range.clone()],
-                    &mut dst[encrypted_range.start..(encrypted_range.end + 16)],
-                )?;
-                // Copy the message header / security header
-                dst[..encrypted_range.start].copy_from_slice(&dst_tmp[..encrypted_range.start]);
-
-                encrypted_range.start + encrypted_size
+                // Encrypt the sequence header, payload, signature and copy the message header /
+                // security header in front of it
+                chunk_cipher::encrypt_chunk(
+                    self.chunk_cipher().as_ref(),
+                    &dst_tmp,
+                    encrypted_range,
+                    dst,
+                )?
             }
             MessageSecurityMode::Invalid => {
                 panic!("Message security mode is invalid");
@@ -1137,6 +1136,30 @@ impl SecureChannel {
         Ok(encrypted_size)
     }
 
+    /// Returns the signer that matches the channel's security mode. Chunks on a channel with no
+    /// security are passed through unsigned.
+    pub fn chunk_signer(&self) -> Box<dyn ChunkSigner + '_> {
+        match self.security_mode {
+            MessageSecurityMode::Sign | MessageSecurityMode::SignAndEncrypt => {
+                Box::new(PolicySigner::new(
+                    self.security_policy,
+                    self.signing_key(),
+                    self.verification_key(),
+                ))
+            }
+            _ => Box::new(NoSigner),
+        }
+    }
+
+    /// Returns the cipher for a channel whose security mode is `SignAndEncrypt`.
+    pub fn chunk_cipher(&self) -> Box<dyn ChunkCipher + '_> {
+        Box::new(PolicyCipher::new(
+            self.security_policy,
+            self.encryption_keys(),
+            self.decryption_keys(),
+        ))
+    }
+
     fn symmetric_sign(
         &self,
         src: &[u8],
@@ -1144,30 +1167,21 @@ impl SecureChannel {
         dst: &mut [u8],
     ) -> Result<usize, StatusCode> {
         let signature_size = self.security_policy.symmetric_signature_size();
-        let mut signature = vec![0u8; signature_size];
-        let signature_range = signed_range.end..(signed_range.end + signature_size);
         trace!(
-            "signed_range = {:?}, signature range = {:?}, signature len = {}",
+            "signed_range = {:?}, signature len = {}",
             signed_range,
-            signature_range,
             signature_size
         );
 
-        // Sign the message header, security header, sequence header, body, padding
-        let signing_key = self.signing_key();
-        self.security_policy.symmetric_sign(
-            signing_key,
-            &src[signed_range.clone()],
-            &mut signature,
-        )?;
-
-        trace!("Signature, len {} = {:?}", signature.len(), signature);
-
-        // Copy the signed portion and the signature to the destination
-        dst[signed_range.clone()].copy_from_slice(&src[signed_range.clone()]);
-        dst[signature_range.clone()].copy_from_slice(&signature);
-
-        Ok(signature_range.end)
+        // Sign the message header, security header, sequence header, body, padding and copy the
+        // signed portion and the signature to the destination
+        chunk_signer::sign_chunk(
+            self.chunk_signer().as_ref(),
+            src,
+            signed_range,
+            signature_size,
+            dst,
+        )
     }
 
     /// Decrypts and verifies data.
@@ -1205,11 +1219,10 @@ impl SecureChannel {
                     signed_range,
                     signed_range.end
                 );
-                let verification_key = self.verification_key();
-                self.security_policy.symmetric_verify_signature(
-                    verification_key,
-                    &dst[signed_range.clone()],
-                    &dst[signed_range.end..],
+                chunk_signer::verify_chunk(
+                    self.chunk_signer().as_ref(),
+                    &dst[..src.len()],
+                    signed_range,
                 )?;
 
                 Ok(encrypted_range.end)
@@ -1224,28 +1237,23 @@ impl SecureChannel {
                 //                    return Err(StatusCode::BadUnexpectedError);
                 //                }
 
-                // Copy security header
-                dst[..encrypted_range.start].copy_from_slice(&src[..encrypted_range.start]);
-
-                // Decrypt encrypted portion
-                let mut decrypted_tmp = vec![0u8; ciphertext_size + 16]; // tmp includes +16 for blocksize
-                let (key, iv) = self.decryption_keys();
+                // Decrypt encrypted portion, copying the security header in front of it
+                let mut decrypted_tmp = vec![0u8; encrypted_range.start + ciphertext_size + 16]; // tmp includes +16 for blocksize
 
                 trace!(
                     "Secure decrypt called with encrypted range {:?}",
                     encrypted_range
                 );
-                let decrypted_size = self.security_policy.symmetric_decrypt(
-                    key,
-                    iv,
-                    &src[encrypted_range.clone()],
-                    &mut decrypted_tmp[..],
+                let decrypted_end = chunk_cipher::decrypt_chunk(
+                    self.chunk_cipher().as_ref(),
+                    src,
+                    encrypted_range.clone(),
+                    &mut decrypted_tmp,
                 )?;
 
                 // Self::log_crypto_data("Encrypted buffer", &src[..encrypted_range.end]);
-                let encrypted_range =
-                    encrypted_range.start..(encrypted_range.start + decrypted_size);
-                dst[encrypted_range.clone()].copy_from_slice(&decrypted_tmp[..decrypted_size]);
+                let encrypted_range = encrypted_range.start..decrypted_end;
+                dst[..decrypted_end].copy_from_slice(&decrypted_tmp[..decrypted_end]);
                 Self::log_crypto_data("Decrypted buffer", &dst[..encrypted_range.end]);
 
                 // Verify signature (after encrypted portion)
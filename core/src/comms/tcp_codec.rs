@@ -25,8 +25,8 @@ use opcua_types::{
 use crate::comms::{
     message_chunk::MessageChunk,
     tcp_types::{
-        AcknowledgeMessage, ErrorMessage, HelloMessage, MessageHeader, MessageType,
-        MESSAGE_HEADER_LEN,
+        encode_with_size, AcknowledgeMessage, CountingWriter, ErrorMessage, HelloMessage,
+        MessageHeader, MessageType, MESSAGE_HEADER_LEN,
     },
 };
 
@@ -38,6 +38,18 @@ pub enum Message {
     Chunk(MessageChunk),
 }
 
+impl Message {
+    /// Returns the type of the message
+    pub fn message_type(&self) -> MessageType {
+        match self {
+            Message::Hello(_) => MessageType::Hello,
+            Message::Acknowledge(_) => MessageType::Acknowledge,
+            Message::Error(_) => MessageType::Error,
+            Message::Chunk(_) => MessageType::Chunk,
+        }
+    }
+}
+
 /// Implements a tokio codec that as close as possible, allows incoming data to be transformed into
 /// OPC UA message chunks with no intermediate buffers. Chunks are subsequently transformed into
 /// messages so there is still some buffers within message chunks, but not at the raw socket level.
@@ -95,7 +107,7 @@ impl Encoder for TcpCodec {
         match data {
             Message::Hello(msg) => self.write(msg, buf),
             Message::Acknowledge(msg) => self.write(msg, buf),
-            Message::Error(msg) => self.write(msg, buf),
+            Message::Error(msg) => self.write_with_size(msg, buf),
             Message::Chunk(msg) => self.write(msg, buf),
         }
     }
@@ -116,13 +128,30 @@ impl TcpCodec {
     where
         T: BinaryEncoder<T> + std::fmt::Debug,
     {
-        buf.reserve(msg.byte_len());
-        msg.encode(&mut buf.writer()).map(|_| ()).map_err(|err| {
+        let byte_len = msg.byte_len();
+        buf.reserve(byte_len);
+        let mut stream = CountingWriter::new(buf.writer(), byte_len);
+        let result = msg.encode(&mut stream).and_then(|_| stream.finish());
+        result.map(|_| ()).map_err(|err| {
             error!("Error writing message {:?}, err = {}", msg, err);
             io::Error::new(io::ErrorKind::Other, format!("Error = {}", err))
         })
     }
 
+    // Writes the encodable thing, which starts with its own header, into the buffer with the
+    // message size taken from what was actually encoded.
+    fn write_with_size<T>(&self, msg: T, buf: &mut BytesMut) -> Result<(), io::Error>
+    where
+        T: BinaryEncoder<T> + std::fmt::Debug,
+    {
+        let bytes = encode_with_size(&msg).map_err(|err| {
+            error!("Error writing message {:?}, err = {}", msg, err);
+            io::Error::from(err)
+        })?;
+        buf.extend_from_slice(&bytes);
+        Ok(())
+    }
+
     fn is_abort(&self) -> bool {
         let abort = self.abort.read().unwrap();
         *abort
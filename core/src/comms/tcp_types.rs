@@ -4,21 +4,20 @@
 
 //! Contains the implementation of various UA over TCP types.
 
-use std::io::{Cursor, Error, ErrorKind, Read, Result, Write};
+use std::io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
 
 use opcua_types::{
     encoding::*, service_types::EndpointDescription, status_code::StatusCode, string::UAString,
 };
 
-use crate::comms::url::url_matches_except_host;
-
-pub const CHUNK_MESSAGE: &[u8] = b"MSG";
-pub const OPEN_SECURE_CHANNEL_MESSAGE: &[u8] = b"OPN";
-pub const CLOSE_SECURE_CHANNEL_MESSAGE: &[u8] = b"CLO";
-
-const HELLO_MESSAGE: &[u8] = b"HEL";
-const ACKNOWLEDGE_MESSAGE: &[u8] = b"ACK";
-const ERROR_MESSAGE: &[u8] = b"ERR";
+use crate::comms::{
+    comms_error::CommsError,
+    message_chunk::{MessageChunkType, MESSAGE_CHUNK_HEADER_SIZE},
+    security_header::{SequenceHeader, SymmetricSecurityHeader},
+    supported_protocol_versions,
+    url::url_matches_except_host,
+    PROTOCOL_VERSION,
+};
 
 pub const CHUNK_FINAL: u8 = b'F';
 pub const CHUNK_INTERMEDIATE: u8 = b'C';
@@ -33,7 +32,7 @@ pub const MIN_CHUNK_SIZE: usize = 8196;
 /// Size in bytes of an OPC UA message header
 pub const MESSAGE_HEADER_LEN: usize = 8;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MessageType {
     Invalid,
     Hello,
@@ -42,6 +41,123 @@ pub enum MessageType {
     Error,
 }
 
+/// The 3-letter tag at the start of every message, e.g. `HEL` or `MSG`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MessageTag(pub [u8; 3]);
+
+/// Every tag that is recognized, along with the message type it maps to and the chunk type
+/// if the tag belongs to a chunk. Where more than one tag maps to the same type, the first is
+/// the one that is written.
+const MESSAGE_TAGS: [(MessageTag, MessageType, Option<MessageChunkType>); 6] = [
+    (MessageTag(*b"HEL"), MessageType::Hello, None),
+    (MessageTag(*b"ACK"), MessageType::Acknowledge, None),
+    (MessageTag(*b"ERR"), MessageType::Error, None),
+    (
+        MessageTag(*b"MSG"),
+        MessageType::Chunk,
+        Some(MessageChunkType::Message),
+    ),
+    (
+        MessageTag(*b"OPN"),
+        MessageType::Chunk,
+        Some(MessageChunkType::OpenSecureChannel),
+    ),
+    (
+        MessageTag(*b"CLO"),
+        MessageType::Chunk,
+        Some(MessageChunkType::CloseSecureChannel),
+    ),
+];
+
+impl From<MessageType> for MessageTag {
+    /// Returns the tag that is written for the message type. A chunk is written as `MSG`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message type is `Invalid` because it has no tag.
+    fn from(message_type: MessageType) -> Self {
+        MESSAGE_TAGS
+            .iter()
+            .find(|(_, t, _)| *t == message_type)
+            .map(|(tag, _, _)| *tag)
+            .expect("Invalid message type has no tag")
+    }
+}
+
+impl From<MessageChunkType> for MessageTag {
+    fn from(chunk_type: MessageChunkType) -> Self {
+        MESSAGE_TAGS
+            .iter()
+            .find(|(_, _, t)| *t == Some(chunk_type))
+            .map(|(tag, _, _)| *tag)
+            // Every chunk type is in the table
+            .unwrap()
+    }
+}
+
+impl MessageTag {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the message type for the tag, or `MessageType::Invalid` if it is not recognized
+    pub fn as_message_type(&self) -> MessageType {
+        self.find()
+            .map(|(_, message_type, _)| message_type.clone())
+            .unwrap_or(MessageType::Invalid)
+    }
+
+    /// Returns the chunk type for the tag, or `None` if it is not the tag of a chunk
+    pub fn as_chunk_type(&self) -> Option<MessageChunkType> {
+        self.find().and_then(|(_, _, chunk_type)| *chunk_type)
+    }
+
+    fn find(&self) -> Option<&(MessageTag, MessageType, Option<MessageChunkType>)> {
+        MESSAGE_TAGS.iter().find(|(tag, _, _)| tag == self)
+    }
+}
+
+impl MessageType {
+    /// Parses the message type from the first 4 bytes of a message header. Returns
+    /// `BadTcpMessageTypeInvalid` if the bytes are not a well formed message type, i.e. there
+    /// are not 4 bytes or the chunk type byte is wrong for the type, or `BadNotSupported` if the
+    /// 3-letter tag is well formed but not a message type that is recognized.
+    pub fn from_bytes(t: &[u8]) -> std::result::Result<MessageType, StatusCode> {
+        if t.len() != 4 {
+            error!("Message type must be 4 bytes, got {}", t.len());
+            return Err(StatusCode::BadTcpMessageTypeInvalid);
+        }
+        let tag = MessageTag([t[0], t[1], t[2]]);
+        let message_type = match tag.as_message_type() {
+            MessageType::Invalid if tag.0.iter().all(|c| c.is_ascii_uppercase()) => {
+                error!("Message type {:?} is not recognized", tag);
+                return Err(StatusCode::BadNotSupported);
+            }
+            MessageType::Invalid => {
+                error!("Message type {:?} is not a valid tag", tag);
+                return Err(StatusCode::BadTcpMessageTypeInvalid);
+            }
+            message_type => message_type,
+        };
+
+        // Check the 4th byte which should be F for messages or F, C or A for chunks. If its
+        // not one of those, the message is invalid
+        match t[3] {
+            CHUNK_FINAL => Ok(message_type),
+            CHUNK_INTERMEDIATE | CHUNK_FINAL_ERROR if message_type == MessageType::Chunk => {
+                Ok(message_type)
+            }
+            chunk_type => {
+                error!(
+                    "Chunk type {} is not valid for message type {:?}",
+                    chunk_type, message_type
+                );
+                Err(StatusCode::BadTcpMessageTypeInvalid)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct MessageHeader {
     pub message_type: MessageType,
@@ -55,18 +171,16 @@ impl BinaryEncoder<MessageHeader> for MessageHeader {
 
     fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
         let mut size: usize = 0;
-        let result = match self.message_type {
-            MessageType::Hello => stream.write(HELLO_MESSAGE),
-            MessageType::Acknowledge => stream.write(ACKNOWLEDGE_MESSAGE),
-            MessageType::Error => stream.write(ERROR_MESSAGE),
+        let tag = match self.message_type {
             MessageType::Chunk => {
                 panic!("Don't write chunks to stream with this call, use Chunk and Chunker");
             }
-            _ => {
+            MessageType::Invalid => {
                 panic!("Unrecognized type");
             }
+            ref message_type => MessageTag::from(message_type.clone()),
         };
-        size += process_encode_io_result(result)?;
+        size += process_encode_io_result(stream.write(tag.as_bytes()))?;
         size += write_u8(stream, b'F')?;
         size += write_u32(stream, self.message_size)?;
         Ok(size)
@@ -91,86 +205,343 @@ impl MessageHeader {
         }
     }
 
-    /// Reads the bytes of the stream to a buffer. If first 4 bytes are invalid,
-    /// code returns an error
+    /// Reads the bytes of the stream to a buffer. If the header is invalid, exceeds the decoding
+    /// limits or the stream fails, the corresponding `CommsError` is returned.
     pub fn read_bytes<S: Read>(
         stream: &mut S,
         decoding_limits: &DecodingLimits,
-    ) -> Result<Vec<u8>> {
-        // Read the bytes of the stream into a vector
-        let mut header = [0u8; 4];
-        stream.read_exact(&mut header)?;
-        if MessageHeader::message_type(&header) == MessageType::Invalid {
-            return Err(Error::new(
-                ErrorKind::Other,
-                "Message type is not recognized, cannot read bytes",
-            ));
+    ) -> std::result::Result<Vec<u8>, CommsError> {
+        // Read the header and then the body through the frame reader
+        let mut frame = FrameReader::new(stream, decoding_limits).map_err(|err| {
+            error!("Cannot read message header, err = {}", err);
+            CommsError::from(err)
+        })?;
+        let message_size = frame.header().message_size as usize;
+
+        // Copy the header and read the remaining bytes into the vec. The vec grows as bytes
+        // arrive rather than being sized up front, so a header which claims a huge size cannot
+        // make it allocate more than the stream actually holds.
+        let mut result = frame.header_bytes().to_vec();
+        frame.read_to_end(&mut result)?;
+        if result.len() < message_size {
+            error!(
+                "Stream ended after {} bytes of a {} byte message",
+                result.len(),
+                message_size
+            );
+            Err(CommsError::Io(Error::from(ErrorKind::UnexpectedEof)))
+        } else {
+            Ok(result)
         }
-        let message_size = u32::decode(stream, decoding_limits);
-        if message_size.is_err() {
-            return Err(Error::new(ErrorKind::Other, "Cannot decode message_size"));
+    }
+
+    /// Reads a whole message frame from the stream and returns its header and the body bytes
+    /// that follow the header, without decoding the body. This allows a frame of any type to be
+    /// inspected. Errors are the same as for `read_bytes()`.
+    pub fn read_frame<S: Read>(
+        stream: &mut S,
+        decoding_limits: &DecodingLimits,
+    ) -> std::result::Result<(MessageHeader, Vec<u8>), CommsError> {
+        let mut frame = FrameReader::new(stream, decoding_limits).map_err(|err| {
+            error!("Cannot read message header, err = {}", err);
+            CommsError::from(err)
+        })?;
+        let body_size = frame.header().message_size as usize - MESSAGE_HEADER_LEN;
+
+        // As with read_bytes, the vec grows as bytes arrive rather than being sized up front
+        let mut body = Vec::new();
+        frame.read_to_end(&mut body)?;
+        if body.len() < body_size {
+            error!(
+                "Stream ended after {} bytes of a {} byte message body",
+                body.len(),
+                body_size
+            );
+            Err(CommsError::Io(Error::from(ErrorKind::UnexpectedEof)))
+        } else {
+            Ok((frame.header().clone(), body))
         }
-        let message_size = message_size.unwrap();
-
-        // Write header to stream
-        let mut out = Cursor::new(Vec::with_capacity(message_size as usize));
-        let result = out.write(&header);
-        if result.is_err() {
-            return Err(Error::new(
-                ErrorKind::Other,
-                "Cannot write message header to buffer ",
-            ));
+    }
+
+    pub fn message_type(t: &[u8]) -> MessageType {
+        MessageType::from_bytes(t).unwrap_or(MessageType::Invalid)
+    }
+}
+
+/// Encodes a message which starts with its own `MessageHeader`, e.g. `ErrorMessage`, into a
+/// buffer in a single pass. The `message_size` of the header is patched by a `SizePrefixedWriter`
+/// from the number of bytes actually written instead of being computed up front with
+/// `byte_len()`, so the two can never disagree.
+pub fn encode_with_size<M>(message: &M) -> EncodingResult<Vec<u8>>
+where
+    M: BinaryEncoder<M>,
+{
+    let mut stream = SizePrefixedWriter::for_message(Cursor::new(Vec::new()))?;
+    message.encode(&mut stream)?;
+    stream.finish().map(|stream| stream.into_inner())
+}
+
+/// Counts the bytes written through it so that a message whose size was declared up front,
+/// e.g. as the `message_size` of its header, can be checked against what was actually written.
+/// A mismatch means a `byte_len()` implementation disagrees with its `encode()` and the message
+/// would be corrupt on the wire.
+pub struct CountingWriter<W: Write> {
+    inner: W,
+    /// Bytes written so far
+    count: usize,
+    /// Bytes the message is declared to be
+    expected: usize,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> CountingWriter<W> {
+    /// Creates a writer for a message which is declared to be `expected` bytes long
+    pub fn new(inner: W, expected: usize) -> CountingWriter<W> {
+        CountingWriter {
+            inner,
+            count: 0,
+            expected,
         }
+    }
 
-        let result = message_size.encode(&mut out);
-        if result.is_err() {
-            return Err(Error::new(
-                ErrorKind::Other,
-                "Cannot write message size to buffer ",
-            ));
+    /// Returns the number of bytes written so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the stream if exactly the declared number of bytes were written, otherwise logs
+    /// the difference and returns `BadEncodingError`.
+    pub fn finish(self) -> EncodingResult<W> {
+        if self.count == self.expected {
+            Ok(self.inner)
+        } else {
+            error!(
+                "Encoded {} bytes but the message was declared to be {} bytes",
+                self.count, self.expected
+            );
+            Err(StatusCode::BadEncodingError)
         }
+    }
+}
 
-        let pos = out.position() as usize;
-        // Read remaining bytes straight into the vec
-        let mut result = out.into_inner();
-        result.resize(message_size as usize, 0u8);
-        stream.read_exact(&mut result[pos..])?;
+/// Writes a message straight to a seekable stream without first encoding it to a scratch buffer.
+/// The header is written up front with a placeholder size, the body is written through the
+/// `Write` implementation, e.g. by `BinaryEncoder::encode`, and `finish()` seeks back to patch
+/// the `message_size` once the size of the body is known. A message which encodes its own header
+/// can be written through `for_message()` and has its size patched the same way.
+pub struct SizePrefixedWriter<W: Write + Seek> {
+    inner: W,
+    /// Position of the start of the header in the stream
+    start: u64,
+    /// Bytes written so far including the header
+    size: usize,
+}
 
-        Ok(result)
+impl<W: Write + Seek> Write for SizePrefixedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.size += written;
+        Ok(written)
     }
 
-    pub fn message_type(t: &[u8]) -> MessageType {
-        if t.len() != 4 {
-            MessageType::Invalid
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write + Seek> SizePrefixedWriter<W> {
+    /// Writes a header of the message type with a placeholder size at the current position of
+    /// the stream.
+    pub fn new(inner: W, message_type: MessageType) -> EncodingResult<SizePrefixedWriter<W>> {
+        let mut writer = Self::for_message(inner)?;
+        MessageHeader::new(message_type).encode(&mut writer)?;
+        Ok(writer)
+    }
+
+    /// Starts a message at the current position of the stream for a message which writes its
+    /// own header, e.g. `ErrorMessage`. Whatever size the header is written with is patched.
+    pub fn for_message(mut inner: W) -> EncodingResult<SizePrefixedWriter<W>> {
+        let start = inner
+            .seek(SeekFrom::Current(0))
+            .map_err(|_| StatusCode::BadEncodingError)?;
+        Ok(SizePrefixedWriter {
+            inner,
+            start,
+            size: 0,
+        })
+    }
+
+    /// Returns the number of bytes written so far including the header
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Patches the message size into the header and returns the stream, positioned after the
+    /// end of the message.
+    pub fn finish(mut self) -> EncodingResult<W> {
+        if self.size < MESSAGE_HEADER_LEN {
+            error!(
+                "Message of {} bytes is too small to hold a header",
+                self.size
+            );
+            return Err(StatusCode::BadEncodingError);
+        } else if self.size > u32::MAX as usize {
+            error!("Message size {} is too large to encode", self.size);
+            return Err(StatusCode::BadEncodingLimitsExceeded);
+        }
+        // The size follows the 3 byte message type and the chunk type byte
+        let end = self.start + self.size as u64;
+        self.inner
+            .seek(SeekFrom::Start(self.start + 4))
+            .map_err(|_| StatusCode::BadEncodingError)?;
+        write_u32(&mut self.inner, self.size as u32)?;
+        self.inner
+            .seek(SeekFrom::Start(end))
+            .map_err(|_| StatusCode::BadEncodingError)?;
+        Ok(self.inner)
+    }
+}
+
+/// A reader which lets no more than a fixed number of bytes be read from the inner stream, e.g.
+/// the declared length of a message body. Once the cap is reached the reader reports the end of
+/// the stream, so a field which claims more bytes than remain fails to decode with
+/// `BadDecodingError` instead of reading into whatever follows.
+pub struct LimitedReader<S: Read> {
+    inner: S,
+    remaining: usize,
+}
+
+impl<S: Read> Read for LimitedReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if self.remaining == 0 {
+            return Ok(0);
+        }
+        let len = buf.len().min(self.remaining);
+        let read = self.inner.read(&mut buf[..len])?;
+        self.remaining -= read;
+        Ok(read)
+    }
+}
+
+impl<S: Read> LimitedReader<S> {
+    /// Creates a reader which reads at most `limit` bytes from the stream
+    pub fn new(inner: S, limit: usize) -> LimitedReader<S> {
+        LimitedReader {
+            inner,
+            remaining: limit,
+        }
+    }
+
+    /// Returns the number of bytes that may still be read
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Consumes the reader, returning the underlying stream. Bytes beyond those read are left
+    /// in the stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+/// Reads a message frame from a stream without buffering the whole message in memory. The
+/// message header is read and validated up front, after which the reader exposes the body as a
+/// `LimitedReader` capped at the remaining `message_size - 8` bytes, so callers can decode the
+/// body incrementally without reading into the next frame.
+pub struct FrameReader<S: Read> {
+    header: MessageHeader,
+    header_bytes: [u8; MESSAGE_HEADER_LEN],
+    body: LimitedReader<S>,
+}
+
+impl<S: Read> Read for FrameReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.body.read(buf)
+    }
+}
+
+impl<S: Read> FrameReader<S> {
+    /// Reads the message header from the stream. An error is returned if the message type is
+    /// not recognized, or if the size is smaller than a header or exceeds the max chunk size.
+    pub fn new(mut stream: S, decoding_limits: &DecodingLimits) -> EncodingResult<FrameReader<S>> {
+        let mut header_bytes = [0u8; MESSAGE_HEADER_LEN];
+        process_decode_io_result(stream.read_exact(&mut header_bytes))?;
+        // Check the message type first to give a precise error if it is wrong
+        let _ = MessageType::from_bytes(&header_bytes[0..4])?;
+        let header = MessageHeader::decode(&mut Cursor::new(&header_bytes[..]), decoding_limits)?;
+        let message_size = header.message_size as usize;
+        if message_size < MESSAGE_HEADER_LEN {
+            error!(
+                "Message size {} is smaller than a message header",
+                message_size
+            );
+            Err(StatusCode::BadDecodingError)
+        } else if decoding_limits.max_chunk_size > 0
+            && message_size > decoding_limits.max_chunk_size
+        {
+            error!(
+                "Message size {} exceeds max chunk size {}",
+                message_size, decoding_limits.max_chunk_size
+            );
+            Err(StatusCode::BadTcpMessageTooLarge)
         } else {
-            let message_type = match &t[0..3] {
-                HELLO_MESSAGE => MessageType::Hello,
-                ACKNOWLEDGE_MESSAGE => MessageType::Acknowledge,
-                ERROR_MESSAGE => MessageType::Error,
-                CHUNK_MESSAGE | OPEN_SECURE_CHANNEL_MESSAGE | CLOSE_SECURE_CHANNEL_MESSAGE => {
-                    MessageType::Chunk
-                }
-                _ => {
-                    error!("message type doesn't match anything");
-                    MessageType::Invalid
-                }
-            };
-
-            // Check the 4th byte which should be F for messages or F, C or A for chunks. If its
-            // not one of those, the message is invalid
-            match t[3] {
-                CHUNK_FINAL => message_type,
-                CHUNK_INTERMEDIATE | CHUNK_FINAL_ERROR => {
-                    if message_type == MessageType::Chunk {
-                        message_type
-                    } else {
-                        MessageType::Invalid
-                    }
-                }
-                _ => MessageType::Invalid,
-            }
+            let body = LimitedReader::new(stream, message_size - MESSAGE_HEADER_LEN);
+            Ok(FrameReader {
+                header,
+                header_bytes,
+                body,
+            })
         }
     }
+
+    /// Returns the message header of the frame
+    pub fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    /// Returns the raw bytes of the message header as they were read from the stream
+    pub fn header_bytes(&self) -> &[u8] {
+        &self.header_bytes
+    }
+
+    /// Returns the number of body bytes that have not been read yet
+    pub fn remaining(&self) -> usize {
+        self.body.remaining()
+    }
+
+    /// Consumes the frame reader, returning the underlying stream. Any unread body bytes are
+    /// left in the stream.
+    pub fn into_inner(self) -> S {
+        self.body.into_inner()
+    }
+}
+
+/// How a server treats a HEL message whose endpoint url is null or empty.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum EndpointUrlPolicy {
+    /// A null or empty endpoint url is rejected
+    Strict,
+    /// A null or empty endpoint url is accepted. This suits a server with a single endpoint,
+    /// where there is nothing to choose between by the url.
+    Permissive,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for EndpointUrlPolicy {
+    fn default() -> Self {
+        EndpointUrlPolicy::Strict
+    }
 }
 
 /// Implementation of the HEL message in OPC UA
@@ -235,7 +606,7 @@ impl HelloMessage {
     ) -> HelloMessage {
         let mut msg = HelloMessage {
             message_header: MessageHeader::new(MessageType::Hello),
-            protocol_version: 0,
+            protocol_version: PROTOCOL_VERSION,
             send_buffer_size: send_buffer_size as u32,
             receive_buffer_size: receive_buffer_size as u32,
             max_message_size: max_message_size as u32,
@@ -247,12 +618,52 @@ impl HelloMessage {
     }
 
     pub fn is_endpoint_url_valid(&self, endpoints: &[EndpointDescription]) -> bool {
-        if self.is_endpoint_valid_length() {
-            self.matches_endpoint(endpoints)
-        } else {
+        self.validate_endpoint_url(endpoints, EndpointUrlPolicy::Strict)
+            .is_ok()
+    }
+
+    /// Checks that the endpoint url is one of the endpoints, returning `BadTcpEndpointUrlInvalid`
+    /// if it is not or if it exceeds the maximum length. A null or empty endpoint url is rejected
+    /// unless the policy is permissive and there is an endpoint for the connection to go to.
+    pub fn validate_endpoint_url(
+        &self,
+        endpoints: &[EndpointDescription],
+        policy: EndpointUrlPolicy,
+    ) -> std::result::Result<(), StatusCode> {
+        let endpoint_urls = endpoints
+            .iter()
+            .map(|e| e.endpoint_url.as_ref())
+            .collect::<Vec<&str>>();
+        self.validate_endpoint_urls(&endpoint_urls, policy)
+    }
+
+    /// Checks the endpoint url against a list of endpoint urls in the same way as
+    /// `validate_endpoint_url`.
+    pub fn validate_endpoint_urls<T: AsRef<str>>(
+        &self,
+        endpoints: &[T],
+        policy: EndpointUrlPolicy,
+    ) -> std::result::Result<(), StatusCode> {
+        if self.endpoint_url.is_empty() {
+            if policy == EndpointUrlPolicy::Permissive && !endpoints.is_empty() {
+                debug!("Hello message contains no endpoint url, permitted by policy");
+                Ok(())
+            } else {
+                error!("Hello message contains no endpoint url");
+                Err(StatusCode::BadTcpEndpointUrlInvalid)
+            }
+        } else if !self.is_endpoint_valid_length() {
             // Length > 4096
             error!("Supplied endpoint url exceeds maximum length");
-            false
+            Err(StatusCode::BadTcpEndpointUrlInvalid)
+        } else if !self.matches_endpoint_url(endpoints) {
+            error!(
+                "Supplied endpoint url {} does not match any endpoint",
+                self.endpoint_url
+            );
+            Err(StatusCode::BadTcpEndpointUrlInvalid)
+        } else {
+            Ok(())
         }
     }
 
@@ -274,10 +685,71 @@ impl HelloMessage {
         })
     }
 
+    fn matches_endpoint_url<T: AsRef<str>>(&self, endpoint_urls: &[T]) -> bool {
+        endpoint_urls.iter().any(|endpoint_url| {
+            url_matches_except_host(endpoint_url.as_ref(), self.endpoint_url.as_ref())
+        })
+    }
+
     pub fn is_valid_buffer_sizes(&self) -> bool {
-        // Set in part 6 as minimum transport buffer size
-        self.receive_buffer_size >= MIN_CHUNK_SIZE as u32
-            && self.send_buffer_size >= MIN_CHUNK_SIZE as u32
+        self.validate_buffer_sizes().is_ok()
+    }
+
+    /// Checks that the send and receive buffers are at least the minimum transport buffer size
+    /// set in part 6, returning `BadTcpNotEnoughResources` if either is smaller.
+    pub fn validate_buffer_sizes(&self) -> std::result::Result<(), StatusCode> {
+        if self.receive_buffer_size < MIN_CHUNK_SIZE as u32
+            || self.send_buffer_size < MIN_CHUNK_SIZE as u32
+        {
+            error!(
+                "HELLO buffer sizes {} / {} are smaller than the minimum of {}",
+                self.receive_buffer_size, self.send_buffer_size, MIN_CHUNK_SIZE
+            );
+            Err(StatusCode::BadTcpNotEnoughResources)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the protocol version is one this implementation supports, returning
+    /// `BadProtocolVersionUnsupported` if it is not.
+    pub fn validate_protocol_version(&self) -> std::result::Result<(), StatusCode> {
+        if supported_protocol_versions().contains(&self.protocol_version) {
+            Ok(())
+        } else {
+            error!(
+                "HELLO protocol version {} is not supported",
+                self.protocol_version
+            );
+            Err(StatusCode::BadProtocolVersionUnsupported)
+        }
+    }
+
+    /// Checks that the message limits the client asked for can actually be satisfied. A max
+    /// message size of 0 means unlimited, otherwise it must be at least one receive buffer or
+    /// no chunk could ever be sent, which fails with `BadTcpMessageTooLarge`. A max chunk count
+    /// of 0 means unlimited, otherwise that many receive buffers must be able to hold a message
+    /// of the max message size, which fails with `BadInvalidArgument`.
+    pub fn validate_limits(&self) -> std::result::Result<(), StatusCode> {
+        if self.max_message_size != 0 && self.max_message_size < self.receive_buffer_size {
+            error!(
+                "HELLO max message size {} is smaller than the receive buffer size {}",
+                self.max_message_size, self.receive_buffer_size
+            );
+            Err(StatusCode::BadTcpMessageTooLarge)
+        } else if self.max_chunk_count != 0
+            && self.max_message_size != 0
+            && (self.max_chunk_count as u64) * (self.receive_buffer_size as u64)
+                < self.max_message_size as u64
+        {
+            error!(
+                "HELLO max chunk count {} of {} bytes cannot hold a max message size of {}",
+                self.max_chunk_count, self.receive_buffer_size, self.max_message_size
+            );
+            Err(StatusCode::BadInvalidArgument)
+        } else {
+            Ok(())
+        }
     }
 }
 
@@ -326,6 +798,64 @@ impl BinaryEncoder<AcknowledgeMessage> for AcknowledgeMessage {
     }
 }
 
+impl AcknowledgeMessage {
+    /// Creates an ACK message with the crate's protocol version
+    pub fn new(
+        receive_buffer_size: u32,
+        send_buffer_size: u32,
+        max_message_size: u32,
+        max_chunk_count: u32,
+    ) -> AcknowledgeMessage {
+        let mut msg = AcknowledgeMessage {
+            message_header: MessageHeader::new(MessageType::Acknowledge),
+            protocol_version: PROTOCOL_VERSION,
+            receive_buffer_size,
+            send_buffer_size,
+            max_message_size,
+            max_chunk_count,
+        };
+        msg.message_header.message_size = msg.byte_len() as u32;
+        msg
+    }
+}
+
+/// The limits negotiated for a connection by the HEL / ACK exchange.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransportLimits {
+    pub receive_buffer_size: usize,
+    pub send_buffer_size: usize,
+    pub max_message_size: usize,
+    pub max_chunk_count: usize,
+}
+
+impl<'a> From<&'a AcknowledgeMessage> for TransportLimits {
+    fn from(acknowledge: &'a AcknowledgeMessage) -> Self {
+        TransportLimits {
+            receive_buffer_size: acknowledge.receive_buffer_size as usize,
+            send_buffer_size: acknowledge.send_buffer_size as usize,
+            max_message_size: acknowledge.max_message_size as usize,
+            max_chunk_count: acknowledge.max_chunk_count as usize,
+        }
+    }
+}
+
+impl TransportLimits {
+    /// Returns the number of body bytes that fit into a MSG chunk of the send buffer size, i.e.
+    /// the buffer size less the message header, secure channel id, token id and sequence header.
+    /// Signing and encryption add a signature and padding on top of this, for which see
+    /// `MessageChunk::body_size_from_message_size`.
+    pub fn max_body_per_chunk(&self) -> usize {
+        let security_header = SymmetricSecurityHeader { token_id: 0 };
+        let sequence_header = SequenceHeader {
+            sequence_number: 0,
+            request_id: 0,
+        };
+        let overhead =
+            MESSAGE_CHUNK_HEADER_SIZE + security_header.byte_len() + sequence_header.byte_len();
+        self.send_buffer_size.saturating_sub(overhead)
+    }
+}
+
 /// Implementation of the ERR message in OPC UA
 #[derive(Debug, Clone, PartialEq)]
 pub struct ErrorMessage {
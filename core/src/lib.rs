@@ -150,12 +150,14 @@ pub mod completion_pact;
 pub mod config;
 pub mod handle;
 pub mod runtime;
+pub mod sessionless;
 pub mod supported_message;
 
 /// Contains most of the things that are typically required from a client / server.
 pub mod prelude {
     pub use crate::comms::prelude::*;
     pub use crate::config::Config;
+    pub use crate::sessionless::*;
     pub use crate::supported_message::*;
     pub use opcua_types::status_code::StatusCode;
     pub use opcua_types::*;
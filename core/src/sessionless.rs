@@ -0,0 +1,179 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the implementation of a sessionless service invocation, i.e. a service request that
+//! is preceded by a `SessionlessInvokeRequestType` header instead of being associated with a session.
+
+use std::{
+    convert::TryFrom,
+    io::{Read, Write},
+};
+
+use opcua_types::{
+    encoding::*, node_id::Identifier, node_ids::ObjectId, status_code::StatusCode,
+    string::UAString, SessionlessInvokeRequestType,
+};
+
+use crate::supported_message::SupportedMessage;
+
+/// How a sessionless invoke whose `service_id` is not a service known to this stack is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum UnknownServicePolicy {
+    /// The invoke is rejected with `BadServiceUnsupported`
+    Reject,
+    /// The invoke is passed through with its request left encoded, e.g. for a proxy which
+    /// forwards it to a server that does know the service
+    PassThrough,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for UnknownServicePolicy {
+    fn default() -> Self {
+        UnknownServicePolicy::Reject
+    }
+}
+
+/// A decoded sessionless invoke, either a request for a known service or an unknown one that
+/// was passed through.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionlessInvoke {
+    /// A request for a service which is known and can be routed
+    Request(SessionlessInvokeRequest),
+    /// A request for an unknown service. The body is the encoded request that followed the header.
+    PassThrough {
+        header: SessionlessInvokeRequestType,
+        body: Vec<u8>,
+    },
+}
+
+/// A sessionless service request. The header identifies the wrapped service through its
+/// `service_id` and supplies the namespace and server uri tables which the indexes in the request
+/// are relative to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionlessInvokeRequest {
+    /// The sessionless invoke header
+    pub header: SessionlessInvokeRequestType,
+    /// The wrapped service request
+    pub request: SupportedMessage,
+}
+
+impl BinaryEncoder<SessionlessInvokeRequest> for SessionlessInvokeRequest {
+    fn byte_len(&self) -> usize {
+        self.header.byte_len() + self.request.byte_len()
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.header.encode(stream)?;
+        size += self.request.encode(stream)?;
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        match Self::decode_with_policy(stream, decoding_limits, UnknownServicePolicy::Reject)? {
+            SessionlessInvoke::Request(request) => Ok(request),
+            SessionlessInvoke::PassThrough { .. } => unreachable!(),
+        }
+    }
+}
+
+impl SessionlessInvokeRequest {
+    /// Creates a sessionless invoke request for the supplied service request, filling in the
+    /// service id from the request's binary encoding id.
+    pub fn new<T>(
+        request: T,
+        namespace_uris: Option<Vec<UAString>>,
+        server_uris: Option<Vec<UAString>>,
+    ) -> SessionlessInvokeRequest
+    where
+        T: Into<SupportedMessage>,
+    {
+        let request = request.into();
+        let service_id = match request.node_id().identifier {
+            Identifier::Numeric(id) => id,
+            _ => panic!("Service request must have a numeric encoding id"),
+        };
+        SessionlessInvokeRequest {
+            header: SessionlessInvokeRequestType {
+                uris_version: None,
+                namespace_uris,
+                server_uris,
+                locale_ids: None,
+                service_id,
+            },
+            request,
+        }
+    }
+
+    /// Decodes a sessionless invoke, handling a service id which is not a known service
+    /// according to the policy. A service id which is known but is not a request, e.g. a
+    /// response, is always rejected with `BadServiceUnsupported`.
+    pub fn decode_with_policy<S: Read>(
+        stream: &mut S,
+        decoding_limits: &DecodingLimits,
+        policy: UnknownServicePolicy,
+    ) -> EncodingResult<SessionlessInvoke> {
+        let header = SessionlessInvokeRequestType::decode(stream, decoding_limits)?;
+        let request = match ObjectId::try_from(header.service_id) {
+            Ok(object_id) => {
+                match SupportedMessage::decode_by_object_id(stream, object_id, decoding_limits)? {
+                    SupportedMessage::Invalid(_) => None,
+                    request => Some(request),
+                }
+            }
+            Err(_) => None,
+        };
+        match request {
+            None if policy == UnknownServicePolicy::PassThrough => {
+                debug!(
+                    "Sessionless invoke service id {} is not recognized and is passed through",
+                    header.service_id
+                );
+                let mut body = Vec::new();
+                process_decode_io_result(stream.read_to_end(&mut body))?;
+                Ok(SessionlessInvoke::PassThrough { header, body })
+            }
+            None => {
+                error!(
+                    "Sessionless invoke service id {} is not a supported service",
+                    header.service_id
+                );
+                Err(StatusCode::BadServiceUnsupported)
+            }
+            Some(request) if !request.is_request() => {
+                error!(
+                    "Sessionless invoke service id {} is not a request",
+                    header.service_id
+                );
+                Err(StatusCode::BadServiceUnsupported)
+            }
+            Some(request) => Ok(SessionlessInvoke::Request(SessionlessInvokeRequest {
+                header,
+                request,
+            })),
+        }
+    }
+
+    /// Returns the namespace uri table which namespace indexes in the request are relative to
+    pub fn namespace_uris(&self) -> &[UAString] {
+        self.header.namespace_uris.as_deref().unwrap_or(&[])
+    }
+
+    /// Returns the server uri table which server indexes in the request are relative to
+    pub fn server_uris(&self) -> &[UAString] {
+        self.header.server_uris.as_deref().unwrap_or(&[])
+    }
+
+    /// Maps the service id of a sessionless invoke header onto the object id of the request's
+    /// binary encoding, returning `BadServiceUnsupported` if the id is not recognized.
+    pub fn service_object_id(service_id: u32) -> EncodingResult<ObjectId> {
+        ObjectId::try_from(service_id).map_err(|_| {
+            error!(
+                "Sessionless invoke service id {} is not recognized",
+                service_id
+            );
+            StatusCode::BadServiceUnsupported
+        })
+    }
+}
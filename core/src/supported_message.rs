@@ -520,6 +520,52 @@ impl SupportedMessage {
     }
 }
 
+/// Returns the binary encoding id of the response which answers a request with the supplied
+/// binary encoding id, or `None` if the id is not a supported request.
+pub fn response_type_for(request_object_id: ObjectId) -> Option<ObjectId> {
+    match request_object_id {
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => Some(ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => Some(ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => Some(ObjectId::GetEndpointsResponse_Encoding_DefaultBinary),
+        ObjectId::FindServersRequest_Encoding_DefaultBinary => Some(ObjectId::FindServersResponse_Encoding_DefaultBinary),
+        ObjectId::RegisterServerRequest_Encoding_DefaultBinary => Some(ObjectId::RegisterServerResponse_Encoding_DefaultBinary),
+        ObjectId::RegisterServer2Request_Encoding_DefaultBinary => Some(ObjectId::RegisterServer2Response_Encoding_DefaultBinary),
+        ObjectId::CreateSessionRequest_Encoding_DefaultBinary => Some(ObjectId::CreateSessionResponse_Encoding_DefaultBinary),
+        ObjectId::CloseSessionRequest_Encoding_DefaultBinary => Some(ObjectId::CloseSessionResponse_Encoding_DefaultBinary),
+        ObjectId::CancelRequest_Encoding_DefaultBinary => Some(ObjectId::CancelResponse_Encoding_DefaultBinary),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => Some(ObjectId::ActivateSessionResponse_Encoding_DefaultBinary),
+        ObjectId::AddNodesRequest_Encoding_DefaultBinary => Some(ObjectId::AddNodesResponse_Encoding_DefaultBinary),
+        ObjectId::AddReferencesRequest_Encoding_DefaultBinary => Some(ObjectId::AddReferencesResponse_Encoding_DefaultBinary),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => Some(ObjectId::DeleteNodesResponse_Encoding_DefaultBinary),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => Some(ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => Some(ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => Some(ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => Some(ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => Some(ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => Some(ObjectId::SetTriggeringResponse_Encoding_DefaultBinary),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => Some(ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => Some(ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => Some(ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => Some(ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => Some(ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary),
+        ObjectId::QueryFirstRequest_Encoding_DefaultBinary => Some(ObjectId::QueryFirstResponse_Encoding_DefaultBinary),
+        ObjectId::QueryNextRequest_Encoding_DefaultBinary => Some(ObjectId::QueryNextResponse_Encoding_DefaultBinary),
+        ObjectId::BrowseRequest_Encoding_DefaultBinary => Some(ObjectId::BrowseResponse_Encoding_DefaultBinary),
+        ObjectId::BrowseNextRequest_Encoding_DefaultBinary => Some(ObjectId::BrowseNextResponse_Encoding_DefaultBinary),
+        ObjectId::PublishRequest_Encoding_DefaultBinary => Some(ObjectId::PublishResponse_Encoding_DefaultBinary),
+        ObjectId::RepublishRequest_Encoding_DefaultBinary => Some(ObjectId::RepublishResponse_Encoding_DefaultBinary),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => Some(ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => Some(ObjectId::RegisterNodesResponse_Encoding_DefaultBinary),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => Some(ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary),
+        ObjectId::ReadRequest_Encoding_DefaultBinary => Some(ObjectId::ReadResponse_Encoding_DefaultBinary),
+        ObjectId::HistoryReadRequest_Encoding_DefaultBinary => Some(ObjectId::HistoryReadResponse_Encoding_DefaultBinary),
+        ObjectId::WriteRequest_Encoding_DefaultBinary => Some(ObjectId::WriteResponse_Encoding_DefaultBinary),
+        ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary => Some(ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary),
+        ObjectId::CallRequest_Encoding_DefaultBinary => Some(ObjectId::CallResponse_Encoding_DefaultBinary),
+        _ => None,
+    }
+}
+
 // These are all the messages handled into and out of streams by the OPCUA server / client code
 supported_messages_enum![
     ServiceFault,
@@ -6,7 +6,14 @@ use opcua_crypto::{x509::X509, SecurityPolicy};
 use opcua_types::DecodingLimits;
 
 use crate::{
-    comms::{chunker::*, message_chunk::*, secure_channel::*, tcp_types::MIN_CHUNK_SIZE},
+    comms::{
+        chunker::*,
+        message_chunk::*,
+        pending_chunks::*,
+        secure_channel::*,
+        security_header::SequenceHeader,
+        tcp_types::{AcknowledgeMessage, TransportLimits, MIN_CHUNK_SIZE},
+    },
     supported_message::SupportedMessage,
     tests::*,
 };
@@ -101,6 +108,7 @@ fn chunk_multi_encode_decode() {
         max_string_length: 65535,
         max_byte_string_length: 65535,
         max_array_length: 20000, // Need to bump this up because large response uses a large array
+        ..Default::default()
     });
 
     let response = make_large_read_response();
@@ -128,6 +136,50 @@ fn chunk_multi_encode_decode() {
     assert_eq!(response, new_response);
 }
 
+/// Each chunk carries a sequence header between the security header and the body, giving its own
+/// sequence number and the request id shared by every chunk of the message.
+#[test]
+fn chunk_sequence_header() {
+    let _ = Test::setup();
+
+    let mut secure_channel = SecureChannel::new_no_certificate_store();
+    secure_channel.set_(DecodingLimits {
+        max_chunk_size: 0,
+        max_string_length: 65535,
+        max_byte_string_length: 65535,
+        max_array_length: 20000,
+        ..Default::default()
+    });
+
+    let response = make_large_read_response();
+    let chunks = Chunker::encode(1000, 100, 0, MIN_CHUNK_SIZE, &secure_channel, &response).unwrap();
+    assert!(chunks.len() > 1);
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let chunk_info = chunk.chunk_info(&secure_channel).unwrap();
+        let sequence_number = 1000 + i as u32;
+        assert_eq!(
+            chunk_info.sequence_header,
+            SequenceHeader {
+                sequence_number,
+                request_id: 100,
+            }
+        );
+
+        // Sequence number then request id, both little endian, directly before the body
+        let offset = chunk_info.sequence_header_offset;
+        assert_eq!(chunk_info.body_offset, offset + 8);
+        let mut expected = sequence_number.to_le_bytes().to_vec();
+        expected.extend_from_slice(&100u32.to_le_bytes());
+        assert_eq!(&chunk.data[offset..offset + 8], &expected[..]);
+
+        let mut stream = Cursor::new(&chunk.data[offset..]);
+        let sequence_header =
+            SequenceHeader::decode(&mut stream, &DecodingLimits::default()).unwrap();
+        assert_eq!(sequence_header, chunk_info.sequence_header);
+    }
+}
+
 /// Encode a large message with multiple chunks. Ensure all but the last chunk is marked intermediate
 /// and the last is marked final.
 #[test]
@@ -164,6 +216,82 @@ fn chunk_multi_chunk_intermediate_final() {
     }
 }
 
+/// The body of a MSG chunk is the send buffer less 12 bytes of message header and secure channel
+/// id, 4 bytes of token id and 8 bytes of sequence header.
+#[test]
+fn transport_limits_max_body_per_chunk() {
+    let _ = Test::setup();
+
+    let acknowledge = AcknowledgeMessage::new(8192, 8192, 0, 0);
+    let transport_limits = TransportLimits::from(&acknowledge);
+    assert_eq!(transport_limits.max_body_per_chunk(), 8192 - 24);
+
+    // Agrees with the chunker when there is no signature or padding
+    let transport_limits = TransportLimits {
+        send_buffer_size: MIN_CHUNK_SIZE,
+        ..transport_limits
+    };
+    let secure_channel = SecureChannel::new_no_certificate_store();
+    let body_size = MessageChunk::body_size_from_message_size(
+        MessageChunkType::Message,
+        &secure_channel,
+        MIN_CHUNK_SIZE,
+    )
+    .unwrap();
+    assert_eq!(transport_limits.max_body_per_chunk(), body_size);
+
+    // A buffer too small for the headers has no room for a body
+    let transport_limits = TransportLimits {
+        send_buffer_size: 16,
+        ..transport_limits
+    };
+    assert_eq!(transport_limits.max_body_per_chunk(), 0);
+}
+
+/// Feed the chunks of a multi chunk message into a buffer that allows one fewer chunk than the
+/// message has and expect the message to be abandoned.
+#[test]
+fn pending_chunks_max_chunk_count() {
+    let _ = Test::setup();
+
+    let secure_channel = SecureChannel::new_no_certificate_store();
+    let response = make_large_read_response();
+    let make_chunks =
+        || Chunker::encode(1, 1, 0, MIN_CHUNK_SIZE, &secure_channel, &response).unwrap();
+    let chunk_count = make_chunks().len();
+    assert!(chunk_count > 2);
+
+    // Exactly the max chunk count is fine
+    let mut pending_chunks = PendingChunks::new(chunk_count);
+    for chunk in make_chunks() {
+        pending_chunks.push(chunk).unwrap();
+    }
+    assert_eq!(pending_chunks.take().len(), chunk_count);
+    assert!(pending_chunks.is_empty());
+
+    // One more than the max chunk count is rejected and the buffered chunks are freed
+    let max_chunk_count = chunk_count - 1;
+    let mut pending_chunks = PendingChunks::new(max_chunk_count);
+    let mut chunks = make_chunks();
+    let last_chunk = chunks.pop().unwrap();
+    for chunk in chunks {
+        pending_chunks.push(chunk).unwrap();
+    }
+    assert_eq!(pending_chunks.len(), max_chunk_count);
+    assert_eq!(
+        pending_chunks.push(last_chunk),
+        Err(StatusCode::BadTcpMessageTooLarge)
+    );
+    assert!(pending_chunks.is_empty());
+
+    // No limit
+    let mut pending_chunks = PendingChunks::new(0);
+    for chunk in make_chunks() {
+        pending_chunks.push(chunk).unwrap();
+    }
+    assert_eq!(pending_chunks.len(), chunk_count);
+}
+
 /// Encode a very large message that matches and exceeds a max message size and expect the appropriate response
 #[test]
 fn max_message_size() {
@@ -283,6 +411,82 @@ fn validate_chunks_sequence_number() {
     );
 }
 
+/// Feed messages whose sequence numbers go backwards or wrap around early and ensure they are
+/// rejected, while a legitimate wrap around is accepted
+#[test]
+fn validate_chunks_out_of_order_messages() {
+    let _ = Test::setup();
+
+    let secure_channel = SecureChannel::new_no_certificate_store();
+    let response = make_large_read_response();
+    let encode = |sequence_number| {
+        Chunker::encode(
+            sequence_number,
+            100,
+            0,
+            MIN_CHUNK_SIZE,
+            &secure_channel,
+            &response,
+        )
+        .unwrap()
+    };
+
+    // Two messages in order
+    let first = encode(1000);
+    let last = Chunker::validate_chunks(1000, &secure_channel, &first).unwrap();
+    let second = encode(last + 1);
+    let last = Chunker::validate_chunks(last + 1, &secure_channel, &second).unwrap();
+
+    // Replaying the first message afterwards is rejected
+    assert_eq!(
+        Chunker::validate_chunks(last + 1, &secure_channel, &first).unwrap_err(),
+        StatusCode::BadSequenceNumberInvalid
+    );
+
+    // Sequence numbers may wrap around to a low value once past the wrap around point...
+    let wrapped = encode(1);
+    assert!(
+        Chunker::validate_chunks(SEQUENCE_NUMBER_WRAP_AROUND + 1, &secure_channel, &wrapped)
+            .is_ok()
+    );
+    // ...but not before it
+    assert_eq!(
+        Chunker::validate_chunks(SEQUENCE_NUMBER_WRAP_AROUND, &secure_channel, &wrapped)
+            .unwrap_err(),
+        StatusCode::BadSequenceNumberInvalid
+    );
+
+    // A message which straddles the wrap around returns the sequence number after it
+    let straddling = encode(u32::MAX - 1);
+    let last = Chunker::validate_chunks(u32::MAX - 1, &secure_channel, &straddling).unwrap();
+    assert_eq!(last, (straddling.len() as u32).wrapping_sub(3));
+}
+
+/// A chunk whose size differs from the size in its header is rejected
+#[test]
+fn validate_chunks_inconsistent_size() {
+    let _ = Test::setup();
+
+    let secure_channel = SecureChannel::new_no_certificate_store();
+    let response = make_large_read_response();
+    let mut chunks =
+        Chunker::encode(1000, 100, 0, MIN_CHUNK_SIZE, &secure_channel, &response).unwrap();
+    chunks[1].data.push(0);
+    assert_eq!(
+        Chunker::validate_chunks(1000, &secure_channel, &chunks).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+
+    // The same check applies when security is removed from a chunk
+    let mut secure_channel = secure_channel;
+    assert_eq!(
+        secure_channel
+            .verify_and_remove_security(&chunks[1].data)
+            .unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+}
+
 /// Encode a large message and ensure verification throws error for request id mismatches
 #[test]
 fn validate_chunks_request_id() {
@@ -1,9 +1,34 @@
-use std::io::*;
+use std::{
+    io::*,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use bytes::BytesMut;
+use futures::{
+    executor::{self, Notify, Spawn},
+    Async, Future, Poll,
+};
+use tokio_io::codec::{Decoder, Encoder};
 
 use opcua_crypto::SecurityPolicy;
-use opcua_types::*;
+use opcua_types::{status_code::StatusCode, *};
 
-use crate::comms::{secure_channel::*, tcp_types::*};
+use crate::{
+    comms::{
+        async_transport::*,
+        comms_error::*,
+        handshake::*,
+        in_flight_requests::*,
+        message_chunk::MessageChunkType,
+        pending_requests::*,
+        secure_channel::*,
+        security_header::*,
+        tcp_codec::{Message, TcpCodec},
+        tcp_types::*,
+    },
+    tests::{make_sample_message, serialize_test, DuplexStream},
+};
 
 fn hello_data() -> Vec<u8> {
     vec![
@@ -83,3 +108,859 @@ pub fn secure_channel_nonce() {
         .set_remote_nonce_from_byte_string(&ByteString::from(b"01234567890123456789012345678901"))
         .is_ok());
 }
+
+#[test]
+pub fn frame_reader() {
+    // Hello followed by the start of another message
+    let mut data = hello_data();
+    data.extend(ack_data());
+
+    let decoding_limits = DecodingLimits::default();
+    let mut stream = Cursor::new(data);
+    {
+        let mut frame = FrameReader::new(&mut stream, &decoding_limits).unwrap();
+        assert_eq!(frame.header().message_type, MessageType::Hello);
+        assert_eq!(frame.header().message_size, 57);
+        assert_eq!(frame.header_bytes(), &hello_data()[0..8]);
+        assert_eq!(frame.remaining(), 57 - 8);
+
+        // Stream the body in small pieces, the reader must stop at the end of the frame
+        let mut body = Vec::new();
+        let mut buf = [0u8; 10];
+        loop {
+            let n = frame.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            body.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(body.as_slice(), &hello_data()[8..]);
+        assert_eq!(frame.remaining(), 0);
+    }
+
+    // The next frame should be intact
+    assert_eq!(stream.position(), 57);
+    let ack = AcknowledgeMessage::decode(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(ack.message_header.message_type, MessageType::Acknowledge);
+}
+
+#[test]
+pub fn frame_reader_invalid() {
+    let decoding_limits = DecodingLimits::default();
+
+    // Malformed message type
+    let mut data = hello_data();
+    data[0] = 0;
+    assert_eq!(
+        FrameReader::new(Cursor::new(data), &decoding_limits).err(),
+        Some(StatusCode::BadTcpMessageTypeInvalid)
+    );
+
+    // Unrecognized message type
+    let mut data = hello_data();
+    data[0] = b'X';
+    assert_eq!(
+        FrameReader::new(Cursor::new(data), &decoding_limits).err(),
+        Some(StatusCode::BadNotSupported)
+    );
+
+    // Message size smaller than the header
+    let mut data = hello_data();
+    data[4] = 4;
+    assert_eq!(
+        FrameReader::new(Cursor::new(data), &decoding_limits).err(),
+        Some(StatusCode::BadDecodingError)
+    );
+
+    // Message size larger than the max chunk size
+    let decoding_limits = DecodingLimits {
+        max_chunk_size: 32,
+        ..Default::default()
+    };
+    assert_eq!(
+        FrameReader::new(Cursor::new(hello_data()), &decoding_limits).err(),
+        Some(StatusCode::BadTcpMessageTooLarge)
+    );
+}
+
+#[test]
+pub fn frame_reader_field_exceeds_frame() {
+    // A hello whose endpoint url claims more bytes than the frame holds, followed by an ack
+    let mut data = hello_data();
+    let url_len_offset = 8 + 20;
+    data[url_len_offset..url_len_offset + 4].copy_from_slice(&200i32.to_le_bytes());
+    data.extend(ack_data());
+
+    let decoding_limits = DecodingLimits::default();
+    let mut stream = Cursor::new(data);
+    {
+        let mut frame = FrameReader::new(&mut stream, &decoding_limits).unwrap();
+        let header_bytes = frame.header_bytes().to_vec();
+        let result = HelloMessage::decode(
+            &mut Cursor::new(header_bytes).chain(&mut frame),
+            &decoding_limits,
+        );
+        assert_eq!(result.unwrap_err(), StatusCode::BadDecodingError);
+        assert_eq!(frame.remaining(), 0);
+    }
+
+    // The url did not bleed into the next frame
+    assert_eq!(stream.position(), 57);
+    let ack = AcknowledgeMessage::decode(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(ack.message_header.message_type, MessageType::Acknowledge);
+
+    // The same applies to a limited reader over any stream
+    let bytes = UAString::from("Hello world").encode_to_vec();
+    let mut reader = LimitedReader::new(Cursor::new(&bytes), bytes.len() - 1);
+    assert_eq!(
+        UAString::decode(&mut reader, &decoding_limits).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(reader.into_inner().position() as usize, bytes.len() - 1);
+}
+
+#[test]
+pub fn read_bytes() {
+    let decoding_limits = DecodingLimits::default();
+    let mut data = hello_data();
+    data.extend(ack_data());
+    let mut stream = Cursor::new(data);
+    let bytes = MessageHeader::read_bytes(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(bytes, hello_data());
+    let bytes = MessageHeader::read_bytes(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(bytes, ack_data());
+}
+
+#[test]
+pub fn read_frame() {
+    let decoding_limits = DecodingLimits::default();
+    let mut data = hello_data();
+    data.extend(ack_data());
+    let mut stream = Cursor::new(data);
+
+    // The header and body of the hello are split, leaving the stream at the acknowledge
+    let (header, body) = MessageHeader::read_frame(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(header.message_type, MessageType::Hello);
+    assert_eq!(header.message_size as usize, hello_data().len());
+    assert_eq!(body, &hello_data()[MESSAGE_HEADER_LEN..]);
+
+    let (header, body) = MessageHeader::read_frame(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(header.message_type, MessageType::Acknowledge);
+    assert_eq!(body, &ack_data()[MESSAGE_HEADER_LEN..]);
+
+    // The same errors as read_bytes
+    let mut data = hello_data();
+    data.truncate(20);
+    let err = MessageHeader::read_frame(&mut Cursor::new(data), &decoding_limits).unwrap_err();
+    assert_eq!(StatusCode::from(err), StatusCode::BadConnectionClosed);
+    let mut data = hello_data();
+    data[0..3].copy_from_slice(b"XYZ");
+    let err = MessageHeader::read_frame(&mut Cursor::new(data), &decoding_limits).unwrap_err();
+    assert_eq!(StatusCode::from(err), StatusCode::BadTcpMessageTypeInvalid);
+}
+
+#[test]
+pub fn read_bytes_errors() {
+    let decoding_limits = DecodingLimits::default();
+
+    // Unrecognized message type
+    let mut data = hello_data();
+    data[0..3].copy_from_slice(b"XYZ");
+    let err = MessageHeader::read_bytes(&mut Cursor::new(data), &decoding_limits).unwrap_err();
+    assert!(matches!(err, CommsError::UnexpectedType));
+    assert_eq!(StatusCode::from(err), StatusCode::BadTcpMessageTypeInvalid);
+
+    // Message larger than the max chunk size
+    let small_limits = DecodingLimits {
+        max_chunk_size: 32,
+        ..Default::default()
+    };
+    let err = MessageHeader::read_bytes(&mut Cursor::new(hello_data()), &small_limits).unwrap_err();
+    assert!(matches!(err, CommsError::MessageTooLarge));
+    assert_eq!(StatusCode::from(err), StatusCode::BadTcpMessageTooLarge);
+
+    // Header is cut short
+    let data = hello_data()[0..4].to_vec();
+    let err = MessageHeader::read_bytes(&mut Cursor::new(data), &decoding_limits).unwrap_err();
+    assert!(matches!(err, CommsError::DecodeFailed));
+    assert_eq!(StatusCode::from(err), StatusCode::BadDecodingError);
+
+    // Body is cut short
+    let mut data = hello_data();
+    data.truncate(20);
+    let err = MessageHeader::read_bytes(&mut Cursor::new(data), &decoding_limits).unwrap_err();
+    match err {
+        CommsError::Io(ref err) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        ref err => panic!("Expected an io error, got {:?}", err),
+    }
+    assert_eq!(StatusCode::from(err), StatusCode::BadConnectionClosed);
+
+    // A header claiming an enormous size is read only as far as the stream goes
+    let mut data = hello_data();
+    data[4..8].copy_from_slice(&u32::max_value().to_le_bytes());
+    let err = MessageHeader::read_bytes(&mut Cursor::new(data), &decoding_limits).unwrap_err();
+    match err {
+        CommsError::Io(ref err) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        ref err => panic!("Expected an io error, got {:?}", err),
+    }
+
+    assert!(!format!("{}", CommsError::MessageTooLarge).is_empty());
+}
+
+/// The part of a hello which follows its message header
+struct HelloBody(HelloMessage);
+
+impl BinaryEncoder<HelloBody> for HelloBody {
+    fn byte_len(&self) -> usize {
+        self.0.byte_len() - MESSAGE_HEADER_LEN
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let bytes = self.0.encode_to_vec();
+        process_encode_io_result(stream.write(&bytes[MESSAGE_HEADER_LEN..]))
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        // Put a header in front of the body so it decodes as a whole hello
+        let header = MessageHeader::new(MessageType::Hello).encode_to_vec();
+        let mut hello =
+            HelloMessage::decode(&mut Cursor::new(header).chain(stream), decoding_limits)?;
+        hello.message_header.message_size = hello.byte_len() as u32;
+        Ok(HelloBody(hello))
+    }
+}
+
+#[test]
+pub fn size_prefixed_writer() {
+    let hello = HelloMessage::new("opc.tcp://foo:1234/bar", 8192, 8192, 65536);
+
+    // Something already in the stream shows the header is patched where it was written
+    let mut stream = Cursor::new(Vec::new());
+    stream.write_all(&[0xffu8; 5]).unwrap();
+
+    let mut writer = SizePrefixedWriter::new(stream, MessageType::Hello).unwrap();
+    assert_eq!(writer.size(), MESSAGE_HEADER_LEN);
+    HelloBody(hello.clone()).encode(&mut writer).unwrap();
+    assert_eq!(writer.size(), hello.byte_len());
+    let mut stream = writer.finish().unwrap();
+
+    // Stream is left at the end so more can follow
+    assert_eq!(stream.position() as usize, 5 + hello.byte_len());
+    stream.write_all(&[0xeeu8; 3]).unwrap();
+
+    let bytes = stream.into_inner();
+    assert_eq!(&bytes[..5], &[0xffu8; 5]);
+    assert_eq!(&bytes[5..5 + hello.byte_len()], &hello.encode_to_vec()[..]);
+    assert_eq!(&bytes[5 + hello.byte_len()..], &[0xeeu8; 3]);
+
+    // The body decodes back without its header
+    let body = &bytes[5 + MESSAGE_HEADER_LEN..5 + hello.byte_len()];
+    let decoded = HelloBody::decode(&mut Cursor::new(body), &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded.0, hello);
+}
+
+#[test]
+pub fn message_type_from_bytes() {
+    assert_eq!(MessageType::from_bytes(b"HELF"), Ok(MessageType::Hello));
+    assert_eq!(
+        MessageType::from_bytes(b"ACKF"),
+        Ok(MessageType::Acknowledge)
+    );
+    assert_eq!(MessageType::from_bytes(b"ERRF"), Ok(MessageType::Error));
+    assert_eq!(MessageType::from_bytes(b"MSGF"), Ok(MessageType::Chunk));
+    assert_eq!(MessageType::from_bytes(b"MSGC"), Ok(MessageType::Chunk));
+    assert_eq!(MessageType::from_bytes(b"OPNA"), Ok(MessageType::Chunk));
+    assert_eq!(MessageType::from_bytes(b"CLOF"), Ok(MessageType::Chunk));
+
+    // Short buffer
+    assert_eq!(
+        MessageType::from_bytes(b"HEL"),
+        Err(StatusCode::BadTcpMessageTypeInvalid)
+    );
+    assert_eq!(
+        MessageType::from_bytes(b""),
+        Err(StatusCode::BadTcpMessageTypeInvalid)
+    );
+
+    // Bad chunk byte, including intermediate chunks of a non-chunk message
+    assert_eq!(
+        MessageType::from_bytes(b"HELX"),
+        Err(StatusCode::BadTcpMessageTypeInvalid)
+    );
+    assert_eq!(
+        MessageType::from_bytes(b"HELC"),
+        Err(StatusCode::BadTcpMessageTypeInvalid)
+    );
+    assert_eq!(
+        MessageType::from_bytes(b"MSGZ"),
+        Err(StatusCode::BadTcpMessageTypeInvalid)
+    );
+
+    // Malformed tag
+    assert_eq!(
+        MessageType::from_bytes(b"h\0lF"),
+        Err(StatusCode::BadTcpMessageTypeInvalid)
+    );
+
+    // Well formed but unknown tag
+    assert_eq!(
+        MessageType::from_bytes(b"XYZF"),
+        Err(StatusCode::BadNotSupported)
+    );
+
+    // The infallible version maps errors to invalid
+    assert_eq!(MessageHeader::message_type(b"XYZF"), MessageType::Invalid);
+    assert_eq!(MessageHeader::message_type(b"HELF"), MessageType::Hello);
+}
+
+#[test]
+pub fn message_tag() {
+    // Message types round trip through their tags
+    for (message_type, tag) in &[
+        (MessageType::Hello, b"HEL"),
+        (MessageType::Acknowledge, b"ACK"),
+        (MessageType::Error, b"ERR"),
+        (MessageType::Chunk, b"MSG"),
+    ] {
+        let message_tag = MessageTag::from(message_type.clone());
+        assert_eq!(message_tag.as_bytes(), &tag[..]);
+        assert_eq!(message_tag.as_message_type(), *message_type);
+    }
+
+    // Chunk types round trip through their tags
+    for (chunk_type, tag) in &[
+        (MessageChunkType::Message, b"MSG"),
+        (MessageChunkType::OpenSecureChannel, b"OPN"),
+        (MessageChunkType::CloseSecureChannel, b"CLO"),
+    ] {
+        let message_tag = MessageTag::from(*chunk_type);
+        assert_eq!(message_tag.as_bytes(), &tag[..]);
+        assert_eq!(message_tag.as_chunk_type(), Some(*chunk_type));
+        assert_eq!(message_tag.as_message_type(), MessageType::Chunk);
+    }
+
+    // Tags which aren't chunks or aren't anything
+    assert_eq!(MessageTag(*b"HEL").as_chunk_type(), None);
+    assert_eq!(MessageTag(*b"XYZ").as_chunk_type(), None);
+    assert_eq!(MessageTag(*b"XYZ").as_message_type(), MessageType::Invalid);
+}
+
+/// The limits a server offers in the handshake tests
+fn server_limits() -> TransportLimits {
+    TransportLimits {
+        receive_buffer_size: 8192,
+        send_buffer_size: 65536,
+        max_message_size: 1_000_000,
+        max_chunk_count: 20,
+    }
+}
+
+/// Processes a message through the handshake of a server whose endpoint is the one in
+/// `hello_data()`
+fn process_handshake(
+    state: &mut HandshakeState,
+    header: &MessageHeader,
+    body: &[u8],
+) -> HandshakeEvent {
+    let endpoints = ["opc.tcp://127.0.0.1:1234/".to_string()];
+    state.process(
+        header,
+        body,
+        &server_limits(),
+        &endpoints,
+        EndpointUrlPolicy::Strict,
+    )
+}
+
+#[test]
+pub fn handshake() {
+    let decoding_limits = DecodingLimits::default();
+    let mut state = HandshakeState::default();
+    assert_eq!(state, HandshakeState::WaitingHello);
+
+    // Hello is acknowledged with the smaller of each side's buffer sizes and the server's limits
+    let hello = hello_data();
+    let frame = FrameReader::new(Cursor::new(&hello), &decoding_limits).unwrap();
+    let header = frame.header().clone();
+    let event = process_handshake(&mut state, &header, &hello[8..]);
+    match event {
+        HandshakeEvent::SendAcknowledge(ack) => {
+            assert_eq!(ack.message_header.message_type, MessageType::Acknowledge);
+            assert_eq!(ack.message_header.message_size, 28);
+            assert_eq!(ack.protocol_version, 0);
+            assert_eq!(ack.receive_buffer_size, 8192);
+            assert_eq!(ack.send_buffer_size, 65536);
+            assert_eq!(ack.max_message_size, 1_000_000);
+            assert_eq!(ack.max_chunk_count, 20);
+        }
+        event => panic!("Expected an acknowledge, got {:?}", event),
+    }
+    assert!(state.is_acknowledged());
+
+    // Chunks pass through
+    let mut chunk_header = MessageHeader::new(MessageType::Chunk);
+    chunk_header.message_size = 8;
+    assert_eq!(
+        process_handshake(&mut state, &chunk_header, &[]),
+        HandshakeEvent::Message
+    );
+    assert!(state.is_acknowledged());
+
+    // A second hello is rejected
+    assert_eq!(
+        process_handshake(&mut state, &header, &hello[8..]),
+        HandshakeEvent::Reject(StatusCode::BadTcpMessageTypeInvalid)
+    );
+    assert_eq!(state, HandshakeState::Failed);
+
+    // Nothing further is accepted
+    assert_eq!(
+        process_handshake(&mut state, &chunk_header, &[]),
+        HandshakeEvent::Reject(StatusCode::BadInvalidState)
+    );
+}
+
+#[test]
+pub fn handshake_over_duplex_stream() {
+    let decoding_limits = DecodingLimits::default();
+    let (mut client, mut server) = DuplexStream::pair();
+
+    // Client sends a hello
+    let hello = HelloMessage::new("opc.tcp://127.0.0.1:1234/", 8196, 16384, 16384);
+    hello.encode(&mut client).unwrap();
+    assert_eq!(server.available(), hello.byte_len());
+
+    // Server reads the frame with the codec and passes the hello through the handshake the same
+    // way as the server's transport
+    let mut codec = TcpCodec::new(Arc::new(RwLock::new(false)), decoding_limits);
+    let mut buf = BytesMut::new();
+    let mut bytes = vec![0u8; server.available()];
+    server.read_exact(&mut bytes).unwrap();
+    buf.extend_from_slice(&bytes);
+    let received = match codec.decode(&mut buf).unwrap() {
+        Some(Message::Hello(hello)) => hello,
+        message => panic!("Expected a hello, got {:?}", message),
+    };
+    assert_eq!(received, hello);
+
+    let mut state = HandshakeState::default();
+    let endpoints = ["opc.tcp://127.0.0.1:1234/".to_string()];
+    let acknowledge = match state.process_hello(
+        &received,
+        &server_limits(),
+        &endpoints,
+        EndpointUrlPolicy::Strict,
+    ) {
+        HandshakeEvent::SendAcknowledge(acknowledge) => acknowledge,
+        event => panic!("Expected an acknowledge, got {:?}", event),
+    };
+    assert!(state.is_acknowledged());
+    codec
+        .encode(Message::Acknowledge(acknowledge), &mut buf)
+        .unwrap();
+    server.write_all(&buf).unwrap();
+
+    // Client reads the acknowledge with the negotiated buffer sizes and the server's limits
+    let acknowledge = AcknowledgeMessage::decode(&mut client, &decoding_limits).unwrap();
+    assert_eq!(
+        acknowledge.message_header.message_type,
+        MessageType::Acknowledge
+    );
+    assert_eq!(
+        acknowledge.message_header.message_size as usize,
+        acknowledge.byte_len()
+    );
+    assert_eq!(acknowledge.receive_buffer_size, 8192);
+    assert_eq!(acknowledge.send_buffer_size, 16384);
+    assert_eq!(acknowledge.max_message_size, 1_000_000);
+    assert_eq!(client.available(), 0);
+
+    // Nothing more to read until the server goes away
+    let mut buf = [0u8; 1];
+    assert_eq!(
+        client.read(&mut buf).unwrap_err().kind(),
+        ErrorKind::WouldBlock
+    );
+    drop(server);
+    assert_eq!(client.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+pub fn handshake_impossible_limits() {
+    // A hello whose max message size is smaller than one buffer fails the handshake
+    let mut hello = HelloMessage::new("opc.tcp://127.0.0.1:1234/", 8196, 8196, 100);
+    hello.max_chunk_count = 0;
+    let bytes = hello.encode_to_vec();
+    let mut state = HandshakeState::default();
+    assert_eq!(
+        process_handshake(&mut state, &hello.message_header, &bytes[8..]),
+        HandshakeEvent::Reject(StatusCode::BadTcpMessageTooLarge)
+    );
+    assert_eq!(state, HandshakeState::Failed);
+}
+
+#[test]
+pub fn handshake_ack_before_hello() {
+    let decoding_limits = DecodingLimits::default();
+    let mut state = HandshakeState::default();
+
+    let ack = ack_data();
+    let frame = FrameReader::new(Cursor::new(&ack), &decoding_limits).unwrap();
+    let header = frame.header().clone();
+    assert_eq!(
+        process_handshake(&mut state, &header, &ack[8..]),
+        HandshakeEvent::Reject(StatusCode::BadTcpMessageTypeInvalid)
+    );
+    assert_eq!(state, HandshakeState::Failed);
+
+    // The hello is too late
+    let hello = hello_data();
+    let frame = FrameReader::new(Cursor::new(&hello), &decoding_limits).unwrap();
+    let header = frame.header().clone();
+    assert_eq!(
+        process_handshake(&mut state, &header, &hello[8..]),
+        HandshakeEvent::Reject(StatusCode::BadInvalidState)
+    );
+}
+
+#[test]
+pub fn pending_requests_complete() {
+    let message = make_sample_message();
+    let mut pending_requests = PendingRequests::new();
+    let receiver1 = pending_requests.register(1, Duration::from_secs(10));
+    let receiver2 = pending_requests.register(2, Duration::from_secs(10));
+    assert_eq!(pending_requests.len(), 2);
+
+    // Complete out of order
+    assert!(pending_requests.complete(2, message.clone()));
+    assert!(!pending_requests.contains(2));
+    assert_eq!(receiver2.wait().unwrap().unwrap(), message);
+
+    assert!(pending_requests.complete(1, message.clone()));
+    assert_eq!(receiver1.wait().unwrap().unwrap(), message);
+    assert!(pending_requests.is_empty());
+
+    // A response to nothing is ignored
+    assert!(!pending_requests.complete(3, message));
+}
+
+#[test]
+pub fn pending_requests_timeout() {
+    let mut pending_requests = PendingRequests::new();
+    let now = Instant::now();
+    let short = pending_requests.register(1, Duration::from_millis(100));
+    let long = pending_requests.register(2, Duration::from_secs(60));
+
+    // Nothing has expired yet
+    assert!(pending_requests.reap_expired(now).is_empty());
+
+    let expired = pending_requests.reap_expired(now + Duration::from_secs(1));
+    assert_eq!(expired, vec![1]);
+    assert_eq!(short.wait().unwrap(), Err(StatusCode::BadTimeout));
+
+    // A late response to the expired request is ignored
+    assert!(!pending_requests.complete(1, make_sample_message()));
+
+    // Remaining requests fail when the connection goes away
+    pending_requests.fail_all(StatusCode::BadConnectionClosed);
+    assert!(pending_requests.is_empty());
+    assert_eq!(long.wait().unwrap(), Err(StatusCode::BadConnectionClosed));
+}
+
+#[test]
+pub fn in_flight_requests_limit() {
+    let mut in_flight_requests = InFlightRequests::new(3);
+
+    // Up to the limit is fine
+    (1..=3).for_each(|request_id| in_flight_requests.begin(request_id).unwrap());
+    assert_eq!(in_flight_requests.len(), 3);
+
+    // The next is rejected
+    assert_eq!(
+        in_flight_requests.begin(4),
+        Err(StatusCode::BadTooManyOperations)
+    );
+    assert!(!in_flight_requests.contains(4));
+
+    // A response frees a slot
+    assert!(in_flight_requests.end(2));
+    assert!(!in_flight_requests.end(2));
+    in_flight_requests.begin(4).unwrap();
+    assert_eq!(
+        in_flight_requests.begin(5),
+        Err(StatusCode::BadTooManyOperations)
+    );
+
+    in_flight_requests.clear();
+    assert!(in_flight_requests.is_empty());
+
+    // No limit
+    let mut in_flight_requests = InFlightRequests::new(0);
+    (1..=100).for_each(|request_id| in_flight_requests.begin(request_id).unwrap());
+    assert_eq!(in_flight_requests.len(), 100);
+}
+
+struct NoopNotify;
+
+impl Notify for NoopNotify {
+    fn notify(&self, _id: usize) {}
+}
+
+/// Polls the future once without blocking
+fn poll_once<F: Future>(future: &mut Spawn<F>) -> Poll<F::Item, F::Error> {
+    future.poll_future_notify(&Arc::new(NoopNotify), 0)
+}
+
+/// Polls the future, writing the next piece of bytes into the pipe each time it is waiting for
+/// more. Returns `NotReady` if the pieces run out before the future resolves.
+fn poll_with_pieces<'a, F, I>(
+    future: &mut Spawn<F>,
+    writer: &mut DuplexStream,
+    pieces: &mut I,
+) -> Poll<F::Item, F::Error>
+where
+    F: Future,
+    I: Iterator<Item = &'a [u8]>,
+{
+    loop {
+        match poll_once(future)? {
+            Async::NotReady => match pieces.next() {
+                Some(piece) => writer.write_all(piece).unwrap(),
+                None => return Ok(Async::NotReady),
+            },
+            ready => return Ok(ready),
+        }
+    }
+}
+
+#[test]
+pub fn async_read_write_message() {
+    let decoding_limits = DecodingLimits::default();
+
+    // Write a hello and an acknowledge through a pipe
+    let hello = HelloMessage::new("opc.tcp://127.0.0.1:1234/", 8196, 8196, 0);
+    let mut acknowledge = AcknowledgeMessage {
+        message_header: MessageHeader::new(MessageType::Acknowledge),
+        protocol_version: 0,
+        receive_buffer_size: 8196,
+        send_buffer_size: 8196,
+        max_message_size: 0,
+        max_chunk_count: 0,
+    };
+    acknowledge.message_header.message_size = acknowledge.byte_len() as u32;
+    let (writer, mut reader) = DuplexStream::pair();
+    let _writer = write_message(writer, &hello)
+        .and_then(|writer| write_message(writer, &acknowledge))
+        .wait()
+        .unwrap();
+    let mut bytes = vec![0u8; reader.available()];
+    reader.read_exact(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), hello.byte_len() + acknowledge.byte_len());
+
+    // Read them back from a pipe which delivers the bytes 5 at a time, so the header and the
+    // body both arrive over several reads which would block in between
+    let (mut writer, reader) = DuplexStream::pair();
+    let mut pieces = bytes.chunks(5);
+    let mut read = executor::spawn(read_message(reader, &decoding_limits));
+    let (reader, header, body) =
+        match poll_with_pieces(&mut read, &mut writer, &mut pieces).unwrap() {
+            Async::Ready(result) => result,
+            Async::NotReady => panic!("hello was not read"),
+        };
+    assert_eq!(header, hello.message_header);
+    assert_eq!(body.len(), hello.byte_len() - 8);
+    let mut stream_hello = Cursor::new(header.encode_to_vec()).chain(&body[..]);
+    assert_eq!(
+        HelloMessage::decode(&mut stream_hello, &decoding_limits).unwrap(),
+        hello
+    );
+
+    // The acknowledge starts in the last piece of the hello
+    assert!(reader.available() > 0);
+    let mut read = executor::spawn(read_message(reader, &decoding_limits));
+    let (reader, header, body) =
+        match poll_with_pieces(&mut read, &mut writer, &mut pieces).unwrap() {
+            Async::Ready(result) => result,
+            Async::NotReady => panic!("acknowledge was not read"),
+        };
+    assert_eq!(header, acknowledge.message_header);
+    let mut stream_ack = Cursor::new(header.encode_to_vec()).chain(&body[..]);
+    assert_eq!(
+        AcknowledgeMessage::decode(&mut stream_ack, &decoding_limits).unwrap(),
+        acknowledge
+    );
+
+    // The pipe is now empty so the next read waits, until the writer goes away and the
+    // connection is closed
+    let mut read = executor::spawn(read_message(reader, &decoding_limits));
+    assert!(poll_with_pieces(&mut read, &mut writer, &mut pieces)
+        .unwrap()
+        .is_not_ready());
+    drop(writer);
+    assert_eq!(
+        poll_once(&mut read).unwrap_err(),
+        StatusCode::BadConnectionClosed
+    );
+
+    // Reads the bytes through a pipe which delivers them 5 at a time and is then closed
+    let read_closed = |bytes: &[u8]| {
+        let (mut writer, reader) = DuplexStream::pair();
+        let mut read = executor::spawn(read_message(reader, &decoding_limits));
+        let mut pieces = bytes.chunks(5);
+        match poll_with_pieces(&mut read, &mut writer, &mut pieces) {
+            Ok(Async::NotReady) => {
+                drop(writer);
+                poll_once(&mut read)
+            }
+            result => result,
+        }
+    };
+
+    // A truncated body is a closed connection too
+    let mut bytes = hello.encode_to_vec();
+    bytes.truncate(bytes.len() - 1);
+    assert_eq!(
+        read_closed(&bytes).unwrap_err(),
+        StatusCode::BadConnectionClosed
+    );
+
+    // A header claiming a huge size does not allocate it up front, the read just fails when
+    // the stream runs out
+    let mut bytes = hello.encode_to_vec();
+    bytes[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        read_closed(&bytes).unwrap_err(),
+        StatusCode::BadConnectionClosed
+    );
+
+    // A bad header is rejected before the body is read
+    let mut bytes = hello.encode_to_vec();
+    bytes[0] = b'X';
+    assert_eq!(
+        read_closed(&bytes).unwrap_err(),
+        StatusCode::BadNotSupported
+    );
+}
+
+#[test]
+fn core_types_round_trip() {
+    // The message types defined in this crate encode through the same BinaryEncoder as the
+    // types crate and must round trip like any other type
+    serialize_test(MessageHeader::new(MessageType::Hello));
+    serialize_test(HelloMessage::new(
+        "opc.tcp://localhost:4855/",
+        8192,
+        8192,
+        65536,
+    ));
+    serialize_test(AcknowledgeMessage::new(8192, 16384, 65536, 10));
+    serialize_test(ErrorMessage::from_status_code(StatusCode::BadTimeout));
+    serialize_test(SymmetricSecurityHeader {
+        token_id: 0x1234_5678,
+    });
+    serialize_test(AsymmetricSecurityHeader::none());
+    serialize_test(AsymmetricSecurityHeader {
+        security_policy_uri: UAString::from(SecurityPolicy::Basic256Sha256.to_uri()),
+        sender_certificate: ByteString::from(vec![1u8, 2, 3, 4]),
+        receiver_certificate_thumbprint: ByteString::from(vec![5u8; 20]),
+    });
+    serialize_test(SequenceHeader {
+        sequence_number: u32::MAX,
+        request_id: 7,
+    });
+}
+
+/// A body whose byte_len under-reports what encode actually writes
+#[derive(Debug)]
+struct UnderReportedBody;
+
+impl BinaryEncoder<UnderReportedBody> for UnderReportedBody {
+    fn byte_len(&self) -> usize {
+        4
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        write_u64(stream, 0u64)
+    }
+
+    fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
+        let _ = read_u64(stream)?;
+        Ok(UnderReportedBody)
+    }
+}
+
+/// Encodes a hello header whose message_size is declared from the byte_len() of the body,
+/// followed by the body, and checks the bytes written against the declared size
+fn encode_declared_size<B: BinaryEncoder<B>>(body: &B) -> EncodingResult<Vec<u8>> {
+    let message_size = MESSAGE_HEADER_LEN + body.byte_len();
+    let header = MessageHeader {
+        message_type: MessageType::Hello,
+        message_size: message_size as u32,
+    };
+    let mut stream = CountingWriter::new(Vec::new(), message_size);
+    header.encode(&mut stream)?;
+    body.encode(&mut stream)?;
+    stream.finish()
+}
+
+#[test]
+fn counting_writer_catches_size_mismatch() {
+    // Exactly the declared size
+    let mut stream = CountingWriter::new(Vec::new(), 4);
+    stream.write_all(&[1, 2, 3, 4]).unwrap();
+    assert_eq!(stream.count(), 4);
+    assert_eq!(stream.finish().unwrap(), vec![1, 2, 3, 4]);
+
+    // Too few or too many bytes
+    let mut stream = CountingWriter::new(Vec::new(), 4);
+    stream.write_all(&[1, 2, 3]).unwrap();
+    assert_eq!(stream.finish().unwrap_err(), StatusCode::BadEncodingError);
+    let mut stream = CountingWriter::new(Vec::new(), 4);
+    stream.write_all(&[1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(stream.finish().unwrap_err(), StatusCode::BadEncodingError);
+
+    // A message whose header would declare the wrong message_size is rejected
+    let result = encode_declared_size(&UnderReportedBody);
+    assert_eq!(result.unwrap_err(), StatusCode::BadEncodingError);
+
+    // A well behaved body is written with the size it declared and decodes back
+    let hello = HelloMessage::new("opc.tcp://localhost:4855/", 8192, 8192, 65536);
+    let bytes = encode_declared_size(&HelloBody(hello.clone())).unwrap();
+    assert_eq!(bytes.len(), hello.byte_len());
+    let decoded = HelloMessage::decode(&mut Cursor::new(&bytes), &DecodingLimits::default());
+    assert_eq!(decoded.unwrap(), hello);
+}
+
+#[test]
+fn encode_with_size_sets_message_size() {
+    let decoding_limits = DecodingLimits::default();
+
+    // The size is taken from the encoded bytes even if the header was never set
+    let mut error = ErrorMessage::from_status_code(StatusCode::BadTcpMessageTooLarge);
+    error.message_header.message_size = 0;
+    let bytes = encode_with_size(&error).unwrap();
+    let decoded = ErrorMessage::decode(&mut Cursor::new(&bytes), &decoding_limits).unwrap();
+    assert_eq!(decoded.message_header.message_size as usize, bytes.len());
+    assert_eq!(decoded.error, StatusCode::BadTcpMessageTooLarge.bits());
+    assert_eq!(
+        decoded.reason.as_ref(),
+        StatusCode::BadTcpMessageTooLarge.description()
+    );
+
+    // The whole frame can be read back using the size in its header
+    let (header, body) =
+        MessageHeader::read_frame(&mut Cursor::new(&bytes), &decoding_limits).unwrap();
+    assert_eq!(header.message_type, MessageType::Error);
+    assert_eq!(&body[..], &bytes[MESSAGE_HEADER_LEN..]);
+
+    // Usable by other message types too
+    let hello = HelloMessage::new("opc.tcp://localhost:4855/", 8192, 8192, 65536);
+    assert_eq!(encode_with_size(&hello).unwrap(), hello.encode_to_vec());
+
+    // Something too small to hold a header is rejected
+    assert_eq!(
+        encode_with_size(&1u32).unwrap_err(),
+        StatusCode::BadEncodingError
+    );
+}
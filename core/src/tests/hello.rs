@@ -1,10 +1,22 @@
+use std::io::Cursor;
+
 use opcua_types::{
     byte_string::ByteString,
+    encoding::{BinaryEncoder, DecodingLimits},
     service_types::{ApplicationDescription, EndpointDescription, MessageSecurityMode},
+    status_code::StatusCode,
     string::UAString,
 };
 
-use crate::comms::tcp_types::{HelloMessage, MessageHeader, MessageType};
+use crate::comms::{
+    handshake::{respond_to_hello, HandshakeEvent, HandshakeState},
+    supported_protocol_versions,
+    tcp_types::{
+        AcknowledgeMessage, EndpointUrlPolicy, ErrorMessage, HelloMessage, MessageHeader,
+        MessageType, TransportLimits,
+    },
+    PROTOCOL_VERSION,
+};
 
 #[test]
 fn endpoint_url() {
@@ -53,6 +65,54 @@ fn endpoint_url() {
     assert!(h.is_endpoint_valid_length())
 }
 
+#[test]
+fn endpoint_url_policy() {
+    let endpoints = vec![EndpointDescription {
+        endpoint_url: UAString::from("opc.tcp://foo"),
+        security_policy_uri: UAString::null(),
+        security_mode: MessageSecurityMode::None,
+        server: ApplicationDescription::default(),
+        security_level: 0,
+        server_certificate: ByteString::null(),
+        transport_profile_uri: UAString::null(),
+        user_identity_tokens: None,
+    }];
+
+    let mut h = HelloMessage::new("", 8196, 8196, 0);
+    for endpoint_url in &[UAString::null(), UAString::from("")] {
+        h.endpoint_url = endpoint_url.clone();
+        // Strict rejects a null or empty url
+        assert_eq!(
+            h.validate_endpoint_url(&endpoints, EndpointUrlPolicy::Strict)
+                .unwrap_err(),
+            StatusCode::BadTcpEndpointUrlInvalid
+        );
+        // Permissive accepts it, but only if there is an endpoint to go to
+        assert!(h
+            .validate_endpoint_url(&endpoints, EndpointUrlPolicy::Permissive)
+            .is_ok());
+        assert_eq!(
+            h.validate_endpoint_url(&[], EndpointUrlPolicy::Permissive)
+                .unwrap_err(),
+            StatusCode::BadTcpEndpointUrlInvalid
+        );
+    }
+
+    // A valid url is accepted and a url for some other endpoint rejected under either policy
+    for policy in &[EndpointUrlPolicy::Strict, EndpointUrlPolicy::Permissive] {
+        h.endpoint_url = UAString::from("opc.tcp://foo/");
+        assert!(h.validate_endpoint_url(&endpoints, *policy).is_ok());
+        h.endpoint_url = UAString::from("opc.tcp://foo/blah");
+        assert_eq!(
+            h.validate_endpoint_url(&endpoints, *policy).unwrap_err(),
+            StatusCode::BadTcpEndpointUrlInvalid
+        );
+    }
+
+    // The default is strict
+    assert_eq!(EndpointUrlPolicy::default(), EndpointUrlPolicy::Strict);
+}
+
 #[test]
 fn valid_buffer_sizes() {
     // Test that invalid buffer sizes are rejected, while valid buffer sizes are accepted
@@ -78,3 +138,219 @@ fn valid_buffer_sizes() {
     h.send_buffer_size = 8196;
     assert!(h.is_valid_buffer_sizes());
 }
+
+#[test]
+fn validate_limits() {
+    let mut h = HelloMessage::new("opc.tcp://foo/", 8196, 8196, 0);
+
+    // Unlimited message size and chunk count
+    h.max_chunk_count = 0;
+    assert!(h.validate_limits().is_ok());
+
+    // A max message size smaller than a single buffer can never be satisfied
+    h.max_message_size = 8195;
+    assert_eq!(
+        h.validate_limits().unwrap_err(),
+        StatusCode::BadTcpMessageTooLarge
+    );
+    h.max_chunk_count = 1;
+    assert_eq!(
+        h.validate_limits().unwrap_err(),
+        StatusCode::BadTcpMessageTooLarge
+    );
+
+    // Max message size of exactly one buffer
+    h.max_message_size = 8196;
+    assert!(h.validate_limits().is_ok());
+
+    // Too few chunks to hold the max message size
+    h.max_message_size = 8196 * 3;
+    h.max_chunk_count = 2;
+    assert_eq!(
+        h.validate_limits().unwrap_err(),
+        StatusCode::BadInvalidArgument
+    );
+    h.max_chunk_count = 3;
+    assert!(h.validate_limits().is_ok());
+
+    // Unlimited chunk count with a max message size
+    h.max_chunk_count = 0;
+    assert!(h.validate_limits().is_ok());
+
+    // Chunk count limit with an unlimited message size
+    h.max_message_size = 0;
+    h.max_chunk_count = 1;
+    assert!(h.validate_limits().is_ok());
+
+    // Large values do not overflow
+    h.receive_buffer_size = u32::max_value();
+    h.max_message_size = u32::max_value();
+    h.max_chunk_count = u32::max_value();
+    assert!(h.validate_limits().is_ok());
+}
+
+#[test]
+fn protocol_version() {
+    let h = HelloMessage::new("opc.tcp://foo/", 8196, 8196, 0);
+    assert_eq!(h.protocol_version, PROTOCOL_VERSION);
+
+    let a = AcknowledgeMessage::new(8196, 8196, 0, 0);
+    assert_eq!(a.protocol_version, PROTOCOL_VERSION);
+    assert_eq!(a.message_header.message_type, MessageType::Acknowledge);
+    assert_eq!(a.message_header.message_size, 28);
+
+    assert!(supported_protocol_versions().contains(&PROTOCOL_VERSION));
+}
+
+#[test]
+fn validation_status_codes() {
+    let mut h = HelloMessage::new("opc.tcp://foo/", 8196, 8196, 0);
+    assert!(h.validate_buffer_sizes().is_ok());
+    assert!(h.validate_protocol_version().is_ok());
+
+    h.send_buffer_size = 1024;
+    assert_eq!(
+        h.validate_buffer_sizes().unwrap_err(),
+        StatusCode::BadTcpNotEnoughResources
+    );
+
+    h.protocol_version = PROTOCOL_VERSION + 1;
+    assert_eq!(
+        h.validate_protocol_version().unwrap_err(),
+        StatusCode::BadProtocolVersionUnsupported
+    );
+
+    // The handshake rejects the hello with the same status
+    let handshake = |h: &HelloMessage| {
+        let bytes = h.encode_to_vec();
+        let server_limits = TransportLimits {
+            receive_buffer_size: 65535,
+            send_buffer_size: 65535,
+            max_message_size: 0,
+            max_chunk_count: 0,
+        };
+        let endpoints = ["opc.tcp://foo/".to_string()];
+        HandshakeState::default().process(
+            &h.message_header,
+            &bytes[8..],
+            &server_limits,
+            &endpoints,
+            EndpointUrlPolicy::Strict,
+        )
+    };
+    assert_eq!(
+        handshake(&h),
+        HandshakeEvent::Reject(StatusCode::BadProtocolVersionUnsupported)
+    );
+    h.protocol_version = PROTOCOL_VERSION;
+    assert_eq!(
+        handshake(&h),
+        HandshakeEvent::Reject(StatusCode::BadTcpNotEnoughResources)
+    );
+    h.send_buffer_size = 8196;
+    h.max_message_size = 1024;
+    assert_eq!(
+        handshake(&h),
+        HandshakeEvent::Reject(StatusCode::BadTcpMessageTooLarge)
+    );
+}
+
+#[test]
+fn error_message_status_codes() {
+    let decoding_limits = DecodingLimits::default();
+    for &status_code in &[
+        StatusCode::BadTcpServerTooBusy,
+        StatusCode::BadTcpMessageTypeInvalid,
+        StatusCode::BadTcpSecureChannelUnknown,
+        StatusCode::BadTcpMessageTooLarge,
+        StatusCode::BadTcpNotEnoughResources,
+        StatusCode::BadTcpInternalError,
+        StatusCode::BadTcpEndpointUrlInvalid,
+        StatusCode::BadProtocolVersionUnsupported,
+    ] {
+        let error = ErrorMessage::from_status_code(status_code);
+        assert_eq!(error.message_header.message_type, MessageType::Error);
+        assert_eq!(error.message_header.message_size as usize, error.byte_len());
+
+        let bytes = error.encode_to_vec();
+        let decoded = ErrorMessage::decode(&mut Cursor::new(bytes), &decoding_limits).unwrap();
+        assert_eq!(StatusCode::from_bits_truncate(decoded.error), status_code);
+        assert_eq!(decoded.reason.as_ref(), status_code.description());
+    }
+}
+
+#[test]
+fn respond_to_hello_acknowledge() {
+    let server_limits = TransportLimits {
+        receive_buffer_size: 65535,
+        send_buffer_size: 16384,
+        max_message_size: 1_000_000,
+        max_chunk_count: 20,
+    };
+    let endpoints = vec!["opc.tcp://foo/".to_string()];
+
+    // The buffer sizes are the smaller of each side's, the message limits are the server's
+    let hello = HelloMessage::new("opc.tcp://bar/", 8196, 32768, 0);
+    let acknowledge = respond_to_hello(
+        &hello,
+        &server_limits,
+        &endpoints,
+        EndpointUrlPolicy::Strict,
+    )
+    .unwrap();
+    assert_eq!(acknowledge.protocol_version, PROTOCOL_VERSION);
+    assert_eq!(acknowledge.receive_buffer_size, 8196);
+    assert_eq!(acknowledge.send_buffer_size, 16384);
+    assert_eq!(acknowledge.max_message_size, 1_000_000);
+    assert_eq!(acknowledge.max_chunk_count, 20);
+    assert_eq!(
+        acknowledge.message_header.message_size as usize,
+        acknowledge.byte_len()
+    );
+
+    // An empty endpoint url is acknowledged when the policy permits it
+    let hello = HelloMessage::new("", 8196, 8196, 0);
+    assert!(respond_to_hello(
+        &hello,
+        &server_limits,
+        &endpoints,
+        EndpointUrlPolicy::Permissive
+    )
+    .is_ok());
+}
+
+#[test]
+fn respond_to_hello_error() {
+    let server_limits = TransportLimits {
+        receive_buffer_size: 65535,
+        send_buffer_size: 65535,
+        max_message_size: 0,
+        max_chunk_count: 0,
+    };
+    let endpoints = vec!["opc.tcp://foo/".to_string()];
+    let respond = |hello: &HelloMessage| {
+        respond_to_hello(hello, &server_limits, &endpoints, EndpointUrlPolicy::Strict).unwrap_err()
+    };
+    let assert_error = |error: ErrorMessage, status_code: StatusCode| {
+        assert_eq!(error.error, status_code.bits());
+        assert_eq!(error.reason.as_ref(), status_code.description());
+        assert_eq!(error.message_header.message_type, MessageType::Error);
+        assert_eq!(error.message_header.message_size as usize, error.byte_len());
+    };
+
+    let mut hello = HelloMessage::new("opc.tcp://foo/", 8196, 8196, 0);
+    hello.protocol_version = PROTOCOL_VERSION + 1;
+    assert_error(respond(&hello), StatusCode::BadProtocolVersionUnsupported);
+
+    let hello = HelloMessage::new("opc.tcp://foo/blah", 8196, 8196, 0);
+    assert_error(respond(&hello), StatusCode::BadTcpEndpointUrlInvalid);
+
+    let hello = HelloMessage::new("", 8196, 8196, 0);
+    assert_error(respond(&hello), StatusCode::BadTcpEndpointUrlInvalid);
+
+    let hello = HelloMessage::new("opc.tcp://foo/", 8196, 1024, 0);
+    assert_error(respond(&hello), StatusCode::BadTcpNotEnoughResources);
+
+    let hello = HelloMessage::new("opc.tcp://foo/", 8196, 8196, 1024);
+    assert_error(respond(&hello), StatusCode::BadTcpMessageTooLarge);
+}
@@ -3,6 +3,8 @@
 
 use opcua_crypto::SecurityPolicy;
 
+use crate::comms::chunk_cipher::*;
+use crate::comms::chunk_signer::*;
 use crate::comms::chunker::*;
 use crate::comms::secure_channel::*;
 
@@ -203,3 +205,189 @@ fn symmetric_sign_and_encrypt_message_chunk_basic256sha256() {
         SecurityPolicy::Basic256Sha256,
     );
 }
+
+/// A signer whose signature is a simple checksum of the data
+struct ChecksumSigner;
+
+impl ChunkSigner for ChecksumSigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        let sum = data.iter().fold(0u32, |sum, b| sum.wrapping_add(*b as u32));
+        Ok(sum.to_le_bytes().to_vec())
+    }
+
+    fn verify(&self, data: &[u8], signature: &[u8]) -> Result<(), StatusCode> {
+        if self.sign(data)? == signature {
+            Ok(())
+        } else {
+            Err(StatusCode::BadSecurityChecksFailed)
+        }
+    }
+}
+
+#[test]
+fn chunk_signer_detects_tampered_body() {
+    let src = [1u8, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0];
+    let signed_range = 0..8;
+    let mut signed = vec![0u8; src.len()];
+    let signed_size =
+        sign_chunk(&ChecksumSigner, &src, signed_range.clone(), 4, &mut signed).unwrap();
+    assert_eq!(signed_size, 12);
+    assert_eq!(&signed[..8], &src[..8]);
+    assert!(verify_chunk(&ChecksumSigner, &signed, signed_range.clone()).is_ok());
+
+    // Tamper with the body
+    signed[5] = 99;
+    assert_eq!(
+        verify_chunk(&ChecksumSigner, &signed, signed_range.clone()).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+
+    // A signer producing the wrong signature size is rejected
+    assert_eq!(
+        sign_chunk(&ChecksumSigner, &src, signed_range, 20, &mut signed).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+}
+
+/// A signer whose signing always fails, e.g. because the key is unusable
+struct FailingSigner;
+
+impl ChunkSigner for FailingSigner {
+    fn sign(&self, _data: &[u8]) -> Result<Vec<u8>, StatusCode> {
+        Err(StatusCode::BadUnexpectedError)
+    }
+
+    fn verify(&self, _data: &[u8], _signature: &[u8]) -> Result<(), StatusCode> {
+        Err(StatusCode::BadSecurityChecksFailed)
+    }
+}
+
+#[test]
+fn chunk_signer_error_is_returned() {
+    let src = [1u8, 2, 3, 4, 0, 0, 0, 0];
+    let mut dst = vec![0u8; src.len()];
+    assert_eq!(
+        sign_chunk(&FailingSigner, &src, 0..4, 4, &mut dst).unwrap_err(),
+        StatusCode::BadUnexpectedError
+    );
+}
+
+#[test]
+fn no_signer_passes_chunks_through() {
+    let src = [1u8, 2, 3, 4];
+    let mut dst = vec![0u8; src.len()];
+    assert_eq!(sign_chunk(&NoSigner, &src, 0..4, 0, &mut dst).unwrap(), 4);
+    assert_eq!(&dst[..], &src[..]);
+    assert!(verify_chunk(&NoSigner, &dst, 0..4).is_ok());
+}
+
+#[test]
+fn symmetric_sign_tampered_chunk_fails_verification() {
+    let _ = Test::setup();
+    let (secure_channel1, mut secure_channel2) =
+        make_secure_channels(MessageSecurityMode::Sign, SecurityPolicy::Basic256Sha256);
+
+    let chunks = Chunker::encode(1, 1, 0, 0, &secure_channel1, &make_sample_message()).unwrap();
+    let chunk = &chunks[0];
+    let mut signed_data = vec![0u8; chunk.data.len() + 4096];
+    let signed_size = secure_channel1
+        .apply_security(chunk, &mut signed_data[..])
+        .unwrap();
+
+    // Flip a byte of the body, which sits after the headers
+    signed_data[signed_size / 2] ^= 0xff;
+    assert_eq!(
+        secure_channel2
+            .verify_and_remove_security(&signed_data[..signed_size])
+            .unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+}
+
+/// A cipher that XORs every byte with a fixed key
+struct XorCipher;
+
+impl ChunkCipher for XorCipher {
+    fn plain_text_block_size(&self) -> usize {
+        8
+    }
+
+    fn encrypt(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        src.iter()
+            .zip(dst.iter_mut())
+            .for_each(|(s, d)| *d = *s ^ 0x5a);
+        Ok(src.len())
+    }
+
+    fn decrypt(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, StatusCode> {
+        self.encrypt(src, dst)
+    }
+}
+
+#[test]
+fn chunk_cipher_round_trip() {
+    // Header, then a 5 byte body padded with 3 bytes of value 2
+    let src = [9u8, 9, 1, 2, 3, 4, 5, 2, 2, 2];
+    let encrypted_range = 2..src.len();
+    let mut encrypted = vec![0u8; src.len()];
+    let encrypted_size =
+        encrypt_chunk(&XorCipher, &src, encrypted_range.clone(), &mut encrypted).unwrap();
+    assert_eq!(encrypted_size, src.len());
+    assert_eq!(&encrypted[..2], &src[..2]);
+    assert_ne!(&encrypted[2..], &src[2..]);
+
+    let mut decrypted = vec![0u8; src.len()];
+    let decrypted_size =
+        decrypt_chunk(&XorCipher, &encrypted, encrypted_range, &mut decrypted).unwrap();
+    assert_eq!(&decrypted[..decrypted_size], &src[..]);
+    assert_eq!(strip_padding(&decrypted, decrypted_size).unwrap(), 7);
+
+    // The plain text must be a multiple of the block size
+    assert_eq!(
+        encrypt_chunk(&XorCipher, &src, 3..src.len(), &mut encrypted).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+}
+
+#[test]
+fn chunk_cipher_rejects_corrupted_padding() {
+    // A padding byte that disagrees with the others
+    let data = [1u8, 2, 3, 4, 5, 2, 7, 2];
+    assert_eq!(
+        strip_padding(&data, data.len()).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+    // A padding size larger than the chunk
+    let data = [1u8, 2, 3, 200];
+    assert_eq!(
+        strip_padding(&data, data.len()).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+    assert_eq!(
+        strip_padding(&data, 0).unwrap_err(),
+        StatusCode::BadSecurityChecksFailed
+    );
+}
+
+#[test]
+fn symmetric_sign_and_encrypt_strips_padding() {
+    let _ = Test::setup();
+    let (secure_channel1, mut secure_channel2) = make_secure_channels(
+        MessageSecurityMode::SignAndEncrypt,
+        SecurityPolicy::Basic256Sha256,
+    );
+
+    let chunks = Chunker::encode(1, 1, 0, 0, &secure_channel1, &make_sample_message()).unwrap();
+    let chunk = &chunks[0];
+    let mut encrypted_data = vec![0u8; chunk.data.len() + 4096];
+    let encrypted_size = secure_channel1
+        .apply_security(chunk, &mut encrypted_data[..])
+        .unwrap();
+    assert!(encrypted_size > chunk.data.len());
+
+    let chunk2 = secure_channel2
+        .verify_and_remove_security(&encrypted_data[..encrypted_size])
+        .unwrap();
+    assert_eq!(chunk2.data.len(), chunk.data.len());
+    assert_eq!(&chunk.data[12..], &chunk2.data[12..]);
+}
@@ -24,3 +24,142 @@ fn get_endpoints_request() {
     };
     serialize_test(r);
 }
+
+#[test]
+fn sessionless_invoke_request() {
+    use crate::sessionless::SessionlessInvokeRequest;
+
+    let r = GetEndpointsRequest {
+        request_header: request_header(),
+        endpoint_url: UAString::from("opc.tcp://localhost/my_path"),
+        locale_ids: None,
+        profile_uris: None,
+    };
+    let r = SessionlessInvokeRequest::new(
+        r,
+        Some(vec![
+            UAString::from("http://opcfoundation.org/UA/"),
+            UAString::from("urn:foo"),
+        ]),
+        None,
+    );
+    assert_eq!(
+        r.header.service_id,
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary as u32
+    );
+
+    let r2 = serialize_test_and_return(r.clone());
+    assert_eq!(r2.header.service_id, r.header.service_id);
+    assert_eq!(r2.namespace_uris().len(), 2);
+    assert_eq!(r2.namespace_uris()[1], UAString::from("urn:foo"));
+    assert!(r2.server_uris().is_empty());
+    assert_eq!(r2.request, r.request);
+}
+
+#[test]
+fn sessionless_invoke_request_unknown_service() {
+    use std::io::Cursor;
+
+    use crate::sessionless::SessionlessInvokeRequest;
+
+    // A response is not a valid service to invoke
+    let mut r = SessionlessInvokeRequest::new(
+        GetEndpointsResponse {
+            response_header: ResponseHeader::new_good(&request_header()),
+            endpoints: None,
+        },
+        None,
+        None,
+    );
+    assert_eq!(
+        r.header.service_id,
+        ObjectId::GetEndpointsResponse_Encoding_DefaultBinary as u32
+    );
+    let mut stream = Cursor::new(r.encode_to_vec());
+    assert_eq!(
+        SessionlessInvokeRequest::decode(&mut stream, &DecodingLimits::default()).unwrap_err(),
+        StatusCode::BadServiceUnsupported
+    );
+
+    // An id which isn't an object at all
+    r.header.service_id = 0xffff_fff0;
+    let mut stream = Cursor::new(r.encode_to_vec());
+    assert_eq!(
+        SessionlessInvokeRequest::decode(&mut stream, &DecodingLimits::default()).unwrap_err(),
+        StatusCode::BadServiceUnsupported
+    );
+}
+
+#[test]
+fn sessionless_invoke_unknown_service_policy() {
+    use std::io::Cursor;
+
+    use crate::sessionless::{SessionlessInvoke, SessionlessInvokeRequest, UnknownServicePolicy};
+
+    let decode = |bytes: &[u8], policy| {
+        let mut stream = Cursor::new(bytes);
+        SessionlessInvokeRequest::decode_with_policy(
+            &mut stream,
+            &DecodingLimits::default(),
+            policy,
+        )
+    };
+
+    let r = SessionlessInvokeRequest::new(
+        GetEndpointsRequest {
+            request_header: request_header(),
+            endpoint_url: UAString::from("opc.tcp://localhost/my_path"),
+            locale_ids: None,
+            profile_uris: None,
+        },
+        None,
+        None,
+    );
+    let known = r.encode_to_vec();
+
+    // A known service is routed whatever the policy
+    for policy in &[
+        UnknownServicePolicy::Reject,
+        UnknownServicePolicy::PassThrough,
+    ] {
+        assert_eq!(
+            decode(&known, *policy).unwrap(),
+            SessionlessInvoke::Request(r.clone())
+        );
+    }
+
+    // An unknown service is rejected or passed through with its body untouched
+    let mut unknown = r.clone();
+    unknown.header.service_id = 0xffff_fff0;
+    let bytes = unknown.encode_to_vec();
+    assert_eq!(
+        decode(&bytes, UnknownServicePolicy::Reject).unwrap_err(),
+        StatusCode::BadServiceUnsupported
+    );
+    assert_eq!(
+        decode(&bytes, UnknownServicePolicy::PassThrough).unwrap(),
+        SessionlessInvoke::PassThrough {
+            header: unknown.header.clone(),
+            body: r.request.encode_to_vec(),
+        }
+    );
+
+    // A known id that is not a request is rejected even when passing through
+    let response = SessionlessInvokeRequest::new(
+        GetEndpointsResponse {
+            response_header: ResponseHeader::new_good(&request_header()),
+            endpoints: None,
+        },
+        None,
+        None,
+    );
+    assert_eq!(
+        decode(&response.encode_to_vec(), UnknownServicePolicy::PassThrough).unwrap_err(),
+        StatusCode::BadServiceUnsupported
+    );
+
+    assert_eq!(
+        UnknownServicePolicy::default(),
+        UnknownServicePolicy::Reject
+    );
+}
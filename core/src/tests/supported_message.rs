@@ -1,4 +1,6 @@
-use crate::supported_message::SupportedMessage;
+use opcua_types::node_ids::ObjectId;
+
+use crate::supported_message::{response_type_for, SupportedMessage};
 
 #[test]
 fn size() {
@@ -8,3 +10,24 @@ fn size() {
     println!("SupportedMessage size = {}", size);
     assert!(size <= 16);
 }
+
+#[test]
+fn response_type() {
+    assert_eq!(
+        response_type_for(ObjectId::BrowseNextRequest_Encoding_DefaultBinary),
+        Some(ObjectId::BrowseNextResponse_Encoding_DefaultBinary)
+    );
+    assert_eq!(
+        response_type_for(ObjectId::ReadRequest_Encoding_DefaultBinary),
+        Some(ObjectId::ReadResponse_Encoding_DefaultBinary)
+    );
+    // Responses and unsupported messages have no response
+    assert_eq!(
+        response_type_for(ObjectId::BrowseNextResponse_Encoding_DefaultBinary),
+        None
+    );
+    assert_eq!(
+        response_type_for(ObjectId::ServiceFault_Encoding_DefaultBinary),
+        None
+    );
+}
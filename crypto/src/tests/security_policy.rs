@@ -150,3 +150,24 @@ fn is_valid_keylength() {
     assert!(!SecurityPolicy::Aes256Sha256RsaPss.is_valid_keylength(1024));
     assert!(!SecurityPolicy::Aes256Sha256RsaPss.is_valid_keylength(8192));
 }
+
+#[test]
+fn uri_round_trip() {
+    for security_policy in &[
+        SecurityPolicy::None,
+        SecurityPolicy::Basic128Rsa15,
+        SecurityPolicy::Basic256,
+        SecurityPolicy::Basic256Sha256,
+        SecurityPolicy::Aes128Sha256RsaOaep,
+        SecurityPolicy::Aes256Sha256RsaPss,
+    ] {
+        assert_eq!(
+            SecurityPolicy::from_uri(security_policy.to_uri()),
+            *security_policy
+        );
+    }
+    assert_eq!(
+        SecurityPolicy::from_uri("http://opcfoundation.org/UA/SecurityPolicy#Unheard"),
+        SecurityPolicy::Unknown
+    );
+}
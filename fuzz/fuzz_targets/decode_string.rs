@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use std::io::Cursor;
+
+use opcua_types::*;
+
+fuzz_target!(|data: &[u8]| {
+    // Decoding may fail but it must not panic
+    let _ = UAString::decode(&mut Cursor::new(data), &DecodingLimits::default());
+});
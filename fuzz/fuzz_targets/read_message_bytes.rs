@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use std::io::Cursor;
+
+use opcua_core::comms::tcp_types::MessageHeader;
+use opcua_types::DecodingLimits;
+
+fuzz_target!(|data: &[u8]| {
+    // Reading may fail but it must not panic
+    let _ = MessageHeader::read_bytes(&mut Cursor::new(data), &DecodingLimits::default());
+});
@@ -10,17 +10,26 @@ use chrono::Utc;
 
 use opcua_types::{
     node_ids::VariableId::*,
-    service_types::{BrowseDirection, CallMethodRequest, CallMethodResult, NodeClass},
+    service_types::{
+        BrowseDirection, BuildInfo, CallMethodRequest, CallMethodResult, NodeClass,
+        ServerState as ServerStateType, ServerStatusDataType,
+    },
     status_code::StatusCode,
     *,
 };
 
 use crate::{
     address_space::{
-        node::{HasNodeId, NodeType},
+        data_type::DataType,
+        method::Method,
+        node::{HasNodeId, Node, NodeType},
         object::{Object, ObjectBuilder},
+        object_type::ObjectType,
+        reference_type::ReferenceType,
         references::{Reference, ReferenceDirection, References},
         variable::Variable,
+        variable_type::VariableType,
+        view::View,
         AttrFnGetter,
     },
     callbacks, constants,
@@ -172,8 +181,8 @@ pub struct AddressSpace {
     audit_namespace: u16,
     /// The namespace to generate sequential internal node ids
     internal_namespace: u16,
-    /// The list of all registered namespaces.
-    namespaces: Vec<String>,
+    /// The table of all registered namespaces.
+    namespaces: NamespaceTable,
 }
 
 impl Default for AddressSpace {
@@ -189,7 +198,7 @@ impl Default for AddressSpace {
             // By default, there will be two standard namespaces. The first is the default
             // OPC UA namespace for its standard nodes. The second is the internal namespace used
             // by this implementation.
-            namespaces: vec!["http://opcfoundation.org/UA/".to_string()],
+            namespaces: NamespaceTable::new(),
         }
     }
 }
@@ -226,10 +235,8 @@ impl AddressSpace {
                 Ok(i)
             } else {
                 // Add and register new namespace
-                self.namespaces.push(namespace.into());
+                let ns = self.namespaces.add(namespace);
                 self.set_namespaces(&now);
-                // New namespace index
-                let ns = (self.namespaces.len() - 1) as u16;
                 // Make this the new default namespace
                 self.default_namespace = ns;
                 Ok(ns)
@@ -237,15 +244,19 @@ impl AddressSpace {
         }
     }
 
+    /// Returns the table of registered namespaces
+    pub fn namespace_table(&self) -> &NamespaceTable {
+        &self.namespaces
+    }
+
+    /// Returns the number of registered namespaces, i.e. the length of the namespace table
+    pub fn namespace_count(&self) -> u16 {
+        self.namespaces.len() as u16
+    }
+
     /// Finds the namespace index of a given namespace
     pub fn namespace_index(&self, namespace: &str) -> Option<u16> {
-        self.namespaces
-            .iter()
-            .position(|ns| {
-                let ns: &str = ns.as_ref();
-                ns == namespace
-            })
-            .map(|i| i as u16)
+        self.namespaces.index_of(namespace)
     }
 
     fn set_servers(&mut self, server_state: Arc<RwLock<ServerState>>, now: &DateTime) {
@@ -261,7 +272,7 @@ impl AddressSpace {
     }
 
     fn set_namespaces(&mut self, now: &DateTime) {
-        let value = Variant::from(&self.namespaces);
+        let value = Variant::from(self.namespaces.uris());
         if let Some(ref mut v) = self.find_variable_mut(Server_NamespaceArray) {
             let _ = v.set_value_direct(value, StatusCode::Good, now, now);
         }
@@ -272,6 +283,124 @@ impl AddressSpace {
         self.set_variable_value(Server_ServiceLevel, service_level, now, now);
     }
 
+    /// Populates the mandatory `Server/ServerStatus` nodes with the server's start time and build
+    /// information. The `CurrentTime`, the `State` and the `ServerStatus` structure itself are
+    /// refreshed each time they are read, the state being obtained from the supplied function.
+    pub fn populate_server_status<F>(
+        &mut self,
+        build_info: BuildInfo,
+        start_time: DateTime,
+        state: F,
+    ) where
+        F: Fn() -> ServerStateType + Send + Sync + 'static,
+    {
+        let now = DateTime::now();
+        let state = Arc::new(state);
+
+        // Server_ServerStatus_StartTime
+        self.set_variable_value(
+            Server_ServerStatus_StartTime,
+            start_time.clone(),
+            &now,
+            &now,
+        );
+
+        // Server_ServerStatus_CurrentTime
+        self.set_variable_getter(
+            Server_ServerStatus_CurrentTime,
+            move |_, timestamps_to_return, _, _, _, _| {
+                let now = DateTime::now();
+                let mut value = DataValue::from(now.clone());
+                value.set_timestamps(timestamps_to_return, now.clone(), now);
+                Ok(Some(value))
+            },
+        );
+
+        // State OPC UA Part 5 12.6, Valid states are
+        //     State (Server_ServerStatus_State)
+        {
+            let state = state.clone();
+            self.set_variable_getter(
+                Server_ServerStatus_State,
+                move |_, timestamps_to_return, _, _, _, _| {
+                    let now = DateTime::now();
+                    let mut value = DataValue::from(state() as i32);
+                    value.set_timestamps(timestamps_to_return, now.clone(), now);
+                    Ok(Some(value))
+                },
+            );
+        }
+
+        // ServerStatus_BuildInfo
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo_ProductUri,
+            build_info.product_uri.clone(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo_ManufacturerName,
+            build_info.manufacturer_name.clone(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo_ProductName,
+            build_info.product_name.clone(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo_SoftwareVersion,
+            build_info.software_version.clone(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo_BuildNumber,
+            build_info.build_number.clone(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo_BuildDate,
+            build_info.build_date.clone(),
+            &now,
+            &now,
+        );
+        self.set_variable_value(
+            Server_ServerStatus_BuildInfo,
+            ExtensionObject::from_encodable(
+                ObjectId::BuildInfo_Encoding_DefaultBinary,
+                &build_info,
+            ),
+            &now,
+            &now,
+        );
+
+        // Server_ServerStatus
+        self.set_variable_getter(
+            Server_ServerStatus,
+            move |_, timestamps_to_return, _, _, _, _| {
+                let now = DateTime::now();
+                let server_status = ServerStatusDataType {
+                    start_time: start_time.clone(),
+                    current_time: now.clone(),
+                    state: state(),
+                    build_info: build_info.clone(),
+                    seconds_till_shutdown: 0,
+                    shutdown_reason: LocalizedText::null(),
+                };
+                let mut value = DataValue::from(Variant::from(ExtensionObject::from_encodable(
+                    ObjectId::ServerStatusDataType_Encoding_DefaultBinary,
+                    &server_status,
+                )));
+                value.set_timestamps(timestamps_to_return, now.clone(), now);
+                Ok(Some(value))
+            },
+        );
+    }
+
     /// Sets values for nodes representing the server.
     pub fn set_server_state(&mut self, server_state: Arc<RwLock<ServerState>>) {
         // Server state requires the generated address space, otherwise nothing
@@ -587,41 +716,24 @@ impl AddressSpace {
             // VendorServiceInfo
             // ServerRedundancy
 
-            // Server_ServerStatus_StartTime
-            self.set_variable_value(Server_ServerStatus_StartTime, now.clone(), &now, &now);
-
-            // Server_ServerStatus_CurrentTime
-            self.set_variable_getter(
-                Server_ServerStatus_CurrentTime,
-                move |_, timestamps_to_return, _, _, _, _| {
-                    let now = DateTime::now();
-                    let mut value = DataValue::from(now.clone());
-                    value.set_timestamps(timestamps_to_return, now.clone(), now);
-                    Ok(Some(value))
-                },
-            );
-
-            // State OPC UA Part 5 12.6, Valid states are
-            //     State (Server_ServerStatus_State)
-            self.set_variable_getter(
-                Server_ServerStatus_State,
-                move |_, timestamps_to_return, _, _, _, _| {
-                    // let server_state =  trace_read_lock_unwrap!(server_state);
-                    let now = DateTime::now();
-                    let mut value = DataValue::from(0i32);
-                    value.set_timestamps(timestamps_to_return, now.clone(), now);
-                    Ok(Some(value))
-                },
-            );
-
-            // ServerStatus_BuildInfo
+            // ServerStatus
             {
-                //    BuildDate
-                //    BuildNumber
-                //    ManufacturerName
-                //    ProductName
-                //    ProductUri
-                //    SoftwareVersion
+                let (build_info, start_time) = {
+                    let server_state = trace_read_lock_unwrap!(server_state);
+                    let build_info = BuildInfo {
+                        product_uri: server_state.product_uri.clone(),
+                        manufacturer_name: UAString::null(),
+                        product_name: server_state.application_name.text.clone(),
+                        software_version: UAString::from(env!("CARGO_PKG_VERSION")),
+                        build_number: UAString::null(),
+                        build_date: DateTime::null(),
+                    };
+                    (build_info, server_state.start_time.clone())
+                };
+                let server_state = server_state.clone();
+                self.populate_server_status(build_info, start_time, move || {
+                    trace_read_lock_unwrap!(server_state).state()
+                });
             }
 
             // Server method handlers
@@ -797,6 +909,164 @@ impl AddressSpace {
         }
     }
 
+    /// Inserts a copy of the template node with a new node id and browse name. The template's
+    /// references are copied too, so references from the template become references from the copy
+    /// and references to the template, e.g. from its parent, also point to the copy.
+    ///
+    /// Returns `false` if the template does not exist or is a method, or if the node id is in use.
+    pub fn clone_node<S>(&mut self, template_id: &NodeId, node_id: &NodeId, browse_name: S) -> bool
+    where
+        S: Into<QualifiedName>,
+    {
+        let node_type = if let Some(template) = self.find_node(template_id) {
+            template.clone_with_id(node_id, browse_name.into())
+        } else {
+            None
+        };
+        if let Some(node_type) = node_type {
+            let mut references = Vec::new();
+            if let Some(forward) = self
+                .references
+                .find_references(template_id, None::<(NodeId, bool)>)
+            {
+                forward.into_iter().for_each(|r| {
+                    references.push((r.target_node, r.reference_type, ReferenceDirection::Forward))
+                });
+            }
+            if let Some(inverse) = self
+                .references
+                .find_inverse_references(template_id, None::<(NodeId, bool)>)
+            {
+                inverse.into_iter().for_each(|r| {
+                    references.push((r.target_node, r.reference_type, ReferenceDirection::Inverse))
+                });
+            }
+            let references = references
+                .iter()
+                .map(|(target_node, reference_type, direction)| {
+                    (target_node, reference_type, *direction)
+                })
+                .collect::<Vec<_>>();
+            self.insert(node_type, Some(&references))
+        } else {
+            error!("Node {} cannot be cloned", template_id);
+            false
+        }
+    }
+
+    /// Creates a node of the node class from the attributes of an `AddNodesItem` and inserts it
+    /// into the address space. The attributes are an extension object holding the `*Attributes`
+    /// type which corresponds to the node class, e.g. `ReferenceTypeAttributes` for a
+    /// `NodeClass::ReferenceType`. No references are added, that is up to the caller.
+    ///
+    /// Returns `BadNodeClassInvalid` if the node class is unspecified, `BadNodeIdExists` if the node
+    /// already exists and `BadNodeAttributesInvalid` if the attributes do not decode, do not match
+    /// the node class or are missing mandatory values.
+    pub fn add_node_from_attributes(
+        &mut self,
+        node_class: NodeClass,
+        node_id: &NodeId,
+        browse_name: QualifiedName,
+        attributes: &ExtensionObject,
+        decoding_limits: &DecodingLimits,
+    ) -> Result<&NodeType, StatusCode> {
+        if node_class == NodeClass::Unspecified {
+            error!(
+                "Node {} cannot be created with an unspecified node class",
+                node_id
+            );
+            return Err(StatusCode::BadNodeClassInvalid);
+        }
+        if self.node_exists(node_id) {
+            error!(
+                "Node {} cannot be created because it already exists",
+                node_id
+            );
+            return Err(StatusCode::BadNodeIdExists);
+        }
+        let node = Self::node_from_attributes(
+            node_class,
+            node_id,
+            browse_name,
+            attributes,
+            decoding_limits,
+        )
+        .map_err(|_| StatusCode::BadNodeAttributesInvalid)?;
+        self.insert::<_, NodeId>(node, None);
+        Ok(self.find_node(node_id).unwrap())
+    }
+
+    fn node_from_attributes(
+        node_class: NodeClass,
+        node_id: &NodeId,
+        browse_name: QualifiedName,
+        attributes: &ExtensionObject,
+        decoding_limits: &DecodingLimits,
+    ) -> Result<NodeType, ()> {
+        let object_id = attributes.node_id.as_object_id().map_err(|_| ())?;
+        // The node class and the object id of the attributes must be for the same thing
+        match (object_id, node_class) {
+            (ObjectId::ObjectAttributes_Encoding_DefaultBinary, NodeClass::Object) => {
+                let attributes = attributes
+                    .decode_inner::<ObjectAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                Object::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (ObjectId::VariableAttributes_Encoding_DefaultBinary, NodeClass::Variable) => {
+                let attributes = attributes
+                    .decode_inner::<VariableAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                Variable::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (ObjectId::MethodAttributes_Encoding_DefaultBinary, NodeClass::Method) => {
+                let attributes = attributes
+                    .decode_inner::<MethodAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                Method::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (ObjectId::ObjectTypeAttributes_Encoding_DefaultBinary, NodeClass::ObjectType) => {
+                let attributes = attributes
+                    .decode_inner::<ObjectTypeAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                ObjectType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (ObjectId::VariableTypeAttributes_Encoding_DefaultBinary, NodeClass::VariableType) => {
+                let attributes = attributes
+                    .decode_inner::<VariableTypeAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                VariableType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (
+                ObjectId::ReferenceTypeAttributes_Encoding_DefaultBinary,
+                NodeClass::ReferenceType,
+            ) => {
+                let attributes = attributes
+                    .decode_inner::<ReferenceTypeAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                ReferenceType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (ObjectId::DataTypeAttributes_Encoding_DefaultBinary, NodeClass::DataType) => {
+                let attributes = attributes
+                    .decode_inner::<DataTypeAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                DataType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (ObjectId::ViewAttributes_Encoding_DefaultBinary, NodeClass::View) => {
+                let attributes = attributes
+                    .decode_inner::<ViewAttributes>(decoding_limits)
+                    .map_err(|_| ())?;
+                View::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            (object_id, node_class) => {
+                error!(
+                    "Node class {:?} and node attributes {:?} are not compatible",
+                    node_class, object_id
+                );
+                Err(())
+            }
+        }
+    }
+
     /// Adds the standard nodeset to the address space
     pub fn add_default_nodes(&mut self) {
         debug!("populating address space");
@@ -962,6 +1232,22 @@ impl AddressSpace {
         self.find_node_mut(&node_id.into())
     }
 
+    /// Returns an iterator over every node in the address space, in node id order.
+    pub fn iter_nodes(&self) -> impl Iterator<Item = &dyn Node> {
+        let mut nodes = self.node_map.iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|&(node_id, _)| node_id);
+        nodes.into_iter().map(|(_, node)| node.as_node())
+    }
+
+    /// Calls the visitor with the source node and each forward reference in the address space.
+    /// Source nodes are visited in node id order so the order is the same every time.
+    pub fn for_each_reference<F>(&self, f: F)
+    where
+        F: FnMut(&NodeId, &Reference),
+    {
+        self.references.for_each(f);
+    }
+
     /// Finds a node by its node id and returns a reference to it.
     pub fn find_node(&self, node_id: &NodeId) -> Option<&NodeType> {
         self.node_map.get(node_id)
@@ -972,6 +1258,18 @@ impl AddressSpace {
         self.node_map.get_mut(node_id)
     }
 
+    /// Finds a node by its node id and returns it as a `Node` trait object so that its
+    /// attributes can be read without matching on the concrete node type.
+    pub fn find_as_node(&self, node_id: &NodeId) -> Option<&dyn Node> {
+        self.find_node(node_id).map(|node| node.as_node())
+    }
+
+    /// Finds a node by its node id and returns it as a mutable `Node` trait object so that its
+    /// attributes can be written without matching on the concrete node type.
+    pub fn find_as_node_mut(&mut self, node_id: &NodeId) -> Option<&mut dyn Node> {
+        self.find_node_mut(node_id).map(|node| node.as_mut_node())
+    }
+
     /// Find and return a variable with the specified node id or return None if it cannot be
     /// found or is not a variable
     pub fn find_variable<N>(&self, node_id: N) -> Option<&Variable>
@@ -1361,6 +1659,77 @@ impl AddressSpace {
             .find_references_by_direction(node_id, browse_direction, reference_filter)
     }
 
+    /// Builds the description of a reference to its target node, as returned by browse, reading
+    /// the target's attributes from the address space. Fields that are not in the result mask are
+    /// left null, and `is_forward` is true unless the mask asks for the direction. Returns `None`
+    /// if the target node is not in the address space.
+    pub fn reference_description(
+        &self,
+        reference: &Reference,
+        is_forward: bool,
+        result_mask: BrowseDescriptionResultMask,
+    ) -> Option<ReferenceDescription> {
+        let target_node = self.find_node(&reference.target_node)?.as_node();
+        let node_class = target_node.node_class();
+
+        let reference_type_id =
+            if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_REFERENCE_TYPE) {
+                reference.reference_type.clone()
+            } else {
+                NodeId::null()
+            };
+        let is_forward =
+            if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_IS_FORWARD) {
+                is_forward
+            } else {
+                true
+            };
+        let browse_name =
+            if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_BROWSE_NAME) {
+                target_node.browse_name()
+            } else {
+                QualifiedName::null()
+            };
+        let display_name =
+            if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_DISPLAY_NAME) {
+                target_node.display_name()
+            } else {
+                LocalizedText::null()
+            };
+        // Type definitions are only available for objects and variables. For all other node
+        // classes a null node id is returned.
+        let type_definition = match node_class {
+            NodeClass::Object | NodeClass::Variable
+                if result_mask
+                    .contains(BrowseDescriptionResultMask::RESULT_MASK_TYPE_DEFINITION) =>
+            {
+                self.find_references(
+                    &reference.target_node,
+                    Some((ReferenceTypeId::HasTypeDefinition, false)),
+                )
+                .and_then(|type_defs| type_defs.first().map(|r| r.target_node.clone()))
+                .map_or_else(ExpandedNodeId::null, ExpandedNodeId::new)
+            }
+            _ => ExpandedNodeId::null(),
+        };
+        let node_class =
+            if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_NODE_CLASS) {
+                node_class
+            } else {
+                NodeClass::Unspecified
+            };
+
+        Some(ReferenceDescription {
+            reference_type_id,
+            is_forward,
+            node_id: ExpandedNodeId::new(reference.target_node.clone()),
+            browse_name,
+            display_name,
+            node_class,
+            type_definition,
+        })
+    }
+
     /// Updates the last modified timestamp to now
     fn update_last_modified(&mut self) {
         self.last_modified = Utc::now();
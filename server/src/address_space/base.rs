@@ -7,7 +7,7 @@ use opcua_types::{status_code::StatusCode, *};
 use super::node::{Node, NodeBase};
 
 /// Base node class contains the attributes that all other kinds of nodes need. Part 3, diagram B.4
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct Base {
     /// The node id of this node
     node_id: NodeId,
@@ -26,6 +26,10 @@ pub(crate) struct Base {
 }
 
 impl NodeBase for Base {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
     fn node_class(&self) -> NodeClass {
         self.node_class
     }
@@ -83,8 +87,11 @@ impl Node for Base {
         _max_age: f64,
     ) -> Option<DataValue> {
         match attribute_id {
-            AttributeId::NodeClass => Some((self.node_class as i32).into()),
-            AttributeId::NodeId => Some(self.node_id().into()),
+            // Every node has a node id and node class so they are always good
+            AttributeId::NodeClass => {
+                Some((Variant::from(self.node_class as i32), StatusCode::Good).into())
+            }
+            AttributeId::NodeId => Some((Variant::from(self.node_id()), StatusCode::Good).into()),
             AttributeId::BrowseName => Some(self.browse_name().into()),
             AttributeId::DisplayName => Some(self.display_name().into()),
             AttributeId::Description => self.description().map(|description| description.into()),
@@ -101,6 +108,9 @@ impl Node for Base {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::NodeClass => {
                 if let Variant::Int32(v) = value {
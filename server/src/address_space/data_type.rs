@@ -11,7 +11,7 @@ use crate::address_space::{base::Base, node::Node, node::NodeBase};
 node_builder_impl!(DataTypeBuilder, DataType);
 
 /// A `DataType` is a type of node within the `AddressSpace`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DataType {
     base: Base,
     is_abstract: bool,
@@ -27,6 +27,7 @@ impl Default for DataType {
 }
 
 node_base_impl!(DataType);
+node_clone_impl!(DataType);
 
 impl Node for DataType {
     fn get_attribute_max_age(
@@ -54,6 +55,9 @@ impl Node for DataType {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::IsAbstract => {
                 if let Variant::Boolean(v) = value {
@@ -123,6 +123,9 @@ impl Node for Method {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::Executable => {
                 if let Variant::Boolean(v) = value {
@@ -391,6 +391,10 @@ macro_rules! node_base_impl {
         }
 
         impl NodeBase for $node_struct {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
             fn node_class(&self) -> NodeClass {
                 self.base.node_class()
             }
@@ -438,11 +442,32 @@ macro_rules! node_base_impl {
     };
 }
 
+/// Implements `clone_with_id` for the node types which can be copied. Methods are excluded
+/// because their callback cannot be copied.
+macro_rules! node_clone_impl {
+    ( $node_struct:ident ) => {
+        impl $node_struct {
+            /// Returns a copy of the node with a new node id and browse name, e.g. to create
+            /// several similar nodes from a template. Every other attribute is copied as is.
+            pub fn clone_with_id<S>(&self, node_id: &NodeId, browse_name: S) -> Self
+            where
+                S: Into<QualifiedName>,
+            {
+                let mut node = self.clone();
+                node.base.set_node_id(node_id.clone());
+                node.base.set_browse_name(browse_name);
+                node
+            }
+        }
+    };
+}
+
 pub mod address_space;
 pub mod base;
 pub mod data_type;
 pub mod method;
 pub mod node;
+pub mod nodeset;
 pub mod object;
 pub mod object_type;
 pub mod reference_type;
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
+use std::any::Any;
+
 use opcua_types::{
     service_types::NodeClass, status_code::StatusCode, AttributeId, DataValue, LocalizedText,
     NodeId, NumericRange, QualifiedName, TimestampsToReturn, Variant, WriteMask,
@@ -61,6 +63,22 @@ impl NodeType {
         }
     }
 
+    /// Returns a copy of the node with a new node id and browse name, or `None` for a method
+    /// since its callback cannot be copied.
+    pub fn clone_with_id(&self, node_id: &NodeId, browse_name: QualifiedName) -> Option<NodeType> {
+        let node_type = match self {
+            NodeType::Object(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::ObjectType(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::ReferenceType(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::Variable(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::VariableType(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::View(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::DataType(value) => value.clone_with_id(node_id, browse_name).into(),
+            NodeType::Method(_) => return None,
+        };
+        Some(node_type)
+    }
+
     // Returns the `NodeClass` of this `NodeType`.
     pub fn node_class(&self) -> NodeClass {
         match self {
@@ -79,6 +97,9 @@ impl NodeType {
 /// Implemented within a macro for all Node types. Functions that return a result in an Option
 /// do so because the attribute is optional and not necessarily there.
 pub trait NodeBase {
+    /// Returns the node as `Any` so that a trait object can be downcast to its concrete type
+    fn as_any(&self) -> &dyn Any;
+
     /// Returns the node class - Object, ObjectType, Method, DataType, ReferenceType, Variable, VariableType or View
     fn node_class(&self) -> NodeClass;
 
@@ -105,6 +126,74 @@ pub trait NodeBase {
     fn user_write_mask(&self) -> Option<WriteMask>;
 
     fn set_user_write_mask(&mut self, write_mask: WriteMask);
+
+    /// Tests if the node's write mask permits the attribute to be written. A node without a
+    /// write mask permits nothing. The value of a variable is governed by its access level
+    /// rather than the write mask so it is always permitted here.
+    fn write_mask_permits(&self, attribute_id: AttributeId) -> bool {
+        if attribute_id == AttributeId::Value && self.node_class() == NodeClass::Variable {
+            return true;
+        }
+        if let Some(write_mask) = self.write_mask() {
+            match attribute_id {
+                AttributeId::Value => {
+                    self.node_class() == NodeClass::VariableType
+                        && write_mask.contains(WriteMask::VALUE_FOR_VARIABLE_TYPE)
+                }
+                AttributeId::NodeId => write_mask.contains(WriteMask::NODE_ID),
+                AttributeId::NodeClass => write_mask.contains(WriteMask::NODE_CLASS),
+                AttributeId::BrowseName => write_mask.contains(WriteMask::BROWSE_NAME),
+                AttributeId::DisplayName => write_mask.contains(WriteMask::DISPLAY_NAME),
+                AttributeId::Description => write_mask.contains(WriteMask::DESCRIPTION),
+                AttributeId::WriteMask => write_mask.contains(WriteMask::WRITE_MASK),
+                AttributeId::UserWriteMask => write_mask.contains(WriteMask::USER_WRITE_MASK),
+                AttributeId::IsAbstract => write_mask.contains(WriteMask::IS_ABSTRACT),
+                AttributeId::Symmetric => write_mask.contains(WriteMask::SYMMETRIC),
+                AttributeId::InverseName => write_mask.contains(WriteMask::INVERSE_NAME),
+                AttributeId::ContainsNoLoops => write_mask.contains(WriteMask::CONTAINS_NO_LOOPS),
+                AttributeId::EventNotifier => write_mask.contains(WriteMask::EVENT_NOTIFIER),
+                AttributeId::DataType => write_mask.contains(WriteMask::DATA_TYPE),
+                AttributeId::ValueRank => write_mask.contains(WriteMask::VALUE_RANK),
+                AttributeId::ArrayDimensions => write_mask.contains(WriteMask::ARRAY_DIMENSIONS),
+                AttributeId::AccessLevel => write_mask.contains(WriteMask::ACCESS_LEVEL),
+                AttributeId::UserAccessLevel => write_mask.contains(WriteMask::USER_ACCESS_LEVEL),
+                AttributeId::MinimumSamplingInterval => {
+                    write_mask.contains(WriteMask::MINIMUM_SAMPLING_INTERVAL)
+                }
+                AttributeId::Historizing => write_mask.contains(WriteMask::HISTORIZING),
+                AttributeId::Executable => write_mask.contains(WriteMask::EXECUTABLE),
+                AttributeId::UserExecutable => write_mask.contains(WriteMask::USER_EXECUTABLE),
+                AttributeId::DataTypeDefinition => {
+                    write_mask.contains(WriteMask::DATA_TYPE_DEFINITION)
+                }
+                AttributeId::RolePermissions => write_mask.contains(WriteMask::ROLE_PERMISSIONS),
+                AttributeId::AccessRestrictions => {
+                    write_mask.contains(WriteMask::ACCESS_RESTRICTIONS)
+                }
+                AttributeId::AccessLevelEx => write_mask.contains(WriteMask::ACCESS_LEVEL_EX),
+                AttributeId::UserRolePermissions => false, // Reserved
+            }
+        } else {
+            false
+        }
+    }
+}
+
+impl<'a> dyn Node + 'a {
+    /// Downcasts the node to its concrete type, returning `None` if it is some other type.
+    pub fn downcast_ref<T: Node + 'static>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+
+    /// Returns the node as a `Variable` or `None` if it is not a variable.
+    pub fn as_variable(&self) -> Option<&Variable> {
+        self.downcast_ref()
+    }
+
+    /// Returns the node as a `ReferenceType` or `None` if it is not a reference type.
+    pub fn as_reference_type(&self) -> Option<&ReferenceType> {
+        self.downcast_ref()
+    }
 }
 
 /// Implemented by each node type's to provide a generic way to set or get attributes, e.g.
@@ -144,7 +233,60 @@ pub trait Node: NodeBase {
         )
     }
 
-    /// Sets the attribute with the new value
+    /// Finds the attribute and returns its value with a status that tells apart why there may be
+    /// no value:
+    ///
+    /// * `Good` - the attribute has a value
+    /// * `GoodNoData` - the attribute belongs to the node class but is optional and is not set
+    /// * `BadAttributeIdInvalid` - the attribute does not belong to the node class
+    fn get_attribute_value(
+        &self,
+        timestamps_to_return: TimestampsToReturn,
+        attribute_id: AttributeId,
+        index_range: NumericRange,
+        data_encoding: &QualifiedName,
+        max_age: f64,
+    ) -> DataValue {
+        let status = if !attribute_id.is_valid_for(self.node_class()) {
+            StatusCode::BadAttributeIdInvalid
+        } else if let Some(value) = self.get_attribute_max_age(
+            timestamps_to_return,
+            attribute_id,
+            index_range,
+            data_encoding,
+            max_age,
+        ) {
+            return value;
+        } else {
+            StatusCode::GoodNoData
+        };
+        DataValue {
+            status: Some(status),
+            ..DataValue::null()
+        }
+    }
+
+    /// Returns the attributes which have a value for this node, i.e. the attributes of its node
+    /// class less any optional ones that are not set. Values are requested with a `max_age` that
+    /// asks for a cached value so a variable's getter is not made to fetch a new one.
+    fn readable_attributes(&self) -> Vec<AttributeId> {
+        AttributeId::for_node_class(self.node_class())
+            .into_iter()
+            .filter(|attribute_id| {
+                self.get_attribute_max_age(
+                    TimestampsToReturn::Neither,
+                    *attribute_id,
+                    NumericRange::None,
+                    &QualifiedName::null(),
+                    f64::from(i32::MAX),
+                )
+                .is_some()
+            })
+            .collect()
+    }
+
+    /// Sets the attribute with the new value. The write is rejected with `BadNotWritable` if
+    /// the node's write mask does not permit the attribute to be written.
     fn set_attribute(
         &mut self,
         attribute_id: AttributeId,
@@ -0,0 +1,431 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains an importer which populates an address space from a `UANodeSet` XML file, the
+//! standard format in which information models are published.
+
+use std::{collections::HashMap, error, fmt, io, io::Read, str::FromStr};
+
+use roxmltree::{Document, Node};
+
+use opcua_types::{
+    service_types::{ObjectAttributes, ReferenceTypeAttributes, VariableAttributes},
+    *,
+};
+
+use crate::address_space::{
+    object::Object, reference_type::ReferenceType, variable::Variable, AddressSpace,
+};
+
+/// The namespace of the elements which hold a variable's value in a node set
+const TYPES_NAMESPACE: &str = "http://opcfoundation.org/UA/2008/02/Types.xsd";
+
+/// The ways in which importing a node set can fail.
+#[derive(Debug)]
+pub enum ImportError {
+    /// The node set could not be read
+    Io(io::Error),
+    /// The node set is not well formed XML
+    Xml(String),
+    /// A node id, alias or qualified name could not be parsed or refers to an unknown namespace
+    InvalidNodeId(String),
+    /// An attribute or value of a node could not be parsed
+    InvalidValue(String),
+    /// A node could not be created from its attributes or already exists
+    InvalidNode(NodeId),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImportError::Io(err) => write!(f, "node set could not be read, {}", err),
+            ImportError::Xml(err) => write!(f, "node set is not valid xml, {}", err),
+            ImportError::InvalidNodeId(v) => write!(f, "node id {} is invalid", v),
+            ImportError::InvalidValue(v) => write!(f, "value {} is invalid", v),
+            ImportError::InvalidNode(node_id) => write!(f, "node {} is invalid", node_id),
+        }
+    }
+}
+
+impl error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ImportError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImportError {
+    fn from(err: io::Error) -> Self {
+        ImportError::Io(err)
+    }
+}
+
+/// Reads a `UANodeSet` XML document and returns an address space holding the default nodes plus
+/// the `UAObject`, `UAVariable` and `UAReferenceType` nodes of the node set and their references.
+/// Other kinds of node are skipped with a warning.
+///
+/// The node set's namespace uris are registered with the address space and the namespace indexes
+/// of its node ids and browse names are remapped to suit. Aliases may be used wherever a node id
+/// is expected, e.g. for a reference type or data type.
+pub fn load_nodeset<R>(mut reader: R) -> Result<AddressSpace, ImportError>
+where
+    R: Read,
+{
+    let mut xml = String::new();
+    reader.read_to_string(&mut xml)?;
+    let document = Document::parse(&xml).map_err(|err| ImportError::Xml(err.to_string()))?;
+
+    let mut address_space = AddressSpace::new();
+    let mut importer = Importer {
+        namespaces: vec![0],
+        aliases: HashMap::new(),
+    };
+
+    let root = document.root_element();
+    for element in child_elements(root) {
+        match element.tag_name().name() {
+            "NamespaceUris" => {
+                for uri in child_elements(element) {
+                    let uri = uri.text().unwrap_or("");
+                    let namespace = address_space
+                        .register_namespace(uri)
+                        .map_err(|_| ImportError::InvalidValue(uri.to_string()))?;
+                    importer.namespaces.push(namespace);
+                }
+            }
+            "Aliases" => {
+                for alias in child_elements(element) {
+                    if let Some(name) = alias.attribute("Alias") {
+                        let node_id = importer.node_id(alias.text().unwrap_or(""))?;
+                        importer.aliases.insert(name.to_string(), node_id);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // References are inserted after all the nodes so they may point at nodes which appear later
+    let mut references = Vec::new();
+    for element in child_elements(root) {
+        let node_class = element.tag_name().name();
+        if !node_class.starts_with("UA") {
+            continue;
+        }
+        let node_id = importer.node_id(required_attribute(element, "NodeId")?)?;
+        let inserted = match node_class {
+            "UAObject" => importer
+                .object(&node_id, element)
+                .map(|node| address_space.insert::<Object, ReferenceTypeId>(node, None)),
+            "UAVariable" => importer
+                .variable(&node_id, element)
+                .map(|node| address_space.insert::<Variable, ReferenceTypeId>(node, None)),
+            "UAReferenceType" => importer
+                .reference_type(&node_id, element)
+                .map(|node| address_space.insert::<ReferenceType, ReferenceTypeId>(node, None)),
+            _ => {
+                warn!("Node {} is a {} which is not imported", node_id, node_class);
+                continue;
+            }
+        }?;
+        if !inserted {
+            return Err(ImportError::InvalidNode(node_id));
+        }
+        for reference in child_elements(element)
+            .filter(|e| e.tag_name().name() == "References")
+            .flat_map(child_elements)
+        {
+            let reference_type =
+                importer.node_id(required_attribute(reference, "ReferenceType")?)?;
+            let target = importer.node_id(reference.text().unwrap_or(""))?;
+            if reference.attribute("IsForward") == Some("false") {
+                references.push((target, node_id.clone(), reference_type));
+            } else {
+                references.push((node_id.clone(), target, reference_type));
+            }
+        }
+    }
+    for (source, target, reference_type) in references {
+        address_space.insert_reference(&source, &target, reference_type);
+    }
+
+    Ok(address_space)
+}
+
+fn child_elements<'a, 'input>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(|n| n.is_element())
+}
+
+fn child_element<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    child_elements(node).find(|n| n.tag_name().name() == name)
+}
+
+fn required_attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, ImportError> {
+    node.attribute(name).ok_or_else(|| {
+        ImportError::InvalidValue(format!(
+            "{} is missing attribute {}",
+            node.tag_name().name(),
+            name
+        ))
+    })
+}
+
+fn parse<T>(value: &str) -> Result<T, ImportError>
+where
+    T: FromStr,
+{
+    T::from_str(value.trim()).map_err(|_| ImportError::InvalidValue(value.to_string()))
+}
+
+fn parse_attribute<T>(node: Node, name: &str, default: T) -> Result<T, ImportError>
+where
+    T: FromStr,
+{
+    node.attribute(name).map_or(Ok(default), parse)
+}
+
+fn localized_text(node: Node) -> LocalizedText {
+    LocalizedText::new(
+        node.attribute("Locale").unwrap_or(""),
+        node.text().unwrap_or(""),
+    )
+}
+
+/// Holds what is needed to turn the node set's ids into those of the address space
+struct Importer {
+    /// The address space namespace index of each node set namespace index
+    namespaces: Vec<u16>,
+    aliases: HashMap<String, NodeId>,
+}
+
+impl Importer {
+    fn namespace(&self, namespace: u16) -> Result<u16, ImportError> {
+        self.namespaces
+            .get(namespace as usize)
+            .copied()
+            .ok_or_else(|| ImportError::InvalidNodeId(format!("ns={}", namespace)))
+    }
+
+    /// Parses a node id or an alias of one
+    fn node_id(&self, value: &str) -> Result<NodeId, ImportError> {
+        let value = value.trim();
+        if let Some(node_id) = self.aliases.get(value) {
+            Ok(node_id.clone())
+        } else {
+            let node_id = NodeId::from_str(value)
+                .map_err(|_| ImportError::InvalidNodeId(value.to_string()))?;
+            Ok(NodeId {
+                namespace: self.namespace(node_id.namespace)?,
+                identifier: node_id.identifier,
+            })
+        }
+    }
+
+    /// Parses a browse name of the form `name` or `namespace:name`
+    fn browse_name(&self, node: Node) -> Result<QualifiedName, ImportError> {
+        let value = required_attribute(node, "BrowseName")?;
+        let mut parts = value.splitn(2, ':');
+        match (parts.next(), parts.next()) {
+            (Some(namespace), Some(name)) if namespace.parse::<u16>().is_ok() => {
+                Ok(QualifiedName::new(self.namespace(parse(namespace)?)?, name))
+            }
+            _ => Ok(QualifiedName::new(0, value)),
+        }
+    }
+
+    /// Returns the display name, which defaults to the name part of the browse name, and the
+    /// description. The mask says which of the two were present.
+    fn display_name_and_description(
+        &self,
+        node: Node,
+        browse_name: &QualifiedName,
+    ) -> (LocalizedText, LocalizedText, AttributesMask) {
+        let mut mask = AttributesMask::DISPLAY_NAME;
+        let display_name = child_element(node, "DisplayName")
+            .map(localized_text)
+            .unwrap_or_else(|| LocalizedText::new("", browse_name.name.as_ref()));
+        let description = match child_element(node, "Description") {
+            Some(description) => {
+                mask |= AttributesMask::DESCRIPTION;
+                localized_text(description)
+            }
+            None => LocalizedText::null(),
+        };
+        (display_name, description, mask)
+    }
+
+    fn object(&self, node_id: &NodeId, node: Node) -> Result<Object, ImportError> {
+        let browse_name = self.browse_name(node)?;
+        let (display_name, description, mask) =
+            self.display_name_and_description(node, &browse_name);
+        let attributes = ObjectAttributes {
+            specified_attributes: (mask | AttributesMask::EVENT_NOTIFIER).bits(),
+            display_name,
+            description,
+            write_mask: 0,
+            user_write_mask: 0,
+            event_notifier: parse_attribute(node, "EventNotifier", 0)?,
+        };
+        Object::from_attributes(node_id, browse_name, attributes)
+            .map_err(|_| ImportError::InvalidNode(node_id.clone()))
+    }
+
+    fn variable(&self, node_id: &NodeId, node: Node) -> Result<Variable, ImportError> {
+        let browse_name = self.browse_name(node)?;
+        let (display_name, description, mut mask) =
+            self.display_name_and_description(node, &browse_name);
+        mask |= AttributesMask::ACCESS_LEVEL
+            | AttributesMask::USER_ACCESS_LEVEL
+            | AttributesMask::DATA_TYPE
+            | AttributesMask::HISTORIZING
+            | AttributesMask::VALUE
+            | AttributesMask::VALUE_RANK
+            | AttributesMask::MINIMUM_SAMPLING_INTERVAL;
+        let data_type = match node.attribute("DataType") {
+            Some(data_type) => self.node_id(data_type)?,
+            None => DataTypeId::BaseDataType.into(),
+        };
+        let array_dimensions = match node.attribute("ArrayDimensions") {
+            Some(array_dimensions) => {
+                mask |= AttributesMask::ARRAY_DIMENSIONS;
+                Some(
+                    array_dimensions
+                        .split(',')
+                        .map(parse)
+                        .collect::<Result<Vec<u32>, _>>()?,
+                )
+            }
+            None => None,
+        };
+        let value = match child_element(node, "Value").and_then(|v| child_elements(v).next()) {
+            Some(value) => variant(value)?,
+            None => Variant::Empty,
+        };
+        let attributes = VariableAttributes {
+            specified_attributes: mask.bits(),
+            display_name,
+            description,
+            write_mask: 0,
+            user_write_mask: 0,
+            value,
+            data_type,
+            value_rank: parse_attribute(node, "ValueRank", -1)?,
+            array_dimensions,
+            access_level: parse_attribute(node, "AccessLevel", 1)?,
+            user_access_level: parse_attribute(node, "UserAccessLevel", 1)?,
+            minimum_sampling_interval: parse_attribute(node, "MinimumSamplingInterval", 0.0)?,
+            historizing: parse_attribute(node, "Historizing", false)?,
+        };
+        Variable::from_attributes(node_id, browse_name, attributes)
+            .map_err(|_| ImportError::InvalidNode(node_id.clone()))
+    }
+
+    fn reference_type(&self, node_id: &NodeId, node: Node) -> Result<ReferenceType, ImportError> {
+        let browse_name = self.browse_name(node)?;
+        let (display_name, description, mut mask) =
+            self.display_name_and_description(node, &browse_name);
+        mask |= AttributesMask::IS_ABSTRACT | AttributesMask::SYMMETRIC;
+        let inverse_name = match child_element(node, "InverseName") {
+            Some(inverse_name) => {
+                mask |= AttributesMask::INVERSE_NAME;
+                localized_text(inverse_name)
+            }
+            None => LocalizedText::null(),
+        };
+        let attributes = ReferenceTypeAttributes {
+            specified_attributes: mask.bits(),
+            display_name,
+            description,
+            write_mask: 0,
+            user_write_mask: 0,
+            is_abstract: parse_attribute(node, "IsAbstract", false)?,
+            symmetric: parse_attribute(node, "Symmetric", false)?,
+            inverse_name,
+        };
+        ReferenceType::from_attributes(node_id, browse_name, attributes)
+            .map_err(|_| ImportError::InvalidNode(node_id.clone()))
+    }
+}
+
+/// Returns the variant type of a value element's name, e.g. `Int32` or the `Int32` of `ListOfInt32`
+fn variant_type_id(name: &str) -> Option<VariantTypeId> {
+    let type_id = match name {
+        "Boolean" => VariantTypeId::Boolean,
+        "SByte" => VariantTypeId::SByte,
+        "Byte" => VariantTypeId::Byte,
+        "Int16" => VariantTypeId::Int16,
+        "UInt16" => VariantTypeId::UInt16,
+        "Int32" => VariantTypeId::Int32,
+        "UInt32" => VariantTypeId::UInt32,
+        "Int64" => VariantTypeId::Int64,
+        "UInt64" => VariantTypeId::UInt64,
+        "Float" => VariantTypeId::Float,
+        "Double" => VariantTypeId::Double,
+        "String" => VariantTypeId::String,
+        "DateTime" => VariantTypeId::DateTime,
+        "LocalizedText" => VariantTypeId::LocalizedText,
+        _ => return None,
+    };
+    Some(type_id)
+}
+
+/// Reads the value of a variable. Scalars and `ListOf` arrays of the numeric types, booleans,
+/// strings, date times and localized texts are supported, any other value is left empty.
+fn variant(node: Node) -> Result<Variant, ImportError> {
+    let name = node.tag_name().name();
+    if node.tag_name().namespace() != Some(TYPES_NAMESPACE) {
+        warn!(
+            "Value {} is not in the types namespace and is ignored",
+            name
+        );
+        return Ok(Variant::Empty);
+    }
+    if let Some(element_name) = name.strip_prefix("ListOf") {
+        if let Some(type_id) = variant_type_id(element_name) {
+            let values = child_elements(node)
+                .map(|v| scalar(type_id, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(if values.is_empty() {
+                Variant::empty_array(type_id)
+            } else {
+                Variant::from(values)
+            });
+        }
+    } else if let Some(type_id) = variant_type_id(name) {
+        return scalar(type_id, node);
+    }
+    warn!("Value of type {} is not supported and is ignored", name);
+    Ok(Variant::Empty)
+}
+
+fn scalar(type_id: VariantTypeId, node: Node) -> Result<Variant, ImportError> {
+    let text = node.text().unwrap_or("");
+    let value = match type_id {
+        VariantTypeId::Boolean => Variant::Boolean(parse(text)?),
+        VariantTypeId::SByte => Variant::SByte(parse(text)?),
+        VariantTypeId::Byte => Variant::Byte(parse(text)?),
+        VariantTypeId::Int16 => Variant::Int16(parse(text)?),
+        VariantTypeId::UInt16 => Variant::UInt16(parse(text)?),
+        VariantTypeId::Int32 => Variant::Int32(parse(text)?),
+        VariantTypeId::UInt32 => Variant::UInt32(parse(text)?),
+        VariantTypeId::Int64 => Variant::Int64(parse(text)?),
+        VariantTypeId::UInt64 => Variant::UInt64(parse(text)?),
+        VariantTypeId::Float => Variant::Float(parse(text)?),
+        VariantTypeId::Double => Variant::Double(parse(text)?),
+        VariantTypeId::String => Variant::from(text),
+        VariantTypeId::DateTime => Variant::from(parse::<DateTime>(text)?),
+        VariantTypeId::LocalizedText => Variant::from(LocalizedText::new(
+            child_element(node, "Locale")
+                .and_then(|v| v.text())
+                .unwrap_or(""),
+            child_element(node, "Text")
+                .and_then(|v| v.text())
+                .unwrap_or(""),
+        )),
+        _ => Variant::Empty,
+    };
+    Ok(value)
+}
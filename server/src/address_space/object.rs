@@ -46,7 +46,7 @@ impl ObjectBuilder {
 }
 
 /// An `Object` is a type of node within the `AddressSpace`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Object {
     base: Base,
     event_notifier: EventNotifier,
@@ -62,6 +62,7 @@ impl Default for Object {
 }
 
 node_base_impl!(Object);
+node_clone_impl!(Object);
 
 impl Node for Object {
     fn get_attribute_max_age(
@@ -89,6 +90,9 @@ impl Node for Object {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::EventNotifier => {
                 if let Variant::Byte(v) = value {
@@ -23,7 +23,7 @@ impl ObjectTypeBuilder {
 }
 
 /// An `ObjectType` is a type of node within the `AddressSpace`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ObjectType {
     base: Base,
     is_abstract: bool,
@@ -39,6 +39,7 @@ impl Default for ObjectType {
 }
 
 node_base_impl!(ObjectType);
+node_clone_impl!(ObjectType);
 
 impl Node for ObjectType {
     fn get_attribute_max_age(
@@ -66,6 +67,9 @@ impl Node for ObjectType {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::IsAbstract => {
                 if let Variant::Boolean(v) = value {
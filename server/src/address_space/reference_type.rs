@@ -12,7 +12,7 @@ node_builder_impl!(ReferenceTypeBuilder, ReferenceType);
 node_builder_impl_subtype!(ReferenceTypeBuilder);
 
 /// A `ReferenceType` is a type of node within the `AddressSpace`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReferenceType {
     base: Base,
     symmetric: bool,
@@ -32,6 +32,7 @@ impl Default for ReferenceType {
 }
 
 node_base_impl!(ReferenceType);
+node_clone_impl!(ReferenceType);
 
 impl Node for ReferenceType {
     fn get_attribute_max_age(
@@ -61,6 +62,9 @@ impl Node for ReferenceType {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::Symmetric => {
                 if let Variant::Boolean(v) = value {
@@ -145,7 +149,7 @@ impl ReferenceType {
                 node.set_is_abstract(attributes.is_abstract);
             }
             if mask.contains(AttributesMask::SYMMETRIC) {
-                node.set_symmetric(attributes.is_abstract);
+                node.set_symmetric(attributes.symmetric);
             }
             if mask.contains(AttributesMask::INVERSE_NAME) {
                 node.set_inverse_name(attributes.inverse_name);
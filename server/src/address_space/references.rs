@@ -52,6 +52,21 @@ impl Default for References {
 }
 
 impl References {
+    /// Calls the visitor with the source node and each of its forward references. Source nodes
+    /// are visited in node id order and each node's references in the order they were inserted.
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&NodeId, &Reference),
+    {
+        let mut source_nodes = self.references_map.keys().collect::<Vec<_>>();
+        source_nodes.sort();
+        source_nodes.into_iter().for_each(|source_node| {
+            self.references_map[source_node]
+                .iter()
+                .for_each(|reference| f(source_node, reference));
+        });
+    }
+
     /// Inserts a single reference into the map.
     pub fn insert<T>(
         &mut self,
@@ -43,10 +43,16 @@ impl VariableBuilder {
         self
     }
 
-    /// Sets the historizing flag for the variable.
+    /// Sets the historizing flag for the variable. A historizing variable is also made
+    /// history-readable so that history clients see it has history. Use `history_updatable()` if
+    /// its history may be written too.
     pub fn historizing(mut self, historizing: bool) -> Self {
         self.node.set_historizing(historizing);
-        self
+        if historizing {
+            self.history_readable()
+        } else {
+            self
+        }
     }
 
     /// Sets the access level for the variable.
@@ -151,7 +157,7 @@ impl VariableBuilder {
 // Note we use derivative builder macro so we can skip over the value getter / setter
 
 /// A `Variable` is a type of node within the `AddressSpace`.
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
 pub struct Variable {
     base: Base,
@@ -188,6 +194,7 @@ impl Default for Variable {
 }
 
 node_base_impl!(Variable);
+node_clone_impl!(Variable);
 
 impl Node for Variable {
     fn get_attribute_max_age(
@@ -233,6 +240,9 @@ impl Node for Variable {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::DataType => {
                 if let Variant::NodeId(v) = value {
@@ -548,6 +558,80 @@ impl Variable {
         }
     }
 
+    /// Validates that the value is consistent with the variable's value rank and array dimensions.
+    /// A value of the wrong array-ness or with the wrong number of dimensions returns
+    /// `BadTypeMismatch`. A dimension whose length differs from a non-zero declared array
+    /// dimension returns `BadIndexRangeNoData`. An empty value is always accepted.
+    pub fn validate_value(&self, value: &Variant) -> Result<(), StatusCode> {
+        // A byte string may be written to a single dimension byte array
+        let byte_array;
+        let value = match value {
+            Variant::ByteString(_)
+                if self.value_rank != -1 && self.data_type == DataTypeId::Byte.into() =>
+            {
+                byte_array = value.to_byte_array();
+                &byte_array
+            }
+            value => value,
+        };
+
+        // The lengths of each dimension of the value, or None for a scalar
+        let dimensions = match value {
+            Variant::Empty => return Ok(()),
+            Variant::Array(array) => Some(if array.dimensions.is_empty() {
+                vec![array.values.len() as u32]
+            } else {
+                array.dimensions.clone()
+            }),
+            _ => None,
+        };
+
+        let valid_rank = match (self.value_rank, &dimensions) {
+            // Any
+            (-2, _) => true,
+            // Scalar or one dimension
+            (-3, None) => true,
+            (-3, Some(dimensions)) => dimensions.len() == 1,
+            // Scalar
+            (-1, dimensions) => dimensions.is_none(),
+            // One or more dimensions
+            (0, dimensions) => dimensions.is_some(),
+            // Exactly the number of dimensions
+            (value_rank, Some(dimensions)) if value_rank > 0 => {
+                dimensions.len() == value_rank as usize
+            }
+            _ => false,
+        };
+        if !valid_rank {
+            error!(
+                "Value {:?} does not match value rank {} of variable {}",
+                value,
+                self.value_rank,
+                self.node_id()
+            );
+            return Err(StatusCode::BadTypeMismatch);
+        }
+
+        if let (Some(dimensions), Some(array_dimensions)) = (dimensions, &self.array_dimensions) {
+            if dimensions.len() == array_dimensions.len() {
+                let valid_dimensions = dimensions
+                    .iter()
+                    .zip(array_dimensions.iter())
+                    .all(|(length, declared)| *declared == 0 || length == declared);
+                if !valid_dimensions {
+                    error!(
+                        "Value dimensions {:?} do not match array dimensions {:?} of variable {}",
+                        dimensions,
+                        array_dimensions,
+                        self.node_id()
+                    );
+                    return Err(StatusCode::BadIndexRangeNoData);
+                }
+            }
+        }
+        Ok(())
+    }
+
     // Set a range value
     pub fn set_value_range(
         &mut self,
@@ -16,7 +16,7 @@ node_builder_impl_generates_event!(VariableTypeBuilder);
 node_builder_impl_subtype!(VariableTypeBuilder);
 
 /// A `VariableType` is a type of node within the `AddressSpace`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VariableType {
     base: Base,
     data_type: NodeId,
@@ -40,6 +40,7 @@ impl Default for VariableType {
 }
 
 node_base_impl!(VariableType);
+node_clone_impl!(VariableType);
 
 impl Node for VariableType {
     fn get_attribute_max_age(
@@ -74,6 +75,9 @@ impl Node for VariableType {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::DataType => {
                 if let Variant::NodeId(v) = value {
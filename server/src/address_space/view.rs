@@ -25,7 +25,7 @@ impl ViewBuilder {
 }
 
 /// A `View` is a type of node within the `AddressSpace`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct View {
     base: Base,
     event_notifier: EventNotifier,
@@ -43,6 +43,7 @@ impl Default for View {
 }
 
 node_base_impl!(View);
+node_clone_impl!(View);
 
 impl Node for View {
     fn get_attribute_max_age(
@@ -71,6 +72,9 @@ impl Node for View {
         attribute_id: AttributeId,
         value: Variant,
     ) -> Result<(), StatusCode> {
+        if !self.write_mask_permits(attribute_id) {
+            return Err(StatusCode::BadNotWritable);
+        }
         match attribute_id {
             AttributeId::EventNotifier => {
                 if let Variant::Byte(v) = value {
@@ -338,6 +338,18 @@ impl ServerBuilder {
         self
     }
 
+    /// Set the max depth to which decoded values may nest inside one another
+    pub fn max_nesting_depth(mut self, max_nesting_depth: u32) -> Self {
+        self.config.limits.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// Set the max number of requests a client may have in flight on a connection, 0 for no limit
+    pub fn max_outstanding_requests(mut self, max_outstanding_requests: u32) -> Self {
+        self.config.limits.max_outstanding_requests = max_outstanding_requests;
+        self
+    }
+
     /// Sets the server to automatically trust client certs. This subverts the
     /// authentication during handshake, so only do this if you understand the risks.
     pub fn trust_client_certs(mut self) -> Self {
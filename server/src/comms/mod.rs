@@ -5,7 +5,7 @@
 //! Provides communication services for the server such as the transport layer and secure
 //! channel implementation
 
-mod secure_channel_service;
+pub(crate) mod secure_channel_service;
 
 pub mod tcp_transport;
 pub mod transport;
@@ -179,7 +179,7 @@ impl SecureChannelService {
 
         let response = OpenSecureChannelResponse {
             response_header: ResponseHeader::new_good(&request.request_header),
-            server_protocol_version: 0,
+            server_protocol_version: PROTOCOL_VERSION,
             security_token: ChannelSecurityToken {
                 channel_id: secure_channel.secure_channel_id(),
                 token_id: secure_channel.token_id(),
@@ -191,11 +191,39 @@ impl SecureChannelService {
         Ok(response.into())
     }
 
+    /// Tests if a secure channel has been issued and not yet closed
+    pub fn is_open(&self) -> bool {
+        self.secure_channel_state.issued
+    }
+
+    /// Closes the secure channel, releasing its id and token so that nothing further can be sent
+    /// over it. There is no response to a close, the caller is expected to drop the connection.
     pub fn close_secure_channel(
         &mut self,
-        _: &SupportedMessage,
-    ) -> Result<SupportedMessage, StatusCode> {
-        info!("CloseSecureChannelRequest received, session closing");
-        Err(StatusCode::BadConnectionClosed)
+        secure_channel: &mut SecureChannel,
+        message: &SupportedMessage,
+    ) -> Result<(), StatusCode> {
+        match message {
+            SupportedMessage::CloseSecureChannelRequest(_) => {}
+            _ => {
+                error!(
+                    "message is not a close secure channel request, got {:?}",
+                    message
+                );
+                return Err(StatusCode::BadUnexpectedError);
+            }
+        }
+        if !self.secure_channel_state.issued {
+            error!("Asked to close a secure channel that is not open");
+            return Err(StatusCode::BadSecureChannelClosed);
+        }
+
+        info!(
+            "CloseSecureChannelRequest received, closing secure channel {}",
+            secure_channel.secure_channel_id()
+        );
+        self.secure_channel_state.issued = false;
+        secure_channel.clear_security_token();
+        Ok(())
     }
 }
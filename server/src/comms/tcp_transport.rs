@@ -140,7 +140,13 @@ pub struct TcpTransport {
     /// Last decoded sequence number
     last_received_sequence_number: u32,
     /// A message may consist of one or more chunks which are stored here until complete.
-    pending_chunks: Vec<MessageChunk>,
+    pending_chunks: PendingChunks,
+    /// Requests which have been dispatched but whose responses have not been written yet.
+    in_flight_requests: InFlightRequests,
+    /// How long a new connection may wait for its hello before it is closed
+    hello_timeout: chrono::Duration,
+    /// Sequences the hello and acknowledge which open the connection
+    handshake: HandshakeState,
 }
 
 impl Transport for TcpTransport {
@@ -193,6 +199,14 @@ impl TcpTransport {
             (session.secure_channel(), session.session_id().clone())
         };
         let secure_channel_service = SecureChannelService::new();
+        let (max_outstanding_requests, hello_timeout) = {
+            let server_state = trace_read_lock_unwrap!(server_state);
+            let config = trace_read_lock_unwrap!(server_state.config);
+            (
+                config.limits.max_outstanding_requests as usize,
+                chrono::Duration::seconds(config.tcp_config.hello_timeout as i64),
+            )
+        };
         TcpTransport {
             server_state,
             session,
@@ -205,7 +219,29 @@ impl TcpTransport {
             secure_channel_service,
             client_protocol_version: 0,
             last_received_sequence_number: 0,
-            pending_chunks: Vec::with_capacity(2),
+            pending_chunks: PendingChunks::new(MAX_CHUNK_COUNT),
+            in_flight_requests: InFlightRequests::new(max_outstanding_requests),
+            hello_timeout,
+            handshake: HandshakeState::default(),
+        }
+    }
+
+    /// Tests if the connection has been waiting for its hello for longer than the hello timeout
+    /// since the session started. If it has, the transport is finished with `BadTimeout` and
+    /// `true` is returned.
+    pub fn check_hello_timeout(
+        &mut self,
+        session_start_time: &chrono::DateTime<Utc>,
+        now: &chrono::DateTime<Utc>,
+    ) -> bool {
+        if self.has_received_hello() || self.is_finished() {
+            false
+        } else if now.signed_duration_since(*session_start_time) > self.hello_timeout {
+            info!("Session has been waiting for a hello for more than the timeout period and will now close");
+            self.finish(StatusCode::BadTimeout);
+            true
+        } else {
+            false
         }
     }
 
@@ -408,7 +444,9 @@ impl TcpTransport {
                         }
                         false
                     }
-                    Message::Message(_, response) => {
+                    Message::Message(request_id, response) => {
+                        // The response frees the slot its request held
+                        transport.in_flight_requests.end(*request_id);
                         if let SupportedMessage::Invalid(_) = response {
                             error!("Writer terminating - received an invalid message");
                             transport.finish(StatusCode::BadCommunicationError);
@@ -537,7 +575,8 @@ impl TcpTransport {
                                 session_status_code = result.unwrap_err();
                             }
                         } else {
-                            session_status_code = StatusCode::BadCommunicationError;
+                            let mut transport = trace_write_lock_unwrap!(transport);
+                            session_status_code = transport.process_unexpected_message(&message);
                         }
                     }
                     TransportState::ProcessMessages => {
@@ -548,7 +587,8 @@ impl TcpTransport {
                                 session_status_code = result.unwrap_err();
                             }
                         } else {
-                            session_status_code = StatusCode::BadCommunicationError;
+                            let mut transport = trace_write_lock_unwrap!(transport);
+                            session_status_code = transport.process_unexpected_message(&message);
                         }
                     }
                     _ => {
@@ -674,21 +714,9 @@ impl TcpTransport {
             pub transport: Arc<RwLock<TcpTransport>>,
             /// Session start time
             pub session_start_time: chrono::DateTime<Utc>,
-            /// Hello timeout duration, i.e. how long a session is waiting for the hello before it times out
-            pub hello_timeout: chrono::Duration,
         }
-        let hello_timeout = {
-            let hello_timeout = {
-                let transport = trace_read_lock_unwrap!(transport);
-                let server_state = trace_read_lock_unwrap!(transport.server_state);
-                let server_config = trace_read_lock_unwrap!(server_state.config);
-                server_config.tcp_config.hello_timeout as i64
-            };
-            chrono::Duration::seconds(hello_timeout)
-        };
         let state = HelloState {
             transport,
-            hello_timeout,
             session_start_time: session_start_time.clone(),
         };
 
@@ -707,27 +735,15 @@ impl TcpTransport {
             })
             .for_each(move |_| {
                 // Check if the session has waited in the hello state for more than the hello timeout period
-                let transport_state = {
-                    let transport = trace_read_lock_unwrap!(state.transport);
-                    transport.state()
-                };
-                if transport_state == TransportState::WaitingHello {
-                    // Check if the time elapsed since the session started exceeds the hello timeout
-                    let now = Utc::now();
-                    if now.signed_duration_since(state.session_start_time.clone()).num_milliseconds() > state.hello_timeout.num_milliseconds() {
-                        // Check if the session has waited in the hello state for more than the hello timeout period
-                        info!("Session has been waiting for a hello for more than the timeout period and will now close");
-                        let mut transport = trace_write_lock_unwrap!(state.transport);
-                        transport.finish(StatusCode::BadTimeout);
-
-                        // Diagnostics
-                        let server_state = trace_read_lock_unwrap!(transport.server_state);
-                        let mut diagnostics = trace_write_lock_unwrap!(server_state.diagnostics);
-                        diagnostics.on_session_timeout();
-
-                        // Make sure sockets go down
-                        let _ = sender.unbounded_send(Message::Quit);
-                    }
+                let mut transport = trace_write_lock_unwrap!(state.transport);
+                if transport.check_hello_timeout(&state.session_start_time, &Utc::now()) {
+                    // Diagnostics
+                    let server_state = trace_read_lock_unwrap!(transport.server_state);
+                    let mut diagnostics = trace_write_lock_unwrap!(server_state.diagnostics);
+                    diagnostics.on_session_timeout();
+
+                    // Make sure sockets go down
+                    let _ = sender.unbounded_send(Message::Quit);
                 }
                 Ok(())
             })
@@ -907,49 +923,63 @@ impl TcpTransport {
         hello: HelloMessage,
         sender: &mut UnboundedSender<Message>,
     ) -> std::result::Result<(), StatusCode> {
-        let server_protocol_version = 0;
-        let endpoints = {
+        let acknowledge = self.acknowledge_hello(&hello)?;
+        debug!("Sending ACK");
+        let _ = sender.unbounded_send(Message::Message(0, acknowledge.into()));
+        Ok(())
+    }
+
+    /// Passes the hello through the handshake, checking it against the server's endpoints and
+    /// limits. If it is acknowledged the transport moves on to processing messages and the
+    /// acknowledge to send back to the client is returned.
+    pub fn acknowledge_hello(
+        &mut self,
+        hello: &HelloMessage,
+    ) -> std::result::Result<AcknowledgeMessage, StatusCode> {
+        let (endpoints, endpoint_url_policy) = {
             let server_state = trace_read_lock_unwrap!(self.server_state);
-            server_state.endpoints(&hello.endpoint_url, &None)
-        }
-        .unwrap();
+            let endpoint_url_policy = {
+                let server_config = trace_read_lock_unwrap!(server_state.config);
+                server_config.tcp_config.endpoint_url_policy
+            };
+            let endpoints = server_state
+                .endpoints(&hello.endpoint_url, &None)
+                .unwrap()
+                .iter()
+                .map(|e| e.endpoint_url.as_ref().to_string())
+                .collect::<Vec<String>>();
+            (endpoints, endpoint_url_policy)
+        };
 
         trace!("Server received HELLO {:?}", hello);
-        if !hello.is_endpoint_url_valid(&endpoints) {
-            error!("HELLO endpoint url is invalid");
-            return Err(StatusCode::BadTcpEndpointUrlInvalid);
-        }
-        if !hello.is_valid_buffer_sizes() {
-            error!("HELLO buffer sizes are invalid");
-            return Err(StatusCode::BadCommunicationError);
+        let server_limits = TransportLimits {
+            receive_buffer_size: RECEIVE_BUFFER_SIZE,
+            send_buffer_size: SEND_BUFFER_SIZE,
+            max_message_size: MAX_MESSAGE_SIZE,
+            max_chunk_count: MAX_CHUNK_COUNT,
+        };
+        match self
+            .handshake
+            .process_hello(hello, &server_limits, &endpoints, endpoint_url_policy)
+        {
+            HandshakeEvent::SendAcknowledge(acknowledge) => {
+                // New state
+                self.transport_state = TransportState::ProcessMessages;
+                self.client_protocol_version = hello.protocol_version;
+                Ok(acknowledge)
+            }
+            HandshakeEvent::Reject(status_code) => Err(status_code),
+            HandshakeEvent::Message => Err(StatusCode::BadTcpInternalError),
         }
+    }
 
-        // Validate protocol version
-        if hello.protocol_version > server_protocol_version {
-            return Err(StatusCode::BadProtocolVersionUnsupported);
+    /// Passes a message which is out of place in the current state through the handshake, which
+    /// supplies the status code the connection is closed with.
+    fn process_unexpected_message(&mut self, message: &tcp_codec::Message) -> StatusCode {
+        match self.handshake.process_message(message.message_type()) {
+            HandshakeEvent::Reject(status_code) => status_code,
+            _ => StatusCode::BadCommunicationError,
         }
-
-        let client_protocol_version = hello.protocol_version;
-
-        // Send acknowledge
-        let mut acknowledge = AcknowledgeMessage {
-            message_header: MessageHeader::new(MessageType::Acknowledge),
-            protocol_version: server_protocol_version,
-            receive_buffer_size: RECEIVE_BUFFER_SIZE as u32,
-            send_buffer_size: SEND_BUFFER_SIZE as u32,
-            max_message_size: MAX_MESSAGE_SIZE as u32,
-            max_chunk_count: MAX_CHUNK_COUNT as u32,
-        };
-        acknowledge.message_header.message_size = acknowledge.byte_len() as u32;
-        let acknowledge: SupportedMessage = acknowledge.into();
-
-        // New state
-        self.transport_state = TransportState::ProcessMessages;
-        self.client_protocol_version = client_protocol_version;
-
-        debug!("Sending ACK");
-        let _ = sender.unbounded_send(Message::Message(0, acknowledge));
-        Ok(())
     }
 
     fn turn_received_chunks_into_message(
@@ -959,7 +989,7 @@ impl TcpTransport {
         // Validate that all chunks have incrementing sequence numbers and valid chunk types
         let secure_channel = trace_read_lock_unwrap!(self.secure_channel);
         self.last_received_sequence_number = Chunker::validate_chunks(
-            self.last_received_sequence_number + 1,
+            self.last_received_sequence_number.wrapping_add(1),
             &secure_channel,
             chunks,
         )?;
@@ -990,11 +1020,8 @@ impl TcpTransport {
                 secure_channel.verify_and_remove_security(&chunk.data)?
             };
 
-            // TODO check how many chunks are pending, produce error and drop connection if it exceeds
-            //  supported chunk limit
-
-            // Put the chunk on the list
-            self.pending_chunks.push(chunk);
+            // Put the chunk on the list, dropping the connection if it exceeds the chunk limit
+            self.pending_chunks.push(chunk)?;
 
             // The final chunk will trigger turning all pending chunks into a request
             if message_header.is_final == MessageIsFinalType::Final {
@@ -1011,7 +1038,7 @@ impl TcpTransport {
         sender: &mut UnboundedSender<Message>,
     ) -> Result<(), StatusCode> {
         // Drain pending chunks and turn them into a message
-        let chunks: Vec<MessageChunk> = self.pending_chunks.drain(..).collect();
+        let chunks = self.pending_chunks.take();
         let chunk_info = {
             let secure_channel = trace_read_lock_unwrap!(self.secure_channel);
             chunks[0].chunk_info(&secure_channel)?
@@ -1032,9 +1059,7 @@ impl TcpTransport {
                 &chunk_info.security_header,
                 &sender,
             ),
-            MessageChunkType::CloseSecureChannel => {
-                self.process_close_secure_channel(request_id, &request, &sender)
-            }
+            MessageChunkType::CloseSecureChannel => self.process_close_secure_channel(&request),
             MessageChunkType::Message => self.process_message(request_id, &request, &sender),
         }
     }
@@ -1059,13 +1084,17 @@ impl TcpTransport {
 
     fn process_close_secure_channel(
         &mut self,
-        request_id: u32,
         request: &SupportedMessage,
-        sender: &MessageSender,
     ) -> Result<(), StatusCode> {
-        let response = self.secure_channel_service.close_secure_channel(request)?;
-        let _ = sender.send_message(request_id, response);
-        Ok(())
+        {
+            let mut secure_channel = trace_write_lock_unwrap!(self.secure_channel);
+            self.secure_channel_service
+                .close_secure_channel(&mut secure_channel, request)?;
+        }
+        // Anything partially received on the channel is discarded along with it
+        self.pending_chunks.clear();
+        self.in_flight_requests.clear();
+        Err(StatusCode::BadConnectionClosed)
     }
 
     fn process_message(
@@ -1074,9 +1103,24 @@ impl TcpTransport {
         request: &SupportedMessage,
         sender: &MessageSender,
     ) -> Result<(), StatusCode> {
-        let _ = self
-            .message_handler
-            .handle_message(request_id, request, sender)?;
-        Ok(())
+        if !self.secure_channel_service.is_open() {
+            error!("Message received on a secure channel that is not open");
+            return Err(StatusCode::BadSecureChannelClosed);
+        }
+        if request.is_request() {
+            // A client with too many requests in flight is told to back off rather than have
+            // the request queued up behind the others
+            if let Err(status_code) = self.in_flight_requests.begin(request_id) {
+                let response = ServiceFault::new(request.request_header(), status_code);
+                sender.send_message(request_id, response.into());
+                return Ok(());
+            }
+        }
+        self.message_handler
+            .handle_message(request_id, request, sender)
+            .map_err(|status_code| {
+                self.in_flight_requests.end(request_id);
+                status_code
+            })
     }
 }
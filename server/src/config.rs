@@ -7,7 +7,11 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 use std::str::FromStr;
 
-use opcua_core::{comms::url::url_matches_except_host, config::Config};
+use opcua_core::{
+    comms::{tcp_types::EndpointUrlPolicy, url::url_matches_except_host},
+    config::Config,
+    sessionless::UnknownServicePolicy,
+};
 use opcua_crypto::{CertificateStore, SecurityPolicy, Thumbprint};
 use opcua_types::{
     constants as opcua_types_constants, service_types::ApplicationType, DecodingLimits,
@@ -26,6 +30,9 @@ pub struct TcpConfig {
     pub host: String,
     /// The port number of the service
     pub port: u16,
+    /// Whether a hello with a null or empty endpoint url is accepted
+    #[serde(default)]
+    pub endpoint_url_policy: EndpointUrlPolicy,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -139,6 +146,13 @@ pub struct ServerLimits {
     pub max_string_length: u32,
     /// Max bytestring length in bytes
     pub max_byte_string_length: u32,
+    /// Max depth to which decoded values may nest inside one another
+    #[serde(default = "ServerLimits::default_max_nesting_depth")]
+    pub max_nesting_depth: u32,
+    /// Maximum number of requests a client may have in flight on a connection before further
+    /// requests are rejected with `BadTooManyOperations`, 0 for no limit
+    #[serde(default = "ServerLimits::default_max_outstanding_requests")]
+    pub max_outstanding_requests: u32,
     /// Specifies the minimum sampling interval for this server in seconds.
     pub min_sampling_interval: f64,
     /// Specifies the minimum publishing interval for this server in seconds.
@@ -151,6 +165,8 @@ impl Default for ServerLimits {
             max_array_length: opcua_types_constants::MAX_ARRAY_LENGTH as u32,
             max_string_length: opcua_types_constants::MAX_STRING_LENGTH as u32,
             max_byte_string_length: opcua_types_constants::MAX_BYTE_STRING_LENGTH as u32,
+            max_nesting_depth: Self::default_max_nesting_depth(),
+            max_outstanding_requests: Self::default_max_outstanding_requests(),
             max_subscriptions: constants::DEFAULT_MAX_SUBSCRIPTIONS,
             max_monitored_items_per_sub: constants::DEFAULT_MAX_MONITORED_ITEMS_PER_SUB,
             clients_can_modify_address_space: false,
@@ -160,6 +176,30 @@ impl Default for ServerLimits {
     }
 }
 
+impl ServerLimits {
+    fn default_max_nesting_depth() -> u32 {
+        opcua_types_constants::MAX_NESTING_DEPTH as u32
+    }
+
+    fn default_max_outstanding_requests() -> u32 {
+        constants::DEFAULT_MAX_OUTSTANDING_REQUESTS
+    }
+}
+
+/// The limits that every message received by the server is decoded with
+impl From<&ServerLimits> for DecodingLimits {
+    fn from(limits: &ServerLimits) -> Self {
+        DecodingLimits {
+            max_chunk_size: 0,
+            max_string_length: limits.max_string_length as usize,
+            max_byte_string_length: limits.max_byte_string_length as usize,
+            max_array_length: limits.max_array_length as usize,
+            max_nesting_depth: limits.max_nesting_depth as usize,
+            depth: 0,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct ServerEndpoint {
     /// Endpoint path
@@ -507,6 +547,9 @@ pub struct ServerConfig {
     pub tcp_config: TcpConfig,
     /// Server limits
     pub limits: ServerLimits,
+    /// How a sessionless invoke for a service id which is not recognized is handled
+    #[serde(default)]
+    pub sessionless_unknown_service_policy: UnknownServicePolicy,
     /// Supported locale ids
     pub locale_ids: Vec<String>,
     /// User tokens
@@ -614,8 +657,10 @@ impl Default for ServerConfig {
                 host: "127.0.0.1".to_string(),
                 port: constants::DEFAULT_RUST_OPC_UA_SERVER_PORT,
                 hello_timeout: constants::DEFAULT_HELLO_TIMEOUT_SECONDS,
+                endpoint_url_policy: EndpointUrlPolicy::default(),
             },
             limits: ServerLimits::default(),
+            sessionless_unknown_service_policy: UnknownServicePolicy::default(),
             user_tokens: BTreeMap::new(),
             locale_ids: vec!["en".to_string()],
             discovery_urls: Vec::new(),
@@ -664,8 +709,10 @@ impl ServerConfig {
                 host,
                 port,
                 hello_timeout: constants::DEFAULT_HELLO_TIMEOUT_SECONDS,
+                endpoint_url_policy: EndpointUrlPolicy::default(),
             },
             limits: ServerLimits::default(),
+            sessionless_unknown_service_policy: UnknownServicePolicy::default(),
             locale_ids,
             user_tokens,
             discovery_urls,
@@ -675,12 +722,7 @@ impl ServerConfig {
     }
 
     pub fn decoding_limits(&self) -> DecodingLimits {
-        DecodingLimits {
-            max_chunk_size: 0,
-            max_string_length: self.limits.max_string_length as usize,
-            max_byte_string_length: self.limits.max_byte_string_length as usize,
-            max_array_length: self.limits.max_array_length as usize,
-        }
+        DecodingLimits::from(&self.limits)
     }
 
     pub fn add_endpoint(&mut self, id: &str, endpoint: ServerEndpoint) {
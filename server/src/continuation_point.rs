@@ -2,10 +2,17 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-//! Provides a browse continuation point type for tracking a browse operation initiated by a client.
+//! Provides a browse continuation point type for tracking a browse operation initiated by a client,
+//! and a manager which holds the continuation points belonging to a session.
 
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::{Arc, Mutex},
+};
 
+use chrono::{self, Utc};
+
+use opcua_crypto::random;
 use opcua_types::{service_types::ReferenceDescription, ByteString, DateTimeUtc};
 
 use crate::prelude::AddressSpace;
@@ -17,6 +24,8 @@ pub struct BrowseContinuationPoint {
     pub max_references_per_node: usize,
     pub starting_index: usize,
     pub reference_descriptions: Arc<Mutex<Vec<ReferenceDescription>>>,
+    /// The time the continuation point was issued
+    pub created_at: DateTimeUtc,
 }
 
 impl BrowseContinuationPoint {
@@ -25,4 +34,129 @@ impl BrowseContinuationPoint {
     pub fn is_valid_browse_continuation_point(&self, address_space: &AddressSpace) -> bool {
         self.address_space_last_modified >= address_space.last_modified()
     }
+
+    /// Test if the continuation point has been held for longer than the timeout.
+    pub fn is_expired(&self, now: &DateTimeUtc, timeout: chrono::Duration) -> bool {
+        now.signed_duration_since(self.created_at) > timeout
+    }
+}
+
+/// Holds the browse continuation points issued to a session. Each point is identified by an
+/// opaque token which the client passes back in a `BrowseNextRequest`. The manager caps the
+/// number of points it holds, discarding the oldest to make way for new ones, and points
+/// which are not used within the timeout are expired.
+#[derive(Debug)]
+pub struct ContinuationPointManager {
+    /// Maximum number of continuation points held at any one time
+    max_continuation_points: usize,
+    /// Time after which an unused continuation point is expired
+    timeout: chrono::Duration,
+    /// The continuation points, oldest first
+    continuation_points: VecDeque<BrowseContinuationPoint>,
+}
+
+impl ContinuationPointManager {
+    /// Creates a manager that holds at most `max_continuation_points` which expire after
+    /// `timeout_ms` milliseconds.
+    pub fn new(max_continuation_points: usize, timeout_ms: i64) -> ContinuationPointManager {
+        ContinuationPointManager {
+            max_continuation_points,
+            timeout: chrono::Duration::milliseconds(timeout_ms),
+            continuation_points: VecDeque::with_capacity(max_continuation_points),
+        }
+    }
+
+    /// Returns the number of continuation points currently held
+    pub fn len(&self) -> usize {
+        self.continuation_points.len()
+    }
+
+    /// Tests if there are no continuation points held
+    pub fn is_empty(&self) -> bool {
+        self.continuation_points.is_empty()
+    }
+
+    /// Issues a continuation point for the remainder of a browse result, returning the token
+    /// which identifies it.
+    pub fn issue(
+        &mut self,
+        address_space: &AddressSpace,
+        reference_descriptions: Arc<Mutex<Vec<ReferenceDescription>>>,
+        starting_index: usize,
+        max_references_per_node: usize,
+    ) -> ByteString {
+        let id = random::byte_string(6);
+        self.add(BrowseContinuationPoint {
+            id: id.clone(),
+            address_space_last_modified: address_space.last_modified(),
+            max_references_per_node,
+            starting_index,
+            reference_descriptions,
+            created_at: Utc::now(),
+        });
+        id
+    }
+
+    /// Adds a continuation point, removing the oldest points if the cap has been reached.
+    pub fn add(&mut self, continuation_point: BrowseContinuationPoint) {
+        if self.max_continuation_points == 0 {
+            debug!(
+                "Continuation point {} is discarded because no continuation points are allowed",
+                continuation_point.id.as_base64()
+            );
+            return;
+        }
+        // Remove excess browse continuation points
+        while self.continuation_points.len() >= self.max_continuation_points {
+            let continuation_point = self.continuation_points.pop_front();
+            debug!(
+                "Removing old continuation point {} to make way for new one",
+                continuation_point.unwrap().id.as_base64()
+            );
+        }
+        self.continuation_points.push_back(continuation_point);
+    }
+
+    /// Finds and REMOVES a continuation point by id. A point is only good for one use, so
+    /// browsing from it issues a new point for any results that still remain.
+    pub fn take(&mut self, id: &ByteString) -> Option<BrowseContinuationPoint> {
+        if let Some(idx) = self
+            .continuation_points
+            .iter()
+            .position(|continuation_point| continuation_point.id == *id)
+        {
+            self.continuation_points.remove(idx)
+        } else {
+            None
+        }
+    }
+
+    /// Removes all the specified continuation points by id
+    pub fn release(&mut self, ids: &[ByteString]) {
+        // Turn the supplied slice into a set
+        let ids: HashSet<&ByteString> = ids.iter().collect();
+        // Now remove any continuation points that are part of that set
+        self.continuation_points
+            .retain(|continuation_point| !ids.contains(&continuation_point.id));
+    }
+
+    /// Removes the continuation points that have outlived the timeout or which were made
+    /// before the address space was last modified.
+    pub fn remove_expired(&mut self, address_space: &AddressSpace, now: &DateTimeUtc) {
+        let timeout = self.timeout;
+        self.continuation_points.retain(|continuation_point| {
+            if continuation_point.is_expired(now, timeout) {
+                debug!(
+                    "Continuation point {:?} has timed out and will be removed",
+                    continuation_point
+                );
+                false
+            } else if !continuation_point.is_valid_browse_continuation_point(address_space) {
+                debug!("Continuation point {:?} is no longer valid and will be removed, address space last modified = {}", continuation_point, address_space.last_modified());
+                false
+            } else {
+                true
+            }
+        });
+    }
 }
@@ -120,6 +120,8 @@ pub mod constants {
     pub const DEFAULT_MAX_SUBSCRIPTIONS: u32 = 100;
     /// Default maximum number of monitored items per subscription
     pub const DEFAULT_MAX_MONITORED_ITEMS_PER_SUB: u32 = 1000;
+    /// Default maximum number of requests a client may have in flight on a connection
+    pub const DEFAULT_MAX_OUTSTANDING_REQUESTS: u32 = 100;
     /// Default, well known address for TCP discovery server
     pub const DEFAULT_DISCOVERY_SERVER_URL: &str = "opc.tcp://localhost:4840/UADiscovery";
 
@@ -150,6 +152,8 @@ pub mod constants {
     pub const MAX_KEEP_ALIVE_COUNT: u32 = 30000;
     /// Maximum browse continuation points
     pub const MAX_BROWSE_CONTINUATION_POINTS: usize = 20;
+    /// Time in millis that an unused browse continuation point is held before it expires
+    pub const BROWSE_CONTINUATION_POINT_TIMEOUT_MS: i64 = 60000;
     /// Maximum history continuation points
     pub const MAX_HISTORY_CONTINUATION_POINTS: usize = 10;
     /// Maximum query continuation points
@@ -5,6 +5,7 @@
 //! Provides the [`Server`] type and functionality related to it.
 
 use std::{
+    io::Read,
     marker::Sync,
     net::SocketAddr,
     sync::{Arc, RwLock},
@@ -35,7 +36,7 @@ use crate::{
     diagnostics::ServerDiagnostics,
     events::audit::AuditLog,
     metrics::ServerMetrics,
-    services::message_handler::MessageHandler,
+    services::{message_handler::MessageHandler, sessionless::SessionlessService},
     session::Session,
     state::{OperationalLimits, ServerState},
     util::PollingAction,
@@ -163,7 +164,8 @@ impl Server {
             },
             servers,
             base_endpoint,
-            state: ServerStateType::Shutdown,
+            // The server is suspended until it runs
+            state: ServerStateType::Suspended,
             start_time,
             config,
             server_certificate,
@@ -275,7 +277,10 @@ impl Server {
                     {
                         let mut server_state = trace_write_lock_unwrap!(server.server_state);
                         server_state.start_time = DateTime::now();
-                        server_state.set_state(ServerStateType::Running);
+                        if server_state.set_state(ServerStateType::Running).is_err() {
+                            error!("Server cannot run from its current state");
+                            return future::err(());
+                        }
                     }
 
                     // Start a timer that registers the server with a discovery server
@@ -368,6 +373,16 @@ impl Server {
         server_state.abort();
     }
 
+    /// Decodes a sessionless invoke received by the server. A request for a known service is
+    /// returned ready to be routed, while one for an unrecognized service id is rejected or passed
+    /// through according to `sessionless_unknown_service_policy` in the server's config.
+    pub fn sessionless_invoke<S: Read>(
+        &self,
+        stream: &mut S,
+    ) -> Result<SessionlessInvoke, StatusCode> {
+        SessionlessService::new().invoke(self.server_state.clone(), stream)
+    }
+
     /// Strip out dead connections, i.e those which have disconnected. Returns `true` if there are
     /// still open connections after this function completes.
     fn remove_dead_connections(&self) -> bool {
@@ -535,7 +535,14 @@ impl AttributeService {
                     }
                 };
 
-                if !Self::is_readable(session, &node, attribute_id) {
+                if !attribute_id.is_valid_for(node.node_class()) {
+                    // The attribute doesn't belong to this class of node
+                    debug!(
+                        "read_node_value result for read node id {}, attribute {} is not valid for node class {:?}",
+                        node_to_read.node_id, node_to_read.attribute_id, node.node_class()
+                    );
+                    result_value.status = Some(StatusCode::BadAttributeIdInvalid);
+                } else if !Self::is_readable(session, &node, attribute_id) {
                     // Can't read this node
                     debug!(
                         "read_node_value result for read node id {}, attribute {} is unreadable",
@@ -646,10 +653,13 @@ impl AttributeService {
         node: &NodeType,
         attribute_id: AttributeId,
     ) -> UserAccessLevel {
-        let user_access_level = if let NodeType::Variable(ref node) = node {
-            node.user_access_level()
-        } else {
-            UserAccessLevel::CURRENT_READ
+        // The access level of a variable governs its value. Other attributes such as the node id
+        // and node class are always readable.
+        let user_access_level = match node {
+            NodeType::Variable(ref node) if attribute_id == AttributeId::Value => {
+                node.user_access_level()
+            }
+            _ => UserAccessLevel::CURRENT_READ,
         };
         session.effective_user_access_level(user_access_level, &node.node_id(), attribute_id)
     }
@@ -670,51 +680,7 @@ impl AttributeService {
             }
         }
 
-        if let Some(write_mask) = node.as_node().write_mask() {
-            match attribute_id {
-                AttributeId::Value => {
-                    if let NodeType::VariableType(_) = node {
-                        write_mask.contains(WriteMask::VALUE_FOR_VARIABLE_TYPE)
-                    } else {
-                        false
-                    }
-                }
-                AttributeId::NodeId => write_mask.contains(WriteMask::NODE_ID),
-                AttributeId::NodeClass => write_mask.contains(WriteMask::NODE_CLASS),
-                AttributeId::BrowseName => write_mask.contains(WriteMask::BROWSE_NAME),
-                AttributeId::DisplayName => write_mask.contains(WriteMask::DISPLAY_NAME),
-                AttributeId::Description => write_mask.contains(WriteMask::DESCRIPTION),
-                AttributeId::WriteMask => write_mask.contains(WriteMask::WRITE_MASK),
-                AttributeId::UserWriteMask => write_mask.contains(WriteMask::USER_WRITE_MASK),
-                AttributeId::IsAbstract => write_mask.contains(WriteMask::IS_ABSTRACT),
-                AttributeId::Symmetric => write_mask.contains(WriteMask::SYMMETRIC),
-                AttributeId::InverseName => write_mask.contains(WriteMask::INVERSE_NAME),
-                AttributeId::ContainsNoLoops => write_mask.contains(WriteMask::CONTAINS_NO_LOOPS),
-                AttributeId::EventNotifier => write_mask.contains(WriteMask::EVENT_NOTIFIER),
-                AttributeId::DataType => write_mask.contains(WriteMask::DATA_TYPE),
-                AttributeId::ValueRank => write_mask.contains(WriteMask::VALUE_RANK),
-                AttributeId::ArrayDimensions => write_mask.contains(WriteMask::ARRAY_DIMENSIONS),
-                AttributeId::AccessLevel => write_mask.contains(WriteMask::ACCESS_LEVEL),
-                AttributeId::UserAccessLevel => write_mask.contains(WriteMask::USER_ACCESS_LEVEL),
-                AttributeId::MinimumSamplingInterval => {
-                    write_mask.contains(WriteMask::MINIMUM_SAMPLING_INTERVAL)
-                }
-                AttributeId::Historizing => write_mask.contains(WriteMask::HISTORIZING),
-                AttributeId::Executable => write_mask.contains(WriteMask::EXECUTABLE),
-                AttributeId::UserExecutable => write_mask.contains(WriteMask::USER_EXECUTABLE),
-                AttributeId::DataTypeDefinition => {
-                    write_mask.contains(WriteMask::DATA_TYPE_DEFINITION)
-                }
-                AttributeId::RolePermissions => write_mask.contains(WriteMask::ROLE_PERMISSIONS),
-                AttributeId::AccessRestrictions => {
-                    write_mask.contains(WriteMask::ACCESS_RESTRICTIONS)
-                }
-                AttributeId::AccessLevelEx => write_mask.contains(WriteMask::ACCESS_LEVEL_EX),
-                AttributeId::UserRolePermissions => false, // Reserved
-            }
-        } else {
-            false
-        }
+        node.as_node().write_mask_permits(attribute_id)
     }
 
     /*
@@ -809,16 +775,23 @@ impl AttributeService {
                     let data_type_valid = if attribute_id == AttributeId::Value {
                         match node {
                             NodeType::Variable(ref variable) => {
-                                Self::validate_value_to_write(address_space, variable, value)
+                                if !Self::validate_value_to_write(address_space, variable, value) {
+                                    Err(StatusCode::BadTypeMismatch)
+                                } else if !index_range.has_range() {
+                                    // A whole value must also suit the value rank and dimensions
+                                    variable.validate_value(value)
+                                } else {
+                                    Ok(())
+                                }
                             }
-                            _ => true, // Other types don't have this attr but they will reject later during set
+                            _ => Ok(()), // Other types don't have this attr but they will reject later during set
                         }
                     } else {
-                        true
+                        Ok(())
                     };
-                    if !data_type_valid {
+                    if let Err(status_code) = data_type_valid {
                         error!("Data type of value is invalid for writing to attribute");
-                        StatusCode::BadTypeMismatch
+                        status_code
                     } else {
                         let node = address_space.find_node_mut(&node_to_write.node_id).unwrap();
                         let result = if attribute_id == AttributeId::Value {
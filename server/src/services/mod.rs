@@ -33,6 +33,7 @@ pub mod monitored_item;
 pub mod node_management;
 pub mod query;
 pub mod session;
+pub mod sessionless;
 pub mod subscription;
 pub mod view;
 
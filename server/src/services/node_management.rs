@@ -2,16 +2,13 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
 
-use std::{
-    result::Result,
-    sync::{Arc, RwLock},
-};
+use std::sync::{Arc, RwLock};
 
 use opcua_core::supported_message::SupportedMessage;
-use opcua_types::{node_ids::ObjectId, status_code::StatusCode, *};
+use opcua_types::{status_code::StatusCode, *};
 
 use crate::{
-    address_space::{relative_path, types::*, AddressSpace},
+    address_space::{relative_path, AddressSpace},
     services::Service,
     session::Session,
     state::ServerState,
@@ -205,100 +202,6 @@ impl NodeManagementService {
         }
     }
 
-    fn create_node(
-        node_id: &NodeId,
-        node_class: NodeClass,
-        browse_name: QualifiedName,
-        node_attributes: &ExtensionObject,
-        decoding_limits: &DecodingLimits,
-    ) -> Result<NodeType, StatusCode> {
-        let object_id = node_attributes
-            .node_id
-            .as_object_id()
-            .map_err(|_| StatusCode::BadNodeAttributesInvalid)?;
-        // Note we are expecting the node_class and the object id for the attributes to be for the same
-        // thing. If they are different, it is an error.
-
-        match object_id {
-            ObjectId::ObjectAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::Object {
-                    let attributes = node_attributes.decode_inner::<ObjectAttributes>(decoding_limits)?;
-                    Object::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and object node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::VariableAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::Variable {
-                    let attributes = node_attributes.decode_inner::<VariableAttributes>(decoding_limits)?;
-                    Variable::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and variable node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::MethodAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::Method {
-                    let attributes = node_attributes.decode_inner::<MethodAttributes>(decoding_limits)?;
-                    Method::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and method node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::ObjectTypeAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::ObjectType {
-                    let attributes = node_attributes.decode_inner::<ObjectTypeAttributes>(decoding_limits)?;
-                    ObjectType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and object type node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::VariableTypeAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::VariableType {
-                    let attributes = node_attributes.decode_inner::<VariableTypeAttributes>(decoding_limits)?;
-                    VariableType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and variable type node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::ReferenceTypeAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::ReferenceType {
-                    let attributes = node_attributes.decode_inner::<ReferenceTypeAttributes>(decoding_limits)?;
-                    ReferenceType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and reference type node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::DataTypeAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::DataType {
-                    let attributes = node_attributes.decode_inner::<DataTypeAttributes>(decoding_limits)?;
-                    DataType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and data type node attributes are not compatible");
-                    Err(())
-                }
-            }
-            ObjectId::ViewAttributes_Encoding_DefaultBinary => {
-                if node_class == NodeClass::View {
-                    let attributes = node_attributes.decode_inner::<ViewAttributes>(decoding_limits)?;
-                    View::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
-                } else {
-                    error!("node class and view node attributes are not compatible");
-                    Err(())
-                }
-            }
-            _ => {
-                error!("create_node was called with an object id which does not match a supported type");
-                Err(())
-            }
-        }.map_err(|_| StatusCode::BadNodeAttributesInvalid)
-    }
-
     fn add_node(
         session: &Session,
         address_space: &mut AddressSpace,
@@ -335,6 +238,12 @@ impl NodeManagementService {
             error!("node cannot be created because the browse name is invalid");
             return (StatusCode::BadBrowseNameInvalid, NodeId::null());
         }
+        if let Err(status_code) = item
+            .browse_name
+            .validate_namespace(address_space.namespace_count())
+        {
+            return (status_code, NodeId::null());
+        }
 
         // Test duplicate browse name to same parent
         let browse_name = if item.browse_name.namespace_index != 0 {
@@ -390,32 +299,27 @@ impl NodeManagementService {
                 return (StatusCode::BadParentNodeIdInvalid, NodeId::null());
             }
 
-            // Create a node
-            if let Ok(node) = Self::create_node(
-                &new_node_id,
+            // Create a node and add it to the address space
+            if let Err(status_code) = address_space.add_node_from_attributes(
                 item.node_class,
+                &new_node_id,
                 item.browse_name.clone(),
                 &item.node_attributes,
                 decoding_limits,
             ) {
-                // Add the node to the address space
-                address_space.insert(
-                    node,
-                    Some(&[(
-                        &item.parent_node_id.node_id,
-                        &reference_type_id,
-                        ReferenceDirection::Forward,
-                    )]),
+                error!("node cannot be created because attributes / not class are not valid");
+                (status_code, NodeId::null())
+            } else {
+                address_space.insert_reference(
+                    &new_node_id,
+                    &item.parent_node_id.node_id,
+                    reference_type_id,
                 );
                 // Object / Variable types must add a reference to the type
                 if item.node_class == NodeClass::Object || item.node_class == NodeClass::Variable {
                     address_space.set_node_type(&new_node_id, item.type_definition.node_id.clone());
                 }
                 (StatusCode::Good, new_node_id)
-            } else {
-                // Create node failed, so assume a problem with the node attributes
-                error!("node cannot be created because attributes / not class are not valid");
-                (StatusCode::BadNodeAttributesInvalid, NodeId::null())
             }
         } else {
             error!("node cannot be created because reference type is invalid");
@@ -0,0 +1,46 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+use std::{
+    io::Read,
+    sync::{Arc, RwLock},
+};
+
+use opcua_core::sessionless::{SessionlessInvoke, SessionlessInvokeRequest};
+use opcua_types::status_code::StatusCode;
+
+use crate::{services::Service, state::ServerState};
+
+/// The sessionless service. Decodes a sessionless invoke, handling a service id which is not
+/// recognized according to the server's configured policy.
+pub(crate) struct SessionlessService;
+
+impl Service for SessionlessService {
+    fn name(&self) -> String {
+        String::from("SessionlessService")
+    }
+}
+
+impl SessionlessService {
+    pub fn new() -> SessionlessService {
+        SessionlessService {}
+    }
+
+    /// Decodes the sessionless invoke in the stream. A known service request is returned to be
+    /// routed, an unknown one is rejected with `BadServiceUnsupported` or passed through, depending
+    /// on `sessionless_unknown_service_policy` in the server config.
+    pub fn invoke<S: Read>(
+        &self,
+        server_state: Arc<RwLock<ServerState>>,
+        stream: &mut S,
+    ) -> Result<SessionlessInvoke, StatusCode> {
+        let server_state = trace_read_lock_unwrap!(server_state);
+        let config = trace_read_lock_unwrap!(server_state.config);
+        SessionlessInvokeRequest::decode_with_policy(
+            stream,
+            &config.decoding_limits(),
+            config.sessionless_unknown_service_policy,
+        )
+    }
+}
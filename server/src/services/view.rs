@@ -6,12 +6,10 @@ use std::result::Result;
 use std::sync::{Arc, Mutex, RwLock};
 
 use opcua_core::supported_message::SupportedMessage;
-use opcua_crypto::random;
-use opcua_types::{node_ids::ReferenceTypeId, status_code::StatusCode, *};
+use opcua_types::{status_code::StatusCode, *};
 
 use crate::{
     address_space::{relative_path, AddressSpace},
-    continuation_point::BrowseContinuationPoint,
     services::Service,
     session::Session,
     state::ServerState,
@@ -392,71 +390,11 @@ impl ViewService {
                 }
             }
 
-            // Prepare the values to put into the struct according to the result mask
-            let reference_type_id =
-                if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_REFERENCE_TYPE) {
-                    reference.reference_type.clone()
-                } else {
-                    NodeId::null()
-                };
-            let is_forward =
-                if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_IS_FORWARD) {
-                    idx < inverse_ref_idx
-                } else {
-                    true
-                };
-
-            let target_node_class =
-                if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_NODE_CLASS) {
-                    target_node_class
-                } else {
-                    NodeClass::Unspecified
-                };
-            let browse_name =
-                if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_BROWSE_NAME) {
-                    target_node.browse_name().clone()
-                } else {
-                    QualifiedName::null()
-                };
-            let display_name =
-                if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_DISPLAY_NAME) {
-                    target_node.display_name().clone()
-                } else {
-                    LocalizedText::null()
-                };
-            let type_definition =
-                if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_TYPE_DEFINITION) {
-                    // Type definition NodeId of the TargetNode. Type definitions are only available
-                    // for the NodeClasses Object and Variable. For all other NodeClasses a null NodeId
-                    // shall be returned.
-                    match target_node_class {
-                        NodeClass::Object | NodeClass::Variable => {
-                            let type_defs = address_space.find_references(
-                                &target_node.node_id(),
-                                Some((ReferenceTypeId::HasTypeDefinition, false)),
-                            );
-                            if let Some(type_defs) = type_defs {
-                                ExpandedNodeId::new(type_defs[0].target_node.clone())
-                            } else {
-                                ExpandedNodeId::null()
-                            }
-                        }
-                        _ => ExpandedNodeId::null(),
-                    }
-                } else {
-                    ExpandedNodeId::null()
-                };
-
-            let reference_description = ReferenceDescription {
-                node_id: ExpandedNodeId::new(target_node_id),
-                reference_type_id,
-                is_forward,
-                node_class: target_node_class,
-                browse_name,
-                display_name,
-                type_definition,
-            };
-            reference_descriptions.push(reference_description);
+            if let Some(reference_description) =
+                address_space.reference_description(reference, idx < inverse_ref_idx, result_mask)
+            {
+                reference_descriptions.push(reference_description);
+            }
         }
 
         Ok(Self::reference_description_to_browse_result(
@@ -524,18 +462,15 @@ impl ViewService {
             //  be factored to allow for that
 
             // Create a continuation point for the remainder of the result. The point will hold the entire result
-            let continuation_point = random::byte_string(6);
+            let continuation_point = session.browse_continuation_points().issue(
+                address_space,
+                Arc::new(Mutex::new(reference_descriptions.to_vec())),
+                next_starting_index,
+                max_references_per_node,
+            );
 
             debug!("References remaining {} exceeds max references {}, returning range {}..{} and creating new continuation point {}", references_remaining, max_references_per_node, starting_index, next_starting_index, continuation_point.as_base64());
 
-            session.add_browse_continuation_point(BrowseContinuationPoint {
-                id: continuation_point.clone(),
-                address_space_last_modified: address_space.last_modified(),
-                max_references_per_node,
-                starting_index: next_starting_index,
-                reference_descriptions: Arc::new(Mutex::new(reference_descriptions.to_vec())),
-            });
-
             (reference_descriptions_slice, continuation_point)
         } else {
             // Returns the remainder of the results
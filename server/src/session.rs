@@ -1,12 +1,9 @@
 // OPCUA for Rust
 // SPDX-License-Identifier: MPL-2.0
 // Copyright (C) 2017-2020 Adam Lock
-use std::{
-    collections::{HashSet, VecDeque},
-    sync::{
-        atomic::{AtomicI32, Ordering},
-        Arc, RwLock,
-    },
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc, RwLock,
 };
 
 use chrono::{self, Utc};
@@ -17,7 +14,7 @@ use opcua_types::{service_types::PublishRequest, status_code::StatusCode, *};
 
 use crate::{
     address_space::{AddressSpace, UserAccessLevel},
-    continuation_point::BrowseContinuationPoint,
+    continuation_point::{BrowseContinuationPoint, ContinuationPointManager},
     diagnostics::ServerDiagnostics,
     identity_token::IdentityToken,
     server::Server,
@@ -81,10 +78,8 @@ pub struct Session {
     max_response_message_size: u32,
     /// Endpoint url for this session
     endpoint_url: UAString,
-    /// Maximum number of continuation points
-    max_browse_continuation_points: usize,
-    /// Browse continuation points (oldest to newest)
-    browse_continuation_points: VecDeque<BrowseContinuationPoint>,
+    /// Browse continuation points
+    browse_continuation_points: ContinuationPointManager,
     /// Diagnostics associated with the server
     diagnostics: Arc<RwLock<ServerDiagnostics>>,
     /// Diagnostics associated with the session
@@ -115,7 +110,6 @@ impl Drop for Session {
 impl Session {
     #[cfg(test)]
     pub fn new_no_certificate_store(secure_channel: SecureChannel) -> Session {
-        let max_browse_continuation_points = super::constants::MAX_BROWSE_CONTINUATION_POINTS;
         let session = Session {
             subscriptions: Subscriptions::new(100, PUBLISH_REQUEST_TIMEOUT),
             session_id: next_session_id(),
@@ -135,8 +129,10 @@ impl Session {
             max_request_message_size: 0,
             max_response_message_size: 0,
             endpoint_url: UAString::null(),
-            max_browse_continuation_points,
-            browse_continuation_points: VecDeque::with_capacity(max_browse_continuation_points),
+            browse_continuation_points: ContinuationPointManager::new(
+                super::constants::MAX_BROWSE_CONTINUATION_POINTS,
+                super::constants::BROWSE_CONTINUATION_POINT_TIMEOUT_MS,
+            ),
             can_modify_address_space: true,
             diagnostics: Arc::new(RwLock::new(ServerDiagnostics::default())),
             session_diagnostics: Arc::new(RwLock::new(SessionDiagnostics::default())),
@@ -151,8 +147,6 @@ impl Session {
 
     /// Create a `Session` from a `Server`
     pub fn new(server: &Server) -> Session {
-        let max_browse_continuation_points = super::constants::MAX_BROWSE_CONTINUATION_POINTS;
-
         let server_state = server.server_state();
         let server_state = trace_read_lock_unwrap!(server_state);
         let max_subscriptions = server_state.max_subscriptions;
@@ -188,8 +182,10 @@ impl Session {
             max_request_message_size: 0,
             max_response_message_size: 0,
             endpoint_url: UAString::null(),
-            max_browse_continuation_points,
-            browse_continuation_points: VecDeque::with_capacity(max_browse_continuation_points),
+            browse_continuation_points: ContinuationPointManager::new(
+                super::constants::MAX_BROWSE_CONTINUATION_POINTS,
+                super::constants::BROWSE_CONTINUATION_POINT_TIMEOUT_MS,
+            ),
             can_modify_address_space,
             diagnostics,
             session_diagnostics: Arc::new(RwLock::new(SessionDiagnostics::default())),
@@ -360,20 +356,8 @@ impl Session {
         self.subscriptions.expire_stale_publish_requests(now);
     }
 
-    pub(crate) fn add_browse_continuation_point(
-        &mut self,
-        continuation_point: BrowseContinuationPoint,
-    ) {
-        // Remove excess browse continuation points
-        while self.browse_continuation_points.len() >= self.max_browse_continuation_points {
-            let continuation_point = self.browse_continuation_points.pop_front();
-            debug!(
-                "Removing old continuation point {} to make way for new one",
-                continuation_point.unwrap().id.as_base64()
-            );
-        }
-        self.browse_continuation_points
-            .push_back(continuation_point);
+    pub(crate) fn browse_continuation_points(&mut self) -> &mut ContinuationPointManager {
+        &mut self.browse_continuation_points
     }
 
     /// Finds and REMOVES a continuation point by id.
@@ -381,38 +365,20 @@ impl Session {
         &mut self,
         id: &ByteString,
     ) -> Option<BrowseContinuationPoint> {
-        if let Some(idx) = self
-            .browse_continuation_points
-            .iter()
-            .position(|continuation_point| continuation_point.id == *id)
-        {
-            self.browse_continuation_points.remove(idx)
-        } else {
-            None
-        }
+        self.browse_continuation_points.take(id)
     }
 
     pub(crate) fn remove_expired_browse_continuation_points(
         &mut self,
         address_space: &AddressSpace,
     ) {
-        self.browse_continuation_points.retain(|continuation_point| {
-            let valid = continuation_point.is_valid_browse_continuation_point(address_space);
-            if !valid {
-                debug!("Continuation point {:?} is no longer valid and will be removed, address space last modified = {}", continuation_point, address_space.last_modified());
-            }
-            valid
-        });
+        self.browse_continuation_points
+            .remove_expired(address_space, &Utc::now());
     }
 
     /// Remove all the specified continuation points by id
     pub(crate) fn remove_browse_continuation_points(&mut self, continuation_points: &[ByteString]) {
-        // Turn the supplied slice into a set
-        let continuation_points_set: HashSet<ByteString> =
-            continuation_points.iter().cloned().collect();
-        // Now remove any continuation points that are part of that set
-        self.browse_continuation_points
-            .retain(|continuation_point| !continuation_points_set.contains(&continuation_point.id));
+        self.browse_continuation_points.release(continuation_points);
     }
 
     pub(crate) fn can_modify_address_space(&self) -> bool {
@@ -378,8 +378,19 @@ impl ServerState {
         self.state
     }
 
-    pub fn set_state(&mut self, state: ServerStateType) {
-        self.state = state;
+    /// Moves the server to the new state, failing with `BadInvalidState` if the current state
+    /// cannot transition to it, e.g. from `Shutdown` back to `Running`.
+    pub fn set_state(&mut self, state: ServerStateType) -> Result<(), StatusCode> {
+        if self.state.can_transition_to(state) {
+            self.state = state;
+            Ok(())
+        } else {
+            error!(
+                "Server cannot transition from state {:?} to {:?}",
+                self.state, state
+            );
+            Err(StatusCode::BadInvalidState)
+        }
     }
 
     pub fn is_abort(&self) -> bool {
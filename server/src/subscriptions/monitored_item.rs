@@ -340,9 +340,12 @@ impl MonitoredItem {
             } else if let Some(ref last_data_value) = self.last_data_value {
                 // If there is a filter on the monitored item then the filter determines
                 // if the value is considered to have changed, otherwise it is a straight
-                // equality test.
+                // equality test, save that a value which stays NaN has not changed.
                 match self.filter {
-                    FilterType::None => data_value.value != last_data_value.value,
+                    FilterType::None => match (&data_value.value, &last_data_value.value) {
+                        (Some(v1), Some(v2)) => !v1.value_eq(v2),
+                        (v1, v2) => v1 != v2,
+                    },
                     FilterType::DataChangeFilter(ref filter) => {
                         !filter.compare(&data_value, last_data_value, None)
                     }
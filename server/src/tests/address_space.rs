@@ -1,6 +1,10 @@
 use crate::{
     address_space::{
-        references::Reference, relative_path::find_node_from_browse_path, EventNotifier,
+        node::Node,
+        nodeset::load_nodeset,
+        references::{Reference, ReferenceDirection},
+        relative_path::find_node_from_browse_path,
+        EventNotifier,
     },
     callbacks,
     prelude::*,
@@ -482,6 +486,133 @@ fn multi_dimension_array_as_variable() {
     assert_eq!(array_dimensions, vec![10u32, 10u32]);
 }
 
+#[test]
+fn variable_validate_value() {
+    // A scalar variable rejects an array
+    let v = VariableBuilder::new(&NodeId::new(1, "Scalar"), "", "")
+        .data_type(DataTypeId::Int32)
+        .value_rank(-1)
+        .value(0i32)
+        .build();
+    assert!(v.validate_value(&Variant::from(1i32)).is_ok());
+    assert!(v.validate_value(&Variant::Empty).is_ok());
+    assert_eq!(
+        v.validate_value(&Variant::from(vec![1i32, 2i32])),
+        Err(StatusCode::BadTypeMismatch)
+    );
+
+    // A [3] variable rejects a scalar and arrays of the wrong length
+    let v = VariableBuilder::new(&NodeId::new(1, "Array"), "", "")
+        .data_type(DataTypeId::Int32)
+        .value_rank(1)
+        .array_dimensions(&[3])
+        .value(vec![0i32, 0i32, 0i32])
+        .build();
+    assert!(v
+        .validate_value(&Variant::from(vec![1i32, 2i32, 3i32]))
+        .is_ok());
+    assert_eq!(
+        v.validate_value(&Variant::from(vec![1i32, 2i32])),
+        Err(StatusCode::BadIndexRangeNoData)
+    );
+    assert_eq!(
+        v.validate_value(&Variant::from(1i32)),
+        Err(StatusCode::BadTypeMismatch)
+    );
+    let mda = Array::new_multi(vec![Variant::from(1i32); 6], vec![3u32, 2u32]);
+    assert_eq!(
+        v.validate_value(&Variant::from(mda)),
+        Err(StatusCode::BadTypeMismatch)
+    );
+
+    // A variable of any rank accepts anything
+    let v = VariableBuilder::new(&NodeId::new(1, "Any"), "", "")
+        .data_type(DataTypeId::Int32)
+        .value_rank(-2)
+        .value(0i32)
+        .build();
+    assert!(v.validate_value(&Variant::from(1i32)).is_ok());
+    assert!(v.validate_value(&Variant::from(vec![1i32, 2i32])).is_ok());
+
+    // A byte string may be written to a byte array
+    let v = VariableBuilder::new(&NodeId::new(1, "Bytes"), "", "")
+        .data_type(DataTypeId::Byte)
+        .value_rank(1)
+        .array_dimensions(&[4])
+        .value(vec![0u8; 4])
+        .build();
+    assert!(v
+        .validate_value(&Variant::from(ByteString::from(vec![1u8, 2u8, 3u8, 4u8])))
+        .is_ok());
+    assert_eq!(
+        v.validate_value(&Variant::from(ByteString::from(vec![1u8, 2u8]))),
+        Err(StatusCode::BadIndexRangeNoData)
+    );
+}
+
+#[test]
+fn populate_server_status() {
+    let mut address_space = AddressSpace::new();
+    let build_info = BuildInfo {
+        product_uri: UAString::from("urn:product"),
+        manufacturer_name: UAString::from("Manufacturer"),
+        product_name: UAString::from("Product"),
+        software_version: UAString::from("1.2.3"),
+        build_number: UAString::from("42"),
+        build_date: DateTime::ymd(2020, 1, 1),
+    };
+    let start_time = DateTime::ymd_hms(2020, 2, 3, 4, 5, 6);
+    address_space.populate_server_status(build_info.clone(), start_time.clone(), || {
+        ServerState::Running
+    });
+
+    let read_value = |node_id: VariableId| {
+        let v = address_space.find_variable(node_id).unwrap();
+        v.value(
+            TimestampsToReturn::Neither,
+            NumericRange::None,
+            &QualifiedName::null(),
+            0.0,
+        )
+        .value
+        .unwrap()
+    };
+
+    // The server status is readable and has the supplied values
+    let server_status = match read_value(VariableId::Server_ServerStatus) {
+        Variant::ExtensionObject(v) => v
+            .decode_inner::<ServerStatusDataType>(&DecodingLimits::default())
+            .unwrap(),
+        v => panic!("Unexpected value {:?}", v),
+    };
+    assert_eq!(server_status.start_time, start_time);
+    assert_eq!(server_status.state, ServerState::Running);
+    assert_eq!(server_status.build_info, build_info);
+
+    assert_eq!(
+        read_value(VariableId::Server_ServerStatus_State),
+        Variant::Int32(ServerState::Running as i32)
+    );
+    assert_eq!(
+        read_value(VariableId::Server_ServerStatus_StartTime),
+        Variant::from(start_time)
+    );
+    assert_eq!(
+        read_value(VariableId::Server_ServerStatus_BuildInfo_ProductName),
+        Variant::from("Product")
+    );
+    assert_eq!(
+        read_value(VariableId::Server_ServerStatus_BuildInfo_SoftwareVersion),
+        Variant::from("1.2.3")
+    );
+    match read_value(VariableId::Server_ServerStatus_CurrentTime) {
+        Variant::DateTime(current_time) => {
+            assert!(current_time.as_chrono() > DateTime::ymd(2020, 1, 1).as_chrono())
+        }
+        v => panic!("Unexpected value {:?}", v),
+    }
+}
+
 #[test]
 fn browse_nodes() {
     let address_space = make_sample_address_space();
@@ -542,6 +673,83 @@ fn object_builder() {
     ));
 }
 
+#[test]
+fn reference_description() {
+    let mut address_space = AddressSpace::new();
+
+    let folder_id = address_space
+        .add_folder("Folder", "Folder", &NodeId::objects_folder_id())
+        .unwrap();
+    let node_id = NodeId::new(1, "Hello");
+    VariableBuilder::new(&node_id, "HelloBrowse", "HelloDisplay")
+        .data_type(DataTypeId::Int32)
+        .value(1i32)
+        .organized_by(&folder_id)
+        .reference(
+            VariableTypeId::BaseDataVariableType,
+            ReferenceTypeId::HasTypeDefinition,
+            ReferenceDirection::Forward,
+        )
+        .insert(&mut address_space);
+
+    // Every field of the description of the folder's reference to the variable
+    let reference = Reference::new(ReferenceTypeId::Organizes, node_id.clone());
+    let description = address_space
+        .reference_description(&reference, true, BrowseDescriptionResultMask::all())
+        .unwrap();
+    assert_eq!(
+        description.reference_type_id,
+        ReferenceTypeId::Organizes.into()
+    );
+    assert!(description.is_forward);
+    assert_eq!(description.node_id, ExpandedNodeId::new(node_id.clone()));
+    assert_eq!(description.browse_name, QualifiedName::from("HelloBrowse"));
+    assert_eq!(
+        description.display_name,
+        LocalizedText::from("HelloDisplay")
+    );
+    assert_eq!(description.node_class, NodeClass::Variable);
+    assert_eq!(
+        description.type_definition,
+        ExpandedNodeId::new(VariableTypeId::BaseDataVariableType)
+    );
+
+    // The inverse reference from the variable back to the folder
+    let reference = Reference::new(ReferenceTypeId::Organizes, folder_id.clone());
+    let description = address_space
+        .reference_description(&reference, false, BrowseDescriptionResultMask::all())
+        .unwrap();
+    assert!(!description.is_forward);
+    assert_eq!(description.node_class, NodeClass::Object);
+    assert_eq!(
+        description.type_definition,
+        ExpandedNodeId::new(ObjectTypeId::FolderType)
+    );
+
+    // Fields outside the result mask are null
+    let reference = Reference::new(ReferenceTypeId::Organizes, node_id.clone());
+    let description = address_space
+        .reference_description(
+            &reference,
+            false,
+            BrowseDescriptionResultMask::RESULT_MASK_BROWSE_NAME,
+        )
+        .unwrap();
+    assert!(description.reference_type_id.is_null());
+    assert!(description.is_forward);
+    assert_eq!(description.node_id, ExpandedNodeId::new(node_id));
+    assert_eq!(description.browse_name, QualifiedName::from("HelloBrowse"));
+    assert!(description.display_name.text.is_null());
+    assert_eq!(description.node_class, NodeClass::Unspecified);
+    assert!(description.type_definition.is_null());
+
+    // A target that is not in the address space has no description
+    let reference = Reference::new(ReferenceTypeId::Organizes, NodeId::new(1, "Missing"));
+    assert!(address_space
+        .reference_description(&reference, true, BrowseDescriptionResultMask::all())
+        .is_none());
+}
+
 #[test]
 fn object_type_builder() {
     let mut address_space = AddressSpace::new();
@@ -633,6 +841,48 @@ fn variable_builder() {
     ));
 }
 
+#[test]
+fn variable_builder_historizing() {
+    // Historizing makes the variable history-readable
+    let v = VariableBuilder::new(&NodeId::new(1, "History"), "History", "History")
+        .data_type(DataTypeId::Double)
+        .historizing(true)
+        .build();
+    assert!(v.historizing());
+    assert_eq!(
+        v.access_level(),
+        AccessLevel::CURRENT_READ | AccessLevel::HISTORY_READ
+    );
+    assert_eq!(
+        v.user_access_level(),
+        UserAccessLevel::CURRENT_READ | UserAccessLevel::HISTORY_READ
+    );
+
+    // History write must be asked for separately, and other bits are kept
+    let v = VariableBuilder::new(&NodeId::new(1, "History"), "History", "History")
+        .data_type(DataTypeId::Double)
+        .writable()
+        .historizing(true)
+        .history_updatable()
+        .build();
+    assert_eq!(
+        v.access_level(),
+        AccessLevel::CURRENT_READ
+            | AccessLevel::CURRENT_WRITE
+            | AccessLevel::HISTORY_READ
+            | AccessLevel::HISTORY_WRITE
+    );
+
+    // Not historizing leaves the access level alone
+    let v = VariableBuilder::new(&NodeId::new(1, "NoHistory"), "NoHistory", "NoHistory")
+        .data_type(DataTypeId::Double)
+        .historizing(false)
+        .build();
+    assert!(!v.historizing());
+    assert_eq!(v.access_level(), AccessLevel::CURRENT_READ);
+    assert_eq!(v.user_access_level(), UserAccessLevel::CURRENT_READ);
+}
+
 #[test]
 fn method_builder() {
     let mut address_space = AddressSpace::new();
@@ -928,3 +1178,492 @@ fn hierarchical_references() {
     assert!(refs.contains(&ObjectId::Server_ServerCapabilities_AggregateFunctions.into()));
     assert!(refs.contains(&ObjectId::HistoryServerCapabilities.into()));
 }
+
+#[test]
+fn find_as_node() {
+    let mut address_space = AddressSpace::new();
+
+    let node_id = NodeId::new(1, "MyReferenceType");
+    let reference_type = ReferenceType::new(
+        &node_id,
+        "MyReferenceType",
+        "MyReferenceType",
+        None,
+        true,
+        false,
+    );
+    assert!(address_space.insert::<ReferenceType, ReferenceTypeId>(reference_type, None));
+
+    let read_symmetric = |address_space: &AddressSpace| {
+        address_space
+            .find_as_node(&node_id)
+            .unwrap()
+            .get_attribute(
+                TimestampsToReturn::Neither,
+                AttributeId::Symmetric,
+                NumericRange::None,
+                &QualifiedName::null(),
+            )
+            .unwrap()
+            .value
+            .unwrap()
+    };
+
+    // Read the attribute without knowing the node is a reference type
+    let node = address_space.find_as_node(&node_id).unwrap();
+    assert_eq!(node.node_class(), NodeClass::ReferenceType);
+    assert_eq!(read_symmetric(&address_space), Variant::Boolean(true));
+
+    // Write it the same way
+    let node = address_space.find_as_node_mut(&node_id).unwrap();
+    node.set_write_mask(WriteMask::SYMMETRIC);
+    node.set_attribute(AttributeId::Symmetric, Variant::Boolean(false))
+        .unwrap();
+    assert_eq!(read_symmetric(&address_space), Variant::Boolean(false));
+
+    // Unknown nodes are not found
+    assert!(address_space
+        .find_as_node(&NodeId::new(1, "NotThere"))
+        .is_none());
+    assert!(address_space
+        .find_as_node_mut(&NodeId::new(1, "NotThere"))
+        .is_none());
+}
+
+#[test]
+fn add_node_from_attributes() {
+    let mut address_space = AddressSpace::new();
+    let ns = address_space.register_namespace("urn:test").unwrap();
+    let decoding_limits = DecodingLimits::default();
+
+    let specified_attributes = AttributesMask::DISPLAY_NAME
+        | AttributesMask::IS_ABSTRACT
+        | AttributesMask::SYMMETRIC
+        | AttributesMask::INVERSE_NAME;
+    let attributes = ExtensionObject::from_encodable(
+        ObjectId::ReferenceTypeAttributes_Encoding_DefaultBinary,
+        &ReferenceTypeAttributes {
+            specified_attributes: specified_attributes.bits(),
+            display_name: LocalizedText::from("IsRelatedTo"),
+            description: LocalizedText::null(),
+            write_mask: 0,
+            user_write_mask: 0,
+            is_abstract: false,
+            symmetric: true,
+            inverse_name: LocalizedText::from("IsRelatedTo"),
+        },
+    );
+
+    // Attributes which don't match the node class are rejected
+    let node_id = NodeId::new(ns, "IsRelatedTo");
+    assert_eq!(
+        address_space
+            .add_node_from_attributes(
+                NodeClass::Object,
+                &node_id,
+                QualifiedName::new(ns, "IsRelatedTo"),
+                &attributes,
+                &decoding_limits,
+            )
+            .unwrap_err(),
+        StatusCode::BadNodeAttributesInvalid
+    );
+    assert_eq!(
+        address_space
+            .add_node_from_attributes(
+                NodeClass::Unspecified,
+                &node_id,
+                QualifiedName::new(ns, "IsRelatedTo"),
+                &attributes,
+                &decoding_limits,
+            )
+            .unwrap_err(),
+        StatusCode::BadNodeClassInvalid
+    );
+    assert!(!address_space.node_exists(&node_id));
+
+    // A reference type is made from reference type attributes
+    let node = address_space
+        .add_node_from_attributes(
+            NodeClass::ReferenceType,
+            &node_id,
+            QualifiedName::new(ns, "IsRelatedTo"),
+            &attributes,
+            &decoding_limits,
+        )
+        .unwrap();
+    if let NodeType::ReferenceType(node) = node {
+        assert_eq!(node.node_id(), node_id);
+        assert_eq!(node.browse_name(), QualifiedName::new(ns, "IsRelatedTo"));
+        assert_eq!(node.display_name().text.as_ref(), "IsRelatedTo");
+        assert!(node.symmetric());
+        assert!(!node.is_abstract());
+        assert_eq!(node.inverse_name().unwrap().text.as_ref(), "IsRelatedTo");
+    } else {
+        panic!("Expected a reference type");
+    }
+
+    // The same node cannot be added twice
+    assert_eq!(
+        address_space
+            .add_node_from_attributes(
+                NodeClass::ReferenceType,
+                &node_id,
+                QualifiedName::new(ns, "IsRelatedTo"),
+                &attributes,
+                &decoding_limits,
+            )
+            .unwrap_err(),
+        StatusCode::BadNodeIdExists
+    );
+}
+
+#[test]
+fn iter_nodes_and_references() {
+    let mut address_space = AddressSpace::new();
+    let count_references = |address_space: &AddressSpace| {
+        let mut count = 0;
+        address_space.for_each_reference(|_, _| count += 1);
+        count
+    };
+    let nodes_before = address_space.iter_nodes().count();
+    let references_before = count_references(&address_space);
+
+    // A folder and a variable organized by it, each with a type definition
+    let folder_id = address_space
+        .add_folder("Folder", "Folder", &NodeId::objects_folder_id())
+        .unwrap();
+    let node_id = NodeId::new(1, "Hello");
+    VariableBuilder::new(&node_id, "Hello", "Hello")
+        .data_type(DataTypeId::Int32)
+        .value(1i32)
+        .organized_by(&folder_id)
+        .has_type_definition(VariableTypeId::BaseDataVariableType)
+        .insert(&mut address_space);
+
+    assert_eq!(address_space.iter_nodes().count(), nodes_before + 2);
+    assert_eq!(count_references(&address_space), references_before + 4);
+
+    // The folder's references are visited from the folder
+    let mut folder_references = Vec::new();
+    address_space.for_each_reference(|source_node, reference| {
+        if *source_node == folder_id {
+            folder_references.push(reference.clone());
+        }
+    });
+    assert!(folder_references.contains(&Reference::new(ReferenceTypeId::Organizes, node_id)));
+
+    // Nodes and references come out in the same, sorted order every time
+    let node_ids = address_space
+        .iter_nodes()
+        .map(|node| node.node_id())
+        .collect::<Vec<_>>();
+    let mut sorted_node_ids = node_ids.clone();
+    sorted_node_ids.sort();
+    assert_eq!(node_ids, sorted_node_ids);
+
+    let collect_references = |address_space: &AddressSpace| {
+        let mut references = Vec::new();
+        address_space.for_each_reference(|source_node, reference| {
+            references.push((source_node.clone(), reference.clone()))
+        });
+        references
+    };
+    let references = collect_references(&address_space);
+    assert!(references.windows(2).all(|w| w[0].0 <= w[1].0));
+    assert_eq!(references, collect_references(&address_space));
+}
+
+#[test]
+fn load_nodeset_fragment() {
+    let nodeset = r#"<?xml version="1.0" encoding="utf-8"?>
+<UANodeSet xmlns="http://opcfoundation.org/UA/2011/03/UANodeSet.xsd">
+  <NamespaceUris>
+    <Uri>urn:nodeset:test</Uri>
+  </NamespaceUris>
+  <Aliases>
+    <Alias Alias="Int32">i=6</Alias>
+    <Alias Alias="Organizes">i=35</Alias>
+    <Alias Alias="HasTypeDefinition">i=40</Alias>
+    <Alias Alias="HasSubtype">i=45</Alias>
+  </Aliases>
+  <UAReferenceType NodeId="ns=1;i=4001" BrowseName="1:Feeds">
+    <DisplayName>Feeds</DisplayName>
+    <InverseName>FedBy</InverseName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=32</Reference>
+    </References>
+  </UAReferenceType>
+  <UAObject NodeId="ns=1;i=5001" BrowseName="1:Pump">
+    <DisplayName>Pump</DisplayName>
+    <References>
+      <Reference ReferenceType="Organizes" IsForward="false">i=85</Reference>
+      <Reference ReferenceType="HasTypeDefinition">i=58</Reference>
+      <Reference ReferenceType="ns=1;i=4001">ns=1;i=6001</Reference>
+    </References>
+  </UAObject>
+  <UAVariable NodeId="ns=1;i=6001" BrowseName="1:Speed" DataType="Int32" AccessLevel="3">
+    <DisplayName Locale="en">Speed</DisplayName>
+    <Description>Revolutions per minute</Description>
+    <Value>
+      <Int32 xmlns="http://opcfoundation.org/UA/2008/02/Types.xsd">1500</Int32>
+    </Value>
+  </UAVariable>
+</UANodeSet>"#;
+    let address_space = load_nodeset(nodeset.as_bytes()).unwrap();
+
+    let ns = address_space.namespace_index("urn:nodeset:test").unwrap();
+    let feeds_id = NodeId::new(ns, 4001);
+    let pump_id = NodeId::new(ns, 5001);
+    let speed_id = NodeId::new(ns, 6001);
+
+    // The reference type
+    if let NodeType::ReferenceType(feeds) = address_space.find_node(&feeds_id).unwrap() {
+        assert_eq!(feeds.browse_name(), QualifiedName::new(ns, "Feeds"));
+        assert_eq!(feeds.inverse_name(), Some(LocalizedText::new("", "FedBy")));
+        assert!(!feeds.is_abstract());
+    } else {
+        panic!("Feeds is not a reference type");
+    }
+
+    // The variable, its attributes and value
+    let speed = address_space.find_variable(speed_id.clone()).unwrap();
+    assert_eq!(speed.browse_name(), QualifiedName::new(ns, "Speed"));
+    assert_eq!(speed.display_name(), LocalizedText::new("en", "Speed"));
+    assert_eq!(
+        speed.description(),
+        Some(LocalizedText::new("", "Revolutions per minute"))
+    );
+    assert_eq!(speed.data_type(), DataTypeId::Int32.into());
+    assert_eq!(speed.value_rank(), -1);
+    assert_eq!(
+        speed.access_level(),
+        AccessLevel::CURRENT_READ | AccessLevel::CURRENT_WRITE
+    );
+    assert_eq!(
+        speed
+            .value(
+                TimestampsToReturn::Neither,
+                NumericRange::None,
+                &QualifiedName::null(),
+                0.0
+            )
+            .value,
+        Some(Variant::Int32(1500))
+    );
+
+    // The references, forward and inverse
+    assert!(address_space.has_reference(
+        &ReferenceTypeId::NonHierarchicalReferences.into(),
+        &feeds_id,
+        ReferenceTypeId::HasSubtype
+    ));
+    assert!(address_space.has_reference(
+        &ObjectId::ObjectsFolder.into(),
+        &pump_id,
+        ReferenceTypeId::Organizes
+    ));
+    assert!(address_space.has_reference(
+        &pump_id,
+        &ObjectTypeId::BaseObjectType.into(),
+        ReferenceTypeId::HasTypeDefinition
+    ));
+    assert!(address_space.has_reference(&pump_id, &speed_id, feeds_id.clone()));
+
+    // A node id in an unknown namespace is an error
+    let nodeset = r#"<UANodeSet><UAObject NodeId="ns=2;i=1" BrowseName="X"/></UANodeSet>"#;
+    assert!(load_nodeset(nodeset.as_bytes()).is_err());
+}
+
+#[test]
+fn clone_node_with_id() {
+    let mut address_space = AddressSpace::new();
+    let template_id = NodeId::new(1, "Template");
+    VariableBuilder::new(&template_id, "Template", "Template Variable")
+        .data_type(DataTypeId::Double)
+        .value(12.5f64)
+        .description("A template")
+        .writable()
+        .organized_by(ObjectId::ObjectsFolder)
+        .has_type_definition(VariableTypeId::BaseDataVariableType)
+        .insert(&mut address_space);
+
+    // Clone the node itself
+    let template = address_space.find_variable(template_id.clone()).unwrap();
+    let clone_id = NodeId::new(1, "Clone");
+    let clone = template.clone_with_id(&clone_id, "Clone");
+    assert_eq!(clone.node_id(), clone_id);
+    assert_eq!(clone.browse_name(), QualifiedName::from("Clone"));
+    assert_eq!(clone.display_name(), template.display_name());
+    assert_eq!(clone.description(), template.description());
+    assert_eq!(clone.data_type(), template.data_type());
+    assert_eq!(clone.access_level(), template.access_level());
+    let value = |v: &Variable| {
+        v.value(
+            TimestampsToReturn::Neither,
+            NumericRange::None,
+            &QualifiedName::null(),
+            0f64,
+        )
+        .value
+    };
+    assert_eq!(value(&clone), value(template));
+
+    // Clone the node and its references into the address space
+    assert!(address_space.clone_node(&template_id, &clone_id, "Clone"));
+    assert!(!address_space.clone_node(&template_id, &clone_id, "Clone"));
+    assert!(!address_space.clone_node(&NodeId::new(1, "Missing"), &NodeId::new(1, "X"), "X"));
+    let clone = address_space.find_variable(clone_id.clone()).unwrap();
+    assert_eq!(value(clone), Some(Variant::from(12.5f64)));
+    assert!(address_space.has_reference(
+        &NodeId::objects_folder_id(),
+        &clone_id,
+        ReferenceTypeId::Organizes
+    ));
+    assert!(address_space.has_reference(
+        &clone_id,
+        &VariableTypeId::BaseDataVariableType.into(),
+        ReferenceTypeId::HasTypeDefinition
+    ));
+
+    // The template is unchanged
+    assert!(address_space.has_reference(
+        &NodeId::objects_folder_id(),
+        &template_id,
+        ReferenceTypeId::Organizes
+    ));
+    assert!(!address_space.has_reference(&clone_id, &template_id, ReferenceTypeId::Organizes));
+}
+
+#[test]
+fn attribute_value_status() {
+    let read = |node: &dyn Node, attribute_id| {
+        node.get_attribute_value(
+            TimestampsToReturn::Neither,
+            attribute_id,
+            NumericRange::None,
+            &QualifiedName::null(),
+            0f64,
+        )
+    };
+
+    let reference_type = ReferenceType::new(
+        &NodeId::new(1, "HasThing"),
+        "HasThing",
+        "HasThing",
+        None,
+        false,
+        false,
+    );
+
+    // A mandatory attribute
+    let value = read(&reference_type, AttributeId::Symmetric);
+    assert_eq!(value.status(), StatusCode::Good);
+    assert_eq!(value.value, Some(Variant::from(false)));
+
+    // An optional attribute which is not set
+    let value = read(&reference_type, AttributeId::InverseName);
+    assert_eq!(value.status, Some(StatusCode::GoodNoData));
+    assert!(value.value.is_none());
+
+    // An attribute which does not belong to a reference type
+    let value = read(&reference_type, AttributeId::Value);
+    assert_eq!(value.status, Some(StatusCode::BadAttributeIdInvalid));
+    assert!(value.value.is_none());
+
+    // The optional attribute when it is set
+    let reference_type = ReferenceType::new(
+        &NodeId::new(1, "HasThing"),
+        "HasThing",
+        "HasThing",
+        Some(LocalizedText::new("", "ThingOf")),
+        false,
+        false,
+    );
+    let value = read(&reference_type, AttributeId::InverseName);
+    assert_eq!(value.status(), StatusCode::Good);
+    assert_eq!(
+        value.value,
+        Some(Variant::from(LocalizedText::new("", "ThingOf")))
+    );
+}
+
+#[test]
+fn readable_attributes() {
+    let reference_type = ReferenceType::new(
+        &NodeId::new(1, "HasThing"),
+        "HasThing",
+        "HasThing",
+        None,
+        false,
+        false,
+    );
+    let base_attributes = [
+        AttributeId::NodeId,
+        AttributeId::NodeClass,
+        AttributeId::BrowseName,
+        AttributeId::DisplayName,
+    ];
+
+    // The inverse name is optional so it is only there when it is set
+    let attributes = reference_type.readable_attributes();
+    base_attributes
+        .iter()
+        .for_each(|a| assert!(attributes.contains(a), "{:?}", a));
+    assert!(attributes.contains(&AttributeId::Symmetric));
+    assert!(attributes.contains(&AttributeId::IsAbstract));
+    assert!(!attributes.contains(&AttributeId::InverseName));
+    assert!(!attributes.contains(&AttributeId::Value));
+
+    let reference_type = ReferenceType::new(
+        &NodeId::new(1, "HasThing"),
+        "HasThing",
+        "HasThing",
+        Some(LocalizedText::new("", "ThingOf")),
+        false,
+        false,
+    );
+    let attributes = reference_type.readable_attributes();
+    base_attributes
+        .iter()
+        .for_each(|a| assert!(attributes.contains(a), "{:?}", a));
+    assert!(attributes.contains(&AttributeId::Symmetric));
+    assert!(attributes.contains(&AttributeId::IsAbstract));
+    assert!(attributes.contains(&AttributeId::InverseName));
+
+    // Every attribute returned can be read
+    attributes.iter().for_each(|a| {
+        let value = reference_type.get_attribute_value(
+            TimestampsToReturn::Neither,
+            *a,
+            NumericRange::None,
+            &QualifiedName::null(),
+            0f64,
+        );
+        assert_eq!(value.status(), StatusCode::Good, "{:?}", a);
+    });
+}
+
+#[test]
+fn downcast_node() {
+    let address_space = AddressSpace::new();
+
+    // Find a node generically and refine it to its concrete type
+    let node = address_space
+        .find(ReferenceTypeId::HierarchicalReferences)
+        .unwrap()
+        .as_node();
+    let reference_type = node.as_reference_type().unwrap();
+    assert_eq!(reference_type.symmetric(), false);
+    assert!(node.downcast_ref::<ReferenceType>().is_some());
+
+    let node = address_space
+        .find(ReferenceTypeId::References)
+        .unwrap()
+        .as_node();
+    assert_eq!(node.as_reference_type().unwrap().symmetric(), true);
+
+    // The wrong type is refused
+    assert!(node.as_variable().is_none());
+    assert!(node.downcast_ref::<Object>().is_none());
+}
@@ -61,6 +61,73 @@ fn add_sample_vars_to_address_space(address_space: Arc<RwLock<AddressSpace>>) {
     let _ = address_space.add_variables(vars, &sample_folder_id);
 }
 
+#[test]
+pub fn server_state_transitions() {
+    use opcua_types::service_types::ServerState as ServerStateType;
+
+    // A server may shut down from any state but never leave shutdown, and a failed server can
+    // only shut down
+    assert!(ServerStateType::Suspended.can_transition_to(ServerStateType::Running));
+    assert!(ServerStateType::Running.can_transition_to(ServerStateType::Running));
+    assert!(ServerStateType::Running.can_transition_to(ServerStateType::Failed));
+    assert!(ServerStateType::Running.can_transition_to(ServerStateType::Shutdown));
+    assert!(ServerStateType::Failed.can_transition_to(ServerStateType::Shutdown));
+    assert!(!ServerStateType::Failed.can_transition_to(ServerStateType::Running));
+    assert!(ServerStateType::Shutdown.can_transition_to(ServerStateType::Shutdown));
+    assert!(!ServerStateType::Shutdown.can_transition_to(ServerStateType::Running));
+    assert!(!ServerStateType::Shutdown.can_transition_to(ServerStateType::Suspended));
+
+    // The state of the server is what the ServerStatus State variable reads
+    let server = ServerBuilder::new_sample().server().unwrap();
+    let server_state = server.server_state();
+    let address_space = server.address_space();
+    let read_state = || {
+        let address_space = trace_read_lock_unwrap!(address_space);
+        let v = address_space
+            .find_variable(VariableId::Server_ServerStatus_State)
+            .unwrap();
+        v.value(
+            TimestampsToReturn::Neither,
+            NumericRange::None,
+            &QualifiedName::null(),
+            0.0,
+        )
+        .value
+        .unwrap()
+    };
+
+    // The server is suspended until it runs
+    assert_eq!(
+        read_state(),
+        Variant::Int32(ServerStateType::Suspended as i32)
+    );
+    {
+        let mut server_state = trace_write_lock_unwrap!(server_state);
+        assert!(server_state.set_state(ServerStateType::Running).is_ok());
+    }
+    assert_eq!(
+        read_state(),
+        Variant::Int32(ServerStateType::Running as i32)
+    );
+
+    // Once shut down, the server cannot run again
+    {
+        let mut server_state = trace_write_lock_unwrap!(server_state);
+        server_state.abort();
+        assert_eq!(
+            server_state
+                .set_state(ServerStateType::Running)
+                .unwrap_err(),
+            StatusCode::BadInvalidState
+        );
+        assert_eq!(server_state.state(), ServerStateType::Shutdown);
+    }
+    assert_eq!(
+        read_state(),
+        Variant::Int32(ServerStateType::Shutdown as i32)
+    );
+}
+
 #[test]
 pub fn server_config_sample_save() {
     // This test only exists to dump a sample config
@@ -85,6 +152,127 @@ pub fn server_config_save() {
     }
 }
 
+#[test]
+pub fn server_limits_decoding_limits() {
+    use opcua_core::comms::chunker::Chunker;
+
+    // The limits in the config are the ones a session decodes its messages with
+    let server = ServerBuilder::new_sample()
+        .max_array_length(2)
+        .max_nesting_depth(8)
+        .server()
+        .unwrap();
+    let session = Session::new(&server);
+    let secure_channel = session.secure_channel();
+    let secure_channel = trace_read_lock_unwrap!(secure_channel);
+    let decoding_limits = secure_channel.decoding_limits();
+    assert_eq!(decoding_limits.max_array_length, 2);
+    assert_eq!(decoding_limits.max_nesting_depth, 8);
+
+    let read_request = |count: u32| -> SupportedMessage {
+        ReadRequest {
+            request_header: RequestHeader::dummy(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Neither,
+            nodes_to_read: Some((0..count).map(|i| NodeId::new(1, i).into()).collect()),
+        }
+        .into()
+    };
+
+    // An incoming request with an array within the limit decodes
+    let request = read_request(2);
+    let chunks = Chunker::encode(1, 1, 0, 0, &secure_channel, &request).unwrap();
+    assert_eq!(
+        Chunker::decode(&chunks, &secure_channel, None).unwrap(),
+        request
+    );
+
+    // An oversized array is rejected, the chunker reports any message it cannot decode as unsupported
+    let chunks = Chunker::encode(1, 1, 0, 0, &secure_channel, &read_request(3)).unwrap();
+    assert_eq!(
+        Chunker::decode(&chunks, &secure_channel, None).unwrap_err(),
+        StatusCode::BadServiceUnsupported
+    );
+}
+
+#[test]
+pub fn hello_timeout() {
+    use crate::comms::transport::{Transport, TransportState};
+
+    let server = ServerBuilder::new_sample().server().unwrap();
+    let hello_timeout = {
+        let server_state = server.server_state();
+        let server_state = trace_read_lock_unwrap!(server_state);
+        let config = trace_read_lock_unwrap!(server_state.config);
+        chrono::Duration::seconds(config.tcp_config.hello_timeout as i64)
+    };
+
+    // A connection that never sends a hello is left alone until the timeout passes
+    let mut transport = server.new_transport();
+    let start = chrono::Utc::now();
+    assert!(!transport.check_hello_timeout(&start, &(start + hello_timeout)));
+    assert!(!transport.is_finished());
+
+    // Then it is closed with BadTimeout
+    let later = start + hello_timeout + chrono::Duration::milliseconds(1);
+    assert!(transport.check_hello_timeout(&start, &later));
+    assert_eq!(
+        transport.state(),
+        TransportState::Finished(StatusCode::BadTimeout)
+    );
+
+    // It only times out once
+    assert!(!transport.check_hello_timeout(&start, &later));
+}
+
+#[test]
+pub fn hello_acknowledged() {
+    use opcua_core::comms::tcp_types::HelloMessage;
+
+    use crate::comms::transport::{Transport, TransportState};
+
+    let server = ServerBuilder::new_sample().server().unwrap();
+    let endpoint_url = {
+        let server_state = server.server_state();
+        let server_state = trace_read_lock_unwrap!(server_state);
+        let base_url = {
+            let config = trace_read_lock_unwrap!(server_state.config);
+            format!(
+                "opc.tcp://{}:{}/",
+                config.tcp_config.host, config.tcp_config.port
+            )
+        };
+        let endpoints = server_state
+            .endpoints(&UAString::from(base_url), &None)
+            .unwrap();
+        endpoints[0].endpoint_url.as_ref().to_string()
+    };
+
+    // The acknowledge has the smaller of each side's buffer sizes and the server's limits
+    let mut transport = server.new_transport();
+    let hello = HelloMessage::new(&endpoint_url, 8196, 1_000_000, 0);
+    let acknowledge = transport.acknowledge_hello(&hello).unwrap();
+    assert_eq!(acknowledge.receive_buffer_size, 8196);
+    assert_eq!(acknowledge.send_buffer_size, std::u16::MAX as u32);
+    assert_eq!(acknowledge.max_message_size, std::u16::MAX as u32);
+    assert_eq!(transport.state(), TransportState::ProcessMessages);
+
+    // A second hello breaks the handshake
+    assert_eq!(
+        transport.acknowledge_hello(&hello).unwrap_err(),
+        StatusCode::BadTcpMessageTypeInvalid
+    );
+
+    // A hello for an endpoint the server does not have is refused
+    let mut transport = server.new_transport();
+    let hello = HelloMessage::new("opc.tcp://nowhere:1234/", 8196, 8196, 0);
+    assert_eq!(
+        transport.acknowledge_hello(&hello).unwrap_err(),
+        StatusCode::BadTcpEndpointUrlInvalid
+    );
+    assert!(!transport.has_received_hello());
+}
+
 #[test]
 pub fn server_config_invalid() {
     // Remove the endpoint
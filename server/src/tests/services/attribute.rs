@@ -1,3 +1,5 @@
+use std::io::Cursor;
+
 use chrono::Duration;
 
 use opcua_types::{Variant, WriteMask};
@@ -45,11 +47,8 @@ fn node_ids(address_space: Arc<RwLock<AddressSpace>>) -> Vec<NodeId> {
     let (_, node_ids) = add_many_vars_to_address_space(address_space.clone(), 10);
     let mut address_space = trace_write_lock_unwrap!(address_space);
     // Remove read access to [3] for a test below
-    let node = address_space.find_node_mut(&node_ids[3]).unwrap();
-    let r = node
-        .as_mut_node()
-        .set_attribute(AttributeId::UserAccessLevel, Variant::from(0u8));
-    assert!(r.is_ok());
+    let node = address_space.find_variable_mut(&node_ids[3]).unwrap();
+    node.set_user_access_level(UserAccessLevel::empty());
     node_ids
 }
 
@@ -165,6 +164,175 @@ fn read() {
     });
 }
 
+#[test]
+fn read_display_name_and_value() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_id = NodeId::new(1, "ReadMe");
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let _ = VariableBuilder::new(&node_id, "ReadMe", "Read Me")
+                .data_type(DataTypeId::Double)
+                .organized_by(ObjectId::ObjectsFolder)
+                .value(12.5f64)
+                .insert(&mut address_space);
+        }
+
+        let read = |timestamps_to_return, max_age, nodes_to_read| {
+            // The request goes through the same decode as one received off the wire
+            let request = ReadRequest {
+                request_header: make_request_header(),
+                max_age,
+                timestamps_to_return,
+                nodes_to_read: Some(nodes_to_read),
+            };
+            let request = ReadRequest::decode(
+                &mut Cursor::new(request.encode_to_vec()),
+                &DecodingLimits::default(),
+            )
+            .unwrap();
+            ats.read(
+                server_state.clone(),
+                session.clone(),
+                address_space.clone(),
+                &request,
+            )
+        };
+
+        let nodes_to_read = vec![
+            read_value(&node_id, AttributeId::DisplayName),
+            read_value(&node_id, AttributeId::Value),
+            ReadValueId {
+                attribute_id: 999,
+                ..read_value(&node_id, AttributeId::Value)
+            },
+        ];
+        let response = read(TimestampsToReturn::Server, 0f64, nodes_to_read.clone());
+        let results = supported_message_as!(response, ReadResponse)
+            .results
+            .unwrap();
+        assert_eq!(results.len(), 3);
+
+        // Display name has no timestamps
+        assert_eq!(
+            results[0].value.as_ref().unwrap(),
+            &Variant::from(LocalizedText::new("", "Read Me"))
+        );
+        assert!(results[0].server_timestamp.is_none());
+
+        // Value only has the timestamp asked for
+        assert_eq!(results[1].value.as_ref().unwrap(), &Variant::Double(12.5));
+        assert_eq!(results[1].status.as_ref().unwrap(), &StatusCode::Good);
+        assert!(results[1].server_timestamp.is_some());
+        assert!(results[1].source_timestamp.is_none());
+
+        // Unknown attribute id
+        assert_eq!(
+            results[2].status.as_ref().unwrap(),
+            &StatusCode::BadAttributeIdInvalid
+        );
+        assert!(results[2].value.is_none());
+
+        // Neither timestamp
+        let response = read(TimestampsToReturn::Neither, 0f64, nodes_to_read.clone());
+        let results = supported_message_as!(response, ReadResponse)
+            .results
+            .unwrap();
+        assert!(results[1].server_timestamp.is_none());
+        assert!(results[1].source_timestamp.is_none());
+
+        // Source timestamp only
+        let response = read(TimestampsToReturn::Source, 0f64, nodes_to_read.clone());
+        let results = supported_message_as!(response, ReadResponse)
+            .results
+            .unwrap();
+        assert!(results[1].server_timestamp.is_none());
+        assert!(results[1].source_timestamp.is_some());
+
+        // Negative max age is rejected
+        let response = read(TimestampsToReturn::Both, -1f64, nodes_to_read);
+        let response = supported_message_as!(response, ServiceFault);
+        assert_eq!(
+            response.response_header.service_result,
+            StatusCode::BadMaxAgeInvalid
+        );
+    });
+}
+
+#[test]
+fn read_attribute_of_node_class() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_ids = node_ids(address_space.clone());
+
+        let nodes_to_read = vec![
+            // Symmetric is not an attribute of a variable
+            read_value(&node_ids[0], AttributeId::Symmetric),
+            // but it is of a reference type
+            read_value(&ReferenceTypeId::HasChild.into(), AttributeId::Symmetric),
+            // Value is not an attribute of a reference type
+            read_value(&ReferenceTypeId::HasChild.into(), AttributeId::Value),
+        ];
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Neither,
+            nodes_to_read: Some(nodes_to_read),
+        };
+        let response = ats.read(server_state, session, address_space, &request);
+        let results = supported_message_as!(response, ReadResponse)
+            .results
+            .unwrap();
+
+        assert_eq!(
+            results[0].status.as_ref().unwrap(),
+            &StatusCode::BadAttributeIdInvalid
+        );
+        assert!(results[0].value.is_none());
+        assert!(results[1].status.is_none());
+        assert_eq!(results[1].value.as_ref().unwrap(), &Variant::Boolean(false));
+        assert_eq!(
+            results[2].status.as_ref().unwrap(),
+            &StatusCode::BadAttributeIdInvalid
+        );
+    });
+}
+
+#[test]
+fn read_node_id_and_node_class() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        let node_ids = node_ids(address_space.clone());
+        let reference_type_id: NodeId = ReferenceTypeId::HasChild.into();
+
+        let nodes_to_read = vec![
+            read_value(&reference_type_id, AttributeId::NodeId),
+            read_value(&reference_type_id, AttributeId::NodeClass),
+            // A variable with no read access still has a readable node id and class
+            read_value(&node_ids[3], AttributeId::NodeId),
+            read_value(&node_ids[3], AttributeId::NodeClass),
+        ];
+        let request = ReadRequest {
+            request_header: make_request_header(),
+            max_age: 0f64,
+            timestamps_to_return: TimestampsToReturn::Neither,
+            nodes_to_read: Some(nodes_to_read),
+        };
+        let response = ats.read(server_state, session, address_space, &request);
+        let results = supported_message_as!(response, ReadResponse)
+            .results
+            .unwrap();
+
+        let expected = [
+            Variant::from(reference_type_id),
+            Variant::Int32(NodeClass::ReferenceType as i32),
+            Variant::from(node_ids[3].clone()),
+            Variant::Int32(NodeClass::Variable as i32),
+        ];
+        for (result, expected) in results.iter().zip(expected.iter()) {
+            assert_eq!(result.status.as_ref().unwrap(), &StatusCode::Good);
+            assert_eq!(result.value.as_ref().unwrap(), expected);
+        }
+    });
+}
+
 #[test]
 fn read_invalid_timestamps() {
     // The TimestampsToReturnEnum will be set to Invalid to simulate a decoding error.
@@ -275,30 +443,19 @@ fn write() {
                     }
                     2 => {
                         // Remove write access to the value by setting access level to 0
-                        let _ = node
-                            .as_mut_node()
-                            .set_attribute(AttributeId::UserAccessLevel, Variant::from(0u8))
-                            .unwrap();
+                        if let NodeType::Variable(node) = node {
+                            node.set_user_access_level(UserAccessLevel::empty());
+                        }
                     }
                     6 => {
                         node.as_mut_node().set_write_mask(WriteMask::ACCESS_LEVEL);
                     }
                     _ => {
                         // Write access
-                        let _ = node
-                            .as_mut_node()
-                            .set_attribute(
-                                AttributeId::AccessLevel,
-                                Variant::from(AccessLevel::CURRENT_WRITE.bits()),
-                            )
-                            .unwrap();
-                        let _ = node
-                            .as_mut_node()
-                            .set_attribute(
-                                AttributeId::UserAccessLevel,
-                                Variant::from(UserAccessLevel::CURRENT_WRITE.bits()),
-                            )
-                            .unwrap();
+                        if let NodeType::Variable(node) = node {
+                            node.set_access_level(AccessLevel::CURRENT_WRITE);
+                            node.set_user_access_level(UserAccessLevel::CURRENT_WRITE);
+                        }
                     }
                 }
             }
@@ -399,6 +556,125 @@ fn write() {
     });
 }
 
+#[test]
+fn set_attribute_write_mask() {
+    let mut address_space = AddressSpace::new();
+    let node = address_space
+        .find_node_mut(&ReferenceTypeId::HasChild.into())
+        .unwrap()
+        .as_mut_node();
+
+    // The write mask bit is clear so the write is rejected and the attribute is unchanged
+    node.set_write_mask(WriteMask::SYMMETRIC);
+    assert_eq!(
+        node.set_attribute(AttributeId::IsAbstract, Variant::Boolean(false)),
+        Err(StatusCode::BadNotWritable)
+    );
+    assert_eq!(
+        node.get_attribute(
+            TimestampsToReturn::Neither,
+            AttributeId::IsAbstract,
+            NumericRange::None,
+            &QualifiedName::null()
+        )
+        .unwrap()
+        .value,
+        Some(Variant::Boolean(true))
+    );
+
+    // The write mask bit is set so the write succeeds
+    node.set_write_mask(WriteMask::IS_ABSTRACT);
+    assert!(node
+        .set_attribute(AttributeId::IsAbstract, Variant::Boolean(false))
+        .is_ok());
+    assert_eq!(
+        node.get_attribute(
+            TimestampsToReturn::Neither,
+            AttributeId::IsAbstract,
+            NumericRange::None,
+            &QualifiedName::null()
+        )
+        .unwrap()
+        .value,
+        Some(Variant::Boolean(false))
+    );
+
+    // Common attributes held by the base are gated too
+    assert_eq!(
+        node.set_attribute(
+            AttributeId::DisplayName,
+            Variant::from(LocalizedText::text_only("Child"))
+        ),
+        Err(StatusCode::BadNotWritable)
+    );
+}
+
+#[test]
+fn write_scalar_value() {
+    do_attribute_service_test(|server_state, session, address_space, ats| {
+        // A writable scalar and a read only scalar
+        let writable_id = NodeId::next_numeric(2);
+        let read_only_id = NodeId::next_numeric(2);
+        {
+            let mut address_space = trace_write_lock_unwrap!(address_space);
+            let _ = VariableBuilder::new(&writable_id, var_name(0), "")
+                .data_type(DataTypeId::Int32)
+                .value_rank(-1)
+                .value(0i32)
+                .organized_by(ObjectId::RootFolder)
+                .writable()
+                .insert(&mut address_space);
+            let _ = VariableBuilder::new(&read_only_id, var_name(1), "")
+                .data_type(DataTypeId::Int32)
+                .value_rank(-1)
+                .value(0i32)
+                .organized_by(ObjectId::RootFolder)
+                .insert(&mut address_space);
+        }
+
+        let nodes_to_write = vec![
+            write_value(&writable_id, AttributeId::Value, DataValue::new_now(42i32)),
+            // An array does not suit a scalar value rank
+            write_value(
+                &writable_id,
+                AttributeId::Value,
+                DataValue::new_now(vec![1i32, 2i32]),
+            ),
+            write_value(&read_only_id, AttributeId::Value, DataValue::new_now(42i32)),
+            // The write mask does not permit the display name to be written
+            write_value(
+                &writable_id,
+                AttributeId::DisplayName,
+                DataValue::new_now(LocalizedText::new("", "Renamed")),
+            ),
+        ];
+        let response = write_request(
+            server_state,
+            session,
+            address_space.clone(),
+            ats,
+            nodes_to_write,
+        );
+        let results = response.results.unwrap();
+        assert_eq!(
+            results,
+            vec![
+                StatusCode::Good,
+                StatusCode::BadTypeMismatch,
+                StatusCode::BadNotWritable,
+                StatusCode::BadNotWritable,
+            ]
+        );
+
+        validate_variable_value(address_space.clone(), &writable_id, |value| {
+            assert_eq!(*value, Variant::Int32(42))
+        });
+        validate_variable_value(address_space, &read_only_id, |value| {
+            assert_eq!(*value, Variant::Int32(0))
+        });
+    });
+}
+
 #[test]
 fn write_bytestring_to_byte_array() {
     // This test checks that writing a byte string to a byte array variable works
@@ -162,6 +162,8 @@ pub mod discovery;
 pub mod method;
 pub mod monitored_item;
 pub mod node_management;
+pub mod secure_channel;
 pub mod session;
+pub mod sessionless;
 pub mod subscription;
 pub mod view;
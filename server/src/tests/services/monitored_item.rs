@@ -401,6 +401,76 @@ fn data_change_deadband_abs_test() {
     assert_eq!(filter.compare(&v1, &v2, None), false);
 }
 
+#[test]
+fn data_change_nan() {
+    let v1 = Variant::Double(f64::NAN);
+    let v2 = Variant::Double(1f64);
+    for deadband_type in &[DeadbandType::None, DeadbandType::Absolute] {
+        let filter = DataChangeFilter {
+            trigger: DataChangeTrigger::StatusValue,
+            deadband_type: *deadband_type as u32,
+            deadband_value: 1f64,
+        };
+        // A value that stays NaN has not changed, going to or from NaN has
+        assert_eq!(filter.compare_value(&v1, &v1, None), Ok(true));
+        assert_eq!(filter.compare_value(&v1, &v2, None), Ok(false));
+        assert_eq!(filter.compare_value(&v2, &v1, None), Ok(false));
+    }
+}
+
+#[test]
+fn monitored_item_no_filter_nan() {
+    let mut address_space = make_address_space();
+    let set_value = |address_space: &mut AddressSpace, value: Variant| {
+        if let &mut NodeType::Variable(ref mut node) =
+            address_space.find_node_mut(&test_var_node_id()).unwrap()
+        {
+            let _ = node.set_value(NumericRange::None, value).unwrap();
+        } else {
+            panic!("Expected a variable, didn't get one!!");
+        }
+    };
+    set_value(&mut address_space, Variant::Double(f64::NAN));
+
+    // No filter so the item compares values for equality
+    let mut monitored_item = MonitoredItem::new(
+        &chrono::Utc::now(),
+        1,
+        TimestampsToReturn::Both,
+        &make_create_request(
+            -1f64,
+            5,
+            test_var_node_id(),
+            AttributeId::Value,
+            ExtensionObject::null(),
+        ),
+    )
+    .unwrap();
+    let now = Utc::now();
+
+    // The NaN is reported once and then not again while it stays NaN
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert_eq!(monitored_item.all_notifications().unwrap().len(), 1);
+    for _ in 0..3 {
+        assert_eq!(
+            monitored_item.tick(&now, &address_space, true, false),
+            TickResult::NoChange
+        );
+    }
+    assert!(monitored_item.notification_queue().is_empty());
+
+    // A number after the NaN is a change
+    set_value(&mut address_space, Variant::Double(1f64));
+    assert_eq!(
+        monitored_item.tick(&now, &address_space, true, false),
+        TickResult::ReportValueChanged
+    );
+    assert_eq!(monitored_item.notification_queue().len(), 1);
+}
+
 // Straight tests of abs function
 #[test]
 fn deadband_abs() {
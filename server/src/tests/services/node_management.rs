@@ -447,6 +447,24 @@ fn add_nodes_browse_name_duplicated() {
     );
 }
 
+#[test]
+fn add_nodes_browse_name_namespace_invalid() {
+    // Add a node whose browse name is in a namespace which doesn't exist
+    do_add_node_test_with_expected_error(
+        true,
+        AddNodesItem {
+            parent_node_id: ObjectId::ObjectsFolder.into(),
+            reference_type_id: ReferenceTypeId::Organizes.into(),
+            requested_new_node_id: ExpandedNodeId::null(),
+            browse_name: QualifiedName::new(100, "boo"), // !!!
+            node_class: NodeClass::Object,
+            node_attributes: object_attributes("foo"),
+            type_definition: ObjectTypeId::BaseObjectType.into(),
+        },
+        StatusCode::BadBrowseNameInvalid,
+    );
+}
+
 #[test]
 fn add_nodes_valid() {
     // Add a node which is valid
@@ -0,0 +1,69 @@
+use opcua_core::comms::prelude::*;
+
+use crate::{comms::secure_channel_service::SecureChannelService, tests::*};
+
+fn open_secure_channel_request(request_type: SecurityTokenRequestType) -> SupportedMessage {
+    OpenSecureChannelRequest {
+        request_header: RequestHeader::dummy(),
+        client_protocol_version: 0,
+        request_type,
+        security_mode: MessageSecurityMode::None,
+        client_nonce: ByteString::null(),
+        requested_lifetime: 60000,
+    }
+    .into()
+}
+
+#[test]
+fn open_then_close_secure_channel() {
+    let mut service = SecureChannelService::new();
+    let mut secure_channel: SecureChannel =
+        (SecurityPolicy::None, MessageSecurityMode::None).into();
+    let security_header = SecurityHeader::Asymmetric(AsymmetricSecurityHeader::none());
+    assert!(!service.is_open());
+
+    let response = service
+        .open_secure_channel(
+            &mut secure_channel,
+            &security_header,
+            0,
+            &open_secure_channel_request(SecurityTokenRequestType::Issue),
+        )
+        .unwrap();
+    assert!(matches!(
+        response,
+        SupportedMessage::OpenSecureChannelResponse(_)
+    ));
+    assert!(service.is_open());
+    assert_ne!(secure_channel.secure_channel_id(), 0);
+
+    // Only a close request can close the channel
+    let result = service.close_secure_channel(
+        &mut secure_channel,
+        &open_secure_channel_request(SecurityTokenRequestType::Issue),
+    );
+    assert_eq!(result.unwrap_err(), StatusCode::BadUnexpectedError);
+    assert!(service.is_open());
+
+    let close: SupportedMessage = CloseSecureChannelRequest {
+        request_header: RequestHeader::dummy(),
+    }
+    .into();
+    service
+        .close_secure_channel(&mut secure_channel, &close)
+        .unwrap();
+    assert!(!service.is_open());
+    assert_eq!(secure_channel.secure_channel_id(), 0);
+    assert_eq!(secure_channel.token_id(), 0);
+
+    // The closed channel can neither be closed again nor renewed
+    let result = service.close_secure_channel(&mut secure_channel, &close);
+    assert_eq!(result.unwrap_err(), StatusCode::BadSecureChannelClosed);
+    let result = service.open_secure_channel(
+        &mut secure_channel,
+        &security_header,
+        0,
+        &open_secure_channel_request(SecurityTokenRequestType::Renew),
+    );
+    assert_eq!(result.unwrap_err(), StatusCode::BadUnexpectedError);
+}
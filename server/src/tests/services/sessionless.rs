@@ -0,0 +1,87 @@
+use std::io::Cursor;
+
+use opcua_core::sessionless::{SessionlessInvoke, SessionlessInvokeRequest, UnknownServicePolicy};
+use opcua_types::UAString;
+
+use super::*;
+
+fn do_sessionless_service_test<F>(policy: UnknownServicePolicy, f: F)
+where
+    F: FnOnce(&Server),
+{
+    let st = ServiceTest::new();
+    {
+        let server_state = trace_read_lock_unwrap!(st.server_state);
+        let mut config = trace_write_lock_unwrap!(server_state.config);
+        config.sessionless_unknown_service_policy = policy;
+    }
+
+    f(&st.server);
+}
+
+fn get_endpoints_invoke() -> SessionlessInvokeRequest {
+    SessionlessInvokeRequest::new(
+        GetEndpointsRequest {
+            request_header: make_request_header(),
+            endpoint_url: UAString::from("opc.tcp://localhost:4855/"),
+            locale_ids: None,
+            profile_uris: None,
+        },
+        None,
+        None,
+    )
+}
+
+fn unknown_invoke(r: &SessionlessInvokeRequest) -> SessionlessInvokeRequest {
+    let mut r = r.clone();
+    r.header.service_id = 0xffff_fff0;
+    r
+}
+
+#[test]
+fn sessionless_default_policy() {
+    let config = ServerConfig::default();
+    assert_eq!(
+        config.sessionless_unknown_service_policy,
+        UnknownServicePolicy::Reject
+    );
+}
+
+#[test]
+fn sessionless_reject_unknown_service() {
+    do_sessionless_service_test(UnknownServicePolicy::Reject, |server| {
+        // A known service is routed
+        let r = get_endpoints_invoke();
+        let mut stream = Cursor::new(r.encode_to_vec());
+        let result = server.sessionless_invoke(&mut stream).unwrap();
+        assert_eq!(result, SessionlessInvoke::Request(r.clone()));
+
+        // An unknown service is rejected
+        let mut stream = Cursor::new(unknown_invoke(&r).encode_to_vec());
+        let result = server.sessionless_invoke(&mut stream).unwrap_err();
+        assert_eq!(result, StatusCode::BadServiceUnsupported);
+    });
+}
+
+#[test]
+fn sessionless_pass_through_unknown_service() {
+    do_sessionless_service_test(UnknownServicePolicy::PassThrough, |server| {
+        // A known service is routed
+        let r = get_endpoints_invoke();
+        let mut stream = Cursor::new(r.encode_to_vec());
+        let result = server.sessionless_invoke(&mut stream).unwrap();
+        assert_eq!(result, SessionlessInvoke::Request(r.clone()));
+
+        // An unknown service is passed through with its request left encoded
+        let unknown = unknown_invoke(&r);
+        let mut stream = Cursor::new(unknown.encode_to_vec());
+        let result = server.sessionless_invoke(&mut stream).unwrap();
+        assert_eq!(
+            result,
+            SessionlessInvoke::PassThrough {
+                header: unknown.header,
+                body: r.request.encode_to_vec(),
+            }
+        );
+    });
+}
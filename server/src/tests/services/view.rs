@@ -1,8 +1,8 @@
-use std::sync::Weak;
+use std::sync::{Mutex, Weak};
 
 use opcua_console_logging;
 
-use crate::services::view::ViewService;
+use crate::{continuation_point::ContinuationPointManager, services::view::ViewService};
 
 use super::*;
 
@@ -855,6 +855,93 @@ fn browse_next_modify_address_space() {
     });
 }
 
+#[test]
+fn browse_next_expired_cp() {
+    // Browse and get a continuation point which then times out before it is used
+    do_view_service_test(|server_state, session, address_space, vs| {
+        let parent_node_id = add_many_vars_to_address_space(address_space.clone(), 100).0;
+        let nodes = vec![parent_node_id.clone()];
+        let response = do_browse(
+            &vs,
+            server_state,
+            session.clone(),
+            address_space.clone(),
+            &nodes,
+            99,
+            BrowseDirection::Forward,
+        );
+        let r1 = &response.results.unwrap()[0];
+        assert!(!r1.continuation_point.is_null());
+
+        // Expire the continuation point as if the timeout had elapsed
+        {
+            let mut session = trace_write_lock_unwrap!(session);
+            let address_space = trace_read_lock_unwrap!(address_space);
+            let now = chrono::Utc::now()
+                + chrono::Duration::milliseconds(
+                    crate::constants::BROWSE_CONTINUATION_POINT_TIMEOUT_MS + 1000,
+                );
+            session
+                .browse_continuation_points()
+                .remove_expired(&address_space, &now);
+        }
+
+        let response = do_browse_next(
+            &vs,
+            session.clone(),
+            address_space.clone(),
+            &r1.continuation_point,
+            false,
+        );
+        let r1 = &response.results.unwrap()[0];
+        assert_eq!(r1.status_code, StatusCode::BadContinuationPointInvalid);
+    });
+}
+
+#[test]
+fn continuation_point_manager() {
+    let address_space = AddressSpace::new();
+    let reference_descriptions = Arc::new(Mutex::new(Vec::new()));
+
+    let mut manager = ContinuationPointManager::new(2, 1000);
+    assert!(manager.is_empty());
+
+    // Issue a point and fetch it back, the point can only be taken once
+    let cp1 = manager.issue(&address_space, reference_descriptions.clone(), 10, 10);
+    assert!(!cp1.is_null());
+    assert_eq!(manager.len(), 1);
+    let point = manager.take(&cp1).unwrap();
+    assert_eq!(point.id, cp1);
+    assert_eq!(point.starting_index, 10);
+    assert_eq!(point.max_references_per_node, 10);
+    assert!(manager.take(&cp1).is_none());
+
+    // Released points are discarded
+    let cp1 = manager.issue(&address_space, reference_descriptions.clone(), 10, 10);
+    let cp2 = manager.issue(&address_space, reference_descriptions.clone(), 20, 10);
+    manager.release(&[cp1.clone()]);
+    assert_eq!(manager.len(), 1);
+    assert!(manager.take(&cp1).is_none());
+    assert!(manager.take(&cp2).is_some());
+
+    // The oldest point makes way when the cap is reached
+    let cp1 = manager.issue(&address_space, reference_descriptions.clone(), 10, 10);
+    let cp2 = manager.issue(&address_space, reference_descriptions.clone(), 20, 10);
+    let cp3 = manager.issue(&address_space, reference_descriptions.clone(), 30, 10);
+    assert_eq!(manager.len(), 2);
+    assert!(manager.take(&cp1).is_none());
+
+    // Points expire after the timeout
+    let now = chrono::Utc::now();
+    manager.remove_expired(&address_space, &now);
+    assert_eq!(manager.len(), 2);
+    let now = now + chrono::Duration::milliseconds(2000);
+    manager.remove_expired(&address_space, &now);
+    assert!(manager.is_empty());
+    assert!(manager.take(&cp2).is_none());
+    assert!(manager.take(&cp3).is_none());
+}
+
 #[test]
 fn translate_browse_paths_to_node_ids() {
     do_view_service_test(|server_state, _session, address_space, vs| {
@@ -183,6 +183,26 @@ impl SupportedMessage {
     }
 }
 
+/// Returns the binary encoding id of the response which answers a request with the supplied
+/// binary encoding id, or \`None\` if the id is not a supported request.
+pub fn response_type_for(request_object_id: ObjectId) -> Option<ObjectId> {
+    match request_object_id {
+`;
+
+    _.each(message_types, message_type => {
+        if (message_type.endsWith("Request")) {
+            let response_type = message_type.replace(/Request$/, "Response");
+            if (message_types.includes(response_type)) {
+                contents += `        ObjectId::${message_type}_Encoding_DefaultBinary => Some(ObjectId::${response_type}_Encoding_DefaultBinary),
+`;
+            }
+        }
+    });
+
+    contents += `        _ => None,
+    }
+}
+
 // These are all the messages handled into and out of streams by the OPCUA server / client code
 supported_messages_enum![
 `;
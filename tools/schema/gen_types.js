@@ -469,9 +469,9 @@ pub struct ${structured_type.name} {
     _.each(structured_type.fields_to_add, field => {
         if (!_.includes(structured_type.fields_to_hide, field.name)) {
             if (_.has(field, 'is_array')) {
-                contents += `        let ${field.name}: ${field.type} = read_array(stream, decoding_limits)?;\n`;
+                contents += `        let ${field.name}: ${field.type} = decode_field("${structured_type.name}", "${field.name}", read_array(stream, decoding_limits))?;\n`;
             } else {
-                contents += `        let ${field.name} = ${field.type}::decode(stream, decoding_limits)?;\n`;
+                contents += `        let ${field.name} = decode_field("${structured_type.name}", "${field.name}", ${field.type}::decode(stream, decoding_limits))?;\n`;
             }
         }
     });
@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate criterion;
+
+use std::io::Cursor;
+
+use criterion::Criterion;
+
+use opcua_types::{encoding::*, ByteString};
+
+const BYTE_STRING_LEN: usize = 64 * 1024;
+
+fn encode_per_byte(stream: &mut Cursor<Vec<u8>>, value: &ByteString) -> usize {
+    // The way bytes used to be written, one value at a time
+    let bytes = value.as_ref();
+    let mut size = write_i32(stream, bytes.len() as i32).unwrap();
+    for b in bytes {
+        size += write_u8(stream, *b).unwrap();
+    }
+    size
+}
+
+fn encode_byte_string_benchmark(c: &mut Criterion) {
+    // This benchmark compares encoding a large byte string a byte at a time vs in one write
+    let value = ByteString::from(vec![0xaau8; BYTE_STRING_LEN]);
+    c.bench_function("encode_byte_string_per_byte", move |b| {
+        let mut stream = Cursor::new(Vec::with_capacity(value.byte_len()));
+        b.iter(|| {
+            stream.set_position(0);
+            encode_per_byte(&mut stream, &value)
+        })
+    });
+
+    let value = ByteString::from(vec![0xaau8; BYTE_STRING_LEN]);
+    c.bench_function("encode_byte_string", move |b| {
+        let mut stream = Cursor::new(Vec::with_capacity(value.byte_len()));
+        b.iter(|| {
+            stream.set_position(0);
+            value.encode(&mut stream).unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, encode_byte_string_benchmark);
+criterion_main!(benches);
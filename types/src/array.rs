@@ -6,7 +6,7 @@ pub(crate) const ARRAY_VALUES_BIT: u8 = 1 << 7;
 /// An array is a vector of values with an optional number of dimensions.
 /// It is expected that the multi-dimensional array is valid, or it might not be encoded or decoded
 /// properly. The dimensions should match the number of values, or the array is invalid.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Array {
     /// Values are stored sequentially
     pub values: Vec<Variant>,
@@ -16,6 +16,29 @@ pub struct Array {
     /// with dimensions [2,2,2] is written in this order - [0,0,0], [0,0,1], [0,1,0], [0,1,1],
     /// [1,0,0], [1,0,1], [1,1,0], [1,1,1].
     pub dimensions: Vec<u32>,
+
+    /// The type of the values. This is what gives an empty array its type, otherwise it is the
+    /// type of the first value.
+    #[serde(default = "default_value_type")]
+    pub value_type: VariantTypeId,
+
+    /// A null array has no values and is encoded with a length of -1, which keeps it distinct
+    /// from an empty array and from a null scalar. It is only created through `new_null()`.
+    #[serde(default)]
+    null: bool,
+}
+
+fn default_value_type() -> VariantTypeId {
+    VariantTypeId::Empty
+}
+
+impl PartialEq for Array {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+            && self.dimensions == other.dimensions
+            && self.value_type == other.value_type
+            && self.is_null() == other.is_null()
+    }
 }
 
 impl Array {
@@ -23,9 +46,31 @@ impl Array {
     where
         V: Into<Vec<Variant>>,
     {
+        let values = values.into();
+        let value_type = Self::first_value_type(&values);
+        Array {
+            values,
+            dimensions: Vec::new(),
+            value_type,
+            null: false,
+        }
+    }
+
+    /// Creates an array with no values that is still of the supplied type
+    pub fn new_empty(value_type: VariantTypeId) -> Array {
         Array {
-            values: values.into(),
+            values: Vec::new(),
             dimensions: Vec::new(),
+            value_type,
+            null: false,
+        }
+    }
+
+    /// Creates a null array of the supplied type
+    pub fn new_null(value_type: VariantTypeId) -> Array {
+        Array {
+            null: true,
+            ..Self::new_empty(value_type)
         }
     }
 
@@ -34,12 +79,38 @@ impl Array {
         V: Into<Vec<Variant>>,
         D: Into<Vec<u32>>,
     {
+        let values = values.into();
+        let value_type = Self::first_value_type(&values);
         Array {
-            values: values.into(),
+            values,
             dimensions: dimensions.into(),
+            value_type,
+            null: false,
+        }
+    }
+
+    fn first_value_type(values: &[Variant]) -> VariantTypeId {
+        values
+            .first()
+            .map(|v| v.type_id())
+            .unwrap_or(VariantTypeId::Empty)
+    }
+
+    /// Returns the type of the values, which for an empty array is the type it was created with
+    pub fn value_type(&self) -> VariantTypeId {
+        if self.values.is_empty() {
+            self.value_type
+        } else {
+            self.values[0].type_id()
         }
     }
 
+    /// Tests if this is a null array, as opposed to one which is merely empty. An array created
+    /// null stops being null once values or dimensions are added to it.
+    pub fn is_null(&self) -> bool {
+        self.null && self.values.is_empty() && self.dimensions.is_empty()
+    }
+
     pub fn is_valid(&self) -> bool {
         self.is_valid_dimensions() && Self::array_is_valid(&self.values)
     }
@@ -50,7 +121,7 @@ impl Array {
 
     pub fn encoding_mask(&self) -> u8 {
         let mut encoding_mask = if self.values.is_empty() {
-            0u8
+            self.value_type.to_encoding_byte()
         } else {
             self.values[0].encoding_mask()
         };
@@ -6,6 +6,8 @@
 
 // Attributes sometimes required and sometimes optional
 
+use crate::service_types::NodeClass;
+
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 pub enum AttributeId {
     NodeId = 1,
@@ -74,4 +76,68 @@ impl AttributeId {
         };
         Ok(attribute_id)
     }
+    /// Returns the attributes that nodes of the node class have, as defined in Part 3. Some of
+    /// the attributes are optional so a particular node may not have a value for all of them.
+    pub fn for_node_class(node_class: NodeClass) -> Vec<AttributeId> {
+        BASE_ATTRIBUTES
+            .iter()
+            .chain(Self::node_class_attributes(node_class).iter())
+            .copied()
+            .collect()
+    }
+
+    /// Tests if nodes of the node class have this attribute
+    pub fn is_valid_for(&self, node_class: NodeClass) -> bool {
+        BASE_ATTRIBUTES.contains(self) || Self::node_class_attributes(node_class).contains(self)
+    }
+
+    /// Returns the attributes that are specific to the node class, i.e. excluding the base
+    /// attributes that all nodes have
+    fn node_class_attributes(node_class: NodeClass) -> &'static [AttributeId] {
+        match node_class {
+            NodeClass::Unspecified => &[],
+            NodeClass::Object => &[AttributeId::EventNotifier],
+            NodeClass::Variable => &[
+                AttributeId::Value,
+                AttributeId::DataType,
+                AttributeId::ValueRank,
+                AttributeId::ArrayDimensions,
+                AttributeId::AccessLevel,
+                AttributeId::UserAccessLevel,
+                AttributeId::MinimumSamplingInterval,
+                AttributeId::Historizing,
+                AttributeId::AccessLevelEx,
+            ],
+            NodeClass::Method => &[AttributeId::Executable, AttributeId::UserExecutable],
+            NodeClass::ObjectType => &[AttributeId::IsAbstract],
+            NodeClass::VariableType => &[
+                AttributeId::Value,
+                AttributeId::DataType,
+                AttributeId::ValueRank,
+                AttributeId::ArrayDimensions,
+                AttributeId::IsAbstract,
+            ],
+            NodeClass::ReferenceType => &[
+                AttributeId::IsAbstract,
+                AttributeId::Symmetric,
+                AttributeId::InverseName,
+            ],
+            NodeClass::DataType => &[AttributeId::IsAbstract, AttributeId::DataTypeDefinition],
+            NodeClass::View => &[AttributeId::ContainsNoLoops, AttributeId::EventNotifier],
+        }
+    }
 }
+
+/// Attributes that every node class has
+const BASE_ATTRIBUTES: [AttributeId; 10] = [
+    AttributeId::NodeId,
+    AttributeId::NodeClass,
+    AttributeId::BrowseName,
+    AttributeId::DisplayName,
+    AttributeId::Description,
+    AttributeId::WriteMask,
+    AttributeId::UserWriteMask,
+    AttributeId::RolePermissions,
+    AttributeId::UserRolePermissions,
+    AttributeId::AccessRestrictions,
+];
@@ -5,13 +5,14 @@
 //! Contains the implementation of `ByteString`.
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::io::{Read, Write};
 
 use base64;
 
 use crate::{
     encoding::{
-        process_decode_io_result, process_encode_io_result, write_i32, BinaryEncoder,
+        encoded_length, process_decode_io_result, write_byte_slice, write_i32, BinaryEncoder,
         DecodingLimits, EncodingResult,
     },
     status_codes::StatusCode,
@@ -19,7 +20,7 @@ use crate::{
 };
 
 /// A sequence of octets.
-#[derive(Eq, PartialEq, Debug, Clone, Hash, Serialize, Deserialize)]
+#[derive(Eq, PartialEq, PartialOrd, Ord, Debug, Clone, Hash, Serialize, Deserialize)]
 pub struct ByteString {
     pub value: Option<Vec<u8>>,
 }
@@ -34,6 +35,17 @@ impl AsRef<[u8]> for ByteString {
     }
 }
 
+impl fmt::Display for ByteString {
+    /// Writes the bytes as lowercase hex, or `[null]` for a null byte string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_null() {
+            write!(f, "[null]")
+        } else {
+            write!(f, "{}", self.as_hex())
+        }
+    }
+}
+
 impl BinaryEncoder<ByteString> for ByteString {
     fn byte_len(&self) -> usize {
         // Length plus the actual length of bytes (if not null)
@@ -51,8 +63,8 @@ impl BinaryEncoder<ByteString> for ByteString {
         } else {
             let mut size: usize = 0;
             let value = self.value.as_ref().unwrap();
-            size += write_i32(stream, value.len() as i32)?;
-            size += process_encode_io_result(stream.write(value))?;
+            size += write_i32(stream, encoded_length(value.len())?)?;
+            size += write_byte_slice(stream, value)?;
             assert_eq!(size, self.byte_len());
             Ok(size)
         }
@@ -145,7 +157,7 @@ impl ByteString {
         self.value.is_none()
     }
 
-    // Test if the bytestring has an empty value (not the same as null)
+    /// Test if the bytestring has an empty value (not the same as null)
     pub fn is_empty(&self) -> bool {
         if let Some(v) = &self.value {
             v.is_empty()
@@ -178,6 +190,17 @@ impl ByteString {
         }
     }
 
+    /// Encodes the bytestring as a string of lowercase hex digits, two per byte. A null byte
+    /// string is an empty string.
+    pub fn as_hex(&self) -> String {
+        let bytes = self.as_ref();
+        let mut hex = String::with_capacity(bytes.len() * 2);
+        bytes
+            .iter()
+            .for_each(|b| hex.push_str(&format!("{:02x}", b)));
+        hex
+    }
+
     /// This function is meant for use with NumericRange. It creates a substring from this string
     /// from min up to and inclusive of max. Note that min must have an index within the string
     /// but max is allowed to be beyond the end in which case the remainder of the string is
@@ -220,6 +243,27 @@ fn bytestring_bytes() {
     assert_eq!(v.value.as_ref().unwrap(), &a);
 }
 
+#[test]
+fn bytestring_hex_base64() {
+    let v = ByteString::from(&[0xdeu8, 0xad, 0xbe, 0xef, 0x00, 0x01, 0x7f]);
+    assert_eq!(v.as_hex(), "deadbeef00017f");
+    assert_eq!(v.as_base64(), "3q2+7wABfw==");
+    assert_eq!(format!("{}", v), "deadbeef00017f");
+
+    let v = ByteString::from(b"hello world");
+    assert_eq!(v.as_hex(), "68656c6c6f20776f726c64");
+    assert_eq!(v.as_base64(), "aGVsbG8gd29ybGQ=");
+
+    let v = ByteString::from(&[]);
+    assert_eq!(v.as_hex(), "");
+    assert_eq!(v.as_base64(), "");
+    assert_eq!(format!("{}", v), "");
+
+    let v = ByteString::null();
+    assert_eq!(v.as_hex(), "");
+    assert_eq!(format!("{}", v), "[null]");
+}
+
 #[test]
 fn bytestring_substring() {
     let a = [0x1u8, 0x2u8, 0x3u8, 0x4u8];
@@ -239,3 +283,26 @@ fn bytestring_substring() {
     assert!(v.substring(4, 10000).is_err());
     assert!(ByteString::null().substring(0, 0).is_err());
 }
+
+#[test]
+fn bytestring_encode_large() {
+    use std::io::Cursor;
+
+    let v = ByteString::from(vec![0xaau8; 64 * 1024]);
+    let mut stream = Cursor::new(Vec::new());
+    let size = v.encode(&mut stream).unwrap();
+    assert_eq!(size, v.byte_len());
+    assert_eq!(stream.position() as usize, v.byte_len());
+
+    stream.set_position(0);
+    let v2 = ByteString::decode(&mut stream, &DecodingLimits::default()).unwrap_err();
+    assert_eq!(v2, StatusCode::BadDecodingError);
+
+    stream.set_position(0);
+    let decoding_limits = DecodingLimits {
+        max_byte_string_length: 64 * 1024,
+        ..Default::default()
+    };
+    let v2 = ByteString::decode(&mut stream, &decoding_limits).unwrap();
+    assert_eq!(v, v2);
+}
@@ -388,6 +388,16 @@ impl DataValue {
         }
     }
 
+    /// Creates a `DataValue` from the supplied value with a `Good` status and the source and
+    /// server timestamps set to now. This is how a server should annotate a freshly read value
+    /// that has no explicit timestamp of its own.
+    pub fn with_value<V>(value: V) -> DataValue
+    where
+        V: Into<Variant>,
+    {
+        Self::new_now(value)
+    }
+
     /// Creates an empty DataValue
     pub fn null() -> DataValue {
         DataValue {
@@ -416,6 +426,13 @@ impl DataValue {
         self.server_picoseconds = Some(0);
     }
 
+    /// Refreshes the server timestamp to now, leaving the source timestamp unchanged. This should
+    /// be called each time the value is read by the server.
+    pub fn touch_server_timestamp(&mut self) {
+        self.server_timestamp = Some(DateTime::now());
+        self.server_picoseconds = Some(0);
+    }
+
     /// Sets the timestamps of the data value based on supplied timestamps to return
     pub fn set_timestamps(
         &mut self,
@@ -486,3 +503,26 @@ impl DataValue {
         encoding_mask
     }
 }
+
+#[test]
+fn data_value_with_value() {
+    let v = DataValue::with_value(Variant::from(100i32));
+    assert_eq!(v.value, Some(Variant::Int32(100)));
+    assert_eq!(v.status, Some(StatusCode::Good));
+    assert!(v.source_timestamp.is_some());
+    assert!(v.server_timestamp.is_some());
+    assert_eq!(v.source_timestamp, v.server_timestamp);
+}
+
+#[test]
+fn data_value_touch_server_timestamp() {
+    let source_timestamp = DateTime::ymd(2020, 1, 1);
+    let mut v = DataValue::value_only(100i32);
+    v.source_timestamp = Some(source_timestamp.clone());
+    v.server_timestamp = Some(source_timestamp.clone());
+
+    v.touch_server_timestamp();
+    assert_eq!(v.source_timestamp, Some(source_timestamp.clone()));
+    assert_ne!(v.server_timestamp, Some(source_timestamp));
+    assert_eq!(v.server_picoseconds, Some(0));
+}
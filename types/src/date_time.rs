@@ -8,6 +8,7 @@ use std::{
     fmt,
     io::{Read, Write},
     str::FromStr,
+    time::SystemTime,
 };
 
 use chrono::{self, Datelike, TimeZone, Timelike, Utc};
@@ -116,6 +117,16 @@ impl From<(u16, u16, u16, u16, u16, u16, u32)> for DateTime {
 
 impl From<DateTimeUtc> for DateTime {
     fn from(date_time: DateTimeUtc) -> Self {
+        // Times outside of the OPC UA range saturate to the null and endtimes sentinels
+        if date_time < Self::epoch_chrono() {
+            return DateTime {
+                date_time: Self::epoch_chrono(),
+            };
+        } else if date_time > Self::endtimes_chrono() {
+            return DateTime {
+                date_time: Self::endtimes_chrono(),
+            };
+        }
         // OPC UA date time is more granular with nanos, so the value supplied is made granular too
         let year = date_time.year();
         let month = date_time.month();
@@ -151,9 +162,21 @@ impl Into<i64> for DateTime {
     }
 }
 
-impl Into<DateTimeUtc> for DateTime {
-    fn into(self) -> DateTimeUtc {
-        self.as_chrono()
+impl From<DateTime> for DateTimeUtc {
+    fn from(date_time: DateTime) -> Self {
+        date_time.as_chrono()
+    }
+}
+
+impl From<SystemTime> for DateTime {
+    fn from(system_time: SystemTime) -> Self {
+        DateTime::from(DateTimeUtc::from(system_time))
+    }
+}
+
+impl From<DateTime> for SystemTime {
+    fn from(date_time: DateTime) -> Self {
+        SystemTime::from(date_time.date_time)
     }
 }
 
@@ -243,13 +266,14 @@ impl DateTime {
     }
 
     /// To checked ticks. Function returns 0 or MAX_INT64
-    /// if date exceeds valid OPC UA range
+    /// if date exceeds valid OPC UA range. The endtimes themselves are MAX_INT64 so that
+    /// they survive a round trip through the encoding.
     pub fn checked_ticks(&self) -> i64 {
         let nanos = self.ticks();
         if nanos < 0 {
             return 0;
         }
-        if nanos > Self::endtimes_ticks() {
+        if nanos >= Self::endtimes_ticks() {
             return i64::max_value();
         }
         nanos
@@ -260,6 +284,16 @@ impl DateTime {
         self.date_time
     }
 
+    /// Time as chrono, or `None` if the date time is null. A null date time means "no time" in
+    /// OPC UA, e.g. an unset timestamp, so it should not be taken to be 1601-01-01.
+    pub fn as_chrono_opt(&self) -> Option<DateTimeUtc> {
+        if self.is_null() {
+            None
+        } else {
+            Some(self.date_time)
+        }
+    }
+
     /// The OPC UA epoch - Jan 1 1601 00:00:00
     fn epoch_chrono() -> DateTimeUtc {
         Utc.ymd(MIN_YEAR as i32, 1, 1).and_hms(0, 0, 0)
@@ -128,7 +128,7 @@ impl BinaryEncoder<DiagnosticInfo> for DiagnosticInfo {
         }
         if let Some(ref inner_diagnostic_info) = self.inner_diagnostic_info {
             // Write inner diagnostic info
-            size += inner_diagnostic_info.clone().encode(stream)?;
+            size += inner_diagnostic_info.encode(stream)?;
         }
         Ok(size)
     }
@@ -163,9 +163,11 @@ impl BinaryEncoder<DiagnosticInfo> for DiagnosticInfo {
             diagnostic_info.inner_status_code = Some(StatusCode::decode(stream, decoding_limits)?);
         }
         if encoding_mask.contains(DiagnosticInfoMask::HAS_INNER_DIAGNOSTIC_INFO) {
-            // Read inner diagnostic info
-            diagnostic_info.inner_diagnostic_info =
-                Some(Box::new(DiagnosticInfo::decode(stream, decoding_limits)?));
+            // Read inner diagnostic info, which counts against the nesting depth
+            diagnostic_info.inner_diagnostic_info = Some(Box::new(DiagnosticInfo::decode(
+                stream,
+                &decoding_limits.nested()?,
+            )?));
         }
         Ok(diagnostic_info)
     }
@@ -7,11 +7,12 @@
 
 use std::{
     self,
-    fmt::Debug,
+    cell::RefCell,
+    fmt::{self, Debug},
     io::{Cursor, Read, Result, Write},
 };
 
-use byteorder::{ByteOrder, LittleEndian, WriteBytesExt};
+use byteorder::{ByteOrder, LittleEndian};
 
 use crate::{constants, status_codes::StatusCode};
 
@@ -27,6 +28,12 @@ pub struct DecodingLimits {
     pub max_byte_string_length: usize,
     /// Maximum number of array elements. 0 actually means 0, i.e. no array permitted
     pub max_array_length: usize,
+    /// Maximum depth to which values may nest inside one another, e.g. inner diagnostic infos or
+    /// the decoded bodies of extension objects. The bound is shared by every kind of nesting.
+    pub max_nesting_depth: usize,
+    /// The nesting depth of the value being decoded. Decoders of nested values go a level deeper
+    /// with `nested()`, so a caller starting a decode leaves this at 0.
+    pub depth: usize,
 }
 
 impl Default for DecodingLimits {
@@ -36,6 +43,8 @@ impl Default for DecodingLimits {
             max_string_length: constants::MAX_STRING_LENGTH,
             max_byte_string_length: constants::MAX_BYTE_STRING_LENGTH,
             max_array_length: constants::MAX_ARRAY_LENGTH,
+            max_nesting_depth: constants::MAX_NESTING_DEPTH,
+            depth: 0,
         }
     }
 }
@@ -49,6 +58,26 @@ impl DecodingLimits {
             max_string_length: 0,
             max_byte_string_length: 0,
             max_array_length: 0,
+            max_nesting_depth: constants::MAX_NESTING_DEPTH,
+            depth: 0,
+        }
+    }
+
+    /// Returns the limits for decoding a value nested one level deeper than the current value,
+    /// or `BadDecodingError` if that would exceed the maximum nesting depth. Recursive decoders
+    /// call this so that hostile input cannot nest deeply enough to overflow the stack.
+    pub fn nested(&self) -> EncodingResult<DecodingLimits> {
+        if self.depth >= self.max_nesting_depth {
+            error!(
+                "Decoding exceeds the maximum nesting depth {}",
+                self.max_nesting_depth
+            );
+            Err(StatusCode::BadDecodingError)
+        } else {
+            Ok(DecodingLimits {
+                depth: self.depth + 1,
+                ..*self
+            })
         }
     }
 }
@@ -76,6 +105,29 @@ pub trait BinaryEncoder<T> {
     }
 }
 
+/// Encodes the value into a new vector of bytes sized from its `byte_len()`.
+pub fn encode_to_vec<T>(value: &T) -> Vec<u8>
+where
+    T: BinaryEncoder<T>,
+{
+    value.encode_to_vec()
+}
+
+/// Decodes a value from the start of the slice, returning the value and the number of bytes it
+/// was decoded from. Any bytes after the value are left alone, so the count can be used to find
+/// where the next value starts.
+pub fn decode_from_slice<T>(
+    bytes: &[u8],
+    decoding_limits: &DecodingLimits,
+) -> EncodingResult<(T, usize)>
+where
+    T: BinaryEncoder<T>,
+{
+    let mut stream = bytes;
+    let value = T::decode(&mut stream, decoding_limits)?;
+    Ok((value, bytes.len() - stream.len()))
+}
+
 /// Converts an IO encoding error (and logs when in error) into an EncodingResult
 pub fn process_encode_io_result(result: Result<usize>) -> EncodingResult<usize> {
     result.map_err(|err| {
@@ -95,6 +147,88 @@ where
     })
 }
 
+thread_local! {
+    /// The fields of a failed decode, outermost first, while `decode_with_context()` is running
+    #[allow(clippy::missing_const_for_thread_local)]
+    static DECODE_CONTEXT: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+/// A decoding error together with the fields that were being decoded when it happened, as
+/// returned by `decode_with_context()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeError {
+    /// The status code the decode failed with
+    pub status_code: StatusCode,
+    /// The type and field names, e.g. `BrowseNextRequest.continuation_points`, from the outermost
+    /// type to the field that failed
+    pub fields: Vec<String>,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for field in &self.fields {
+            write!(f, "decoding {}: ", field)?;
+        }
+        write!(f, "{}", self.status_code)
+    }
+}
+
+impl From<DecodeError> for StatusCode {
+    fn from(err: DecodeError) -> Self {
+        err.status_code
+    }
+}
+
+/// Adds the name of the type and field being decoded to a decoding error. The context is logged
+/// as the error propagates out, so a failure in a nested structure leaves a trail from the field
+/// that failed up to the outermost type, e.g. "decoding BrowseNextRequest.continuation_points".
+/// Under `decode_with_context()` the trail is also returned to the caller.
+pub fn decode_field<T>(
+    type_name: &str,
+    field_name: &str,
+    result: EncodingResult<T>,
+) -> EncodingResult<T> {
+    result.map_err(|err| {
+        error!("decoding {}.{}: {}", type_name, field_name, err);
+        DECODE_CONTEXT.with(|context| {
+            if let Some(ref mut fields) = *context.borrow_mut() {
+                fields.insert(0, format!("{}.{}", type_name, field_name));
+            }
+        });
+        err
+    })
+}
+
+/// Decodes a value the same as `BinaryEncoder::decode()`, except that a failure returns the
+/// fields which were being decoded as well as the status code.
+pub fn decode_with_context<T, S>(
+    stream: &mut S,
+    decoding_limits: &DecodingLimits,
+) -> std::result::Result<T, DecodeError>
+where
+    T: BinaryEncoder<T>,
+    S: Read,
+{
+    let previous = DECODE_CONTEXT.with(|context| context.replace(Some(Vec::new())));
+    let result = T::decode(stream, decoding_limits);
+    let fields = DECODE_CONTEXT.with(|context| context.replace(previous));
+    result.map_err(|status_code| DecodeError {
+        status_code,
+        fields: fields.unwrap_or_default(),
+    })
+}
+
+/// Converts the length of a string, byte string or array into the `Int32` length that prefixes it
+/// when encoded, failing with `BadEncodingLimitsExceeded` if the length does not fit.
+pub fn encoded_length(len: usize) -> EncodingResult<i32> {
+    if len > i32::MAX as usize {
+        error!("Length {} is too large to be encoded", len);
+        Err(StatusCode::BadEncodingLimitsExceeded)
+    } else {
+        Ok(len as i32)
+    }
+}
+
 /// Calculates the length in bytes of an array of encoded type
 pub fn byte_len_array<T: BinaryEncoder<T>>(values: &Option<Vec<T>>) -> usize {
     let mut size = 4;
@@ -111,7 +245,7 @@ pub fn write_array<S: Write, T: BinaryEncoder<T>>(
 ) -> EncodingResult<usize> {
     let mut size = 0;
     if let Some(ref values) = values {
-        size += write_i32(stream, values.len() as i32)?;
+        size += write_i32(stream, encoded_length(values.len())?)?;
         for value in values.iter() {
             size += value.encode(stream)?;
         }
@@ -121,7 +255,9 @@ pub fn write_array<S: Write, T: BinaryEncoder<T>>(
     Ok(size)
 }
 
-/// Reads an array of the encoded type from a stream, preserving distinction between null array and empty array
+/// Reads an array of the encoded type from a stream, preserving distinction between null array and empty array.
+/// A length of -1 is a null array (`None`), 0 is an empty array (`Some(vec![])`) and any other
+/// negative length is a `BadDecodingError`.
 pub fn read_array<S: Read, T: BinaryEncoder<T>>(
     stream: &mut S,
     decoding_limits: &DecodingLimits,
@@ -147,16 +283,67 @@ pub fn read_array<S: Read, T: BinaryEncoder<T>>(
     }
 }
 
+/// An array is encoded as an `i32` length followed by its elements. A vec is never null, so
+/// it is written with its length even when it is empty and a null array decodes as an empty vec.
+/// Use `Option<Vec<T>>` where the distinction between null and empty matters.
+impl<T> BinaryEncoder<Vec<T>> for Vec<T>
+where
+    T: BinaryEncoder<T>,
+{
+    fn byte_len(&self) -> usize {
+        4 + self.iter().map(|v| v.byte_len()).sum::<usize>()
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = write_i32(stream, encoded_length(self.len())?)?;
+        for value in self.iter() {
+            size += value.encode(stream)?;
+        }
+        Ok(size)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(read_array(stream, decoding_limits)?.unwrap_or_default())
+    }
+}
+
+/// A null array is `None`, written with a length of -1
+impl<T> BinaryEncoder<Option<Vec<T>>> for Option<Vec<T>>
+where
+    T: BinaryEncoder<T>,
+{
+    fn byte_len(&self) -> usize {
+        byte_len_array(self)
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        write_array(stream, self)
+    }
+
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        read_array(stream, decoding_limits)
+    }
+}
+
 /// Writes a series of identical bytes to the stream
 pub fn write_bytes(stream: &mut dyn Write, value: u8, count: usize) -> EncodingResult<usize> {
-    for _ in 0..count {
-        let _ = stream
-            .write_u8(value)
-            .map_err(|_| StatusCode::BadEncodingError)?;
+    // Write from a block of the value rather than a byte at a time
+    let buf = [value; 64];
+    let mut remaining = count;
+    while remaining > 0 {
+        let len = remaining.min(buf.len());
+        write_byte_slice(stream, &buf[..len])?;
+        remaining -= len;
     }
     Ok(count)
 }
 
+/// Writes a slice of bytes to the stream in a single call. This is preferable to writing the
+/// bytes one at a time when encoding a large byte string or similar.
+pub fn write_byte_slice(stream: &mut dyn Write, buf: &[u8]) -> EncodingResult<usize> {
+    process_encode_io_result(stream.write_all(buf).map(|_| buf.len()))
+}
+
 /// Writes an unsigned byte to the stream
 pub fn write_u8<T>(stream: &mut dyn Write, value: T) -> EncodingResult<usize>
 where
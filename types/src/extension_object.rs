@@ -4,11 +4,17 @@
 
 //! Contains the implementation of `ExtensionObject`.
 
-use std::io::{Cursor, Read, Write};
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt,
+    io::{Cursor, Read, Write},
+};
 
 use crate::{
-    byte_string::ByteString, encoding::*, node_id::NodeId, node_ids::ObjectId,
-    status_codes::StatusCode, string::XmlElement,
+    byte_string::ByteString, data_value::DataValue, encoding::*, node_id::NodeId,
+    node_ids::ObjectId, service_types::MessageInfo, status_codes::StatusCode, string::XmlElement,
+    variant::Variant,
 };
 
 /// Enumeration that holds the kinds of encoding that an ExtensionObject data may be encoded with.
@@ -133,9 +139,38 @@ impl ExtensionObject {
         }
     }
 
+    /// Creates an extension object holding the encoded message. The node id is the object id of
+    /// the message's default binary encoding.
+    pub fn from_message<T>(message: &T) -> ExtensionObject
+    where
+        T: BinaryEncoder<T> + MessageInfo,
+    {
+        Self::from_encodable(message.object_id(), message)
+    }
+
+    /// Decodes the message held by the extension object, checking that the node id matches the
+    /// message's default binary encoding. A mismatch is a decoding error.
+    pub fn decode_message<T>(&self, decoding_limits: &DecodingLimits) -> EncodingResult<T>
+    where
+        T: BinaryEncoder<T> + MessageInfo,
+    {
+        let message = self.decode_inner::<T>(decoding_limits)?;
+        if self.node_id == message.object_id().into() {
+            Ok(message)
+        } else {
+            error!(
+                "Extension object node id {} does not match the decoded message's object id {:?}",
+                self.node_id,
+                message.object_id()
+            );
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+
     /// Decodes the inner content of the extension object and returns it. The node id is ignored
     /// for decoding. The caller supplies the binary encoder impl that should be used to extract
-    /// the data. Errors result in a decoding error.
+    /// the data. The content is a level deeper than the extension object for the purposes of the
+    /// nesting depth. Errors result in a decoding error.
     pub fn decode_inner<T>(&self, decoding_limits: &DecodingLimits) -> EncodingResult<T>
     where
         T: BinaryEncoder<T>,
@@ -145,7 +180,7 @@ impl ExtensionObject {
                 if let Some(ref value) = byte_string.value {
                     // let value = value.clone();
                     let mut stream = Cursor::new(value);
-                    T::decode(&mut stream, decoding_limits)
+                    T::decode(&mut stream, &decoding_limits.nested()?)
                 } else {
                     Err(StatusCode::BadDecodingError)
                 }
@@ -157,3 +192,163 @@ impl ExtensionObject {
         }
     }
 }
+
+/// The value an extension object or variant resolves to through an `ExtensionObjectRegistry`.
+#[derive(Debug)]
+pub enum ResolvedValue {
+    /// The body of an extension object decoded into the type registered for its node id
+    Typed(NodeId, Box<dyn Any + Send>),
+    /// The resolved elements of an array
+    Array(Vec<ResolvedValue>),
+    /// A value that could not be resolved, e.g. an extension object of an unknown encoding,
+    /// which is kept as it is
+    Unresolved(Variant),
+}
+
+impl ResolvedValue {
+    /// Returns a reference to the typed value if it was resolved to the type `T`
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            ResolvedValue::Typed(_, value) => value.downcast_ref::<T>(),
+            _ => None,
+        }
+    }
+
+    /// Tests if the value was resolved to a typed value
+    pub fn is_typed(&self) -> bool {
+        match self {
+            ResolvedValue::Typed(_, _) => true,
+            _ => false,
+        }
+    }
+}
+
+type BodyDecoder = Box<
+    dyn Fn(&ExtensionObjectRegistry, &[u8], &DecodingLimits) -> EncodingResult<Box<dyn Any + Send>>
+        + Send
+        + Sync,
+>;
+
+/// A registry of the structures whose binary encodings are known, keyed by the node id of the
+/// encoding. The registry resolves the body of an extension object into the registered type
+/// instead of leaving it as a raw byte string.
+#[derive(Default)]
+pub struct ExtensionObjectRegistry {
+    decoders: HashMap<NodeId, BodyDecoder>,
+}
+
+impl fmt::Debug for ExtensionObjectRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExtensionObjectRegistry")
+            .field("encodings", &self.decoders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ExtensionObjectRegistry {
+    pub fn new() -> ExtensionObjectRegistry {
+        ExtensionObjectRegistry::default()
+    }
+
+    /// Registers the type `T` as the structure encoded by the node id.
+    pub fn register<T, N>(&mut self, node_id: N)
+    where
+        T: BinaryEncoder<T> + Any + Send,
+        N: Into<NodeId>,
+    {
+        self.register_with(node_id, |_, body, decoding_limits| {
+            let value = T::decode(&mut Cursor::new(body), decoding_limits)?;
+            Ok(Box::new(value) as Box<dyn Any + Send>)
+        });
+    }
+
+    /// Registers a function that decodes the body of the encoding. The function is passed the
+    /// registry so a structure which holds extension objects of its own can resolve them too.
+    pub fn register_with<N, F>(&mut self, node_id: N, decoder: F)
+    where
+        N: Into<NodeId>,
+        F: Fn(
+                &ExtensionObjectRegistry,
+                &[u8],
+                &DecodingLimits,
+            ) -> EncodingResult<Box<dyn Any + Send>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decoders.insert(node_id.into(), Box::new(decoder));
+    }
+
+    /// Tests if a structure is registered for the encoding node id
+    pub fn is_registered(&self, node_id: &NodeId) -> bool {
+        self.decoders.contains_key(node_id)
+    }
+
+    /// Decodes the body of the extension object into its registered type. An extension object
+    /// whose encoding is not registered, or which has no byte string body, is returned
+    /// unresolved with its node id intact. A registered body which fails to decode is an error.
+    pub fn decode(
+        &self,
+        extension_object: &ExtensionObject,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<ResolvedValue> {
+        let decoder = self.decoders.get(&extension_object.node_id);
+        match (decoder, &extension_object.body) {
+            (Some(decoder), ExtensionObjectEncoding::ByteString(byte_string))
+                if !byte_string.is_null() =>
+            {
+                let body = byte_string.as_ref();
+                let value = decoder(self, body, &decoding_limits.nested()?).map_err(|err| {
+                    error!(
+                        "Extension object body of encoding {} could not be decoded, {}",
+                        extension_object.node_id, err
+                    );
+                    err
+                })?;
+                Ok(ResolvedValue::Typed(
+                    extension_object.node_id.clone(),
+                    value,
+                ))
+            }
+            _ => Ok(ResolvedValue::Unresolved(Variant::from(
+                extension_object.clone(),
+            ))),
+        }
+    }
+
+    /// Resolves the variant. An extension object is decoded through the registry and an array
+    /// has each of its elements resolved. Anything else is returned unresolved.
+    pub fn resolve(
+        &self,
+        variant: &Variant,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<ResolvedValue> {
+        match variant {
+            Variant::ExtensionObject(extension_object) => {
+                self.decode(extension_object, decoding_limits)
+            }
+            Variant::Array(array) => {
+                let values = array
+                    .values
+                    .iter()
+                    .map(|v| self.resolve(v, decoding_limits))
+                    .collect::<EncodingResult<Vec<_>>>()?;
+                Ok(ResolvedValue::Array(values))
+            }
+            variant => Ok(ResolvedValue::Unresolved(variant.clone())),
+        }
+    }
+
+    /// Resolves the value of the data value, or returns `None` if it has no value.
+    pub fn resolve_data_value(
+        &self,
+        data_value: &DataValue,
+        decoding_limits: &DecodingLimits,
+    ) -> EncodingResult<Option<ResolvedValue>> {
+        data_value
+            .value
+            .as_ref()
+            .map(|value| self.resolve(value, decoding_limits))
+            .transpose()
+    }
+}
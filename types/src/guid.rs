@@ -15,7 +15,7 @@ use uuid::Uuid;
 use crate::encoding::*;
 
 /// A Guid is a 16 byte Globally Unique Identifier.
-#[derive(Eq, PartialEq, Clone, Hash)]
+#[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Hash)]
 pub struct Guid {
     uuid: Uuid,
 }
@@ -64,17 +64,15 @@ impl BinaryEncoder<Guid> for Guid {
     }
 
     fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
-        let mut size: usize = 0;
-        size += process_encode_io_result(stream.write(self.uuid.as_bytes()))?;
+        let size = write_byte_slice(stream, &self.to_bytes_le())?;
+        assert_eq!(size, 16);
         Ok(size)
     }
 
     fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
         let mut bytes = [0u8; 16];
         process_decode_io_result(stream.read_exact(&mut bytes))?;
-        Ok(Guid {
-            uuid: Uuid::from_bytes(bytes),
-        })
+        Ok(Guid::from_bytes_le(&bytes))
     }
 }
 
@@ -100,6 +98,11 @@ impl Guid {
         Guid { uuid: Uuid::nil() }
     }
 
+    /// Test if the guid is null, i.e. all sixteen bytes are zero
+    pub fn is_null(&self) -> bool {
+        self.uuid.is_nil()
+    }
+
     /// Creates a random Guid
     pub fn new() -> Guid {
         Guid {
@@ -118,4 +121,27 @@ impl Guid {
             uuid: Uuid::from_bytes(bytes),
         }
     }
+
+    /// Returns the bytes of the Guid in the Microsoft GUID memory layout, which is also how a
+    /// Guid is encoded in OPC UA binary. The first three fields, `Data1` (4 bytes), `Data2`
+    /// (2 bytes) and `Data3` (2 bytes), are little endian while the last 8 bytes are in the order
+    /// they are printed. So `72962B91-FA75-4AE6-8D28-B404DC7DAF63` becomes
+    /// `91 2B 96 72 75 FA E6 4A 8D 28 B4 04 DC 7D AF 63`.
+    pub fn to_bytes_le(&self) -> [u8; 16] {
+        let mut bytes = *self.uuid.as_bytes();
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+        bytes
+    }
+
+    /// Creates a guid from bytes in the Microsoft GUID memory layout, i.e. the reverse of
+    /// `to_bytes_le()`.
+    pub fn from_bytes_le(bytes: &[u8; 16]) -> Guid {
+        let mut bytes = *bytes;
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+        Guid::from_bytes(bytes)
+    }
 }
@@ -20,7 +20,7 @@ extern crate lazy_static;
 extern crate bitflags;
 #[macro_use]
 extern crate serde_derive;
-#[cfg(test)]
+#[cfg(any(test, feature = "json"))]
 extern crate serde_json;
 
 ///Contains constants recognized by OPC UA clients and servers to describe various protocols and
@@ -48,6 +48,10 @@ pub mod constants {
     pub const MAX_BYTE_STRING_LENGTH: usize = 65535;
     /// Maximum size of a certificate to send
     pub const MAX_CERTIFICATE_LENGTH: u32 = 32767;
+    /// The uri of the OPC UA namespace, which is always index 0 of a namespace table
+    pub const OPC_UA_NAMESPACE_URI: &str = "http://opcfoundation.org/UA/";
+    /// Maximum depth to which decoded values may nest inside one another
+    pub const MAX_NESTING_DEPTH: usize = 64;
 
     /// URI supplied for the None security policy
     pub const SECURITY_POLICY_NONE_URI: &str = "http://opcfoundation.org/UA/SecurityPolicy#None";
@@ -204,11 +208,13 @@ pub mod encoding;
 pub mod extension_object;
 pub mod guid;
 pub mod localized_text;
+pub mod namespace_table;
 pub mod node_id;
 pub mod node_ids;
 pub mod notification_message;
 pub mod numeric_range;
 pub mod operand;
+pub mod pretty_print;
 pub mod qualified_name;
 pub mod relative_path;
 pub mod request_header;
@@ -217,12 +223,15 @@ pub mod service_types;
 pub mod status_code;
 pub mod string;
 pub mod variant;
+#[cfg(feature = "json")]
+mod variant_json;
 
 pub use crate::{
     argument::*, array::*, attribute::*, basic_types::*, byte_string::*, data_types::*,
     data_value::*, date_time::*, diagnostic_info::*, encoding::*, extension_object::*, guid::*,
-    localized_text::*, node_id::*, node_ids::*, numeric_range::*, operand::*, qualified_name::*,
-    request_header::*, response_header::*, service_types::*, string::*, variant::*,
+    localized_text::*, namespace_table::*, node_id::*, node_ids::*, numeric_range::*, operand::*,
+    pretty_print::*, qualified_name::*, request_header::*, response_header::*, service_types::*,
+    string::*, variant::*,
 };
 
 #[cfg(test)]
@@ -10,6 +10,11 @@ use std::{
 
 use crate::{encoding::*, string::*};
 
+/// Bit in the encoding mask that indicates the locale is present in the stream
+const LOCALE_MASK: u8 = 0x1;
+/// Bit in the encoding mask that indicates the text is present in the stream
+const TEXT_MASK: u8 = 0x2;
+
 /// A human readable text with an optional locale identifier.
 #[derive(PartialEq, Default, Debug, Clone, Serialize, Deserialize)]
 pub struct LocalizedText {
@@ -66,18 +71,7 @@ impl BinaryEncoder<LocalizedText> for LocalizedText {
 
     fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
         let mut size = 0;
-        // A bit mask that indicates which fields are present in the stream.
-        // The mask has the following bits:
-        // 0x01    Locale
-        // 0x02    Text
-        let mut encoding_mask: u8 = 0;
-        if !self.locale.is_empty() {
-            encoding_mask |= 0x1;
-        }
-        if !self.text.is_empty() {
-            encoding_mask |= 0x2;
-        }
-        size += encoding_mask.encode(stream)?;
+        size += self.encoding_mask().encode(stream)?;
         if !self.locale.is_empty() {
             size += self.locale.encode(stream)?;
         }
@@ -89,12 +83,12 @@ impl BinaryEncoder<LocalizedText> for LocalizedText {
 
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
         let encoding_mask = u8::decode(stream, decoding_limits)?;
-        let locale = if encoding_mask & 0x1 != 0 {
+        let locale = if encoding_mask & LOCALE_MASK != 0 {
             UAString::decode(stream, decoding_limits)?
         } else {
             UAString::null()
         };
-        let text = if encoding_mask & 0x2 != 0 {
+        let text = if encoding_mask & TEXT_MASK != 0 {
             UAString::decode(stream, decoding_limits)?
         } else {
             UAString::null()
@@ -104,6 +98,7 @@ impl BinaryEncoder<LocalizedText> for LocalizedText {
 }
 
 impl LocalizedText {
+    /// Creates a localized text with a locale and text
     pub fn new(locale: &str, text: &str) -> LocalizedText {
         LocalizedText {
             locale: UAString::from(locale),
@@ -117,4 +112,66 @@ impl LocalizedText {
             text: UAString::null(),
         }
     }
+
+    /// Creates a localized text which has text but no locale, which is what most server code
+    /// wants.
+    pub fn text_only(text: &str) -> LocalizedText {
+        LocalizedText {
+            locale: UAString::null(),
+            text: UAString::from(text),
+        }
+    }
+
+    /// Tests if the locale is one of the preferred locales, either exactly or by language, so
+    /// that "en" matches "en-US" and "en-GB" matches "en". Case is ignored.
+    pub fn matches_locale(&self, preferred: &[&str]) -> bool {
+        preferred
+            .iter()
+            .any(|p| self.is_exact_locale(p) || self.is_language_of(p))
+    }
+
+    fn is_exact_locale(&self, locale: &str) -> bool {
+        self.locale.as_ref().eq_ignore_ascii_case(locale)
+    }
+
+    fn is_language_of(&self, locale: &str) -> bool {
+        fn language(locale: &str) -> &str {
+            locale.split(['-', '_']).next().unwrap_or("")
+        }
+        let language_of_self = language(self.locale.as_ref());
+        !language_of_self.is_empty() && language_of_self.eq_ignore_ascii_case(language(locale))
+    }
+
+    /// Returns the mask that is written to the stream to indicate which fields are present,
+    /// where 0x01 is the locale and 0x02 is the text. Empty fields are omitted from the stream.
+    pub fn encoding_mask(&self) -> u8 {
+        let mut encoding_mask = 0;
+        if !self.locale.is_empty() {
+            encoding_mask |= LOCALE_MASK;
+        }
+        if !self.text.is_empty() {
+            encoding_mask |= TEXT_MASK;
+        }
+        encoding_mask
+    }
+}
+
+/// Selects the text which best suits the preferred locales, e.g. the locale ids of a read
+/// request. Each preferred locale is tried in order, first for an exact match and then for a
+/// text in the same language. If nothing matches, the default is the text without a locale, or
+/// failing that the first text. Returns `None` only if there are no texts.
+pub fn select_localized_text<'a>(
+    options: &'a [LocalizedText],
+    preferred: &[&str],
+) -> Option<&'a LocalizedText> {
+    preferred
+        .iter()
+        .find_map(|p| {
+            options
+                .iter()
+                .find(|o| o.is_exact_locale(p))
+                .or_else(|| options.iter().find(|o| o.is_language_of(p)))
+        })
+        .or_else(|| options.iter().find(|o| o.locale.is_empty()))
+        .or_else(|| options.first())
 }
@@ -0,0 +1,108 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the implementation of `NamespaceTable`.
+
+use crate::{constants, node_id::NodeId, qualified_name::QualifiedName};
+
+/// The table of namespace uris that the namespace index of a `NodeId` or `QualifiedName` refers
+/// to. The index of a uri is its position in the table. Index 0 is always the OPC UA namespace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamespaceTable {
+    uris: Vec<String>,
+}
+
+impl Default for NamespaceTable {
+    fn default() -> Self {
+        NamespaceTable::new()
+    }
+}
+
+impl From<Vec<String>> for NamespaceTable {
+    /// Creates a table from the uris in index order, e.g. as read from a server's namespace array
+    fn from(uris: Vec<String>) -> Self {
+        NamespaceTable { uris }
+    }
+}
+
+impl NamespaceTable {
+    /// Creates a table holding only the OPC UA namespace at index 0
+    pub fn new() -> NamespaceTable {
+        NamespaceTable {
+            uris: vec![constants::OPC_UA_NAMESPACE_URI.to_string()],
+        }
+    }
+
+    /// Adds the uri to the table and returns its index. Adding a uri which is already in the
+    /// table returns its existing index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table is full, i.e. there is no index left for a new uri.
+    pub fn add(&mut self, uri: &str) -> u16 {
+        if let Some(index) = self.index_of(uri) {
+            index
+        } else if self.uris.len() > u16::MAX as usize {
+            panic!("Namespace table is full, cannot add {}", uri);
+        } else {
+            self.uris.push(uri.to_string());
+            (self.uris.len() - 1) as u16
+        }
+    }
+
+    /// Returns the index of the uri, or `None` if it is not in the table
+    pub fn index_of(&self, uri: &str) -> Option<u16> {
+        self.uris.iter().position(|u| u == uri).map(|i| i as u16)
+    }
+
+    /// Returns the uri at the index, or `None` if there is no such index
+    pub fn uri_at(&self, index: u16) -> Option<&str> {
+        self.uris.get(index as usize).map(|u| u.as_str())
+    }
+
+    /// Returns the number of uris in the table
+    pub fn len(&self) -> usize {
+        self.uris.len()
+    }
+
+    /// Tests if the table holds no uris
+    pub fn is_empty(&self) -> bool {
+        self.uris.is_empty()
+    }
+
+    /// Returns the uris in index order
+    pub fn uris(&self) -> &[String] {
+        &self.uris
+    }
+
+    /// Returns the index in the other table of the uri at the index in this table, or `None` if
+    /// this table has no such index or the other table does not hold its uri.
+    pub fn remap_index(&self, index: u16, to: &NamespaceTable) -> Option<u16> {
+        self.uri_at(index).and_then(|uri| to.index_of(uri))
+    }
+
+    /// Returns a copy of the node id whose namespace index refers to the same uri in the other
+    /// table, or `None` if the index cannot be remapped.
+    pub fn remap_node_id(&self, node_id: &NodeId, to: &NamespaceTable) -> Option<NodeId> {
+        self.remap_index(node_id.namespace, to)
+            .map(|namespace| NodeId {
+                namespace,
+                identifier: node_id.identifier.clone(),
+            })
+    }
+
+    /// Returns a copy of the qualified name whose namespace index refers to the same uri in the
+    /// other table, or `None` if the index cannot be remapped.
+    pub fn remap_qualified_name(
+        &self,
+        qualified_name: &QualifiedName,
+        to: &NamespaceTable,
+    ) -> Option<QualifiedName> {
+        self.remap_index(qualified_name.namespace_index, to)
+            .map(|namespace_index| QualifiedName {
+                namespace_index,
+                name: qualified_name.name.clone(),
+            })
+    }
+}
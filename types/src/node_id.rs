@@ -24,7 +24,7 @@ use crate::{
 };
 
 /// The kind of identifier, numeric, string, guid or byte
-#[derive(Eq, PartialEq, Clone, Debug, Hash, Serialize, Deserialize)]
+#[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Debug, Hash, Serialize, Deserialize)]
 pub enum Identifier {
     Numeric(u32),
     String(UAString),
@@ -112,7 +112,7 @@ impl From<ByteString> for Identifier {
 }
 
 /// An identifier for a node in the address space of an OPC UA Server.
-#[derive(PartialEq, Eq, Clone, Debug, Hash, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Hash, Serialize, Deserialize)]
 pub struct NodeId {
     /// The index for a namespace
     pub namespace: u16,
@@ -357,9 +357,15 @@ impl NodeId {
         ObjectId::ViewsFolder.into()
     }
 
-    /// Test if the node id is null, i.e. 0 namespace and 0 identifier
+    /// Test if the node id is null, i.e. 0 namespace and 0 identifier. A null guid identifier in
+    /// namespace 0 is also a null node id.
     pub fn is_null(&self) -> bool {
-        self.namespace == 0 && self.identifier == Identifier::Numeric(0)
+        self.namespace == 0
+            && match self.identifier {
+                Identifier::Numeric(0) => true,
+                Identifier::Guid(ref guid) => guid.is_null(),
+                _ => false,
+            }
     }
 
     /// Returns a null node id
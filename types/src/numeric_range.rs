@@ -8,6 +8,8 @@ use std::str::FromStr;
 
 use regex::Regex;
 
+use crate::{status_code::StatusCode, variant::Variant};
+
 /// Numeric range describes a range within an array. See OPCUA Part 4 7.22
 ///
 /// This parameter is defined in Table 159. A formal BNF definition of the numeric range can be
@@ -50,6 +52,17 @@ impl NumericRange {
     pub fn has_range(&self) -> bool {
         *self != NumericRange::None
     }
+
+    /// Applies the range to a value, returning the selected element, sub-array or block of a
+    /// multi dimensional array. Returns `BadIndexRangeInvalid` if the range is malformed and
+    /// `BadIndexRangeNoData` if it selects nothing from the value.
+    pub fn apply(&self, value: &Variant) -> Result<Variant, StatusCode> {
+        if !self.is_valid() {
+            Err(StatusCode::BadIndexRangeInvalid)
+        } else {
+            value.range_of(self.clone())
+        }
+    }
 }
 
 // Valid inputs
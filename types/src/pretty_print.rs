@@ -0,0 +1,263 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Renders decoded messages as an indented tree for debugging.
+//!
+//! The tree is the pretty `Debug` form, `{:#?}`, with node ids, strings, byte strings and date
+//! times collapsed onto a single line. Node ids and date times are written in their string form,
+//! strings are quoted, byte strings are written as hex and a null string or byte string is written
+//! as `[null]`. The `Debug` impls of the types themselves are left untouched.
+
+use std::{fmt, iter::Peekable, str::FromStr};
+
+use crate::{
+    byte_string::ByteString,
+    data_value::DataValue,
+    date_time::{DateTime, DateTimeUtc},
+    diagnostic_info::DiagnosticInfo,
+    extension_object::ExtensionObject,
+    guid::Guid,
+    localized_text::LocalizedText,
+    node_id::{ExpandedNodeId, Identifier, NodeId},
+    qualified_name::QualifiedName,
+    request_header::RequestHeader,
+    response_header::ResponseHeader,
+    service_types::MessageInfo,
+    string::UAString,
+    variant::Variant,
+};
+
+/// How a null string or byte string is written, the same as the `Display` form of both
+const NULL: &str = "[null]";
+
+/// Implemented for the service types, i.e. anything which implements `MessageInfo`, and for the
+/// built-in types they are made of such as `NodeId` and `Variant`.
+pub trait PrettyPrint: fmt::Debug {
+    /// Returns the value as a tree of nested fields with every line indented by `indent` spaces
+    fn pretty_print(&self, indent: usize) -> String {
+        let debug = format!("{:#?}", self);
+        let mut lines = debug.lines().peekable();
+        let mut out = Vec::new();
+        while let Some(node) = Node::parse(&mut lines) {
+            node.render(indent, false, &mut out);
+        }
+        out.join("\n")
+    }
+}
+
+impl<T> PrettyPrint for T where T: MessageInfo + fmt::Debug {}
+
+impl PrettyPrint for DataValue {}
+impl PrettyPrint for DiagnosticInfo {}
+impl PrettyPrint for ExpandedNodeId {}
+impl PrettyPrint for ExtensionObject {}
+impl PrettyPrint for LocalizedText {}
+impl PrettyPrint for NodeId {}
+impl PrettyPrint for QualifiedName {}
+impl PrettyPrint for RequestHeader {}
+impl PrettyPrint for ResponseHeader {}
+impl PrettyPrint for Variant {}
+
+/// A line of the pretty `Debug` form, or a group of lines opened by a `{`, `(` or `[` and closed
+/// by the matching bracket.
+enum Node {
+    Line(String),
+    Group {
+        head: String,
+        open: char,
+        children: Vec<Node>,
+    },
+}
+
+impl Node {
+    /// Parses the next line, or the group it opens, collapsing the group onto one line if it is
+    /// one of the types written in a string form
+    fn parse<'a, I>(lines: &mut Peekable<I>) -> Option<Node>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let line = lines.next()?.trim();
+        let line = line.strip_suffix(',').unwrap_or(line);
+        let (head, open, close) = if let Some(head) = line.strip_suffix(" {") {
+            (head, '{', '}')
+        } else if let Some(head) = line.strip_suffix('(') {
+            (head, '(', ')')
+        } else if let Some(head) = line.strip_suffix('[') {
+            (head, '[', ']')
+        } else {
+            return Some(Node::Line(line.to_string()));
+        };
+        let mut children = Vec::new();
+        while let Some(line) = lines.peek() {
+            if line.trim_start().starts_with(close) {
+                let _ = lines.next();
+                break;
+            }
+            children.extend(Node::parse(lines));
+        }
+        let (label, type_name) = match head.rfind(": ") {
+            Some(idx) => head.split_at(idx + 2),
+            None => ("", head),
+        };
+        let string_form = match (type_name, open) {
+            ("NodeId", '{') => node_id(&children).map(|node_id| node_id.to_string()),
+            ("UAString", '{') => ua_string(&children),
+            ("ByteString", '{') => byte_string(&children).map(|v| v.to_string()),
+            ("DateTime", '{') => date_time(&children).map(|v| v.to_string()),
+            _ => None,
+        };
+        Some(match string_form {
+            Some(string_form) => Node::Line(format!("{}{}", label, string_form)),
+            None => Node::Group {
+                head: head.to_string(),
+                open,
+                children,
+            },
+        })
+    }
+
+    fn render(&self, indent: usize, trailing_comma: bool, out: &mut Vec<String>) {
+        let comma = if trailing_comma { "," } else { "" };
+        match self {
+            Node::Line(line) => {
+                out.push(format!("{:indent$}{}{}", "", line, comma, indent = indent))
+            }
+            Node::Group {
+                head,
+                open,
+                children,
+            } => {
+                let (open, close) = match open {
+                    '{' => (" {", "}"),
+                    '(' => ("(", ")"),
+                    _ => ("[", "]"),
+                };
+                out.push(format!("{:indent$}{}{}", "", head, open, indent = indent));
+                children
+                    .iter()
+                    .for_each(|child| child.render(indent + 4, true, out));
+                out.push(format!("{:indent$}{}{}", "", close, comma, indent = indent));
+            }
+        }
+    }
+
+    /// Returns the text of a line holding the named field
+    fn field(&self, name: &str) -> Option<&str> {
+        match self {
+            Node::Line(line) => line.strip_prefix(name)?.strip_prefix(": "),
+            _ => None,
+        }
+    }
+
+    /// Returns the only child of a group holding the named field, e.g. `value: Some(...)`
+    fn wrapped(&self, name: &str) -> Option<&Node> {
+        match self {
+            Node::Group { head, children, .. } if head == name && children.len() == 1 => {
+                children.first()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A `NodeId` from its fields, `namespace` and `identifier`, where a string or byte string
+/// identifier has already been collapsed
+fn node_id(children: &[Node]) -> Option<NodeId> {
+    let namespace = children.first()?.field("namespace")?.parse::<u16>().ok()?;
+    let identifier = children.get(1)?;
+    let identifier = if let Some(Node::Line(v)) = identifier.wrapped("identifier: Numeric") {
+        Identifier::Numeric(v.parse::<u32>().ok()?)
+    } else if let Some(Node::Line(v)) = identifier.wrapped("identifier: String") {
+        Identifier::String(if v == NULL {
+            UAString::null()
+        } else {
+            UAString::from(unescape(v)?)
+        })
+    } else if let Some(Node::Line(v)) = identifier.wrapped("identifier: Guid") {
+        Identifier::Guid(Guid::from_str(v).ok()?)
+    } else if let Some(Node::Line(v)) = identifier.wrapped("identifier: ByteString") {
+        Identifier::ByteString(if v == NULL {
+            ByteString::null()
+        } else {
+            ByteString::from(from_hex(v)?)
+        })
+    } else {
+        return None;
+    };
+    Some(NodeId {
+        namespace,
+        identifier,
+    })
+}
+
+/// The quoted string of a `UAString` from its `value` field
+fn ua_string(children: &[Node]) -> Option<String> {
+    let value = children.first()?;
+    if value.field("value") == Some("None") {
+        Some(NULL.to_string())
+    } else if let Some(Node::Line(v)) = value.wrapped("value: Some") {
+        Some(v.clone())
+    } else {
+        None
+    }
+}
+
+/// A `ByteString` from its `value` field, which holds a byte per line
+fn byte_string(children: &[Node]) -> Option<ByteString> {
+    let value = children.first()?;
+    if value.field("value") == Some("None") {
+        Some(ByteString::null())
+    } else {
+        let bytes = match value.wrapped("value: Some")? {
+            Node::Line(v) if v == "[]" => Vec::new(),
+            Node::Group { head, children, .. } if head.is_empty() => children
+                .iter()
+                .map(|b| match b {
+                    Node::Line(b) => b.parse::<u8>().ok(),
+                    _ => None,
+                })
+                .collect::<Option<Vec<u8>>>()?,
+            _ => return None,
+        };
+        Some(ByteString::from(bytes))
+    }
+}
+
+/// A `DateTime` from its `date_time` field
+fn date_time(children: &[Node]) -> Option<DateTime> {
+    let date_time = children.first()?.field("date_time")?;
+    DateTimeUtc::from_str(date_time).ok().map(DateTime::from)
+}
+
+/// The bytes of a byte string collapsed to hex
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// The string held by a quoted and escaped `Debug` string literal
+fn unescape(quoted: &str) -> Option<String> {
+    let mut chars = quoted.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        value.push(match chars.next()? {
+            't' => '\t',
+            'r' => '\r',
+            'n' => '\n',
+            '0' => '\0',
+            'u' => {
+                let code: String = chars.by_ref().skip(1).take_while(|c| *c != '}').collect();
+                std::char::from_u32(u32::from_str_radix(&code, 16).ok()?)?
+            }
+            c => c,
+        });
+    }
+    Some(value)
+}
@@ -5,7 +5,7 @@
 //! Contains the definition of `QualifiedName`.
 use std::io::{Read, Write};
 
-use crate::{encoding::*, string::*};
+use crate::{encoding::*, status_codes::StatusCode, string::*};
 
 /// An identifier for a error or condition that is associated with a value or an operation.
 ///
@@ -63,7 +63,13 @@ impl BinaryEncoder<QualifiedName> for QualifiedName {
 
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
         let namespace_index = u16::decode(stream, decoding_limits)?;
-        let name = UAString::decode(stream, decoding_limits)?;
+        let name = UAString::decode(stream, decoding_limits).map_err(|err| {
+            error!(
+                "QualifiedName name could not be decoded, namespace index = {}, max string length = {}, err = {}",
+                namespace_index, decoding_limits.max_string_length, err
+            );
+            StatusCode::BadDecodingError
+        })?;
         Ok(QualifiedName {
             namespace_index,
             name,
@@ -92,4 +98,19 @@ impl QualifiedName {
     pub fn is_null(&self) -> bool {
         self.namespace_index == 0 && self.name.is_null()
     }
+
+    /// Checks the namespace index refers to an entry in a namespace table of the supplied
+    /// length, returning `BadBrowseNameInvalid` if it does not. Decoding cannot check this so it
+    /// should be called on names received from a peer before they are used.
+    pub fn validate_namespace(&self, table_len: u16) -> Result<(), StatusCode> {
+        if self.namespace_index < table_len {
+            Ok(())
+        } else {
+            error!(
+                "Qualified name {} has namespace index {} which is outside of namespace table of length {}",
+                self.name, self.namespace_index, table_len
+            );
+            Err(StatusCode::BadBrowseNameInvalid)
+        }
+    }
 }
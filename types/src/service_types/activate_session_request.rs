@@ -63,12 +63,12 @@ impl BinaryEncoder<ActivateSessionRequest> for ActivateSessionRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let client_signature = SignatureData::decode(stream, decoding_limits)?;
-        let client_software_certificates: Option<Vec<SignedSoftwareCertificate>> = read_array(stream, decoding_limits)?;
-        let locale_ids: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let user_identity_token = ExtensionObject::decode(stream, decoding_limits)?;
-        let user_token_signature = SignatureData::decode(stream, decoding_limits)?;
+        let request_header = decode_field("ActivateSessionRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let client_signature = decode_field("ActivateSessionRequest", "client_signature", SignatureData::decode(stream, decoding_limits))?;
+        let client_software_certificates: Option<Vec<SignedSoftwareCertificate>> = decode_field("ActivateSessionRequest", "client_software_certificates", read_array(stream, decoding_limits))?;
+        let locale_ids: Option<Vec<UAString>> = decode_field("ActivateSessionRequest", "locale_ids", read_array(stream, decoding_limits))?;
+        let user_identity_token = decode_field("ActivateSessionRequest", "user_identity_token", ExtensionObject::decode(stream, decoding_limits))?;
+        let user_token_signature = decode_field("ActivateSessionRequest", "user_token_signature", SignatureData::decode(stream, decoding_limits))?;
         Ok(ActivateSessionRequest {
             request_header,
             client_signature,
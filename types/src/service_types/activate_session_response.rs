@@ -56,10 +56,10 @@ impl BinaryEncoder<ActivateSessionResponse> for ActivateSessionResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let server_nonce = ByteString::decode(stream, decoding_limits)?;
-        let results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("ActivateSessionResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let server_nonce = decode_field("ActivateSessionResponse", "server_nonce", ByteString::decode(stream, decoding_limits))?;
+        let results: Option<Vec<StatusCode>> = decode_field("ActivateSessionResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("ActivateSessionResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(ActivateSessionResponse {
             response_header,
             server_nonce,
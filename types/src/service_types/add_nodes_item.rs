@@ -66,13 +66,13 @@ impl BinaryEncoder<AddNodesItem> for AddNodesItem {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let parent_node_id = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let requested_new_node_id = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let browse_name = QualifiedName::decode(stream, decoding_limits)?;
-        let node_class = NodeClass::decode(stream, decoding_limits)?;
-        let node_attributes = ExtensionObject::decode(stream, decoding_limits)?;
-        let type_definition = ExpandedNodeId::decode(stream, decoding_limits)?;
+        let parent_node_id = decode_field("AddNodesItem", "parent_node_id", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let reference_type_id = decode_field("AddNodesItem", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let requested_new_node_id = decode_field("AddNodesItem", "requested_new_node_id", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let browse_name = decode_field("AddNodesItem", "browse_name", QualifiedName::decode(stream, decoding_limits))?;
+        let node_class = decode_field("AddNodesItem", "node_class", NodeClass::decode(stream, decoding_limits))?;
+        let node_attributes = decode_field("AddNodesItem", "node_attributes", ExtensionObject::decode(stream, decoding_limits))?;
+        let type_definition = decode_field("AddNodesItem", "type_definition", ExpandedNodeId::decode(stream, decoding_limits))?;
         Ok(AddNodesItem {
             parent_node_id,
             reference_type_id,
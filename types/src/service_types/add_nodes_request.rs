@@ -48,8 +48,8 @@ impl BinaryEncoder<AddNodesRequest> for AddNodesRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let nodes_to_add: Option<Vec<AddNodesItem>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("AddNodesRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let nodes_to_add: Option<Vec<AddNodesItem>> = decode_field("AddNodesRequest", "nodes_to_add", read_array(stream, decoding_limits))?;
         Ok(AddNodesRequest {
             request_header,
             nodes_to_add,
@@ -52,9 +52,9 @@ impl BinaryEncoder<AddNodesResponse> for AddNodesResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<AddNodesResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("AddNodesResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<AddNodesResult>> = decode_field("AddNodesResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("AddNodesResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(AddNodesResponse {
             response_header,
             results,
@@ -48,8 +48,8 @@ impl BinaryEncoder<AddNodesResult> for AddNodesResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let added_node_id = NodeId::decode(stream, decoding_limits)?;
+        let status_code = decode_field("AddNodesResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let added_node_id = decode_field("AddNodesResult", "added_node_id", NodeId::decode(stream, decoding_limits))?;
         Ok(AddNodesResult {
             status_code,
             added_node_id,
@@ -62,12 +62,12 @@ impl BinaryEncoder<AddReferencesItem> for AddReferencesItem {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let source_node_id = NodeId::decode(stream, decoding_limits)?;
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let is_forward = bool::decode(stream, decoding_limits)?;
-        let target_server_uri = UAString::decode(stream, decoding_limits)?;
-        let target_node_id = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let target_node_class = NodeClass::decode(stream, decoding_limits)?;
+        let source_node_id = decode_field("AddReferencesItem", "source_node_id", NodeId::decode(stream, decoding_limits))?;
+        let reference_type_id = decode_field("AddReferencesItem", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let is_forward = decode_field("AddReferencesItem", "is_forward", bool::decode(stream, decoding_limits))?;
+        let target_server_uri = decode_field("AddReferencesItem", "target_server_uri", UAString::decode(stream, decoding_limits))?;
+        let target_node_id = decode_field("AddReferencesItem", "target_node_id", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let target_node_class = decode_field("AddReferencesItem", "target_node_class", NodeClass::decode(stream, decoding_limits))?;
         Ok(AddReferencesItem {
             source_node_id,
             reference_type_id,
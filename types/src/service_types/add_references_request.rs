@@ -48,8 +48,8 @@ impl BinaryEncoder<AddReferencesRequest> for AddReferencesRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let references_to_add: Option<Vec<AddReferencesItem>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("AddReferencesRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let references_to_add: Option<Vec<AddReferencesItem>> = decode_field("AddReferencesRequest", "references_to_add", read_array(stream, decoding_limits))?;
         Ok(AddReferencesRequest {
             request_header,
             references_to_add,
@@ -47,10 +47,10 @@ impl BinaryEncoder<AggregateFilter> for AggregateFilter {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let start_time = DateTime::decode(stream, decoding_limits)?;
-        let aggregate_type = NodeId::decode(stream, decoding_limits)?;
-        let processing_interval = f64::decode(stream, decoding_limits)?;
-        let aggregate_configuration = AggregateConfiguration::decode(stream, decoding_limits)?;
+        let start_time = decode_field("AggregateFilter", "start_time", DateTime::decode(stream, decoding_limits))?;
+        let aggregate_type = decode_field("AggregateFilter", "aggregate_type", NodeId::decode(stream, decoding_limits))?;
+        let processing_interval = decode_field("AggregateFilter", "processing_interval", f64::decode(stream, decoding_limits))?;
+        let aggregate_configuration = decode_field("AggregateFilter", "aggregate_configuration", AggregateConfiguration::decode(stream, decoding_limits))?;
         Ok(AggregateFilter {
             start_time,
             aggregate_type,
@@ -43,9 +43,9 @@ impl BinaryEncoder<AggregateFilterResult> for AggregateFilterResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let revised_start_time = DateTime::decode(stream, decoding_limits)?;
-        let revised_processing_interval = f64::decode(stream, decoding_limits)?;
-        let revised_aggregate_configuration = AggregateConfiguration::decode(stream, decoding_limits)?;
+        let revised_start_time = decode_field("AggregateFilterResult", "revised_start_time", DateTime::decode(stream, decoding_limits))?;
+        let revised_processing_interval = decode_field("AggregateFilterResult", "revised_processing_interval", f64::decode(stream, decoding_limits))?;
+        let revised_aggregate_configuration = decode_field("AggregateFilterResult", "revised_aggregate_configuration", AggregateConfiguration::decode(stream, decoding_limits))?;
         Ok(AggregateFilterResult {
             revised_start_time,
             revised_processing_interval,
@@ -51,9 +51,9 @@ impl BinaryEncoder<Annotation> for Annotation {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let message = UAString::decode(stream, decoding_limits)?;
-        let user_name = UAString::decode(stream, decoding_limits)?;
-        let annotation_time = DateTime::decode(stream, decoding_limits)?;
+        let message = decode_field("Annotation", "message", UAString::decode(stream, decoding_limits))?;
+        let user_name = decode_field("Annotation", "user_name", UAString::decode(stream, decoding_limits))?;
+        let annotation_time = decode_field("Annotation", "annotation_time", DateTime::decode(stream, decoding_limits))?;
         Ok(Annotation {
             message,
             user_name,
@@ -36,7 +36,7 @@ impl BinaryEncoder<AnonymousIdentityToken> for AnonymousIdentityToken {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let policy_id = UAString::decode(stream, decoding_limits)?;
+        let policy_id = decode_field("AnonymousIdentityToken", "policy_id", UAString::decode(stream, decoding_limits))?;
         Ok(AnonymousIdentityToken {
             policy_id,
         })
@@ -64,13 +64,13 @@ impl BinaryEncoder<ApplicationDescription> for ApplicationDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let application_uri = UAString::decode(stream, decoding_limits)?;
-        let product_uri = UAString::decode(stream, decoding_limits)?;
-        let application_name = LocalizedText::decode(stream, decoding_limits)?;
-        let application_type = ApplicationType::decode(stream, decoding_limits)?;
-        let gateway_server_uri = UAString::decode(stream, decoding_limits)?;
-        let discovery_profile_uri = UAString::decode(stream, decoding_limits)?;
-        let discovery_urls: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
+        let application_uri = decode_field("ApplicationDescription", "application_uri", UAString::decode(stream, decoding_limits))?;
+        let product_uri = decode_field("ApplicationDescription", "product_uri", UAString::decode(stream, decoding_limits))?;
+        let application_name = decode_field("ApplicationDescription", "application_name", LocalizedText::decode(stream, decoding_limits))?;
+        let application_type = decode_field("ApplicationDescription", "application_type", ApplicationType::decode(stream, decoding_limits))?;
+        let gateway_server_uri = decode_field("ApplicationDescription", "gateway_server_uri", UAString::decode(stream, decoding_limits))?;
+        let discovery_profile_uri = decode_field("ApplicationDescription", "discovery_profile_uri", UAString::decode(stream, decoding_limits))?;
+        let discovery_urls: Option<Vec<UAString>> = decode_field("ApplicationDescription", "discovery_urls", read_array(stream, decoding_limits))?;
         Ok(ApplicationDescription {
             application_uri,
             product_uri,
@@ -58,11 +58,11 @@ impl BinaryEncoder<Argument> for Argument {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let data_type = NodeId::decode(stream, decoding_limits)?;
-        let value_rank = i32::decode(stream, decoding_limits)?;
-        let array_dimensions: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
+        let name = decode_field("Argument", "name", UAString::decode(stream, decoding_limits))?;
+        let data_type = decode_field("Argument", "data_type", NodeId::decode(stream, decoding_limits))?;
+        let value_rank = decode_field("Argument", "value_rank", i32::decode(stream, decoding_limits))?;
+        let array_dimensions: Option<Vec<u32>> = decode_field("Argument", "array_dimensions", read_array(stream, decoding_limits))?;
+        let description = decode_field("Argument", "description", LocalizedText::decode(stream, decoding_limits))?;
         Ok(Argument {
             name,
             data_type,
@@ -50,11 +50,11 @@ impl BinaryEncoder<AttributeOperand> for AttributeOperand {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let alias = UAString::decode(stream, decoding_limits)?;
-        let browse_path = RelativePath::decode(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
+        let node_id = decode_field("AttributeOperand", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let alias = decode_field("AttributeOperand", "alias", UAString::decode(stream, decoding_limits))?;
+        let browse_path = decode_field("AttributeOperand", "browse_path", RelativePath::decode(stream, decoding_limits))?;
+        let attribute_id = decode_field("AttributeOperand", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let index_range = decode_field("AttributeOperand", "index_range", UAString::decode(stream, decoding_limits))?;
         Ok(AttributeOperand {
             node_id,
             alias,
@@ -59,11 +59,11 @@ impl BinaryEncoder<AxisInformation> for AxisInformation {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let engineering_units = EUInformation::decode(stream, decoding_limits)?;
-        let eu_range = Range::decode(stream, decoding_limits)?;
-        let title = LocalizedText::decode(stream, decoding_limits)?;
-        let axis_scale_type = AxisScaleEnumeration::decode(stream, decoding_limits)?;
-        let axis_steps: Option<Vec<f64>> = read_array(stream, decoding_limits)?;
+        let engineering_units = decode_field("AxisInformation", "engineering_units", EUInformation::decode(stream, decoding_limits))?;
+        let eu_range = decode_field("AxisInformation", "eu_range", Range::decode(stream, decoding_limits))?;
+        let title = decode_field("AxisInformation", "title", LocalizedText::decode(stream, decoding_limits))?;
+        let axis_scale_type = decode_field("AxisInformation", "axis_scale_type", AxisScaleEnumeration::decode(stream, decoding_limits))?;
+        let axis_steps: Option<Vec<f64>> = decode_field("AxisInformation", "axis_steps", read_array(stream, decoding_limits))?;
         Ok(AxisInformation {
             engineering_units,
             eu_range,
@@ -39,8 +39,8 @@ impl BinaryEncoder<BrokerConnectionTransportDataType> for BrokerConnectionTransp
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let resource_uri = UAString::decode(stream, decoding_limits)?;
-        let authentication_profile_uri = UAString::decode(stream, decoding_limits)?;
+        let resource_uri = decode_field("BrokerConnectionTransportDataType", "resource_uri", UAString::decode(stream, decoding_limits))?;
+        let authentication_profile_uri = decode_field("BrokerConnectionTransportDataType", "authentication_profile_uri", UAString::decode(stream, decoding_limits))?;
         Ok(BrokerConnectionTransportDataType {
             resource_uri,
             authentication_profile_uri,
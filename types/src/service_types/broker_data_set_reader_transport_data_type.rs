@@ -49,11 +49,11 @@ impl BinaryEncoder<BrokerDataSetReaderTransportDataType> for BrokerDataSetReader
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let queue_name = UAString::decode(stream, decoding_limits)?;
-        let resource_uri = UAString::decode(stream, decoding_limits)?;
-        let authentication_profile_uri = UAString::decode(stream, decoding_limits)?;
-        let requested_delivery_guarantee = BrokerTransportQualityOfService::decode(stream, decoding_limits)?;
-        let meta_data_queue_name = UAString::decode(stream, decoding_limits)?;
+        let queue_name = decode_field("BrokerDataSetReaderTransportDataType", "queue_name", UAString::decode(stream, decoding_limits))?;
+        let resource_uri = decode_field("BrokerDataSetReaderTransportDataType", "resource_uri", UAString::decode(stream, decoding_limits))?;
+        let authentication_profile_uri = decode_field("BrokerDataSetReaderTransportDataType", "authentication_profile_uri", UAString::decode(stream, decoding_limits))?;
+        let requested_delivery_guarantee = decode_field("BrokerDataSetReaderTransportDataType", "requested_delivery_guarantee", BrokerTransportQualityOfService::decode(stream, decoding_limits))?;
+        let meta_data_queue_name = decode_field("BrokerDataSetReaderTransportDataType", "meta_data_queue_name", UAString::decode(stream, decoding_limits))?;
         Ok(BrokerDataSetReaderTransportDataType {
             queue_name,
             resource_uri,
@@ -52,12 +52,12 @@ impl BinaryEncoder<BrokerDataSetWriterTransportDataType> for BrokerDataSetWriter
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let queue_name = UAString::decode(stream, decoding_limits)?;
-        let resource_uri = UAString::decode(stream, decoding_limits)?;
-        let authentication_profile_uri = UAString::decode(stream, decoding_limits)?;
-        let requested_delivery_guarantee = BrokerTransportQualityOfService::decode(stream, decoding_limits)?;
-        let meta_data_queue_name = UAString::decode(stream, decoding_limits)?;
-        let meta_data_update_time = f64::decode(stream, decoding_limits)?;
+        let queue_name = decode_field("BrokerDataSetWriterTransportDataType", "queue_name", UAString::decode(stream, decoding_limits))?;
+        let resource_uri = decode_field("BrokerDataSetWriterTransportDataType", "resource_uri", UAString::decode(stream, decoding_limits))?;
+        let authentication_profile_uri = decode_field("BrokerDataSetWriterTransportDataType", "authentication_profile_uri", UAString::decode(stream, decoding_limits))?;
+        let requested_delivery_guarantee = decode_field("BrokerDataSetWriterTransportDataType", "requested_delivery_guarantee", BrokerTransportQualityOfService::decode(stream, decoding_limits))?;
+        let meta_data_queue_name = decode_field("BrokerDataSetWriterTransportDataType", "meta_data_queue_name", UAString::decode(stream, decoding_limits))?;
+        let meta_data_update_time = decode_field("BrokerDataSetWriterTransportDataType", "meta_data_update_time", f64::decode(stream, decoding_limits))?;
         Ok(BrokerDataSetWriterTransportDataType {
             queue_name,
             resource_uri,
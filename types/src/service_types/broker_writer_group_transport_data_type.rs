@@ -46,10 +46,10 @@ impl BinaryEncoder<BrokerWriterGroupTransportDataType> for BrokerWriterGroupTran
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let queue_name = UAString::decode(stream, decoding_limits)?;
-        let resource_uri = UAString::decode(stream, decoding_limits)?;
-        let authentication_profile_uri = UAString::decode(stream, decoding_limits)?;
-        let requested_delivery_guarantee = BrokerTransportQualityOfService::decode(stream, decoding_limits)?;
+        let queue_name = decode_field("BrokerWriterGroupTransportDataType", "queue_name", UAString::decode(stream, decoding_limits))?;
+        let resource_uri = decode_field("BrokerWriterGroupTransportDataType", "resource_uri", UAString::decode(stream, decoding_limits))?;
+        let authentication_profile_uri = decode_field("BrokerWriterGroupTransportDataType", "authentication_profile_uri", UAString::decode(stream, decoding_limits))?;
+        let requested_delivery_guarantee = decode_field("BrokerWriterGroupTransportDataType", "requested_delivery_guarantee", BrokerTransportQualityOfService::decode(stream, decoding_limits))?;
         Ok(BrokerWriterGroupTransportDataType {
             queue_name,
             resource_uri,
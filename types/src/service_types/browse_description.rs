@@ -60,12 +60,12 @@ impl BinaryEncoder<BrowseDescription> for BrowseDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let browse_direction = BrowseDirection::decode(stream, decoding_limits)?;
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let include_subtypes = bool::decode(stream, decoding_limits)?;
-        let node_class_mask = u32::decode(stream, decoding_limits)?;
-        let result_mask = u32::decode(stream, decoding_limits)?;
+        let node_id = decode_field("BrowseDescription", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let browse_direction = decode_field("BrowseDescription", "browse_direction", BrowseDirection::decode(stream, decoding_limits))?;
+        let reference_type_id = decode_field("BrowseDescription", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let include_subtypes = decode_field("BrowseDescription", "include_subtypes", bool::decode(stream, decoding_limits))?;
+        let node_class_mask = decode_field("BrowseDescription", "node_class_mask", u32::decode(stream, decoding_limits))?;
+        let result_mask = decode_field("BrowseDescription", "result_mask", u32::decode(stream, decoding_limits))?;
         Ok(BrowseDescription {
             node_id,
             browse_direction,
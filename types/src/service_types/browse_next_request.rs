@@ -51,9 +51,9 @@ impl BinaryEncoder<BrowseNextRequest> for BrowseNextRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let release_continuation_points = bool::decode(stream, decoding_limits)?;
-        let continuation_points: Option<Vec<ByteString>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("BrowseNextRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let release_continuation_points = decode_field("BrowseNextRequest", "release_continuation_points", bool::decode(stream, decoding_limits))?;
+        let continuation_points: Option<Vec<ByteString>> = decode_field("BrowseNextRequest", "continuation_points", read_array(stream, decoding_limits))?;
         Ok(BrowseNextRequest {
             request_header,
             release_continuation_points,
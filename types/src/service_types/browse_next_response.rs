@@ -52,9 +52,9 @@ impl BinaryEncoder<BrowseNextResponse> for BrowseNextResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<BrowseResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("BrowseNextResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<BrowseResult>> = decode_field("BrowseNextResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("BrowseNextResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(BrowseNextResponse {
             response_header,
             results,
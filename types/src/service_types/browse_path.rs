@@ -48,8 +48,8 @@ impl BinaryEncoder<BrowsePath> for BrowsePath {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let starting_node = NodeId::decode(stream, decoding_limits)?;
-        let relative_path = RelativePath::decode(stream, decoding_limits)?;
+        let starting_node = decode_field("BrowsePath", "starting_node", NodeId::decode(stream, decoding_limits))?;
+        let relative_path = decode_field("BrowsePath", "relative_path", RelativePath::decode(stream, decoding_limits))?;
         Ok(BrowsePath {
             starting_node,
             relative_path,
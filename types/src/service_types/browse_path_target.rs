@@ -47,8 +47,8 @@ impl BinaryEncoder<BrowsePathTarget> for BrowsePathTarget {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let target_id = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let remaining_path_index = u32::decode(stream, decoding_limits)?;
+        let target_id = decode_field("BrowsePathTarget", "target_id", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let remaining_path_index = decode_field("BrowsePathTarget", "remaining_path_index", u32::decode(stream, decoding_limits))?;
         Ok(BrowsePathTarget {
             target_id,
             remaining_path_index,
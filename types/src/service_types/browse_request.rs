@@ -55,10 +55,10 @@ impl BinaryEncoder<BrowseRequest> for BrowseRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let view = ViewDescription::decode(stream, decoding_limits)?;
-        let requested_max_references_per_node = u32::decode(stream, decoding_limits)?;
-        let nodes_to_browse: Option<Vec<BrowseDescription>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("BrowseRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let view = decode_field("BrowseRequest", "view", ViewDescription::decode(stream, decoding_limits))?;
+        let requested_max_references_per_node = decode_field("BrowseRequest", "requested_max_references_per_node", u32::decode(stream, decoding_limits))?;
+        let nodes_to_browse: Option<Vec<BrowseDescription>> = decode_field("BrowseRequest", "nodes_to_browse", read_array(stream, decoding_limits))?;
         Ok(BrowseRequest {
             request_header,
             view,
@@ -52,9 +52,9 @@ impl BinaryEncoder<BrowseResponse> for BrowseResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<BrowseResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("BrowseResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<BrowseResult>> = decode_field("BrowseResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("BrowseResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(BrowseResponse {
             response_header,
             results,
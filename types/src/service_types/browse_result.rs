@@ -52,9 +52,9 @@ impl BinaryEncoder<BrowseResult> for BrowseResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let continuation_point = ByteString::decode(stream, decoding_limits)?;
-        let references: Option<Vec<ReferenceDescription>> = read_array(stream, decoding_limits)?;
+        let status_code = decode_field("BrowseResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let continuation_point = decode_field("BrowseResult", "continuation_point", ByteString::decode(stream, decoding_limits))?;
+        let references: Option<Vec<ReferenceDescription>> = decode_field("BrowseResult", "references", read_array(stream, decoding_limits))?;
         Ok(BrowseResult {
             status_code,
             continuation_point,
@@ -60,12 +60,12 @@ impl BinaryEncoder<BuildInfo> for BuildInfo {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let product_uri = UAString::decode(stream, decoding_limits)?;
-        let manufacturer_name = UAString::decode(stream, decoding_limits)?;
-        let product_name = UAString::decode(stream, decoding_limits)?;
-        let software_version = UAString::decode(stream, decoding_limits)?;
-        let build_number = UAString::decode(stream, decoding_limits)?;
-        let build_date = DateTime::decode(stream, decoding_limits)?;
+        let product_uri = decode_field("BuildInfo", "product_uri", UAString::decode(stream, decoding_limits))?;
+        let manufacturer_name = decode_field("BuildInfo", "manufacturer_name", UAString::decode(stream, decoding_limits))?;
+        let product_name = decode_field("BuildInfo", "product_name", UAString::decode(stream, decoding_limits))?;
+        let software_version = decode_field("BuildInfo", "software_version", UAString::decode(stream, decoding_limits))?;
+        let build_number = decode_field("BuildInfo", "build_number", UAString::decode(stream, decoding_limits))?;
+        let build_date = decode_field("BuildInfo", "build_date", DateTime::decode(stream, decoding_limits))?;
         Ok(BuildInfo {
             product_uri,
             manufacturer_name,
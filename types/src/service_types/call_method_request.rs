@@ -51,9 +51,9 @@ impl BinaryEncoder<CallMethodRequest> for CallMethodRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let object_id = NodeId::decode(stream, decoding_limits)?;
-        let method_id = NodeId::decode(stream, decoding_limits)?;
-        let input_arguments: Option<Vec<Variant>> = read_array(stream, decoding_limits)?;
+        let object_id = decode_field("CallMethodRequest", "object_id", NodeId::decode(stream, decoding_limits))?;
+        let method_id = decode_field("CallMethodRequest", "method_id", NodeId::decode(stream, decoding_limits))?;
+        let input_arguments: Option<Vec<Variant>> = decode_field("CallMethodRequest", "input_arguments", read_array(stream, decoding_limits))?;
         Ok(CallMethodRequest {
             object_id,
             method_id,
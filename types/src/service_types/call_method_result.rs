@@ -55,10 +55,10 @@ impl BinaryEncoder<CallMethodResult> for CallMethodResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let input_argument_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let input_argument_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
-        let output_arguments: Option<Vec<Variant>> = read_array(stream, decoding_limits)?;
+        let status_code = decode_field("CallMethodResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let input_argument_results: Option<Vec<StatusCode>> = decode_field("CallMethodResult", "input_argument_results", read_array(stream, decoding_limits))?;
+        let input_argument_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("CallMethodResult", "input_argument_diagnostic_infos", read_array(stream, decoding_limits))?;
+        let output_arguments: Option<Vec<Variant>> = decode_field("CallMethodResult", "output_arguments", read_array(stream, decoding_limits))?;
         Ok(CallMethodResult {
             status_code,
             input_argument_results,
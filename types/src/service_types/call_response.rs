@@ -52,9 +52,9 @@ impl BinaryEncoder<CallResponse> for CallResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<CallMethodResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("CallResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<CallMethodResult>> = decode_field("CallResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("CallResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(CallResponse {
             response_header,
             results,
@@ -47,8 +47,8 @@ impl BinaryEncoder<CancelRequest> for CancelRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let request_handle = u32::decode(stream, decoding_limits)?;
+        let request_header = decode_field("CancelRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let request_handle = decode_field("CancelRequest", "request_handle", u32::decode(stream, decoding_limits))?;
         Ok(CancelRequest {
             request_header,
             request_handle,
@@ -47,8 +47,8 @@ impl BinaryEncoder<CancelResponse> for CancelResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let cancel_count = u32::decode(stream, decoding_limits)?;
+        let response_header = decode_field("CancelResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let cancel_count = decode_field("CancelResponse", "cancel_count", u32::decode(stream, decoding_limits))?;
         Ok(CancelResponse {
             response_header,
             cancel_count,
@@ -53,10 +53,10 @@ impl BinaryEncoder<ChannelSecurityToken> for ChannelSecurityToken {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let channel_id = u32::decode(stream, decoding_limits)?;
-        let token_id = u32::decode(stream, decoding_limits)?;
-        let created_at = DateTime::decode(stream, decoding_limits)?;
-        let revised_lifetime = u32::decode(stream, decoding_limits)?;
+        let channel_id = decode_field("ChannelSecurityToken", "channel_id", u32::decode(stream, decoding_limits))?;
+        let token_id = decode_field("ChannelSecurityToken", "token_id", u32::decode(stream, decoding_limits))?;
+        let created_at = decode_field("ChannelSecurityToken", "created_at", DateTime::decode(stream, decoding_limits))?;
+        let revised_lifetime = decode_field("ChannelSecurityToken", "revised_lifetime", u32::decode(stream, decoding_limits))?;
         Ok(ChannelSecurityToken {
             channel_id,
             token_id,
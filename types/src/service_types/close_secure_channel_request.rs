@@ -44,7 +44,7 @@ impl BinaryEncoder<CloseSecureChannelRequest> for CloseSecureChannelRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
+        let request_header = decode_field("CloseSecureChannelRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
         Ok(CloseSecureChannelRequest {
             request_header,
         })
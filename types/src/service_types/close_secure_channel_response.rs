@@ -44,7 +44,7 @@ impl BinaryEncoder<CloseSecureChannelResponse> for CloseSecureChannelResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
+        let response_header = decode_field("CloseSecureChannelResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
         Ok(CloseSecureChannelResponse {
             response_header,
         })
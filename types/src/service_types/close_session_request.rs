@@ -47,8 +47,8 @@ impl BinaryEncoder<CloseSessionRequest> for CloseSessionRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let delete_subscriptions = bool::decode(stream, decoding_limits)?;
+        let request_header = decode_field("CloseSessionRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let delete_subscriptions = decode_field("CloseSessionRequest", "delete_subscriptions", bool::decode(stream, decoding_limits))?;
         Ok(CloseSessionRequest {
             request_header,
             delete_subscriptions,
@@ -46,8 +46,8 @@ impl BinaryEncoder<ComplexNumberType> for ComplexNumberType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let real = f32::decode(stream, decoding_limits)?;
-        let imaginary = f32::decode(stream, decoding_limits)?;
+        let real = decode_field("ComplexNumberType", "real", f32::decode(stream, decoding_limits))?;
+        let imaginary = decode_field("ComplexNumberType", "imaginary", f32::decode(stream, decoding_limits))?;
         Ok(ComplexNumberType {
             real,
             imaginary,
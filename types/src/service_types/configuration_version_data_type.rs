@@ -46,8 +46,8 @@ impl BinaryEncoder<ConfigurationVersionDataType> for ConfigurationVersionDataTyp
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let major_version = u32::decode(stream, decoding_limits)?;
-        let minor_version = u32::decode(stream, decoding_limits)?;
+        let major_version = decode_field("ConfigurationVersionDataType", "major_version", u32::decode(stream, decoding_limits))?;
+        let minor_version = decode_field("ConfigurationVersionDataType", "minor_version", u32::decode(stream, decoding_limits))?;
         Ok(ConfigurationVersionDataType {
             major_version,
             minor_version,
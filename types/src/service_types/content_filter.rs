@@ -44,7 +44,7 @@ impl BinaryEncoder<ContentFilter> for ContentFilter {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let elements: Option<Vec<ContentFilterElement>> = read_array(stream, decoding_limits)?;
+        let elements: Option<Vec<ContentFilterElement>> = decode_field("ContentFilter", "elements", read_array(stream, decoding_limits))?;
         Ok(ContentFilter {
             elements,
         })
@@ -48,8 +48,8 @@ impl BinaryEncoder<ContentFilterElement> for ContentFilterElement {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let filter_operator = FilterOperator::decode(stream, decoding_limits)?;
-        let filter_operands: Option<Vec<ExtensionObject>> = read_array(stream, decoding_limits)?;
+        let filter_operator = decode_field("ContentFilterElement", "filter_operator", FilterOperator::decode(stream, decoding_limits))?;
+        let filter_operands: Option<Vec<ExtensionObject>> = decode_field("ContentFilterElement", "filter_operands", read_array(stream, decoding_limits))?;
         Ok(ContentFilterElement {
             filter_operator,
             filter_operands,
@@ -51,9 +51,9 @@ impl BinaryEncoder<ContentFilterElementResult> for ContentFilterElementResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let operand_status_codes: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let operand_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let status_code = decode_field("ContentFilterElementResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let operand_status_codes: Option<Vec<StatusCode>> = decode_field("ContentFilterElementResult", "operand_status_codes", read_array(stream, decoding_limits))?;
+        let operand_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("ContentFilterElementResult", "operand_diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(ContentFilterElementResult {
             status_code,
             operand_status_codes,
@@ -48,8 +48,8 @@ impl BinaryEncoder<ContentFilterResult> for ContentFilterResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let element_results: Option<Vec<ContentFilterElementResult>> = read_array(stream, decoding_limits)?;
-        let element_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let element_results: Option<Vec<ContentFilterElementResult>> = decode_field("ContentFilterResult", "element_results", read_array(stream, decoding_limits))?;
+        let element_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("ContentFilterResult", "element_diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(ContentFilterResult {
             element_results,
             element_diagnostic_infos,
@@ -55,10 +55,10 @@ impl BinaryEncoder<CreateMonitoredItemsRequest> for CreateMonitoredItemsRequest
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let timestamps_to_return = TimestampsToReturn::decode(stream, decoding_limits)?;
-        let items_to_create: Option<Vec<MonitoredItemCreateRequest>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("CreateMonitoredItemsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("CreateMonitoredItemsRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let timestamps_to_return = decode_field("CreateMonitoredItemsRequest", "timestamps_to_return", TimestampsToReturn::decode(stream, decoding_limits))?;
+        let items_to_create: Option<Vec<MonitoredItemCreateRequest>> = decode_field("CreateMonitoredItemsRequest", "items_to_create", read_array(stream, decoding_limits))?;
         Ok(CreateMonitoredItemsRequest {
             request_header,
             subscription_id,
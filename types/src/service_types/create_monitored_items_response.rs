@@ -52,9 +52,9 @@ impl BinaryEncoder<CreateMonitoredItemsResponse> for CreateMonitoredItemsRespons
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<MonitoredItemCreateResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("CreateMonitoredItemsResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<MonitoredItemCreateResult>> = decode_field("CreateMonitoredItemsResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("CreateMonitoredItemsResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(CreateMonitoredItemsResponse {
             response_header,
             results,
@@ -71,15 +71,15 @@ impl BinaryEncoder<CreateSessionRequest> for CreateSessionRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let client_description = ApplicationDescription::decode(stream, decoding_limits)?;
-        let server_uri = UAString::decode(stream, decoding_limits)?;
-        let endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let session_name = UAString::decode(stream, decoding_limits)?;
-        let client_nonce = ByteString::decode(stream, decoding_limits)?;
-        let client_certificate = ByteString::decode(stream, decoding_limits)?;
-        let requested_session_timeout = f64::decode(stream, decoding_limits)?;
-        let max_response_message_size = u32::decode(stream, decoding_limits)?;
+        let request_header = decode_field("CreateSessionRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let client_description = decode_field("CreateSessionRequest", "client_description", ApplicationDescription::decode(stream, decoding_limits))?;
+        let server_uri = decode_field("CreateSessionRequest", "server_uri", UAString::decode(stream, decoding_limits))?;
+        let endpoint_url = decode_field("CreateSessionRequest", "endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let session_name = decode_field("CreateSessionRequest", "session_name", UAString::decode(stream, decoding_limits))?;
+        let client_nonce = decode_field("CreateSessionRequest", "client_nonce", ByteString::decode(stream, decoding_limits))?;
+        let client_certificate = decode_field("CreateSessionRequest", "client_certificate", ByteString::decode(stream, decoding_limits))?;
+        let requested_session_timeout = decode_field("CreateSessionRequest", "requested_session_timeout", f64::decode(stream, decoding_limits))?;
+        let max_response_message_size = decode_field("CreateSessionRequest", "max_response_message_size", u32::decode(stream, decoding_limits))?;
         Ok(CreateSessionRequest {
             request_header,
             client_description,
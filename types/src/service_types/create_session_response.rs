@@ -76,16 +76,16 @@ impl BinaryEncoder<CreateSessionResponse> for CreateSessionResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let session_id = NodeId::decode(stream, decoding_limits)?;
-        let authentication_token = NodeId::decode(stream, decoding_limits)?;
-        let revised_session_timeout = f64::decode(stream, decoding_limits)?;
-        let server_nonce = ByteString::decode(stream, decoding_limits)?;
-        let server_certificate = ByteString::decode(stream, decoding_limits)?;
-        let server_endpoints: Option<Vec<EndpointDescription>> = read_array(stream, decoding_limits)?;
-        let server_software_certificates: Option<Vec<SignedSoftwareCertificate>> = read_array(stream, decoding_limits)?;
-        let server_signature = SignatureData::decode(stream, decoding_limits)?;
-        let max_request_message_size = u32::decode(stream, decoding_limits)?;
+        let response_header = decode_field("CreateSessionResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let session_id = decode_field("CreateSessionResponse", "session_id", NodeId::decode(stream, decoding_limits))?;
+        let authentication_token = decode_field("CreateSessionResponse", "authentication_token", NodeId::decode(stream, decoding_limits))?;
+        let revised_session_timeout = decode_field("CreateSessionResponse", "revised_session_timeout", f64::decode(stream, decoding_limits))?;
+        let server_nonce = decode_field("CreateSessionResponse", "server_nonce", ByteString::decode(stream, decoding_limits))?;
+        let server_certificate = decode_field("CreateSessionResponse", "server_certificate", ByteString::decode(stream, decoding_limits))?;
+        let server_endpoints: Option<Vec<EndpointDescription>> = decode_field("CreateSessionResponse", "server_endpoints", read_array(stream, decoding_limits))?;
+        let server_software_certificates: Option<Vec<SignedSoftwareCertificate>> = decode_field("CreateSessionResponse", "server_software_certificates", read_array(stream, decoding_limits))?;
+        let server_signature = decode_field("CreateSessionResponse", "server_signature", SignatureData::decode(stream, decoding_limits))?;
+        let max_request_message_size = decode_field("CreateSessionResponse", "max_request_message_size", u32::decode(stream, decoding_limits))?;
         Ok(CreateSessionResponse {
             response_header,
             session_id,
@@ -62,13 +62,13 @@ impl BinaryEncoder<CreateSubscriptionRequest> for CreateSubscriptionRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let requested_publishing_interval = f64::decode(stream, decoding_limits)?;
-        let requested_lifetime_count = u32::decode(stream, decoding_limits)?;
-        let requested_max_keep_alive_count = u32::decode(stream, decoding_limits)?;
-        let max_notifications_per_publish = u32::decode(stream, decoding_limits)?;
-        let publishing_enabled = bool::decode(stream, decoding_limits)?;
-        let priority = u8::decode(stream, decoding_limits)?;
+        let request_header = decode_field("CreateSubscriptionRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let requested_publishing_interval = decode_field("CreateSubscriptionRequest", "requested_publishing_interval", f64::decode(stream, decoding_limits))?;
+        let requested_lifetime_count = decode_field("CreateSubscriptionRequest", "requested_lifetime_count", u32::decode(stream, decoding_limits))?;
+        let requested_max_keep_alive_count = decode_field("CreateSubscriptionRequest", "requested_max_keep_alive_count", u32::decode(stream, decoding_limits))?;
+        let max_notifications_per_publish = decode_field("CreateSubscriptionRequest", "max_notifications_per_publish", u32::decode(stream, decoding_limits))?;
+        let publishing_enabled = decode_field("CreateSubscriptionRequest", "publishing_enabled", bool::decode(stream, decoding_limits))?;
+        let priority = decode_field("CreateSubscriptionRequest", "priority", u8::decode(stream, decoding_limits))?;
         Ok(CreateSubscriptionRequest {
             request_header,
             requested_publishing_interval,
@@ -56,11 +56,11 @@ impl BinaryEncoder<CreateSubscriptionResponse> for CreateSubscriptionResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let revised_publishing_interval = f64::decode(stream, decoding_limits)?;
-        let revised_lifetime_count = u32::decode(stream, decoding_limits)?;
-        let revised_max_keep_alive_count = u32::decode(stream, decoding_limits)?;
+        let response_header = decode_field("CreateSubscriptionResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("CreateSubscriptionResponse", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let revised_publishing_interval = decode_field("CreateSubscriptionResponse", "revised_publishing_interval", f64::decode(stream, decoding_limits))?;
+        let revised_lifetime_count = decode_field("CreateSubscriptionResponse", "revised_lifetime_count", u32::decode(stream, decoding_limits))?;
+        let revised_max_keep_alive_count = decode_field("CreateSubscriptionResponse", "revised_max_keep_alive_count", u32::decode(stream, decoding_limits))?;
         Ok(CreateSubscriptionResponse {
             response_header,
             subscription_id,
@@ -42,9 +42,9 @@ impl BinaryEncoder<DataChangeFilter> for DataChangeFilter {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let trigger = DataChangeTrigger::decode(stream, decoding_limits)?;
-        let deadband_type = u32::decode(stream, decoding_limits)?;
-        let deadband_value = f64::decode(stream, decoding_limits)?;
+        let trigger = decode_field("DataChangeFilter", "trigger", DataChangeTrigger::decode(stream, decoding_limits))?;
+        let deadband_type = decode_field("DataChangeFilter", "deadband_type", u32::decode(stream, decoding_limits))?;
+        let deadband_value = decode_field("DataChangeFilter", "deadband_value", f64::decode(stream, decoding_limits))?;
         Ok(DataChangeFilter {
             trigger,
             deadband_type,
@@ -40,8 +40,8 @@ impl BinaryEncoder<DataChangeNotification> for DataChangeNotification {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let monitored_items: Option<Vec<MonitoredItemNotification>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let monitored_items: Option<Vec<MonitoredItemNotification>> = decode_field("DataChangeNotification", "monitored_items", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("DataChangeNotification", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(DataChangeNotification {
             monitored_items,
             diagnostic_infos,
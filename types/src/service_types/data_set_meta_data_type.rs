@@ -67,15 +67,15 @@ impl BinaryEncoder<DataSetMetaDataType> for DataSetMetaDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let namespaces: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let structure_data_types: Option<Vec<StructureDescription>> = read_array(stream, decoding_limits)?;
-        let enum_data_types: Option<Vec<EnumDescription>> = read_array(stream, decoding_limits)?;
-        let simple_data_types: Option<Vec<SimpleTypeDescription>> = read_array(stream, decoding_limits)?;
-        let name = UAString::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let fields: Option<Vec<FieldMetaData>> = read_array(stream, decoding_limits)?;
-        let data_set_class_id = Guid::decode(stream, decoding_limits)?;
-        let configuration_version = ConfigurationVersionDataType::decode(stream, decoding_limits)?;
+        let namespaces: Option<Vec<UAString>> = decode_field("DataSetMetaDataType", "namespaces", read_array(stream, decoding_limits))?;
+        let structure_data_types: Option<Vec<StructureDescription>> = decode_field("DataSetMetaDataType", "structure_data_types", read_array(stream, decoding_limits))?;
+        let enum_data_types: Option<Vec<EnumDescription>> = decode_field("DataSetMetaDataType", "enum_data_types", read_array(stream, decoding_limits))?;
+        let simple_data_types: Option<Vec<SimpleTypeDescription>> = decode_field("DataSetMetaDataType", "simple_data_types", read_array(stream, decoding_limits))?;
+        let name = decode_field("DataSetMetaDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let description = decode_field("DataSetMetaDataType", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let fields: Option<Vec<FieldMetaData>> = decode_field("DataSetMetaDataType", "fields", read_array(stream, decoding_limits))?;
+        let data_set_class_id = decode_field("DataSetMetaDataType", "data_set_class_id", Guid::decode(stream, decoding_limits))?;
+        let configuration_version = decode_field("DataSetMetaDataType", "configuration_version", ConfigurationVersionDataType::decode(stream, decoding_limits))?;
         Ok(DataSetMetaDataType {
             namespaces,
             structure_data_types,
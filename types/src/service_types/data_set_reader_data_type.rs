@@ -99,23 +99,23 @@ impl BinaryEncoder<DataSetReaderDataType> for DataSetReaderDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
-        let publisher_id = Variant::decode(stream, decoding_limits)?;
-        let writer_group_id = u16::decode(stream, decoding_limits)?;
-        let data_set_writer_id = u16::decode(stream, decoding_limits)?;
-        let data_set_meta_data = DataSetMetaDataType::decode(stream, decoding_limits)?;
-        let data_set_field_content_mask = DataSetFieldContentMask::decode(stream, decoding_limits)?;
-        let message_receive_timeout = f64::decode(stream, decoding_limits)?;
-        let key_frame_count = u32::decode(stream, decoding_limits)?;
-        let header_layout_uri = UAString::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_group_id = UAString::decode(stream, decoding_limits)?;
-        let security_key_services: Option<Vec<EndpointDescription>> = read_array(stream, decoding_limits)?;
-        let data_set_reader_properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let transport_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let message_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let subscribed_data_set = ExtensionObject::decode(stream, decoding_limits)?;
+        let name = decode_field("DataSetReaderDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let enabled = decode_field("DataSetReaderDataType", "enabled", bool::decode(stream, decoding_limits))?;
+        let publisher_id = decode_field("DataSetReaderDataType", "publisher_id", Variant::decode(stream, decoding_limits))?;
+        let writer_group_id = decode_field("DataSetReaderDataType", "writer_group_id", u16::decode(stream, decoding_limits))?;
+        let data_set_writer_id = decode_field("DataSetReaderDataType", "data_set_writer_id", u16::decode(stream, decoding_limits))?;
+        let data_set_meta_data = decode_field("DataSetReaderDataType", "data_set_meta_data", DataSetMetaDataType::decode(stream, decoding_limits))?;
+        let data_set_field_content_mask = decode_field("DataSetReaderDataType", "data_set_field_content_mask", DataSetFieldContentMask::decode(stream, decoding_limits))?;
+        let message_receive_timeout = decode_field("DataSetReaderDataType", "message_receive_timeout", f64::decode(stream, decoding_limits))?;
+        let key_frame_count = decode_field("DataSetReaderDataType", "key_frame_count", u32::decode(stream, decoding_limits))?;
+        let header_layout_uri = decode_field("DataSetReaderDataType", "header_layout_uri", UAString::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("DataSetReaderDataType", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_group_id = decode_field("DataSetReaderDataType", "security_group_id", UAString::decode(stream, decoding_limits))?;
+        let security_key_services: Option<Vec<EndpointDescription>> = decode_field("DataSetReaderDataType", "security_key_services", read_array(stream, decoding_limits))?;
+        let data_set_reader_properties: Option<Vec<KeyValuePair>> = decode_field("DataSetReaderDataType", "data_set_reader_properties", read_array(stream, decoding_limits))?;
+        let transport_settings = decode_field("DataSetReaderDataType", "transport_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let message_settings = decode_field("DataSetReaderDataType", "message_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let subscribed_data_set = decode_field("DataSetReaderDataType", "subscribed_data_set", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(DataSetReaderDataType {
             name,
             enabled,
@@ -71,15 +71,15 @@ impl BinaryEncoder<DataSetWriterDataType> for DataSetWriterDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
-        let data_set_writer_id = u16::decode(stream, decoding_limits)?;
-        let data_set_field_content_mask = DataSetFieldContentMask::decode(stream, decoding_limits)?;
-        let key_frame_count = u32::decode(stream, decoding_limits)?;
-        let data_set_name = UAString::decode(stream, decoding_limits)?;
-        let data_set_writer_properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let transport_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let message_settings = ExtensionObject::decode(stream, decoding_limits)?;
+        let name = decode_field("DataSetWriterDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let enabled = decode_field("DataSetWriterDataType", "enabled", bool::decode(stream, decoding_limits))?;
+        let data_set_writer_id = decode_field("DataSetWriterDataType", "data_set_writer_id", u16::decode(stream, decoding_limits))?;
+        let data_set_field_content_mask = decode_field("DataSetWriterDataType", "data_set_field_content_mask", DataSetFieldContentMask::decode(stream, decoding_limits))?;
+        let key_frame_count = decode_field("DataSetWriterDataType", "key_frame_count", u32::decode(stream, decoding_limits))?;
+        let data_set_name = decode_field("DataSetWriterDataType", "data_set_name", UAString::decode(stream, decoding_limits))?;
+        let data_set_writer_properties: Option<Vec<KeyValuePair>> = decode_field("DataSetWriterDataType", "data_set_writer_properties", read_array(stream, decoding_limits))?;
+        let transport_settings = decode_field("DataSetWriterDataType", "transport_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let message_settings = decode_field("DataSetWriterDataType", "message_settings", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(DataSetWriterDataType {
             name,
             enabled,
@@ -51,12 +51,12 @@ impl BinaryEncoder<DataTypeAttributes> for DataTypeAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let is_abstract = bool::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("DataTypeAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("DataTypeAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("DataTypeAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("DataTypeAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("DataTypeAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let is_abstract = decode_field("DataTypeAttributes", "is_abstract", bool::decode(stream, decoding_limits))?;
         Ok(DataTypeAttributes {
             specified_attributes,
             display_name,
@@ -48,8 +48,8 @@ impl BinaryEncoder<DataTypeDescription> for DataTypeDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_type_id = NodeId::decode(stream, decoding_limits)?;
-        let name = QualifiedName::decode(stream, decoding_limits)?;
+        let data_type_id = decode_field("DataTypeDescription", "data_type_id", NodeId::decode(stream, decoding_limits))?;
+        let name = decode_field("DataTypeDescription", "name", QualifiedName::decode(stream, decoding_limits))?;
         Ok(DataTypeDescription {
             data_type_id,
             name,
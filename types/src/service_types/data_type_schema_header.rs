@@ -56,10 +56,10 @@ impl BinaryEncoder<DataTypeSchemaHeader> for DataTypeSchemaHeader {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let namespaces: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let structure_data_types: Option<Vec<StructureDescription>> = read_array(stream, decoding_limits)?;
-        let enum_data_types: Option<Vec<EnumDescription>> = read_array(stream, decoding_limits)?;
-        let simple_data_types: Option<Vec<SimpleTypeDescription>> = read_array(stream, decoding_limits)?;
+        let namespaces: Option<Vec<UAString>> = decode_field("DataTypeSchemaHeader", "namespaces", read_array(stream, decoding_limits))?;
+        let structure_data_types: Option<Vec<StructureDescription>> = decode_field("DataTypeSchemaHeader", "structure_data_types", read_array(stream, decoding_limits))?;
+        let enum_data_types: Option<Vec<EnumDescription>> = decode_field("DataTypeSchemaHeader", "enum_data_types", read_array(stream, decoding_limits))?;
+        let simple_data_types: Option<Vec<SimpleTypeDescription>> = decode_field("DataTypeSchemaHeader", "simple_data_types", read_array(stream, decoding_limits))?;
         Ok(DataTypeSchemaHeader {
             namespaces,
             structure_data_types,
@@ -36,7 +36,7 @@ impl BinaryEncoder<DatagramConnectionTransportDataType> for DatagramConnectionTr
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let discovery_address = ExtensionObject::decode(stream, decoding_limits)?;
+        let discovery_address = decode_field("DatagramConnectionTransportDataType", "discovery_address", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(DatagramConnectionTransportDataType {
             discovery_address,
         })
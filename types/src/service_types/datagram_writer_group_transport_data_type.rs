@@ -38,8 +38,8 @@ impl BinaryEncoder<DatagramWriterGroupTransportDataType> for DatagramWriterGroup
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let message_repeat_count = u8::decode(stream, decoding_limits)?;
-        let message_repeat_delay = f64::decode(stream, decoding_limits)?;
+        let message_repeat_count = decode_field("DatagramWriterGroupTransportDataType", "message_repeat_count", u8::decode(stream, decoding_limits))?;
+        let message_repeat_delay = decode_field("DatagramWriterGroupTransportDataType", "message_repeat_delay", f64::decode(stream, decoding_limits))?;
         Ok(DatagramWriterGroupTransportDataType {
             message_repeat_count,
             message_repeat_delay,
@@ -47,8 +47,8 @@ impl BinaryEncoder<DecimalDataType> for DecimalDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let scale = i16::decode(stream, decoding_limits)?;
-        let value = ByteString::decode(stream, decoding_limits)?;
+        let scale = decode_field("DecimalDataType", "scale", i16::decode(stream, decoding_limits))?;
+        let value = decode_field("DecimalDataType", "value", ByteString::decode(stream, decoding_limits))?;
         Ok(DecimalDataType {
             scale,
             value,
@@ -40,8 +40,8 @@ impl BinaryEncoder<DeleteAtTimeDetails> for DeleteAtTimeDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let req_times: Option<Vec<DateTime>> = read_array(stream, decoding_limits)?;
+        let node_id = decode_field("DeleteAtTimeDetails", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let req_times: Option<Vec<DateTime>> = decode_field("DeleteAtTimeDetails", "req_times", read_array(stream, decoding_limits))?;
         Ok(DeleteAtTimeDetails {
             node_id,
             req_times,
@@ -50,9 +50,9 @@ impl BinaryEncoder<DeleteMonitoredItemsRequest> for DeleteMonitoredItemsRequest
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let monitored_item_ids: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("DeleteMonitoredItemsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("DeleteMonitoredItemsRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let monitored_item_ids: Option<Vec<u32>> = decode_field("DeleteMonitoredItemsRequest", "monitored_item_ids", read_array(stream, decoding_limits))?;
         Ok(DeleteMonitoredItemsRequest {
             request_header,
             subscription_id,
@@ -52,9 +52,9 @@ impl BinaryEncoder<DeleteMonitoredItemsResponse> for DeleteMonitoredItemsRespons
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("DeleteMonitoredItemsResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<StatusCode>> = decode_field("DeleteMonitoredItemsResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("DeleteMonitoredItemsResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(DeleteMonitoredItemsResponse {
             response_header,
             results,
@@ -47,8 +47,8 @@ impl BinaryEncoder<DeleteNodesItem> for DeleteNodesItem {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let delete_target_references = bool::decode(stream, decoding_limits)?;
+        let node_id = decode_field("DeleteNodesItem", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let delete_target_references = decode_field("DeleteNodesItem", "delete_target_references", bool::decode(stream, decoding_limits))?;
         Ok(DeleteNodesItem {
             node_id,
             delete_target_references,
@@ -48,8 +48,8 @@ impl BinaryEncoder<DeleteNodesRequest> for DeleteNodesRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let nodes_to_delete: Option<Vec<DeleteNodesItem>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("DeleteNodesRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let nodes_to_delete: Option<Vec<DeleteNodesItem>> = decode_field("DeleteNodesRequest", "nodes_to_delete", read_array(stream, decoding_limits))?;
         Ok(DeleteNodesRequest {
             request_header,
             nodes_to_delete,
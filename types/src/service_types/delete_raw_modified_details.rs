@@ -46,10 +46,10 @@ impl BinaryEncoder<DeleteRawModifiedDetails> for DeleteRawModifiedDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let is_delete_modified = bool::decode(stream, decoding_limits)?;
-        let start_time = DateTime::decode(stream, decoding_limits)?;
-        let end_time = DateTime::decode(stream, decoding_limits)?;
+        let node_id = decode_field("DeleteRawModifiedDetails", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let is_delete_modified = decode_field("DeleteRawModifiedDetails", "is_delete_modified", bool::decode(stream, decoding_limits))?;
+        let start_time = decode_field("DeleteRawModifiedDetails", "start_time", DateTime::decode(stream, decoding_limits))?;
+        let end_time = decode_field("DeleteRawModifiedDetails", "end_time", DateTime::decode(stream, decoding_limits))?;
         Ok(DeleteRawModifiedDetails {
             node_id,
             is_delete_modified,
@@ -57,11 +57,11 @@ impl BinaryEncoder<DeleteReferencesItem> for DeleteReferencesItem {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let source_node_id = NodeId::decode(stream, decoding_limits)?;
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let is_forward = bool::decode(stream, decoding_limits)?;
-        let target_node_id = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let delete_bidirectional = bool::decode(stream, decoding_limits)?;
+        let source_node_id = decode_field("DeleteReferencesItem", "source_node_id", NodeId::decode(stream, decoding_limits))?;
+        let reference_type_id = decode_field("DeleteReferencesItem", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let is_forward = decode_field("DeleteReferencesItem", "is_forward", bool::decode(stream, decoding_limits))?;
+        let target_node_id = decode_field("DeleteReferencesItem", "target_node_id", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let delete_bidirectional = decode_field("DeleteReferencesItem", "delete_bidirectional", bool::decode(stream, decoding_limits))?;
         Ok(DeleteReferencesItem {
             source_node_id,
             reference_type_id,
@@ -48,8 +48,8 @@ impl BinaryEncoder<DeleteReferencesRequest> for DeleteReferencesRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let references_to_delete: Option<Vec<DeleteReferencesItem>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("DeleteReferencesRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let references_to_delete: Option<Vec<DeleteReferencesItem>> = decode_field("DeleteReferencesRequest", "references_to_delete", read_array(stream, decoding_limits))?;
         Ok(DeleteReferencesRequest {
             request_header,
             references_to_delete,
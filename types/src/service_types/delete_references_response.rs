@@ -52,9 +52,9 @@ impl BinaryEncoder<DeleteReferencesResponse> for DeleteReferencesResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("DeleteReferencesResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<StatusCode>> = decode_field("DeleteReferencesResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("DeleteReferencesResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(DeleteReferencesResponse {
             response_header,
             results,
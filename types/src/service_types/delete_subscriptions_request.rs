@@ -47,8 +47,8 @@ impl BinaryEncoder<DeleteSubscriptionsRequest> for DeleteSubscriptionsRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_ids: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("DeleteSubscriptionsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_ids: Option<Vec<u32>> = decode_field("DeleteSubscriptionsRequest", "subscription_ids", read_array(stream, decoding_limits))?;
         Ok(DeleteSubscriptionsRequest {
             request_header,
             subscription_ids,
@@ -46,8 +46,8 @@ impl BinaryEncoder<DoubleComplexNumberType> for DoubleComplexNumberType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let real = f64::decode(stream, decoding_limits)?;
-        let imaginary = f64::decode(stream, decoding_limits)?;
+        let real = decode_field("DoubleComplexNumberType", "real", f64::decode(stream, decoding_limits))?;
+        let imaginary = decode_field("DoubleComplexNumberType", "imaginary", f64::decode(stream, decoding_limits))?;
         Ok(DoubleComplexNumberType {
             real,
             imaginary,
@@ -35,7 +35,7 @@ impl BinaryEncoder<ElementOperand> for ElementOperand {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let index = u32::decode(stream, decoding_limits)?;
+        let index = decode_field("ElementOperand", "index", u32::decode(stream, decoding_limits))?;
         Ok(ElementOperand {
             index,
         })
@@ -67,15 +67,15 @@ impl BinaryEncoder<EndpointConfiguration> for EndpointConfiguration {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let operation_timeout = i32::decode(stream, decoding_limits)?;
-        let use_binary_encoding = bool::decode(stream, decoding_limits)?;
-        let max_string_length = i32::decode(stream, decoding_limits)?;
-        let max_byte_string_length = i32::decode(stream, decoding_limits)?;
-        let max_array_length = i32::decode(stream, decoding_limits)?;
-        let max_message_size = i32::decode(stream, decoding_limits)?;
-        let max_buffer_size = i32::decode(stream, decoding_limits)?;
-        let channel_lifetime = i32::decode(stream, decoding_limits)?;
-        let security_token_lifetime = i32::decode(stream, decoding_limits)?;
+        let operation_timeout = decode_field("EndpointConfiguration", "operation_timeout", i32::decode(stream, decoding_limits))?;
+        let use_binary_encoding = decode_field("EndpointConfiguration", "use_binary_encoding", bool::decode(stream, decoding_limits))?;
+        let max_string_length = decode_field("EndpointConfiguration", "max_string_length", i32::decode(stream, decoding_limits))?;
+        let max_byte_string_length = decode_field("EndpointConfiguration", "max_byte_string_length", i32::decode(stream, decoding_limits))?;
+        let max_array_length = decode_field("EndpointConfiguration", "max_array_length", i32::decode(stream, decoding_limits))?;
+        let max_message_size = decode_field("EndpointConfiguration", "max_message_size", i32::decode(stream, decoding_limits))?;
+        let max_buffer_size = decode_field("EndpointConfiguration", "max_buffer_size", i32::decode(stream, decoding_limits))?;
+        let channel_lifetime = decode_field("EndpointConfiguration", "channel_lifetime", i32::decode(stream, decoding_limits))?;
+        let security_token_lifetime = decode_field("EndpointConfiguration", "security_token_lifetime", i32::decode(stream, decoding_limits))?;
         Ok(EndpointConfiguration {
             operation_timeout,
             use_binary_encoding,
@@ -69,14 +69,14 @@ impl BinaryEncoder<EndpointDescription> for EndpointDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let server = ApplicationDescription::decode(stream, decoding_limits)?;
-        let server_certificate = ByteString::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_policy_uri = UAString::decode(stream, decoding_limits)?;
-        let user_identity_tokens: Option<Vec<UserTokenPolicy>> = read_array(stream, decoding_limits)?;
-        let transport_profile_uri = UAString::decode(stream, decoding_limits)?;
-        let security_level = u8::decode(stream, decoding_limits)?;
+        let endpoint_url = decode_field("EndpointDescription", "endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let server = decode_field("EndpointDescription", "server", ApplicationDescription::decode(stream, decoding_limits))?;
+        let server_certificate = decode_field("EndpointDescription", "server_certificate", ByteString::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("EndpointDescription", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_policy_uri = decode_field("EndpointDescription", "security_policy_uri", UAString::decode(stream, decoding_limits))?;
+        let user_identity_tokens: Option<Vec<UserTokenPolicy>> = decode_field("EndpointDescription", "user_identity_tokens", read_array(stream, decoding_limits))?;
+        let transport_profile_uri = decode_field("EndpointDescription", "transport_profile_uri", UAString::decode(stream, decoding_limits))?;
+        let security_level = decode_field("EndpointDescription", "security_level", u8::decode(stream, decoding_limits))?;
         Ok(EndpointDescription {
             endpoint_url,
             server,
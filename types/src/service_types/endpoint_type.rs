@@ -54,10 +54,10 @@ impl BinaryEncoder<EndpointType> for EndpointType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_policy_uri = UAString::decode(stream, decoding_limits)?;
-        let transport_profile_uri = UAString::decode(stream, decoding_limits)?;
+        let endpoint_url = decode_field("EndpointType", "endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("EndpointType", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_policy_uri = decode_field("EndpointType", "security_policy_uri", UAString::decode(stream, decoding_limits))?;
+        let transport_profile_uri = decode_field("EndpointType", "transport_profile_uri", UAString::decode(stream, decoding_limits))?;
         Ok(EndpointType {
             endpoint_url,
             security_mode,
@@ -44,7 +44,7 @@ impl BinaryEncoder<EndpointUrlListDataType> for EndpointUrlListDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let endpoint_url_list: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
+        let endpoint_url_list: Option<Vec<UAString>> = decode_field("EndpointUrlListDataType", "endpoint_url_list", read_array(stream, decoding_limits))?;
         Ok(EndpointUrlListDataType {
             endpoint_url_list,
         })
@@ -36,7 +36,7 @@ impl BinaryEncoder<EnumDefinition> for EnumDefinition {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let fields: Option<Vec<EnumField>> = read_array(stream, decoding_limits)?;
+        let fields: Option<Vec<EnumField>> = decode_field("EnumDefinition", "fields", read_array(stream, decoding_limits))?;
         Ok(EnumDefinition {
             fields,
         })
@@ -47,10 +47,10 @@ impl BinaryEncoder<EnumDescription> for EnumDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_type_id = NodeId::decode(stream, decoding_limits)?;
-        let name = QualifiedName::decode(stream, decoding_limits)?;
-        let enum_definition = EnumDefinition::decode(stream, decoding_limits)?;
-        let built_in_type = u8::decode(stream, decoding_limits)?;
+        let data_type_id = decode_field("EnumDescription", "data_type_id", NodeId::decode(stream, decoding_limits))?;
+        let name = decode_field("EnumDescription", "name", QualifiedName::decode(stream, decoding_limits))?;
+        let enum_definition = decode_field("EnumDescription", "enum_definition", EnumDefinition::decode(stream, decoding_limits))?;
+        let built_in_type = decode_field("EnumDescription", "built_in_type", u8::decode(stream, decoding_limits))?;
         Ok(EnumDescription {
             data_type_id,
             name,
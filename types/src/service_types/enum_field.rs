@@ -46,10 +46,10 @@ impl BinaryEncoder<EnumField> for EnumField {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let value = i64::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let name = UAString::decode(stream, decoding_limits)?;
+        let value = decode_field("EnumField", "value", i64::decode(stream, decoding_limits))?;
+        let display_name = decode_field("EnumField", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("EnumField", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let name = decode_field("EnumField", "name", UAString::decode(stream, decoding_limits))?;
         Ok(EnumField {
             value,
             display_name,
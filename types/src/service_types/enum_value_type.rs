@@ -50,9 +50,9 @@ impl BinaryEncoder<EnumValueType> for EnumValueType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let value = i64::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
+        let value = decode_field("EnumValueType", "value", i64::decode(stream, decoding_limits))?;
+        let display_name = decode_field("EnumValueType", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("EnumValueType", "description", LocalizedText::decode(stream, decoding_limits))?;
         Ok(EnumValueType {
             value,
             display_name,
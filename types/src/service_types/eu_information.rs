@@ -54,10 +54,10 @@ impl BinaryEncoder<EUInformation> for EUInformation {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let namespace_uri = UAString::decode(stream, decoding_limits)?;
-        let unit_id = i32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
+        let namespace_uri = decode_field("EUInformation", "namespace_uri", UAString::decode(stream, decoding_limits))?;
+        let unit_id = decode_field("EUInformation", "unit_id", i32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("EUInformation", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("EUInformation", "description", LocalizedText::decode(stream, decoding_limits))?;
         Ok(EUInformation {
             namespace_uri,
             unit_id,
@@ -47,8 +47,8 @@ impl BinaryEncoder<EventFieldList> for EventFieldList {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let client_handle = u32::decode(stream, decoding_limits)?;
-        let event_fields: Option<Vec<Variant>> = read_array(stream, decoding_limits)?;
+        let client_handle = decode_field("EventFieldList", "client_handle", u32::decode(stream, decoding_limits))?;
+        let event_fields: Option<Vec<Variant>> = decode_field("EventFieldList", "event_fields", read_array(stream, decoding_limits))?;
         Ok(EventFieldList {
             client_handle,
             event_fields,
@@ -44,9 +44,9 @@ impl BinaryEncoder<EventFilterResult> for EventFilterResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let select_clause_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let select_clause_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
-        let where_clause_result = ContentFilterResult::decode(stream, decoding_limits)?;
+        let select_clause_results: Option<Vec<StatusCode>> = decode_field("EventFilterResult", "select_clause_results", read_array(stream, decoding_limits))?;
+        let select_clause_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("EventFilterResult", "select_clause_diagnostic_infos", read_array(stream, decoding_limits))?;
+        let where_clause_result = decode_field("EventFilterResult", "where_clause_result", ContentFilterResult::decode(stream, decoding_limits))?;
         Ok(EventFilterResult {
             select_clause_results,
             select_clause_diagnostic_infos,
@@ -36,7 +36,7 @@ impl BinaryEncoder<EventNotificationList> for EventNotificationList {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let events: Option<Vec<EventFieldList>> = read_array(stream, decoding_limits)?;
+        let events: Option<Vec<EventFieldList>> = decode_field("EventNotificationList", "events", read_array(stream, decoding_limits))?;
         Ok(EventNotificationList {
             events,
         })
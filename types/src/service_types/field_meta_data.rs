@@ -76,16 +76,16 @@ impl BinaryEncoder<FieldMetaData> for FieldMetaData {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let field_flags = DataSetFieldFlags::decode(stream, decoding_limits)?;
-        let built_in_type = u8::decode(stream, decoding_limits)?;
-        let data_type = NodeId::decode(stream, decoding_limits)?;
-        let value_rank = i32::decode(stream, decoding_limits)?;
-        let array_dimensions: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let max_string_length = u32::decode(stream, decoding_limits)?;
-        let data_set_field_id = Guid::decode(stream, decoding_limits)?;
-        let properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
+        let name = decode_field("FieldMetaData", "name", UAString::decode(stream, decoding_limits))?;
+        let description = decode_field("FieldMetaData", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let field_flags = decode_field("FieldMetaData", "field_flags", DataSetFieldFlags::decode(stream, decoding_limits))?;
+        let built_in_type = decode_field("FieldMetaData", "built_in_type", u8::decode(stream, decoding_limits))?;
+        let data_type = decode_field("FieldMetaData", "data_type", NodeId::decode(stream, decoding_limits))?;
+        let value_rank = decode_field("FieldMetaData", "value_rank", i32::decode(stream, decoding_limits))?;
+        let array_dimensions: Option<Vec<u32>> = decode_field("FieldMetaData", "array_dimensions", read_array(stream, decoding_limits))?;
+        let max_string_length = decode_field("FieldMetaData", "max_string_length", u32::decode(stream, decoding_limits))?;
+        let data_set_field_id = decode_field("FieldMetaData", "data_set_field_id", Guid::decode(stream, decoding_limits))?;
+        let properties: Option<Vec<KeyValuePair>> = decode_field("FieldMetaData", "properties", read_array(stream, decoding_limits))?;
         Ok(FieldMetaData {
             name,
             description,
@@ -66,13 +66,13 @@ impl BinaryEncoder<FieldTargetDataType> for FieldTargetDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_set_field_id = Guid::decode(stream, decoding_limits)?;
-        let receiver_index_range = UAString::decode(stream, decoding_limits)?;
-        let target_node_id = NodeId::decode(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let write_index_range = UAString::decode(stream, decoding_limits)?;
-        let override_value_handling = OverrideValueHandling::decode(stream, decoding_limits)?;
-        let override_value = Variant::decode(stream, decoding_limits)?;
+        let data_set_field_id = decode_field("FieldTargetDataType", "data_set_field_id", Guid::decode(stream, decoding_limits))?;
+        let receiver_index_range = decode_field("FieldTargetDataType", "receiver_index_range", UAString::decode(stream, decoding_limits))?;
+        let target_node_id = decode_field("FieldTargetDataType", "target_node_id", NodeId::decode(stream, decoding_limits))?;
+        let attribute_id = decode_field("FieldTargetDataType", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let write_index_range = decode_field("FieldTargetDataType", "write_index_range", UAString::decode(stream, decoding_limits))?;
+        let override_value_handling = decode_field("FieldTargetDataType", "override_value_handling", OverrideValueHandling::decode(stream, decoding_limits))?;
+        let override_value = decode_field("FieldTargetDataType", "override_value", Variant::decode(stream, decoding_limits))?;
         Ok(FieldTargetDataType {
             data_set_field_id,
             receiver_index_range,
@@ -54,10 +54,10 @@ impl BinaryEncoder<FindServersOnNetworkRequest> for FindServersOnNetworkRequest
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let starting_record_id = u32::decode(stream, decoding_limits)?;
-        let max_records_to_return = u32::decode(stream, decoding_limits)?;
-        let server_capability_filter: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("FindServersOnNetworkRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let starting_record_id = decode_field("FindServersOnNetworkRequest", "starting_record_id", u32::decode(stream, decoding_limits))?;
+        let max_records_to_return = decode_field("FindServersOnNetworkRequest", "max_records_to_return", u32::decode(stream, decoding_limits))?;
+        let server_capability_filter: Option<Vec<UAString>> = decode_field("FindServersOnNetworkRequest", "server_capability_filter", read_array(stream, decoding_limits))?;
         Ok(FindServersOnNetworkRequest {
             request_header,
             starting_record_id,
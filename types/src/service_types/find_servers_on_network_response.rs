@@ -52,9 +52,9 @@ impl BinaryEncoder<FindServersOnNetworkResponse> for FindServersOnNetworkRespons
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let last_counter_reset_time = DateTime::decode(stream, decoding_limits)?;
-        let servers: Option<Vec<ServerOnNetwork>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("FindServersOnNetworkResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let last_counter_reset_time = decode_field("FindServersOnNetworkResponse", "last_counter_reset_time", DateTime::decode(stream, decoding_limits))?;
+        let servers: Option<Vec<ServerOnNetwork>> = decode_field("FindServersOnNetworkResponse", "servers", read_array(stream, decoding_limits))?;
         Ok(FindServersOnNetworkResponse {
             response_header,
             last_counter_reset_time,
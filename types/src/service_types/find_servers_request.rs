@@ -54,10 +54,10 @@ impl BinaryEncoder<FindServersRequest> for FindServersRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let locale_ids: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let server_uris: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("FindServersRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let endpoint_url = decode_field("FindServersRequest", "endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let locale_ids: Option<Vec<UAString>> = decode_field("FindServersRequest", "locale_ids", read_array(stream, decoding_limits))?;
+        let server_uris: Option<Vec<UAString>> = decode_field("FindServersRequest", "server_uris", read_array(stream, decoding_limits))?;
         Ok(FindServersRequest {
             request_header,
             endpoint_url,
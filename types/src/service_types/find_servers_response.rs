@@ -48,8 +48,8 @@ impl BinaryEncoder<FindServersResponse> for FindServersResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let servers: Option<Vec<ApplicationDescription>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("FindServersResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let servers: Option<Vec<ApplicationDescription>> = decode_field("FindServersResponse", "servers", read_array(stream, decoding_limits))?;
         Ok(FindServersResponse {
             response_header,
             servers,
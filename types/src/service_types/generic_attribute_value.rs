@@ -47,8 +47,8 @@ impl BinaryEncoder<GenericAttributeValue> for GenericAttributeValue {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let value = Variant::decode(stream, decoding_limits)?;
+        let attribute_id = decode_field("GenericAttributeValue", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let value = decode_field("GenericAttributeValue", "value", Variant::decode(stream, decoding_limits))?;
         Ok(GenericAttributeValue {
             attribute_id,
             value,
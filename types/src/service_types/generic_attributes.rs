@@ -52,12 +52,12 @@ impl BinaryEncoder<GenericAttributes> for GenericAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let attribute_values: Option<Vec<GenericAttributeValue>> = read_array(stream, decoding_limits)?;
+        let specified_attributes = decode_field("GenericAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("GenericAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("GenericAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("GenericAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("GenericAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let attribute_values: Option<Vec<GenericAttributeValue>> = decode_field("GenericAttributes", "attribute_values", read_array(stream, decoding_limits))?;
         Ok(GenericAttributes {
             specified_attributes,
             display_name,
@@ -54,10 +54,10 @@ impl BinaryEncoder<GetEndpointsRequest> for GetEndpointsRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let locale_ids: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let profile_uris: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("GetEndpointsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let endpoint_url = decode_field("GetEndpointsRequest", "endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let locale_ids: Option<Vec<UAString>> = decode_field("GetEndpointsRequest", "locale_ids", read_array(stream, decoding_limits))?;
+        let profile_uris: Option<Vec<UAString>> = decode_field("GetEndpointsRequest", "profile_uris", read_array(stream, decoding_limits))?;
         Ok(GetEndpointsRequest {
             request_header,
             endpoint_url,
@@ -44,7 +44,7 @@ impl BinaryEncoder<HistoryData> for HistoryData {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
+        let data_values: Option<Vec<DataValue>> = decode_field("HistoryData", "data_values", read_array(stream, decoding_limits))?;
         Ok(HistoryData {
             data_values,
         })
@@ -44,7 +44,7 @@ impl BinaryEncoder<HistoryEvent> for HistoryEvent {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let events: Option<Vec<HistoryEventFieldList>> = read_array(stream, decoding_limits)?;
+        let events: Option<Vec<HistoryEventFieldList>> = decode_field("HistoryEvent", "events", read_array(stream, decoding_limits))?;
         Ok(HistoryEvent {
             events,
         })
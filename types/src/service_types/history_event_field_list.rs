@@ -44,7 +44,7 @@ impl BinaryEncoder<HistoryEventFieldList> for HistoryEventFieldList {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let event_fields: Option<Vec<Variant>> = read_array(stream, decoding_limits)?;
+        let event_fields: Option<Vec<Variant>> = decode_field("HistoryEventFieldList", "event_fields", read_array(stream, decoding_limits))?;
         Ok(HistoryEventFieldList {
             event_fields,
         })
@@ -40,8 +40,8 @@ impl BinaryEncoder<HistoryModifiedData> for HistoryModifiedData {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
-        let modification_infos: Option<Vec<ModificationInfo>> = read_array(stream, decoding_limits)?;
+        let data_values: Option<Vec<DataValue>> = decode_field("HistoryModifiedData", "data_values", read_array(stream, decoding_limits))?;
+        let modification_infos: Option<Vec<ModificationInfo>> = decode_field("HistoryModifiedData", "modification_infos", read_array(stream, decoding_limits))?;
         Ok(HistoryModifiedData {
             data_values,
             modification_infos,
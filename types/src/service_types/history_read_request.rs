@@ -59,11 +59,11 @@ impl BinaryEncoder<HistoryReadRequest> for HistoryReadRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let history_read_details = ExtensionObject::decode(stream, decoding_limits)?;
-        let timestamps_to_return = TimestampsToReturn::decode(stream, decoding_limits)?;
-        let release_continuation_points = bool::decode(stream, decoding_limits)?;
-        let nodes_to_read: Option<Vec<HistoryReadValueId>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("HistoryReadRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let history_read_details = decode_field("HistoryReadRequest", "history_read_details", ExtensionObject::decode(stream, decoding_limits))?;
+        let timestamps_to_return = decode_field("HistoryReadRequest", "timestamps_to_return", TimestampsToReturn::decode(stream, decoding_limits))?;
+        let release_continuation_points = decode_field("HistoryReadRequest", "release_continuation_points", bool::decode(stream, decoding_limits))?;
+        let nodes_to_read: Option<Vec<HistoryReadValueId>> = decode_field("HistoryReadRequest", "nodes_to_read", read_array(stream, decoding_limits))?;
         Ok(HistoryReadRequest {
             request_header,
             history_read_details,
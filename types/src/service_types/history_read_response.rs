@@ -52,9 +52,9 @@ impl BinaryEncoder<HistoryReadResponse> for HistoryReadResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<HistoryReadResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("HistoryReadResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<HistoryReadResult>> = decode_field("HistoryReadResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("HistoryReadResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(HistoryReadResponse {
             response_header,
             results,
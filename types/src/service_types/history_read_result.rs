@@ -52,9 +52,9 @@ impl BinaryEncoder<HistoryReadResult> for HistoryReadResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let continuation_point = ByteString::decode(stream, decoding_limits)?;
-        let history_data = ExtensionObject::decode(stream, decoding_limits)?;
+        let status_code = decode_field("HistoryReadResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let continuation_point = decode_field("HistoryReadResult", "continuation_point", ByteString::decode(stream, decoding_limits))?;
+        let history_data = decode_field("HistoryReadResult", "history_data", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(HistoryReadResult {
             status_code,
             continuation_point,
@@ -56,10 +56,10 @@ impl BinaryEncoder<HistoryReadValueId> for HistoryReadValueId {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
-        let data_encoding = QualifiedName::decode(stream, decoding_limits)?;
-        let continuation_point = ByteString::decode(stream, decoding_limits)?;
+        let node_id = decode_field("HistoryReadValueId", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let index_range = decode_field("HistoryReadValueId", "index_range", UAString::decode(stream, decoding_limits))?;
+        let data_encoding = decode_field("HistoryReadValueId", "data_encoding", QualifiedName::decode(stream, decoding_limits))?;
+        let continuation_point = decode_field("HistoryReadValueId", "continuation_point", ByteString::decode(stream, decoding_limits))?;
         Ok(HistoryReadValueId {
             node_id,
             index_range,
@@ -44,7 +44,7 @@ impl BinaryEncoder<HistoryUpdateDetails> for HistoryUpdateDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
+        let node_id = decode_field("HistoryUpdateDetails", "node_id", NodeId::decode(stream, decoding_limits))?;
         Ok(HistoryUpdateDetails {
             node_id,
         })
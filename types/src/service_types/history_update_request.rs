@@ -48,8 +48,8 @@ impl BinaryEncoder<HistoryUpdateRequest> for HistoryUpdateRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let history_update_details: Option<Vec<ExtensionObject>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("HistoryUpdateRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let history_update_details: Option<Vec<ExtensionObject>> = decode_field("HistoryUpdateRequest", "history_update_details", read_array(stream, decoding_limits))?;
         Ok(HistoryUpdateRequest {
             request_header,
             history_update_details,
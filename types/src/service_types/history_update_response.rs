@@ -52,9 +52,9 @@ impl BinaryEncoder<HistoryUpdateResponse> for HistoryUpdateResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<HistoryUpdateResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("HistoryUpdateResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<HistoryUpdateResult>> = decode_field("HistoryUpdateResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("HistoryUpdateResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(HistoryUpdateResponse {
             response_header,
             results,
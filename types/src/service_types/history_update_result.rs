@@ -51,9 +51,9 @@ impl BinaryEncoder<HistoryUpdateResult> for HistoryUpdateResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let operation_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let status_code = decode_field("HistoryUpdateResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let operation_results: Option<Vec<StatusCode>> = decode_field("HistoryUpdateResult", "operation_results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("HistoryUpdateResult", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(HistoryUpdateResult {
             status_code,
             operation_results,
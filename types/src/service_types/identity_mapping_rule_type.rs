@@ -48,8 +48,8 @@ impl BinaryEncoder<IdentityMappingRuleType> for IdentityMappingRuleType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let criteria_type = IdentityCriteriaType::decode(stream, decoding_limits)?;
-        let criteria = UAString::decode(stream, decoding_limits)?;
+        let criteria_type = decode_field("IdentityMappingRuleType", "criteria_type", IdentityCriteriaType::decode(stream, decoding_limits))?;
+        let criteria = decode_field("IdentityMappingRuleType", "criteria", UAString::decode(stream, decoding_limits))?;
         Ok(IdentityMappingRuleType {
             criteria_type,
             criteria,
@@ -16,7 +16,7 @@ use crate::{
     service_types::{
         AnonymousIdentityToken, ApplicationDescription, ApplicationType, Argument, CallMethodRequest,
         DataChangeFilter, DataChangeTrigger, EndpointDescription, enums::DeadbandType, MessageSecurityMode, MonitoredItemCreateRequest, MonitoringMode,
-        MonitoringParameters, ReadValueId, ServerDiagnosticsSummaryDataType, ServiceCounterDataType, ServiceFault,
+        MonitoringParameters, ReadValueId, ServerDiagnosticsSummaryDataType, ServerState, ServiceCounterDataType, ServiceFault,
         SignatureData, UserNameIdentityToken, UserTokenPolicy, UserTokenType,
     },
     status_codes::StatusCode,
@@ -101,12 +101,16 @@ impl DataChangeFilter {
     /// type, or the args were invalid. A (low, high) range must be supplied for a percentage deadband compare.
     pub fn compare_value(&self, v1: &Variant, v2: &Variant, eu_range: Option<(f64, f64)>) -> std::result::Result<bool, StatusCode> {
         // TODO be able to compare arrays of numbers
-        if self.deadband_type == DeadbandType::None as u32 {
+        if v1.is_nan() || v2.is_nan() {
+            // NaN never equals itself, so a value that stays NaN is compared numerically or it
+            // would be reported as a change every time it is sampled
+            Ok(v1.value_eq(v2))
+        } else if self.deadband_type == DeadbandType::None as u32 {
             // Straight comparison of values
             Ok(v1 == v2)
         } else {
             // Absolute
-            match (v1.as_f64(), v2.as_f64()) {
+            match (v1.to_f64(), v2.to_f64()) {
                 (None, _) | (_, None) => Ok(false),
                 (Some(v1), Some(v2)) => {
                     if self.deadband_value < 0f64 {
@@ -451,4 +455,17 @@ impl ServiceCounterDataType {
         self.total_count += 1;
         self.error_count += 1;
     }
-}
\ No newline at end of file
+}
+impl ServerState {
+    /// Tests if a server in this state may move to the next state. A server may always remain in
+    /// its current state or shut down, but once shut down it cannot leave that state, and a
+    /// failed server can do nothing except shut down.
+    pub fn can_transition_to(&self, next: ServerState) -> bool {
+        match *self {
+            _ if *self == next => true,
+            ServerState::Shutdown => false,
+            ServerState::Failed => next == ServerState::Shutdown,
+            _ => true,
+        }
+    }
+}
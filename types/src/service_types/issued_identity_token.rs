@@ -43,9 +43,9 @@ impl BinaryEncoder<IssuedIdentityToken> for IssuedIdentityToken {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let policy_id = UAString::decode(stream, decoding_limits)?;
-        let token_data = ByteString::decode(stream, decoding_limits)?;
-        let encryption_algorithm = UAString::decode(stream, decoding_limits)?;
+        let policy_id = decode_field("IssuedIdentityToken", "policy_id", UAString::decode(stream, decoding_limits))?;
+        let token_data = decode_field("IssuedIdentityToken", "token_data", ByteString::decode(stream, decoding_limits))?;
+        let encryption_algorithm = decode_field("IssuedIdentityToken", "encryption_algorithm", UAString::decode(stream, decoding_limits))?;
         Ok(IssuedIdentityToken {
             policy_id,
             token_data,
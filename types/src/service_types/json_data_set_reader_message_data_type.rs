@@ -40,8 +40,8 @@ impl BinaryEncoder<JsonDataSetReaderMessageDataType> for JsonDataSetReaderMessag
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let network_message_content_mask = JsonNetworkMessageContentMask::decode(stream, decoding_limits)?;
-        let data_set_message_content_mask = JsonDataSetMessageContentMask::decode(stream, decoding_limits)?;
+        let network_message_content_mask = decode_field("JsonDataSetReaderMessageDataType", "network_message_content_mask", JsonNetworkMessageContentMask::decode(stream, decoding_limits))?;
+        let data_set_message_content_mask = decode_field("JsonDataSetReaderMessageDataType", "data_set_message_content_mask", JsonDataSetMessageContentMask::decode(stream, decoding_limits))?;
         Ok(JsonDataSetReaderMessageDataType {
             network_message_content_mask,
             data_set_message_content_mask,
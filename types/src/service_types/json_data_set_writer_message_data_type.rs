@@ -36,7 +36,7 @@ impl BinaryEncoder<JsonDataSetWriterMessageDataType> for JsonDataSetWriterMessag
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_set_message_content_mask = JsonDataSetMessageContentMask::decode(stream, decoding_limits)?;
+        let data_set_message_content_mask = decode_field("JsonDataSetWriterMessageDataType", "data_set_message_content_mask", JsonDataSetMessageContentMask::decode(stream, decoding_limits))?;
         Ok(JsonDataSetWriterMessageDataType {
             data_set_message_content_mask,
         })
@@ -36,7 +36,7 @@ impl BinaryEncoder<JsonWriterGroupMessageDataType> for JsonWriterGroupMessageDat
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let network_message_content_mask = JsonNetworkMessageContentMask::decode(stream, decoding_limits)?;
+        let network_message_content_mask = decode_field("JsonWriterGroupMessageDataType", "network_message_content_mask", JsonNetworkMessageContentMask::decode(stream, decoding_limits))?;
         Ok(JsonWriterGroupMessageDataType {
             network_message_content_mask,
         })
@@ -48,8 +48,8 @@ impl BinaryEncoder<KeyValuePair> for KeyValuePair {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let key = QualifiedName::decode(stream, decoding_limits)?;
-        let value = Variant::decode(stream, decoding_limits)?;
+        let key = decode_field("KeyValuePair", "key", QualifiedName::decode(stream, decoding_limits))?;
+        let value = decode_field("KeyValuePair", "value", Variant::decode(stream, decoding_limits))?;
         Ok(KeyValuePair {
             key,
             value,
@@ -36,7 +36,7 @@ impl BinaryEncoder<LiteralOperand> for LiteralOperand {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let value = Variant::decode(stream, decoding_limits)?;
+        let value = decode_field("LiteralOperand", "value", Variant::decode(stream, decoding_limits))?;
         Ok(LiteralOperand {
             value,
         })
@@ -54,13 +54,13 @@ impl BinaryEncoder<MethodAttributes> for MethodAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let executable = bool::decode(stream, decoding_limits)?;
-        let user_executable = bool::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("MethodAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("MethodAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("MethodAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("MethodAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("MethodAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let executable = decode_field("MethodAttributes", "executable", bool::decode(stream, decoding_limits))?;
+        let user_executable = decode_field("MethodAttributes", "user_executable", bool::decode(stream, decoding_limits))?;
         Ok(MethodAttributes {
             specified_attributes,
             display_name,
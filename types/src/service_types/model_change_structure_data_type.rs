@@ -50,9 +50,9 @@ impl BinaryEncoder<ModelChangeStructureDataType> for ModelChangeStructureDataTyp
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let affected = NodeId::decode(stream, decoding_limits)?;
-        let affected_type = NodeId::decode(stream, decoding_limits)?;
-        let verb = u8::decode(stream, decoding_limits)?;
+        let affected = decode_field("ModelChangeStructureDataType", "affected", NodeId::decode(stream, decoding_limits))?;
+        let affected_type = decode_field("ModelChangeStructureDataType", "affected_type", NodeId::decode(stream, decoding_limits))?;
+        let verb = decode_field("ModelChangeStructureDataType", "verb", u8::decode(stream, decoding_limits))?;
         Ok(ModelChangeStructureDataType {
             affected,
             affected_type,
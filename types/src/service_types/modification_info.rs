@@ -52,9 +52,9 @@ impl BinaryEncoder<ModificationInfo> for ModificationInfo {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let modification_time = DateTime::decode(stream, decoding_limits)?;
-        let update_type = HistoryUpdateType::decode(stream, decoding_limits)?;
-        let user_name = UAString::decode(stream, decoding_limits)?;
+        let modification_time = decode_field("ModificationInfo", "modification_time", DateTime::decode(stream, decoding_limits))?;
+        let update_type = decode_field("ModificationInfo", "update_type", HistoryUpdateType::decode(stream, decoding_limits))?;
+        let user_name = decode_field("ModificationInfo", "user_name", UAString::decode(stream, decoding_limits))?;
         Ok(ModificationInfo {
             modification_time,
             update_type,
@@ -55,10 +55,10 @@ impl BinaryEncoder<ModifyMonitoredItemsRequest> for ModifyMonitoredItemsRequest
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let timestamps_to_return = TimestampsToReturn::decode(stream, decoding_limits)?;
-        let items_to_modify: Option<Vec<MonitoredItemModifyRequest>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("ModifyMonitoredItemsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("ModifyMonitoredItemsRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let timestamps_to_return = decode_field("ModifyMonitoredItemsRequest", "timestamps_to_return", TimestampsToReturn::decode(stream, decoding_limits))?;
+        let items_to_modify: Option<Vec<MonitoredItemModifyRequest>> = decode_field("ModifyMonitoredItemsRequest", "items_to_modify", read_array(stream, decoding_limits))?;
         Ok(ModifyMonitoredItemsRequest {
             request_header,
             subscription_id,
@@ -62,13 +62,13 @@ impl BinaryEncoder<ModifySubscriptionRequest> for ModifySubscriptionRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let requested_publishing_interval = f64::decode(stream, decoding_limits)?;
-        let requested_lifetime_count = u32::decode(stream, decoding_limits)?;
-        let requested_max_keep_alive_count = u32::decode(stream, decoding_limits)?;
-        let max_notifications_per_publish = u32::decode(stream, decoding_limits)?;
-        let priority = u8::decode(stream, decoding_limits)?;
+        let request_header = decode_field("ModifySubscriptionRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("ModifySubscriptionRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let requested_publishing_interval = decode_field("ModifySubscriptionRequest", "requested_publishing_interval", f64::decode(stream, decoding_limits))?;
+        let requested_lifetime_count = decode_field("ModifySubscriptionRequest", "requested_lifetime_count", u32::decode(stream, decoding_limits))?;
+        let requested_max_keep_alive_count = decode_field("ModifySubscriptionRequest", "requested_max_keep_alive_count", u32::decode(stream, decoding_limits))?;
+        let max_notifications_per_publish = decode_field("ModifySubscriptionRequest", "max_notifications_per_publish", u32::decode(stream, decoding_limits))?;
+        let priority = decode_field("ModifySubscriptionRequest", "priority", u8::decode(stream, decoding_limits))?;
         Ok(ModifySubscriptionRequest {
             request_header,
             subscription_id,
@@ -53,10 +53,10 @@ impl BinaryEncoder<ModifySubscriptionResponse> for ModifySubscriptionResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let revised_publishing_interval = f64::decode(stream, decoding_limits)?;
-        let revised_lifetime_count = u32::decode(stream, decoding_limits)?;
-        let revised_max_keep_alive_count = u32::decode(stream, decoding_limits)?;
+        let response_header = decode_field("ModifySubscriptionResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let revised_publishing_interval = decode_field("ModifySubscriptionResponse", "revised_publishing_interval", f64::decode(stream, decoding_limits))?;
+        let revised_lifetime_count = decode_field("ModifySubscriptionResponse", "revised_lifetime_count", u32::decode(stream, decoding_limits))?;
+        let revised_max_keep_alive_count = decode_field("ModifySubscriptionResponse", "revised_max_keep_alive_count", u32::decode(stream, decoding_limits))?;
         Ok(ModifySubscriptionResponse {
             response_header,
             revised_publishing_interval,
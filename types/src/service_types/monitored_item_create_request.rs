@@ -52,9 +52,9 @@ impl BinaryEncoder<MonitoredItemCreateRequest> for MonitoredItemCreateRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let item_to_monitor = ReadValueId::decode(stream, decoding_limits)?;
-        let monitoring_mode = MonitoringMode::decode(stream, decoding_limits)?;
-        let requested_parameters = MonitoringParameters::decode(stream, decoding_limits)?;
+        let item_to_monitor = decode_field("MonitoredItemCreateRequest", "item_to_monitor", ReadValueId::decode(stream, decoding_limits))?;
+        let monitoring_mode = decode_field("MonitoredItemCreateRequest", "monitoring_mode", MonitoringMode::decode(stream, decoding_limits))?;
+        let requested_parameters = decode_field("MonitoredItemCreateRequest", "requested_parameters", MonitoringParameters::decode(stream, decoding_limits))?;
         Ok(MonitoredItemCreateRequest {
             item_to_monitor,
             monitoring_mode,
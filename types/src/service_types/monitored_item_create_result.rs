@@ -57,11 +57,11 @@ impl BinaryEncoder<MonitoredItemCreateResult> for MonitoredItemCreateResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let monitored_item_id = u32::decode(stream, decoding_limits)?;
-        let revised_sampling_interval = f64::decode(stream, decoding_limits)?;
-        let revised_queue_size = u32::decode(stream, decoding_limits)?;
-        let filter_result = ExtensionObject::decode(stream, decoding_limits)?;
+        let status_code = decode_field("MonitoredItemCreateResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let monitored_item_id = decode_field("MonitoredItemCreateResult", "monitored_item_id", u32::decode(stream, decoding_limits))?;
+        let revised_sampling_interval = decode_field("MonitoredItemCreateResult", "revised_sampling_interval", f64::decode(stream, decoding_limits))?;
+        let revised_queue_size = decode_field("MonitoredItemCreateResult", "revised_queue_size", u32::decode(stream, decoding_limits))?;
+        let filter_result = decode_field("MonitoredItemCreateResult", "filter_result", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(MonitoredItemCreateResult {
             status_code,
             monitored_item_id,
@@ -47,8 +47,8 @@ impl BinaryEncoder<MonitoredItemModifyRequest> for MonitoredItemModifyRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let monitored_item_id = u32::decode(stream, decoding_limits)?;
-        let requested_parameters = MonitoringParameters::decode(stream, decoding_limits)?;
+        let monitored_item_id = decode_field("MonitoredItemModifyRequest", "monitored_item_id", u32::decode(stream, decoding_limits))?;
+        let requested_parameters = decode_field("MonitoredItemModifyRequest", "requested_parameters", MonitoringParameters::decode(stream, decoding_limits))?;
         Ok(MonitoredItemModifyRequest {
             monitored_item_id,
             requested_parameters,
@@ -54,10 +54,10 @@ impl BinaryEncoder<MonitoredItemModifyResult> for MonitoredItemModifyResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let revised_sampling_interval = f64::decode(stream, decoding_limits)?;
-        let revised_queue_size = u32::decode(stream, decoding_limits)?;
-        let filter_result = ExtensionObject::decode(stream, decoding_limits)?;
+        let status_code = decode_field("MonitoredItemModifyResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let revised_sampling_interval = decode_field("MonitoredItemModifyResult", "revised_sampling_interval", f64::decode(stream, decoding_limits))?;
+        let revised_queue_size = decode_field("MonitoredItemModifyResult", "revised_queue_size", u32::decode(stream, decoding_limits))?;
+        let filter_result = decode_field("MonitoredItemModifyResult", "filter_result", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(MonitoredItemModifyResult {
             status_code,
             revised_sampling_interval,
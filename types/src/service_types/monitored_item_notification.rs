@@ -47,8 +47,8 @@ impl BinaryEncoder<MonitoredItemNotification> for MonitoredItemNotification {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let client_handle = u32::decode(stream, decoding_limits)?;
-        let value = DataValue::decode(stream, decoding_limits)?;
+        let client_handle = decode_field("MonitoredItemNotification", "client_handle", u32::decode(stream, decoding_limits))?;
+        let value = decode_field("MonitoredItemNotification", "value", DataValue::decode(stream, decoding_limits))?;
         Ok(MonitoredItemNotification {
             client_handle,
             value,
@@ -56,11 +56,11 @@ impl BinaryEncoder<MonitoringParameters> for MonitoringParameters {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let client_handle = u32::decode(stream, decoding_limits)?;
-        let sampling_interval = f64::decode(stream, decoding_limits)?;
-        let filter = ExtensionObject::decode(stream, decoding_limits)?;
-        let queue_size = u32::decode(stream, decoding_limits)?;
-        let discard_oldest = bool::decode(stream, decoding_limits)?;
+        let client_handle = decode_field("MonitoringParameters", "client_handle", u32::decode(stream, decoding_limits))?;
+        let sampling_interval = decode_field("MonitoringParameters", "sampling_interval", f64::decode(stream, decoding_limits))?;
+        let filter = decode_field("MonitoringParameters", "filter", ExtensionObject::decode(stream, decoding_limits))?;
+        let queue_size = decode_field("MonitoringParameters", "queue_size", u32::decode(stream, decoding_limits))?;
+        let discard_oldest = decode_field("MonitoringParameters", "discard_oldest", bool::decode(stream, decoding_limits))?;
         Ok(MonitoringParameters {
             client_handle,
             sampling_interval,
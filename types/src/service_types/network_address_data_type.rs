@@ -44,7 +44,7 @@ impl BinaryEncoder<NetworkAddressDataType> for NetworkAddressDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let network_interface = UAString::decode(stream, decoding_limits)?;
+        let network_interface = decode_field("NetworkAddressDataType", "network_interface", UAString::decode(stream, decoding_limits))?;
         Ok(NetworkAddressDataType {
             network_interface,
         })
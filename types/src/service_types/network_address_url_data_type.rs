@@ -39,8 +39,8 @@ impl BinaryEncoder<NetworkAddressUrlDataType> for NetworkAddressUrlDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let network_interface = UAString::decode(stream, decoding_limits)?;
-        let url = UAString::decode(stream, decoding_limits)?;
+        let network_interface = decode_field("NetworkAddressUrlDataType", "network_interface", UAString::decode(stream, decoding_limits))?;
+        let url = decode_field("NetworkAddressUrlDataType", "url", UAString::decode(stream, decoding_limits))?;
         Ok(NetworkAddressUrlDataType {
             network_interface,
             url,
@@ -48,8 +48,8 @@ impl BinaryEncoder<NetworkGroupDataType> for NetworkGroupDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let server_uri = UAString::decode(stream, decoding_limits)?;
-        let network_paths: Option<Vec<EndpointUrlListDataType>> = read_array(stream, decoding_limits)?;
+        let server_uri = decode_field("NetworkGroupDataType", "server_uri", UAString::decode(stream, decoding_limits))?;
+        let network_paths: Option<Vec<EndpointUrlListDataType>> = decode_field("NetworkGroupDataType", "network_paths", read_array(stream, decoding_limits))?;
         Ok(NetworkGroupDataType {
             server_uri,
             network_paths,
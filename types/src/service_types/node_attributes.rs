@@ -56,11 +56,11 @@ impl BinaryEncoder<NodeAttributes> for NodeAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("NodeAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("NodeAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("NodeAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("NodeAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("NodeAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
         Ok(NodeAttributes {
             specified_attributes,
             display_name,
@@ -53,10 +53,10 @@ impl BinaryEncoder<NodeReference> for NodeReference {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let is_forward = bool::decode(stream, decoding_limits)?;
-        let referenced_node_ids: Option<Vec<NodeId>> = read_array(stream, decoding_limits)?;
+        let node_id = decode_field("NodeReference", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let reference_type_id = decode_field("NodeReference", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let is_forward = decode_field("NodeReference", "is_forward", bool::decode(stream, decoding_limits))?;
+        let referenced_node_ids: Option<Vec<NodeId>> = decode_field("NodeReference", "referenced_node_ids", read_array(stream, decoding_limits))?;
         Ok(NodeReference {
             node_id,
             reference_type_id,
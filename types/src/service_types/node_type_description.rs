@@ -51,9 +51,9 @@ impl BinaryEncoder<NodeTypeDescription> for NodeTypeDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let type_definition_node = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let include_sub_types = bool::decode(stream, decoding_limits)?;
-        let data_to_return: Option<Vec<QueryDataDescription>> = read_array(stream, decoding_limits)?;
+        let type_definition_node = decode_field("NodeTypeDescription", "type_definition_node", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let include_sub_types = decode_field("NodeTypeDescription", "include_sub_types", bool::decode(stream, decoding_limits))?;
+        let data_to_return: Option<Vec<QueryDataDescription>> = decode_field("NodeTypeDescription", "data_to_return", read_array(stream, decoding_limits))?;
         Ok(NodeTypeDescription {
             type_definition_node,
             include_sub_types,
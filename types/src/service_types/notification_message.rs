@@ -51,9 +51,9 @@ impl BinaryEncoder<NotificationMessage> for NotificationMessage {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let sequence_number = u32::decode(stream, decoding_limits)?;
-        let publish_time = DateTime::decode(stream, decoding_limits)?;
-        let notification_data: Option<Vec<ExtensionObject>> = read_array(stream, decoding_limits)?;
+        let sequence_number = decode_field("NotificationMessage", "sequence_number", u32::decode(stream, decoding_limits))?;
+        let publish_time = decode_field("NotificationMessage", "publish_time", DateTime::decode(stream, decoding_limits))?;
+        let notification_data: Option<Vec<ExtensionObject>> = decode_field("NotificationMessage", "notification_data", read_array(stream, decoding_limits))?;
         Ok(NotificationMessage {
             sequence_number,
             publish_time,
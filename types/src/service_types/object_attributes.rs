@@ -51,12 +51,12 @@ impl BinaryEncoder<ObjectAttributes> for ObjectAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let event_notifier = u8::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("ObjectAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("ObjectAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("ObjectAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("ObjectAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("ObjectAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let event_notifier = decode_field("ObjectAttributes", "event_notifier", u8::decode(stream, decoding_limits))?;
         Ok(ObjectAttributes {
             specified_attributes,
             display_name,
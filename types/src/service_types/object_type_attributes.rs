@@ -51,12 +51,12 @@ impl BinaryEncoder<ObjectTypeAttributes> for ObjectTypeAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let is_abstract = bool::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("ObjectTypeAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("ObjectTypeAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("ObjectTypeAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("ObjectTypeAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("ObjectTypeAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let is_abstract = decode_field("ObjectTypeAttributes", "is_abstract", bool::decode(stream, decoding_limits))?;
         Ok(ObjectTypeAttributes {
             specified_attributes,
             display_name,
@@ -62,12 +62,12 @@ impl BinaryEncoder<OpenSecureChannelRequest> for OpenSecureChannelRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let client_protocol_version = u32::decode(stream, decoding_limits)?;
-        let request_type = SecurityTokenRequestType::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let client_nonce = ByteString::decode(stream, decoding_limits)?;
-        let requested_lifetime = u32::decode(stream, decoding_limits)?;
+        let request_header = decode_field("OpenSecureChannelRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let client_protocol_version = decode_field("OpenSecureChannelRequest", "client_protocol_version", u32::decode(stream, decoding_limits))?;
+        let request_type = decode_field("OpenSecureChannelRequest", "request_type", SecurityTokenRequestType::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("OpenSecureChannelRequest", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let client_nonce = decode_field("OpenSecureChannelRequest", "client_nonce", ByteString::decode(stream, decoding_limits))?;
+        let requested_lifetime = decode_field("OpenSecureChannelRequest", "requested_lifetime", u32::decode(stream, decoding_limits))?;
         Ok(OpenSecureChannelRequest {
             request_header,
             client_protocol_version,
@@ -55,10 +55,10 @@ impl BinaryEncoder<OpenSecureChannelResponse> for OpenSecureChannelResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let server_protocol_version = u32::decode(stream, decoding_limits)?;
-        let security_token = ChannelSecurityToken::decode(stream, decoding_limits)?;
-        let server_nonce = ByteString::decode(stream, decoding_limits)?;
+        let response_header = decode_field("OpenSecureChannelResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let server_protocol_version = decode_field("OpenSecureChannelResponse", "server_protocol_version", u32::decode(stream, decoding_limits))?;
+        let security_token = decode_field("OpenSecureChannelResponse", "security_token", ChannelSecurityToken::decode(stream, decoding_limits))?;
+        let server_nonce = decode_field("OpenSecureChannelResponse", "server_nonce", ByteString::decode(stream, decoding_limits))?;
         Ok(OpenSecureChannelResponse {
             response_header,
             server_protocol_version,
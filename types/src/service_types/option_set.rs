@@ -47,8 +47,8 @@ impl BinaryEncoder<OptionSet> for OptionSet {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let value = ByteString::decode(stream, decoding_limits)?;
-        let valid_bits = ByteString::decode(stream, decoding_limits)?;
+        let value = decode_field("OptionSet", "value", ByteString::decode(stream, decoding_limits))?;
+        let valid_bits = decode_field("OptionSet", "valid_bits", ByteString::decode(stream, decoding_limits))?;
         Ok(OptionSet {
             value,
             valid_bits,
@@ -51,9 +51,9 @@ impl BinaryEncoder<ParsingResult> for ParsingResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let data_status_codes: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let data_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let status_code = decode_field("ParsingResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let data_status_codes: Option<Vec<StatusCode>> = decode_field("ParsingResult", "data_status_codes", read_array(stream, decoding_limits))?;
+        let data_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("ParsingResult", "data_diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(ParsingResult {
             status_code,
             data_status_codes,
@@ -82,18 +82,18 @@ impl BinaryEncoder<ProgramDiagnostic2DataType> for ProgramDiagnostic2DataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let create_session_id = NodeId::decode(stream, decoding_limits)?;
-        let create_client_name = UAString::decode(stream, decoding_limits)?;
-        let invocation_creation_time = DateTime::decode(stream, decoding_limits)?;
-        let last_transition_time = DateTime::decode(stream, decoding_limits)?;
-        let last_method_call = UAString::decode(stream, decoding_limits)?;
-        let last_method_session_id = NodeId::decode(stream, decoding_limits)?;
-        let last_method_input_arguments: Option<Vec<Argument>> = read_array(stream, decoding_limits)?;
-        let last_method_output_arguments: Option<Vec<Argument>> = read_array(stream, decoding_limits)?;
-        let last_method_input_values: Option<Vec<Variant>> = read_array(stream, decoding_limits)?;
-        let last_method_output_values: Option<Vec<Variant>> = read_array(stream, decoding_limits)?;
-        let last_method_call_time = DateTime::decode(stream, decoding_limits)?;
-        let last_method_return_status = StatusResult::decode(stream, decoding_limits)?;
+        let create_session_id = decode_field("ProgramDiagnostic2DataType", "create_session_id", NodeId::decode(stream, decoding_limits))?;
+        let create_client_name = decode_field("ProgramDiagnostic2DataType", "create_client_name", UAString::decode(stream, decoding_limits))?;
+        let invocation_creation_time = decode_field("ProgramDiagnostic2DataType", "invocation_creation_time", DateTime::decode(stream, decoding_limits))?;
+        let last_transition_time = decode_field("ProgramDiagnostic2DataType", "last_transition_time", DateTime::decode(stream, decoding_limits))?;
+        let last_method_call = decode_field("ProgramDiagnostic2DataType", "last_method_call", UAString::decode(stream, decoding_limits))?;
+        let last_method_session_id = decode_field("ProgramDiagnostic2DataType", "last_method_session_id", NodeId::decode(stream, decoding_limits))?;
+        let last_method_input_arguments: Option<Vec<Argument>> = decode_field("ProgramDiagnostic2DataType", "last_method_input_arguments", read_array(stream, decoding_limits))?;
+        let last_method_output_arguments: Option<Vec<Argument>> = decode_field("ProgramDiagnostic2DataType", "last_method_output_arguments", read_array(stream, decoding_limits))?;
+        let last_method_input_values: Option<Vec<Variant>> = decode_field("ProgramDiagnostic2DataType", "last_method_input_values", read_array(stream, decoding_limits))?;
+        let last_method_output_values: Option<Vec<Variant>> = decode_field("ProgramDiagnostic2DataType", "last_method_output_values", read_array(stream, decoding_limits))?;
+        let last_method_call_time = decode_field("ProgramDiagnostic2DataType", "last_method_call_time", DateTime::decode(stream, decoding_limits))?;
+        let last_method_return_status = decode_field("ProgramDiagnostic2DataType", "last_method_return_status", StatusResult::decode(stream, decoding_limits))?;
         Ok(ProgramDiagnostic2DataType {
             create_session_id,
             create_client_name,
@@ -51,9 +51,9 @@ impl BinaryEncoder<PubSubConfigurationDataType> for PubSubConfigurationDataType
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let published_data_sets: Option<Vec<PublishedDataSetDataType>> = read_array(stream, decoding_limits)?;
-        let connections: Option<Vec<PubSubConnectionDataType>> = read_array(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
+        let published_data_sets: Option<Vec<PublishedDataSetDataType>> = decode_field("PubSubConfigurationDataType", "published_data_sets", read_array(stream, decoding_limits))?;
+        let connections: Option<Vec<PubSubConnectionDataType>> = decode_field("PubSubConfigurationDataType", "connections", read_array(stream, decoding_limits))?;
+        let enabled = decode_field("PubSubConfigurationDataType", "enabled", bool::decode(stream, decoding_limits))?;
         Ok(PubSubConfigurationDataType {
             published_data_sets,
             connections,
@@ -73,15 +73,15 @@ impl BinaryEncoder<PubSubConnectionDataType> for PubSubConnectionDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
-        let publisher_id = Variant::decode(stream, decoding_limits)?;
-        let transport_profile_uri = UAString::decode(stream, decoding_limits)?;
-        let address = ExtensionObject::decode(stream, decoding_limits)?;
-        let connection_properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let transport_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let writer_groups: Option<Vec<WriterGroupDataType>> = read_array(stream, decoding_limits)?;
-        let reader_groups: Option<Vec<ReaderGroupDataType>> = read_array(stream, decoding_limits)?;
+        let name = decode_field("PubSubConnectionDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let enabled = decode_field("PubSubConnectionDataType", "enabled", bool::decode(stream, decoding_limits))?;
+        let publisher_id = decode_field("PubSubConnectionDataType", "publisher_id", Variant::decode(stream, decoding_limits))?;
+        let transport_profile_uri = decode_field("PubSubConnectionDataType", "transport_profile_uri", UAString::decode(stream, decoding_limits))?;
+        let address = decode_field("PubSubConnectionDataType", "address", ExtensionObject::decode(stream, decoding_limits))?;
+        let connection_properties: Option<Vec<KeyValuePair>> = decode_field("PubSubConnectionDataType", "connection_properties", read_array(stream, decoding_limits))?;
+        let transport_settings = decode_field("PubSubConnectionDataType", "transport_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let writer_groups: Option<Vec<WriterGroupDataType>> = decode_field("PubSubConnectionDataType", "writer_groups", read_array(stream, decoding_limits))?;
+        let reader_groups: Option<Vec<ReaderGroupDataType>> = decode_field("PubSubConnectionDataType", "reader_groups", read_array(stream, decoding_limits))?;
         Ok(PubSubConnectionDataType {
             name,
             enabled,
@@ -65,13 +65,13 @@ impl BinaryEncoder<PubSubGroupDataType> for PubSubGroupDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_group_id = UAString::decode(stream, decoding_limits)?;
-        let security_key_services: Option<Vec<EndpointDescription>> = read_array(stream, decoding_limits)?;
-        let max_network_message_size = u32::decode(stream, decoding_limits)?;
-        let group_properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
+        let name = decode_field("PubSubGroupDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let enabled = decode_field("PubSubGroupDataType", "enabled", bool::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("PubSubGroupDataType", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_group_id = decode_field("PubSubGroupDataType", "security_group_id", UAString::decode(stream, decoding_limits))?;
+        let security_key_services: Option<Vec<EndpointDescription>> = decode_field("PubSubGroupDataType", "security_key_services", read_array(stream, decoding_limits))?;
+        let max_network_message_size = decode_field("PubSubGroupDataType", "max_network_message_size", u32::decode(stream, decoding_limits))?;
+        let group_properties: Option<Vec<KeyValuePair>> = decode_field("PubSubGroupDataType", "group_properties", read_array(stream, decoding_limits))?;
         Ok(PubSubGroupDataType {
             name,
             enabled,
@@ -48,8 +48,8 @@ impl BinaryEncoder<PublishRequest> for PublishRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_acknowledgements: Option<Vec<SubscriptionAcknowledgement>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("PublishRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_acknowledgements: Option<Vec<SubscriptionAcknowledgement>> = decode_field("PublishRequest", "subscription_acknowledgements", read_array(stream, decoding_limits))?;
         Ok(PublishRequest {
             request_header,
             subscription_acknowledgements,
@@ -65,13 +65,13 @@ impl BinaryEncoder<PublishResponse> for PublishResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let available_sequence_numbers: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let more_notifications = bool::decode(stream, decoding_limits)?;
-        let notification_message = NotificationMessage::decode(stream, decoding_limits)?;
-        let results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("PublishResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("PublishResponse", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let available_sequence_numbers: Option<Vec<u32>> = decode_field("PublishResponse", "available_sequence_numbers", read_array(stream, decoding_limits))?;
+        let more_notifications = decode_field("PublishResponse", "more_notifications", bool::decode(stream, decoding_limits))?;
+        let notification_message = decode_field("PublishResponse", "notification_message", NotificationMessage::decode(stream, decoding_limits))?;
+        let results: Option<Vec<StatusCode>> = decode_field("PublishResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("PublishResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(PublishResponse {
             response_header,
             subscription_id,
@@ -36,7 +36,7 @@ impl BinaryEncoder<PublishedDataItemsDataType> for PublishedDataItemsDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let published_data: Option<Vec<PublishedVariableDataType>> = read_array(stream, decoding_limits)?;
+        let published_data: Option<Vec<PublishedVariableDataType>> = decode_field("PublishedDataItemsDataType", "published_data", read_array(stream, decoding_limits))?;
         Ok(PublishedDataItemsDataType {
             published_data,
         })
@@ -59,11 +59,11 @@ impl BinaryEncoder<PublishedDataSetDataType> for PublishedDataSetDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let data_set_folder: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let data_set_meta_data = DataSetMetaDataType::decode(stream, decoding_limits)?;
-        let extension_fields: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let data_set_source = ExtensionObject::decode(stream, decoding_limits)?;
+        let name = decode_field("PublishedDataSetDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let data_set_folder: Option<Vec<UAString>> = decode_field("PublishedDataSetDataType", "data_set_folder", read_array(stream, decoding_limits))?;
+        let data_set_meta_data = decode_field("PublishedDataSetDataType", "data_set_meta_data", DataSetMetaDataType::decode(stream, decoding_limits))?;
+        let extension_fields: Option<Vec<KeyValuePair>> = decode_field("PublishedDataSetDataType", "extension_fields", read_array(stream, decoding_limits))?;
+        let data_set_source = decode_field("PublishedDataSetDataType", "data_set_source", ExtensionObject::decode(stream, decoding_limits))?;
         Ok(PublishedDataSetDataType {
             name,
             data_set_folder,
@@ -44,9 +44,9 @@ impl BinaryEncoder<PublishedEventsDataType> for PublishedEventsDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let event_notifier = NodeId::decode(stream, decoding_limits)?;
-        let selected_fields: Option<Vec<SimpleAttributeOperand>> = read_array(stream, decoding_limits)?;
-        let filter = ContentFilter::decode(stream, decoding_limits)?;
+        let event_notifier = decode_field("PublishedEventsDataType", "event_notifier", NodeId::decode(stream, decoding_limits))?;
+        let selected_fields: Option<Vec<SimpleAttributeOperand>> = decode_field("PublishedEventsDataType", "selected_fields", read_array(stream, decoding_limits))?;
+        let filter = decode_field("PublishedEventsDataType", "filter", ContentFilter::decode(stream, decoding_limits))?;
         Ok(PublishedEventsDataType {
             event_notifier,
             selected_fields,
@@ -68,14 +68,14 @@ impl BinaryEncoder<PublishedVariableDataType> for PublishedVariableDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let published_variable = NodeId::decode(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let sampling_interval_hint = f64::decode(stream, decoding_limits)?;
-        let deadband_type = u32::decode(stream, decoding_limits)?;
-        let deadband_value = f64::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
-        let substitute_value = Variant::decode(stream, decoding_limits)?;
-        let meta_data_properties: Option<Vec<QualifiedName>> = read_array(stream, decoding_limits)?;
+        let published_variable = decode_field("PublishedVariableDataType", "published_variable", NodeId::decode(stream, decoding_limits))?;
+        let attribute_id = decode_field("PublishedVariableDataType", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let sampling_interval_hint = decode_field("PublishedVariableDataType", "sampling_interval_hint", f64::decode(stream, decoding_limits))?;
+        let deadband_type = decode_field("PublishedVariableDataType", "deadband_type", u32::decode(stream, decoding_limits))?;
+        let deadband_value = decode_field("PublishedVariableDataType", "deadband_value", f64::decode(stream, decoding_limits))?;
+        let index_range = decode_field("PublishedVariableDataType", "index_range", UAString::decode(stream, decoding_limits))?;
+        let substitute_value = decode_field("PublishedVariableDataType", "substitute_value", Variant::decode(stream, decoding_limits))?;
+        let meta_data_properties: Option<Vec<QualifiedName>> = decode_field("PublishedVariableDataType", "meta_data_properties", read_array(stream, decoding_limits))?;
         Ok(PublishedVariableDataType {
             published_variable,
             attribute_id,
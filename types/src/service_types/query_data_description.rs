@@ -51,9 +51,9 @@ impl BinaryEncoder<QueryDataDescription> for QueryDataDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let relative_path = RelativePath::decode(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
+        let relative_path = decode_field("QueryDataDescription", "relative_path", RelativePath::decode(stream, decoding_limits))?;
+        let attribute_id = decode_field("QueryDataDescription", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let index_range = decode_field("QueryDataDescription", "index_range", UAString::decode(stream, decoding_limits))?;
         Ok(QueryDataDescription {
             relative_path,
             attribute_id,
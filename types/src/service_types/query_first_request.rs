@@ -62,12 +62,12 @@ impl BinaryEncoder<QueryFirstRequest> for QueryFirstRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let view = ViewDescription::decode(stream, decoding_limits)?;
-        let node_types: Option<Vec<NodeTypeDescription>> = read_array(stream, decoding_limits)?;
-        let filter = ContentFilter::decode(stream, decoding_limits)?;
-        let max_data_sets_to_return = u32::decode(stream, decoding_limits)?;
-        let max_references_to_return = u32::decode(stream, decoding_limits)?;
+        let request_header = decode_field("QueryFirstRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let view = decode_field("QueryFirstRequest", "view", ViewDescription::decode(stream, decoding_limits))?;
+        let node_types: Option<Vec<NodeTypeDescription>> = decode_field("QueryFirstRequest", "node_types", read_array(stream, decoding_limits))?;
+        let filter = decode_field("QueryFirstRequest", "filter", ContentFilter::decode(stream, decoding_limits))?;
+        let max_data_sets_to_return = decode_field("QueryFirstRequest", "max_data_sets_to_return", u32::decode(stream, decoding_limits))?;
+        let max_references_to_return = decode_field("QueryFirstRequest", "max_references_to_return", u32::decode(stream, decoding_limits))?;
         Ok(QueryFirstRequest {
             request_header,
             view,
@@ -64,12 +64,12 @@ impl BinaryEncoder<QueryFirstResponse> for QueryFirstResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let query_data_sets: Option<Vec<QueryDataSet>> = read_array(stream, decoding_limits)?;
-        let continuation_point = ByteString::decode(stream, decoding_limits)?;
-        let parsing_results: Option<Vec<ParsingResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
-        let filter_result = ContentFilterResult::decode(stream, decoding_limits)?;
+        let response_header = decode_field("QueryFirstResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let query_data_sets: Option<Vec<QueryDataSet>> = decode_field("QueryFirstResponse", "query_data_sets", read_array(stream, decoding_limits))?;
+        let continuation_point = decode_field("QueryFirstResponse", "continuation_point", ByteString::decode(stream, decoding_limits))?;
+        let parsing_results: Option<Vec<ParsingResult>> = decode_field("QueryFirstResponse", "parsing_results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("QueryFirstResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
+        let filter_result = decode_field("QueryFirstResponse", "filter_result", ContentFilterResult::decode(stream, decoding_limits))?;
         Ok(QueryFirstResponse {
             response_header,
             query_data_sets,
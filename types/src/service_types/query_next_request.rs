@@ -51,9 +51,9 @@ impl BinaryEncoder<QueryNextRequest> for QueryNextRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let release_continuation_point = bool::decode(stream, decoding_limits)?;
-        let continuation_point = ByteString::decode(stream, decoding_limits)?;
+        let request_header = decode_field("QueryNextRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let release_continuation_point = decode_field("QueryNextRequest", "release_continuation_point", bool::decode(stream, decoding_limits))?;
+        let continuation_point = decode_field("QueryNextRequest", "continuation_point", ByteString::decode(stream, decoding_limits))?;
         Ok(QueryNextRequest {
             request_header,
             release_continuation_point,
@@ -46,8 +46,8 @@ impl BinaryEncoder<Range> for Range {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let low = f64::decode(stream, decoding_limits)?;
-        let high = f64::decode(stream, decoding_limits)?;
+        let low = decode_field("Range", "low", f64::decode(stream, decoding_limits))?;
+        let high = decode_field("Range", "high", f64::decode(stream, decoding_limits))?;
         Ok(Range {
             low,
             high,
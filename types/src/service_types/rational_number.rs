@@ -46,8 +46,8 @@ impl BinaryEncoder<RationalNumber> for RationalNumber {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let numerator = i32::decode(stream, decoding_limits)?;
-        let denominator = u32::decode(stream, decoding_limits)?;
+        let numerator = decode_field("RationalNumber", "numerator", i32::decode(stream, decoding_limits))?;
+        let denominator = decode_field("RationalNumber", "denominator", u32::decode(stream, decoding_limits))?;
         Ok(RationalNumber {
             numerator,
             denominator,
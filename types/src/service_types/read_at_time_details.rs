@@ -39,8 +39,8 @@ impl BinaryEncoder<ReadAtTimeDetails> for ReadAtTimeDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let req_times: Option<Vec<DateTime>> = read_array(stream, decoding_limits)?;
-        let use_simple_bounds = bool::decode(stream, decoding_limits)?;
+        let req_times: Option<Vec<DateTime>> = decode_field("ReadAtTimeDetails", "req_times", read_array(stream, decoding_limits))?;
+        let use_simple_bounds = decode_field("ReadAtTimeDetails", "use_simple_bounds", bool::decode(stream, decoding_limits))?;
         Ok(ReadAtTimeDetails {
             req_times,
             use_simple_bounds,
@@ -46,10 +46,10 @@ impl BinaryEncoder<ReadEventDetails> for ReadEventDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let num_values_per_node = u32::decode(stream, decoding_limits)?;
-        let start_time = DateTime::decode(stream, decoding_limits)?;
-        let end_time = DateTime::decode(stream, decoding_limits)?;
-        let filter = EventFilter::decode(stream, decoding_limits)?;
+        let num_values_per_node = decode_field("ReadEventDetails", "num_values_per_node", u32::decode(stream, decoding_limits))?;
+        let start_time = decode_field("ReadEventDetails", "start_time", DateTime::decode(stream, decoding_limits))?;
+        let end_time = decode_field("ReadEventDetails", "end_time", DateTime::decode(stream, decoding_limits))?;
+        let filter = decode_field("ReadEventDetails", "filter", EventFilter::decode(stream, decoding_limits))?;
         Ok(ReadEventDetails {
             num_values_per_node,
             start_time,
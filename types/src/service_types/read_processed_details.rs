@@ -50,11 +50,11 @@ impl BinaryEncoder<ReadProcessedDetails> for ReadProcessedDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let start_time = DateTime::decode(stream, decoding_limits)?;
-        let end_time = DateTime::decode(stream, decoding_limits)?;
-        let processing_interval = f64::decode(stream, decoding_limits)?;
-        let aggregate_type: Option<Vec<NodeId>> = read_array(stream, decoding_limits)?;
-        let aggregate_configuration = AggregateConfiguration::decode(stream, decoding_limits)?;
+        let start_time = decode_field("ReadProcessedDetails", "start_time", DateTime::decode(stream, decoding_limits))?;
+        let end_time = decode_field("ReadProcessedDetails", "end_time", DateTime::decode(stream, decoding_limits))?;
+        let processing_interval = decode_field("ReadProcessedDetails", "processing_interval", f64::decode(stream, decoding_limits))?;
+        let aggregate_type: Option<Vec<NodeId>> = decode_field("ReadProcessedDetails", "aggregate_type", read_array(stream, decoding_limits))?;
+        let aggregate_configuration = decode_field("ReadProcessedDetails", "aggregate_configuration", AggregateConfiguration::decode(stream, decoding_limits))?;
         Ok(ReadProcessedDetails {
             start_time,
             end_time,
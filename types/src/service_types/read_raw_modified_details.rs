@@ -48,11 +48,11 @@ impl BinaryEncoder<ReadRawModifiedDetails> for ReadRawModifiedDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let is_read_modified = bool::decode(stream, decoding_limits)?;
-        let start_time = DateTime::decode(stream, decoding_limits)?;
-        let end_time = DateTime::decode(stream, decoding_limits)?;
-        let num_values_per_node = u32::decode(stream, decoding_limits)?;
-        let return_bounds = bool::decode(stream, decoding_limits)?;
+        let is_read_modified = decode_field("ReadRawModifiedDetails", "is_read_modified", bool::decode(stream, decoding_limits))?;
+        let start_time = decode_field("ReadRawModifiedDetails", "start_time", DateTime::decode(stream, decoding_limits))?;
+        let end_time = decode_field("ReadRawModifiedDetails", "end_time", DateTime::decode(stream, decoding_limits))?;
+        let num_values_per_node = decode_field("ReadRawModifiedDetails", "num_values_per_node", u32::decode(stream, decoding_limits))?;
+        let return_bounds = decode_field("ReadRawModifiedDetails", "return_bounds", bool::decode(stream, decoding_limits))?;
         Ok(ReadRawModifiedDetails {
             is_read_modified,
             start_time,
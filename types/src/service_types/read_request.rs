@@ -55,10 +55,10 @@ impl BinaryEncoder<ReadRequest> for ReadRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let max_age = f64::decode(stream, decoding_limits)?;
-        let timestamps_to_return = TimestampsToReturn::decode(stream, decoding_limits)?;
-        let nodes_to_read: Option<Vec<ReadValueId>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("ReadRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let max_age = decode_field("ReadRequest", "max_age", f64::decode(stream, decoding_limits))?;
+        let timestamps_to_return = decode_field("ReadRequest", "timestamps_to_return", TimestampsToReturn::decode(stream, decoding_limits))?;
+        let nodes_to_read: Option<Vec<ReadValueId>> = decode_field("ReadRequest", "nodes_to_read", read_array(stream, decoding_limits))?;
         Ok(ReadRequest {
             request_header,
             max_age,
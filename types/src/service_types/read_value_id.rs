@@ -55,10 +55,10 @@ impl BinaryEncoder<ReadValueId> for ReadValueId {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
-        let data_encoding = QualifiedName::decode(stream, decoding_limits)?;
+        let node_id = decode_field("ReadValueId", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let attribute_id = decode_field("ReadValueId", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let index_range = decode_field("ReadValueId", "index_range", UAString::decode(stream, decoding_limits))?;
+        let data_encoding = decode_field("ReadValueId", "data_encoding", QualifiedName::decode(stream, decoding_limits))?;
         Ok(ReadValueId {
             node_id,
             attribute_id,
@@ -68,16 +68,16 @@ impl BinaryEncoder<ReaderGroupDataType> for ReaderGroupDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_group_id = UAString::decode(stream, decoding_limits)?;
-        let security_key_services: Option<Vec<EndpointDescription>> = read_array(stream, decoding_limits)?;
-        let max_network_message_size = u32::decode(stream, decoding_limits)?;
-        let group_properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let transport_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let message_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let data_set_readers: Option<Vec<DataSetReaderDataType>> = read_array(stream, decoding_limits)?;
+        let name = decode_field("ReaderGroupDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let enabled = decode_field("ReaderGroupDataType", "enabled", bool::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("ReaderGroupDataType", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_group_id = decode_field("ReaderGroupDataType", "security_group_id", UAString::decode(stream, decoding_limits))?;
+        let security_key_services: Option<Vec<EndpointDescription>> = decode_field("ReaderGroupDataType", "security_key_services", read_array(stream, decoding_limits))?;
+        let max_network_message_size = decode_field("ReaderGroupDataType", "max_network_message_size", u32::decode(stream, decoding_limits))?;
+        let group_properties: Option<Vec<KeyValuePair>> = decode_field("ReaderGroupDataType", "group_properties", read_array(stream, decoding_limits))?;
+        let transport_settings = decode_field("ReaderGroupDataType", "transport_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let message_settings = decode_field("ReaderGroupDataType", "message_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let data_set_readers: Option<Vec<DataSetReaderDataType>> = decode_field("ReaderGroupDataType", "data_set_readers", read_array(stream, decoding_limits))?;
         Ok(ReaderGroupDataType {
             name,
             enabled,
@@ -51,9 +51,9 @@ impl BinaryEncoder<RedundantServerDataType> for RedundantServerDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let server_id = UAString::decode(stream, decoding_limits)?;
-        let service_level = u8::decode(stream, decoding_limits)?;
-        let server_state = ServerState::decode(stream, decoding_limits)?;
+        let server_id = decode_field("RedundantServerDataType", "server_id", UAString::decode(stream, decoding_limits))?;
+        let service_level = decode_field("RedundantServerDataType", "service_level", u8::decode(stream, decoding_limits))?;
+        let server_state = decode_field("RedundantServerDataType", "server_state", ServerState::decode(stream, decoding_limits))?;
         Ok(RedundantServerDataType {
             server_id,
             service_level,
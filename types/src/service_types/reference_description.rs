@@ -66,13 +66,13 @@ impl BinaryEncoder<ReferenceDescription> for ReferenceDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let is_forward = bool::decode(stream, decoding_limits)?;
-        let node_id = ExpandedNodeId::decode(stream, decoding_limits)?;
-        let browse_name = QualifiedName::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let node_class = NodeClass::decode(stream, decoding_limits)?;
-        let type_definition = ExpandedNodeId::decode(stream, decoding_limits)?;
+        let reference_type_id = decode_field("ReferenceDescription", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let is_forward = decode_field("ReferenceDescription", "is_forward", bool::decode(stream, decoding_limits))?;
+        let node_id = decode_field("ReferenceDescription", "node_id", ExpandedNodeId::decode(stream, decoding_limits))?;
+        let browse_name = decode_field("ReferenceDescription", "browse_name", QualifiedName::decode(stream, decoding_limits))?;
+        let display_name = decode_field("ReferenceDescription", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let node_class = decode_field("ReferenceDescription", "node_class", NodeClass::decode(stream, decoding_limits))?;
+        let type_definition = decode_field("ReferenceDescription", "type_definition", ExpandedNodeId::decode(stream, decoding_limits))?;
         Ok(ReferenceDescription {
             reference_type_id,
             is_forward,
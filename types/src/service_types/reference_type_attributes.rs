@@ -57,14 +57,14 @@ impl BinaryEncoder<ReferenceTypeAttributes> for ReferenceTypeAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let is_abstract = bool::decode(stream, decoding_limits)?;
-        let symmetric = bool::decode(stream, decoding_limits)?;
-        let inverse_name = LocalizedText::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("ReferenceTypeAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("ReferenceTypeAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("ReferenceTypeAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("ReferenceTypeAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("ReferenceTypeAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let is_abstract = decode_field("ReferenceTypeAttributes", "is_abstract", bool::decode(stream, decoding_limits))?;
+        let symmetric = decode_field("ReferenceTypeAttributes", "symmetric", bool::decode(stream, decoding_limits))?;
+        let inverse_name = decode_field("ReferenceTypeAttributes", "inverse_name", LocalizedText::decode(stream, decoding_limits))?;
         Ok(ReferenceTypeAttributes {
             specified_attributes,
             display_name,
@@ -48,8 +48,8 @@ impl BinaryEncoder<RegisterNodesRequest> for RegisterNodesRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let nodes_to_register: Option<Vec<NodeId>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("RegisterNodesRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let nodes_to_register: Option<Vec<NodeId>> = decode_field("RegisterNodesRequest", "nodes_to_register", read_array(stream, decoding_limits))?;
         Ok(RegisterNodesRequest {
             request_header,
             nodes_to_register,
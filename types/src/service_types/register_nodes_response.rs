@@ -48,8 +48,8 @@ impl BinaryEncoder<RegisterNodesResponse> for RegisterNodesResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let registered_node_ids: Option<Vec<NodeId>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("RegisterNodesResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let registered_node_ids: Option<Vec<NodeId>> = decode_field("RegisterNodesResponse", "registered_node_ids", read_array(stream, decoding_limits))?;
         Ok(RegisterNodesResponse {
             response_header,
             registered_node_ids,
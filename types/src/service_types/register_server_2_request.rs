@@ -52,9 +52,9 @@ impl BinaryEncoder<RegisterServer2Request> for RegisterServer2Request {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let server = RegisteredServer::decode(stream, decoding_limits)?;
-        let discovery_configuration: Option<Vec<ExtensionObject>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("RegisterServer2Request", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let server = decode_field("RegisterServer2Request", "server", RegisteredServer::decode(stream, decoding_limits))?;
+        let discovery_configuration: Option<Vec<ExtensionObject>> = decode_field("RegisterServer2Request", "discovery_configuration", read_array(stream, decoding_limits))?;
         Ok(RegisterServer2Request {
             request_header,
             server,
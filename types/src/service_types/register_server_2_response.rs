@@ -52,9 +52,9 @@ impl BinaryEncoder<RegisterServer2Response> for RegisterServer2Response {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let configuration_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("RegisterServer2Response", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let configuration_results: Option<Vec<StatusCode>> = decode_field("RegisterServer2Response", "configuration_results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("RegisterServer2Response", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(RegisterServer2Response {
             response_header,
             configuration_results,
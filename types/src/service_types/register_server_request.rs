@@ -48,8 +48,8 @@ impl BinaryEncoder<RegisterServerRequest> for RegisterServerRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let server = RegisteredServer::decode(stream, decoding_limits)?;
+        let request_header = decode_field("RegisterServerRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let server = decode_field("RegisterServerRequest", "server", RegisteredServer::decode(stream, decoding_limits))?;
         Ok(RegisterServerRequest {
             request_header,
             server,
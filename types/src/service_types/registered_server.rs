@@ -67,14 +67,14 @@ impl BinaryEncoder<RegisteredServer> for RegisteredServer {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let server_uri = UAString::decode(stream, decoding_limits)?;
-        let product_uri = UAString::decode(stream, decoding_limits)?;
-        let server_names: Option<Vec<LocalizedText>> = read_array(stream, decoding_limits)?;
-        let server_type = ApplicationType::decode(stream, decoding_limits)?;
-        let gateway_server_uri = UAString::decode(stream, decoding_limits)?;
-        let discovery_urls: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let semaphore_file_path = UAString::decode(stream, decoding_limits)?;
-        let is_online = bool::decode(stream, decoding_limits)?;
+        let server_uri = decode_field("RegisteredServer", "server_uri", UAString::decode(stream, decoding_limits))?;
+        let product_uri = decode_field("RegisteredServer", "product_uri", UAString::decode(stream, decoding_limits))?;
+        let server_names: Option<Vec<LocalizedText>> = decode_field("RegisteredServer", "server_names", read_array(stream, decoding_limits))?;
+        let server_type = decode_field("RegisteredServer", "server_type", ApplicationType::decode(stream, decoding_limits))?;
+        let gateway_server_uri = decode_field("RegisteredServer", "gateway_server_uri", UAString::decode(stream, decoding_limits))?;
+        let discovery_urls: Option<Vec<UAString>> = decode_field("RegisteredServer", "discovery_urls", read_array(stream, decoding_limits))?;
+        let semaphore_file_path = decode_field("RegisteredServer", "semaphore_file_path", UAString::decode(stream, decoding_limits))?;
+        let is_online = decode_field("RegisteredServer", "is_online", bool::decode(stream, decoding_limits))?;
         Ok(RegisteredServer {
             server_uri,
             product_uri,
@@ -44,7 +44,7 @@ impl BinaryEncoder<RelativePath> for RelativePath {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let elements: Option<Vec<RelativePathElement>> = read_array(stream, decoding_limits)?;
+        let elements: Option<Vec<RelativePathElement>> = decode_field("RelativePath", "elements", read_array(stream, decoding_limits))?;
         Ok(RelativePath {
             elements,
         })
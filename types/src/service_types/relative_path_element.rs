@@ -54,10 +54,10 @@ impl BinaryEncoder<RelativePathElement> for RelativePathElement {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let reference_type_id = NodeId::decode(stream, decoding_limits)?;
-        let is_inverse = bool::decode(stream, decoding_limits)?;
-        let include_subtypes = bool::decode(stream, decoding_limits)?;
-        let target_name = QualifiedName::decode(stream, decoding_limits)?;
+        let reference_type_id = decode_field("RelativePathElement", "reference_type_id", NodeId::decode(stream, decoding_limits))?;
+        let is_inverse = decode_field("RelativePathElement", "is_inverse", bool::decode(stream, decoding_limits))?;
+        let include_subtypes = decode_field("RelativePathElement", "include_subtypes", bool::decode(stream, decoding_limits))?;
+        let target_name = decode_field("RelativePathElement", "target_name", QualifiedName::decode(stream, decoding_limits))?;
         Ok(RelativePathElement {
             reference_type_id,
             is_inverse,
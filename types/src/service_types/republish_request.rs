@@ -50,9 +50,9 @@ impl BinaryEncoder<RepublishRequest> for RepublishRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let retransmit_sequence_number = u32::decode(stream, decoding_limits)?;
+        let request_header = decode_field("RepublishRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("RepublishRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let retransmit_sequence_number = decode_field("RepublishRequest", "retransmit_sequence_number", u32::decode(stream, decoding_limits))?;
         Ok(RepublishRequest {
             request_header,
             subscription_id,
@@ -48,8 +48,8 @@ impl BinaryEncoder<RolePermissionType> for RolePermissionType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let role_id = NodeId::decode(stream, decoding_limits)?;
-        let permissions = PermissionType::decode(stream, decoding_limits)?;
+        let role_id = decode_field("RolePermissionType", "role_id", NodeId::decode(stream, decoding_limits))?;
+        let permissions = decode_field("RolePermissionType", "permissions", PermissionType::decode(stream, decoding_limits))?;
         Ok(RolePermissionType {
             role_id,
             permissions,
@@ -52,10 +52,10 @@ impl BinaryEncoder<SamplingIntervalDiagnosticsDataType> for SamplingIntervalDiag
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let sampling_interval = f64::decode(stream, decoding_limits)?;
-        let monitored_item_count = u32::decode(stream, decoding_limits)?;
-        let max_monitored_item_count = u32::decode(stream, decoding_limits)?;
-        let disabled_monitored_item_count = u32::decode(stream, decoding_limits)?;
+        let sampling_interval = decode_field("SamplingIntervalDiagnosticsDataType", "sampling_interval", f64::decode(stream, decoding_limits))?;
+        let monitored_item_count = decode_field("SamplingIntervalDiagnosticsDataType", "monitored_item_count", u32::decode(stream, decoding_limits))?;
+        let max_monitored_item_count = decode_field("SamplingIntervalDiagnosticsDataType", "max_monitored_item_count", u32::decode(stream, decoding_limits))?;
+        let disabled_monitored_item_count = decode_field("SamplingIntervalDiagnosticsDataType", "disabled_monitored_item_count", u32::decode(stream, decoding_limits))?;
         Ok(SamplingIntervalDiagnosticsDataType {
             sampling_interval,
             monitored_item_count,
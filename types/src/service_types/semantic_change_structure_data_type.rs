@@ -47,8 +47,8 @@ impl BinaryEncoder<SemanticChangeStructureDataType> for SemanticChangeStructureD
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let affected = NodeId::decode(stream, decoding_limits)?;
-        let affected_type = NodeId::decode(stream, decoding_limits)?;
+        let affected = decode_field("SemanticChangeStructureDataType", "affected", NodeId::decode(stream, decoding_limits))?;
+        let affected_type = decode_field("SemanticChangeStructureDataType", "affected_type", NodeId::decode(stream, decoding_limits))?;
         Ok(SemanticChangeStructureDataType {
             affected,
             affected_type,
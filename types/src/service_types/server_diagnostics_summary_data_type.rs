@@ -76,18 +76,18 @@ impl BinaryEncoder<ServerDiagnosticsSummaryDataType> for ServerDiagnosticsSummar
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let server_view_count = u32::decode(stream, decoding_limits)?;
-        let current_session_count = u32::decode(stream, decoding_limits)?;
-        let cumulated_session_count = u32::decode(stream, decoding_limits)?;
-        let security_rejected_session_count = u32::decode(stream, decoding_limits)?;
-        let rejected_session_count = u32::decode(stream, decoding_limits)?;
-        let session_timeout_count = u32::decode(stream, decoding_limits)?;
-        let session_abort_count = u32::decode(stream, decoding_limits)?;
-        let current_subscription_count = u32::decode(stream, decoding_limits)?;
-        let cumulated_subscription_count = u32::decode(stream, decoding_limits)?;
-        let publishing_interval_count = u32::decode(stream, decoding_limits)?;
-        let security_rejected_requests_count = u32::decode(stream, decoding_limits)?;
-        let rejected_requests_count = u32::decode(stream, decoding_limits)?;
+        let server_view_count = decode_field("ServerDiagnosticsSummaryDataType", "server_view_count", u32::decode(stream, decoding_limits))?;
+        let current_session_count = decode_field("ServerDiagnosticsSummaryDataType", "current_session_count", u32::decode(stream, decoding_limits))?;
+        let cumulated_session_count = decode_field("ServerDiagnosticsSummaryDataType", "cumulated_session_count", u32::decode(stream, decoding_limits))?;
+        let security_rejected_session_count = decode_field("ServerDiagnosticsSummaryDataType", "security_rejected_session_count", u32::decode(stream, decoding_limits))?;
+        let rejected_session_count = decode_field("ServerDiagnosticsSummaryDataType", "rejected_session_count", u32::decode(stream, decoding_limits))?;
+        let session_timeout_count = decode_field("ServerDiagnosticsSummaryDataType", "session_timeout_count", u32::decode(stream, decoding_limits))?;
+        let session_abort_count = decode_field("ServerDiagnosticsSummaryDataType", "session_abort_count", u32::decode(stream, decoding_limits))?;
+        let current_subscription_count = decode_field("ServerDiagnosticsSummaryDataType", "current_subscription_count", u32::decode(stream, decoding_limits))?;
+        let cumulated_subscription_count = decode_field("ServerDiagnosticsSummaryDataType", "cumulated_subscription_count", u32::decode(stream, decoding_limits))?;
+        let publishing_interval_count = decode_field("ServerDiagnosticsSummaryDataType", "publishing_interval_count", u32::decode(stream, decoding_limits))?;
+        let security_rejected_requests_count = decode_field("ServerDiagnosticsSummaryDataType", "security_rejected_requests_count", u32::decode(stream, decoding_limits))?;
+        let rejected_requests_count = decode_field("ServerDiagnosticsSummaryDataType", "rejected_requests_count", u32::decode(stream, decoding_limits))?;
         Ok(ServerDiagnosticsSummaryDataType {
             server_view_count,
             current_session_count,
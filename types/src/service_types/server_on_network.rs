@@ -53,10 +53,10 @@ impl BinaryEncoder<ServerOnNetwork> for ServerOnNetwork {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let record_id = u32::decode(stream, decoding_limits)?;
-        let server_name = UAString::decode(stream, decoding_limits)?;
-        let discovery_url = UAString::decode(stream, decoding_limits)?;
-        let server_capabilities: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
+        let record_id = decode_field("ServerOnNetwork", "record_id", u32::decode(stream, decoding_limits))?;
+        let server_name = decode_field("ServerOnNetwork", "server_name", UAString::decode(stream, decoding_limits))?;
+        let discovery_url = decode_field("ServerOnNetwork", "discovery_url", UAString::decode(stream, decoding_limits))?;
+        let server_capabilities: Option<Vec<UAString>> = decode_field("ServerOnNetwork", "server_capabilities", read_array(stream, decoding_limits))?;
         Ok(ServerOnNetwork {
             record_id,
             server_name,
@@ -62,12 +62,12 @@ impl BinaryEncoder<ServerStatusDataType> for ServerStatusDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let start_time = DateTime::decode(stream, decoding_limits)?;
-        let current_time = DateTime::decode(stream, decoding_limits)?;
-        let state = ServerState::decode(stream, decoding_limits)?;
-        let build_info = BuildInfo::decode(stream, decoding_limits)?;
-        let seconds_till_shutdown = u32::decode(stream, decoding_limits)?;
-        let shutdown_reason = LocalizedText::decode(stream, decoding_limits)?;
+        let start_time = decode_field("ServerStatusDataType", "start_time", DateTime::decode(stream, decoding_limits))?;
+        let current_time = decode_field("ServerStatusDataType", "current_time", DateTime::decode(stream, decoding_limits))?;
+        let state = decode_field("ServerStatusDataType", "state", ServerState::decode(stream, decoding_limits))?;
+        let build_info = decode_field("ServerStatusDataType", "build_info", BuildInfo::decode(stream, decoding_limits))?;
+        let seconds_till_shutdown = decode_field("ServerStatusDataType", "seconds_till_shutdown", u32::decode(stream, decoding_limits))?;
+        let shutdown_reason = decode_field("ServerStatusDataType", "shutdown_reason", LocalizedText::decode(stream, decoding_limits))?;
         Ok(ServerStatusDataType {
             start_time,
             current_time,
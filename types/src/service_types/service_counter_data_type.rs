@@ -46,8 +46,8 @@ impl BinaryEncoder<ServiceCounterDataType> for ServiceCounterDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let total_count = u32::decode(stream, decoding_limits)?;
-        let error_count = u32::decode(stream, decoding_limits)?;
+        let total_count = decode_field("ServiceCounterDataType", "total_count", u32::decode(stream, decoding_limits))?;
+        let error_count = decode_field("ServiceCounterDataType", "error_count", u32::decode(stream, decoding_limits))?;
         Ok(ServiceCounterDataType {
             total_count,
             error_count,
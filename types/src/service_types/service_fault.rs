@@ -44,7 +44,7 @@ impl BinaryEncoder<ServiceFault> for ServiceFault {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
+        let response_header = decode_field("ServiceFault", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
         Ok(ServiceFault {
             response_header,
         })
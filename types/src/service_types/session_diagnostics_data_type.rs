@@ -174,49 +174,49 @@ impl BinaryEncoder<SessionDiagnosticsDataType> for SessionDiagnosticsDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let session_id = NodeId::decode(stream, decoding_limits)?;
-        let session_name = UAString::decode(stream, decoding_limits)?;
-        let client_description = ApplicationDescription::decode(stream, decoding_limits)?;
-        let server_uri = UAString::decode(stream, decoding_limits)?;
-        let endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let locale_ids: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let actual_session_timeout = f64::decode(stream, decoding_limits)?;
-        let max_response_message_size = u32::decode(stream, decoding_limits)?;
-        let client_connection_time = DateTime::decode(stream, decoding_limits)?;
-        let client_last_contact_time = DateTime::decode(stream, decoding_limits)?;
-        let current_subscriptions_count = u32::decode(stream, decoding_limits)?;
-        let current_monitored_items_count = u32::decode(stream, decoding_limits)?;
-        let current_publish_requests_in_queue = u32::decode(stream, decoding_limits)?;
-        let total_request_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let unauthorized_request_count = u32::decode(stream, decoding_limits)?;
-        let read_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let history_read_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let write_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let history_update_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let call_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let create_monitored_items_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let modify_monitored_items_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let set_monitoring_mode_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let set_triggering_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let delete_monitored_items_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let create_subscription_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let modify_subscription_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let set_publishing_mode_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let publish_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let republish_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let transfer_subscriptions_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let delete_subscriptions_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let add_nodes_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let add_references_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let delete_nodes_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let delete_references_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let browse_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let browse_next_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let translate_browse_paths_to_node_ids_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let query_first_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let query_next_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let register_nodes_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
-        let unregister_nodes_count = ServiceCounterDataType::decode(stream, decoding_limits)?;
+        let session_id = decode_field("SessionDiagnosticsDataType", "session_id", NodeId::decode(stream, decoding_limits))?;
+        let session_name = decode_field("SessionDiagnosticsDataType", "session_name", UAString::decode(stream, decoding_limits))?;
+        let client_description = decode_field("SessionDiagnosticsDataType", "client_description", ApplicationDescription::decode(stream, decoding_limits))?;
+        let server_uri = decode_field("SessionDiagnosticsDataType", "server_uri", UAString::decode(stream, decoding_limits))?;
+        let endpoint_url = decode_field("SessionDiagnosticsDataType", "endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let locale_ids: Option<Vec<UAString>> = decode_field("SessionDiagnosticsDataType", "locale_ids", read_array(stream, decoding_limits))?;
+        let actual_session_timeout = decode_field("SessionDiagnosticsDataType", "actual_session_timeout", f64::decode(stream, decoding_limits))?;
+        let max_response_message_size = decode_field("SessionDiagnosticsDataType", "max_response_message_size", u32::decode(stream, decoding_limits))?;
+        let client_connection_time = decode_field("SessionDiagnosticsDataType", "client_connection_time", DateTime::decode(stream, decoding_limits))?;
+        let client_last_contact_time = decode_field("SessionDiagnosticsDataType", "client_last_contact_time", DateTime::decode(stream, decoding_limits))?;
+        let current_subscriptions_count = decode_field("SessionDiagnosticsDataType", "current_subscriptions_count", u32::decode(stream, decoding_limits))?;
+        let current_monitored_items_count = decode_field("SessionDiagnosticsDataType", "current_monitored_items_count", u32::decode(stream, decoding_limits))?;
+        let current_publish_requests_in_queue = decode_field("SessionDiagnosticsDataType", "current_publish_requests_in_queue", u32::decode(stream, decoding_limits))?;
+        let total_request_count = decode_field("SessionDiagnosticsDataType", "total_request_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let unauthorized_request_count = decode_field("SessionDiagnosticsDataType", "unauthorized_request_count", u32::decode(stream, decoding_limits))?;
+        let read_count = decode_field("SessionDiagnosticsDataType", "read_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let history_read_count = decode_field("SessionDiagnosticsDataType", "history_read_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let write_count = decode_field("SessionDiagnosticsDataType", "write_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let history_update_count = decode_field("SessionDiagnosticsDataType", "history_update_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let call_count = decode_field("SessionDiagnosticsDataType", "call_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let create_monitored_items_count = decode_field("SessionDiagnosticsDataType", "create_monitored_items_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let modify_monitored_items_count = decode_field("SessionDiagnosticsDataType", "modify_monitored_items_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let set_monitoring_mode_count = decode_field("SessionDiagnosticsDataType", "set_monitoring_mode_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let set_triggering_count = decode_field("SessionDiagnosticsDataType", "set_triggering_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let delete_monitored_items_count = decode_field("SessionDiagnosticsDataType", "delete_monitored_items_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let create_subscription_count = decode_field("SessionDiagnosticsDataType", "create_subscription_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let modify_subscription_count = decode_field("SessionDiagnosticsDataType", "modify_subscription_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let set_publishing_mode_count = decode_field("SessionDiagnosticsDataType", "set_publishing_mode_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let publish_count = decode_field("SessionDiagnosticsDataType", "publish_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let republish_count = decode_field("SessionDiagnosticsDataType", "republish_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let transfer_subscriptions_count = decode_field("SessionDiagnosticsDataType", "transfer_subscriptions_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let delete_subscriptions_count = decode_field("SessionDiagnosticsDataType", "delete_subscriptions_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let add_nodes_count = decode_field("SessionDiagnosticsDataType", "add_nodes_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let add_references_count = decode_field("SessionDiagnosticsDataType", "add_references_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let delete_nodes_count = decode_field("SessionDiagnosticsDataType", "delete_nodes_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let delete_references_count = decode_field("SessionDiagnosticsDataType", "delete_references_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let browse_count = decode_field("SessionDiagnosticsDataType", "browse_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let browse_next_count = decode_field("SessionDiagnosticsDataType", "browse_next_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let translate_browse_paths_to_node_ids_count = decode_field("SessionDiagnosticsDataType", "translate_browse_paths_to_node_ids_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let query_first_count = decode_field("SessionDiagnosticsDataType", "query_first_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let query_next_count = decode_field("SessionDiagnosticsDataType", "query_next_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let register_nodes_count = decode_field("SessionDiagnosticsDataType", "register_nodes_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
+        let unregister_nodes_count = decode_field("SessionDiagnosticsDataType", "unregister_nodes_count", ServiceCounterDataType::decode(stream, decoding_limits))?;
         Ok(SessionDiagnosticsDataType {
             session_id,
             session_name,
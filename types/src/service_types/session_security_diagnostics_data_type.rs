@@ -71,15 +71,15 @@ impl BinaryEncoder<SessionSecurityDiagnosticsDataType> for SessionSecurityDiagno
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let session_id = NodeId::decode(stream, decoding_limits)?;
-        let client_user_id_of_session = UAString::decode(stream, decoding_limits)?;
-        let client_user_id_history: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let authentication_mechanism = UAString::decode(stream, decoding_limits)?;
-        let encoding = UAString::decode(stream, decoding_limits)?;
-        let transport_protocol = UAString::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_policy_uri = UAString::decode(stream, decoding_limits)?;
-        let client_certificate = ByteString::decode(stream, decoding_limits)?;
+        let session_id = decode_field("SessionSecurityDiagnosticsDataType", "session_id", NodeId::decode(stream, decoding_limits))?;
+        let client_user_id_of_session = decode_field("SessionSecurityDiagnosticsDataType", "client_user_id_of_session", UAString::decode(stream, decoding_limits))?;
+        let client_user_id_history: Option<Vec<UAString>> = decode_field("SessionSecurityDiagnosticsDataType", "client_user_id_history", read_array(stream, decoding_limits))?;
+        let authentication_mechanism = decode_field("SessionSecurityDiagnosticsDataType", "authentication_mechanism", UAString::decode(stream, decoding_limits))?;
+        let encoding = decode_field("SessionSecurityDiagnosticsDataType", "encoding", UAString::decode(stream, decoding_limits))?;
+        let transport_protocol = decode_field("SessionSecurityDiagnosticsDataType", "transport_protocol", UAString::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("SessionSecurityDiagnosticsDataType", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_policy_uri = decode_field("SessionSecurityDiagnosticsDataType", "security_policy_uri", UAString::decode(stream, decoding_limits))?;
+        let client_certificate = decode_field("SessionSecurityDiagnosticsDataType", "client_certificate", ByteString::decode(stream, decoding_limits))?;
         Ok(SessionSecurityDiagnosticsDataType {
             session_id,
             client_user_id_of_session,
@@ -56,11 +56,11 @@ impl BinaryEncoder<SessionlessInvokeRequestType> for SessionlessInvokeRequestTyp
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let uris_version: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let namespace_uris: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let server_uris: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let locale_ids: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let service_id = u32::decode(stream, decoding_limits)?;
+        let uris_version: Option<Vec<u32>> = decode_field("SessionlessInvokeRequestType", "uris_version", read_array(stream, decoding_limits))?;
+        let namespace_uris: Option<Vec<UAString>> = decode_field("SessionlessInvokeRequestType", "namespace_uris", read_array(stream, decoding_limits))?;
+        let server_uris: Option<Vec<UAString>> = decode_field("SessionlessInvokeRequestType", "server_uris", read_array(stream, decoding_limits))?;
+        let locale_ids: Option<Vec<UAString>> = decode_field("SessionlessInvokeRequestType", "locale_ids", read_array(stream, decoding_limits))?;
+        let service_id = decode_field("SessionlessInvokeRequestType", "service_id", u32::decode(stream, decoding_limits))?;
         Ok(SessionlessInvokeRequestType {
             uris_version,
             namespace_uris,
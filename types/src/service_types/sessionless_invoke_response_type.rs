@@ -50,9 +50,9 @@ impl BinaryEncoder<SessionlessInvokeResponseType> for SessionlessInvokeResponseT
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let namespace_uris: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let server_uris: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let service_id = u32::decode(stream, decoding_limits)?;
+        let namespace_uris: Option<Vec<UAString>> = decode_field("SessionlessInvokeResponseType", "namespace_uris", read_array(stream, decoding_limits))?;
+        let server_uris: Option<Vec<UAString>> = decode_field("SessionlessInvokeResponseType", "server_uris", read_array(stream, decoding_limits))?;
+        let service_id = decode_field("SessionlessInvokeResponseType", "service_id", u32::decode(stream, decoding_limits))?;
         Ok(SessionlessInvokeResponseType {
             namespace_uris,
             server_uris,
@@ -54,10 +54,10 @@ impl BinaryEncoder<SetMonitoringModeRequest> for SetMonitoringModeRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let monitoring_mode = MonitoringMode::decode(stream, decoding_limits)?;
-        let monitored_item_ids: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("SetMonitoringModeRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("SetMonitoringModeRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let monitoring_mode = decode_field("SetMonitoringModeRequest", "monitoring_mode", MonitoringMode::decode(stream, decoding_limits))?;
+        let monitored_item_ids: Option<Vec<u32>> = decode_field("SetMonitoringModeRequest", "monitored_item_ids", read_array(stream, decoding_limits))?;
         Ok(SetMonitoringModeRequest {
             request_header,
             subscription_id,
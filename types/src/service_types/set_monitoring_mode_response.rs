@@ -52,9 +52,9 @@ impl BinaryEncoder<SetMonitoringModeResponse> for SetMonitoringModeResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("SetMonitoringModeResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<StatusCode>> = decode_field("SetMonitoringModeResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("SetMonitoringModeResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(SetMonitoringModeResponse {
             response_header,
             results,
@@ -50,9 +50,9 @@ impl BinaryEncoder<SetPublishingModeRequest> for SetPublishingModeRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let publishing_enabled = bool::decode(stream, decoding_limits)?;
-        let subscription_ids: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("SetPublishingModeRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let publishing_enabled = decode_field("SetPublishingModeRequest", "publishing_enabled", bool::decode(stream, decoding_limits))?;
+        let subscription_ids: Option<Vec<u32>> = decode_field("SetPublishingModeRequest", "subscription_ids", read_array(stream, decoding_limits))?;
         Ok(SetPublishingModeRequest {
             request_header,
             publishing_enabled,
@@ -56,11 +56,11 @@ impl BinaryEncoder<SetTriggeringRequest> for SetTriggeringRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let triggering_item_id = u32::decode(stream, decoding_limits)?;
-        let links_to_add: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let links_to_remove: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("SetTriggeringRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("SetTriggeringRequest", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let triggering_item_id = decode_field("SetTriggeringRequest", "triggering_item_id", u32::decode(stream, decoding_limits))?;
+        let links_to_add: Option<Vec<u32>> = decode_field("SetTriggeringRequest", "links_to_add", read_array(stream, decoding_limits))?;
+        let links_to_remove: Option<Vec<u32>> = decode_field("SetTriggeringRequest", "links_to_remove", read_array(stream, decoding_limits))?;
         Ok(SetTriggeringRequest {
             request_header,
             subscription_id,
@@ -58,11 +58,11 @@ impl BinaryEncoder<SetTriggeringResponse> for SetTriggeringResponse {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let add_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let add_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
-        let remove_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
-        let remove_diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("SetTriggeringResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let add_results: Option<Vec<StatusCode>> = decode_field("SetTriggeringResponse", "add_results", read_array(stream, decoding_limits))?;
+        let add_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("SetTriggeringResponse", "add_diagnostic_infos", read_array(stream, decoding_limits))?;
+        let remove_results: Option<Vec<StatusCode>> = decode_field("SetTriggeringResponse", "remove_results", read_array(stream, decoding_limits))?;
+        let remove_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("SetTriggeringResponse", "remove_diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(SetTriggeringResponse {
             response_header,
             add_results,
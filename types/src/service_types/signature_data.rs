@@ -48,8 +48,8 @@ impl BinaryEncoder<SignatureData> for SignatureData {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let algorithm = UAString::decode(stream, decoding_limits)?;
-        let signature = ByteString::decode(stream, decoding_limits)?;
+        let algorithm = decode_field("SignatureData", "algorithm", UAString::decode(stream, decoding_limits))?;
+        let signature = decode_field("SignatureData", "signature", ByteString::decode(stream, decoding_limits))?;
         Ok(SignatureData {
             algorithm,
             signature,
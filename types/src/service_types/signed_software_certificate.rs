@@ -47,8 +47,8 @@ impl BinaryEncoder<SignedSoftwareCertificate> for SignedSoftwareCertificate {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let certificate_data = ByteString::decode(stream, decoding_limits)?;
-        let signature = ByteString::decode(stream, decoding_limits)?;
+        let certificate_data = decode_field("SignedSoftwareCertificate", "certificate_data", ByteString::decode(stream, decoding_limits))?;
+        let signature = decode_field("SignedSoftwareCertificate", "signature", ByteString::decode(stream, decoding_limits))?;
         Ok(SignedSoftwareCertificate {
             certificate_data,
             signature,
@@ -47,10 +47,10 @@ impl BinaryEncoder<SimpleAttributeOperand> for SimpleAttributeOperand {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let type_definition_id = NodeId::decode(stream, decoding_limits)?;
-        let browse_path: Option<Vec<QualifiedName>> = read_array(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
+        let type_definition_id = decode_field("SimpleAttributeOperand", "type_definition_id", NodeId::decode(stream, decoding_limits))?;
+        let browse_path: Option<Vec<QualifiedName>> = decode_field("SimpleAttributeOperand", "browse_path", read_array(stream, decoding_limits))?;
+        let attribute_id = decode_field("SimpleAttributeOperand", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let index_range = decode_field("SimpleAttributeOperand", "index_range", UAString::decode(stream, decoding_limits))?;
         Ok(SimpleAttributeOperand {
             type_definition_id,
             browse_path,
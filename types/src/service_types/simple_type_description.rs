@@ -46,10 +46,10 @@ impl BinaryEncoder<SimpleTypeDescription> for SimpleTypeDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_type_id = NodeId::decode(stream, decoding_limits)?;
-        let name = QualifiedName::decode(stream, decoding_limits)?;
-        let base_data_type = NodeId::decode(stream, decoding_limits)?;
-        let built_in_type = u8::decode(stream, decoding_limits)?;
+        let data_type_id = decode_field("SimpleTypeDescription", "data_type_id", NodeId::decode(stream, decoding_limits))?;
+        let name = decode_field("SimpleTypeDescription", "name", QualifiedName::decode(stream, decoding_limits))?;
+        let base_data_type = decode_field("SimpleTypeDescription", "base_data_type", NodeId::decode(stream, decoding_limits))?;
+        let built_in_type = decode_field("SimpleTypeDescription", "built_in_type", u8::decode(stream, decoding_limits))?;
         Ok(SimpleTypeDescription {
             data_type_id,
             name,
@@ -40,8 +40,8 @@ impl BinaryEncoder<StatusChangeNotification> for StatusChangeNotification {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status = StatusCode::decode(stream, decoding_limits)?;
-        let diagnostic_info = DiagnosticInfo::decode(stream, decoding_limits)?;
+        let status = decode_field("StatusChangeNotification", "status", StatusCode::decode(stream, decoding_limits))?;
+        let diagnostic_info = decode_field("StatusChangeNotification", "diagnostic_info", DiagnosticInfo::decode(stream, decoding_limits))?;
         Ok(StatusChangeNotification {
             status,
             diagnostic_info,
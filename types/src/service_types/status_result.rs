@@ -48,8 +48,8 @@ impl BinaryEncoder<StatusResult> for StatusResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let diagnostic_info = DiagnosticInfo::decode(stream, decoding_limits)?;
+        let status_code = decode_field("StatusResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let diagnostic_info = decode_field("StatusResult", "diagnostic_info", DiagnosticInfo::decode(stream, decoding_limits))?;
         Ok(StatusResult {
             status_code,
             diagnostic_info,
@@ -47,10 +47,10 @@ impl BinaryEncoder<StructureDefinition> for StructureDefinition {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let default_encoding_id = NodeId::decode(stream, decoding_limits)?;
-        let base_data_type = NodeId::decode(stream, decoding_limits)?;
-        let structure_type = StructureType::decode(stream, decoding_limits)?;
-        let fields: Option<Vec<StructureField>> = read_array(stream, decoding_limits)?;
+        let default_encoding_id = decode_field("StructureDefinition", "default_encoding_id", NodeId::decode(stream, decoding_limits))?;
+        let base_data_type = decode_field("StructureDefinition", "base_data_type", NodeId::decode(stream, decoding_limits))?;
+        let structure_type = decode_field("StructureDefinition", "structure_type", StructureType::decode(stream, decoding_limits))?;
+        let fields: Option<Vec<StructureField>> = decode_field("StructureDefinition", "fields", read_array(stream, decoding_limits))?;
         Ok(StructureDefinition {
             default_encoding_id,
             base_data_type,
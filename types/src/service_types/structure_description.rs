@@ -44,9 +44,9 @@ impl BinaryEncoder<StructureDescription> for StructureDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_type_id = NodeId::decode(stream, decoding_limits)?;
-        let name = QualifiedName::decode(stream, decoding_limits)?;
-        let structure_definition = StructureDefinition::decode(stream, decoding_limits)?;
+        let data_type_id = decode_field("StructureDescription", "data_type_id", NodeId::decode(stream, decoding_limits))?;
+        let name = decode_field("StructureDescription", "name", QualifiedName::decode(stream, decoding_limits))?;
+        let structure_definition = decode_field("StructureDescription", "structure_definition", StructureDefinition::decode(stream, decoding_limits))?;
         Ok(StructureDescription {
             data_type_id,
             name,
@@ -64,13 +64,13 @@ impl BinaryEncoder<StructureField> for StructureField {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let data_type = NodeId::decode(stream, decoding_limits)?;
-        let value_rank = i32::decode(stream, decoding_limits)?;
-        let array_dimensions: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let max_string_length = u32::decode(stream, decoding_limits)?;
-        let is_optional = bool::decode(stream, decoding_limits)?;
+        let name = decode_field("StructureField", "name", UAString::decode(stream, decoding_limits))?;
+        let description = decode_field("StructureField", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let data_type = decode_field("StructureField", "data_type", NodeId::decode(stream, decoding_limits))?;
+        let value_rank = decode_field("StructureField", "value_rank", i32::decode(stream, decoding_limits))?;
+        let array_dimensions: Option<Vec<u32>> = decode_field("StructureField", "array_dimensions", read_array(stream, decoding_limits))?;
+        let max_string_length = decode_field("StructureField", "max_string_length", u32::decode(stream, decoding_limits))?;
+        let is_optional = decode_field("StructureField", "is_optional", bool::decode(stream, decoding_limits))?;
         Ok(StructureField {
             name,
             description,
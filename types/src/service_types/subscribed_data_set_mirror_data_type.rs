@@ -40,8 +40,8 @@ impl BinaryEncoder<SubscribedDataSetMirrorDataType> for SubscribedDataSetMirrorD
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let parent_node_name = UAString::decode(stream, decoding_limits)?;
-        let role_permissions: Option<Vec<RolePermissionType>> = read_array(stream, decoding_limits)?;
+        let parent_node_name = decode_field("SubscribedDataSetMirrorDataType", "parent_node_name", UAString::decode(stream, decoding_limits))?;
+        let role_permissions: Option<Vec<RolePermissionType>> = decode_field("SubscribedDataSetMirrorDataType", "role_permissions", read_array(stream, decoding_limits))?;
         Ok(SubscribedDataSetMirrorDataType {
             parent_node_name,
             role_permissions,
@@ -46,8 +46,8 @@ impl BinaryEncoder<SubscriptionAcknowledgement> for SubscriptionAcknowledgement
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let sequence_number = u32::decode(stream, decoding_limits)?;
+        let subscription_id = decode_field("SubscriptionAcknowledgement", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let sequence_number = decode_field("SubscriptionAcknowledgement", "sequence_number", u32::decode(stream, decoding_limits))?;
         Ok(SubscriptionAcknowledgement {
             subscription_id,
             sequence_number,
@@ -134,37 +134,37 @@ impl BinaryEncoder<SubscriptionDiagnosticsDataType> for SubscriptionDiagnosticsD
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let session_id = NodeId::decode(stream, decoding_limits)?;
-        let subscription_id = u32::decode(stream, decoding_limits)?;
-        let priority = u8::decode(stream, decoding_limits)?;
-        let publishing_interval = f64::decode(stream, decoding_limits)?;
-        let max_keep_alive_count = u32::decode(stream, decoding_limits)?;
-        let max_lifetime_count = u32::decode(stream, decoding_limits)?;
-        let max_notifications_per_publish = u32::decode(stream, decoding_limits)?;
-        let publishing_enabled = bool::decode(stream, decoding_limits)?;
-        let modify_count = u32::decode(stream, decoding_limits)?;
-        let enable_count = u32::decode(stream, decoding_limits)?;
-        let disable_count = u32::decode(stream, decoding_limits)?;
-        let republish_request_count = u32::decode(stream, decoding_limits)?;
-        let republish_message_request_count = u32::decode(stream, decoding_limits)?;
-        let republish_message_count = u32::decode(stream, decoding_limits)?;
-        let transfer_request_count = u32::decode(stream, decoding_limits)?;
-        let transferred_to_alt_client_count = u32::decode(stream, decoding_limits)?;
-        let transferred_to_same_client_count = u32::decode(stream, decoding_limits)?;
-        let publish_request_count = u32::decode(stream, decoding_limits)?;
-        let data_change_notifications_count = u32::decode(stream, decoding_limits)?;
-        let event_notifications_count = u32::decode(stream, decoding_limits)?;
-        let notifications_count = u32::decode(stream, decoding_limits)?;
-        let late_publish_request_count = u32::decode(stream, decoding_limits)?;
-        let current_keep_alive_count = u32::decode(stream, decoding_limits)?;
-        let current_lifetime_count = u32::decode(stream, decoding_limits)?;
-        let unacknowledged_message_count = u32::decode(stream, decoding_limits)?;
-        let discarded_message_count = u32::decode(stream, decoding_limits)?;
-        let monitored_item_count = u32::decode(stream, decoding_limits)?;
-        let disabled_monitored_item_count = u32::decode(stream, decoding_limits)?;
-        let monitoring_queue_overflow_count = u32::decode(stream, decoding_limits)?;
-        let next_sequence_number = u32::decode(stream, decoding_limits)?;
-        let event_queue_over_flow_count = u32::decode(stream, decoding_limits)?;
+        let session_id = decode_field("SubscriptionDiagnosticsDataType", "session_id", NodeId::decode(stream, decoding_limits))?;
+        let subscription_id = decode_field("SubscriptionDiagnosticsDataType", "subscription_id", u32::decode(stream, decoding_limits))?;
+        let priority = decode_field("SubscriptionDiagnosticsDataType", "priority", u8::decode(stream, decoding_limits))?;
+        let publishing_interval = decode_field("SubscriptionDiagnosticsDataType", "publishing_interval", f64::decode(stream, decoding_limits))?;
+        let max_keep_alive_count = decode_field("SubscriptionDiagnosticsDataType", "max_keep_alive_count", u32::decode(stream, decoding_limits))?;
+        let max_lifetime_count = decode_field("SubscriptionDiagnosticsDataType", "max_lifetime_count", u32::decode(stream, decoding_limits))?;
+        let max_notifications_per_publish = decode_field("SubscriptionDiagnosticsDataType", "max_notifications_per_publish", u32::decode(stream, decoding_limits))?;
+        let publishing_enabled = decode_field("SubscriptionDiagnosticsDataType", "publishing_enabled", bool::decode(stream, decoding_limits))?;
+        let modify_count = decode_field("SubscriptionDiagnosticsDataType", "modify_count", u32::decode(stream, decoding_limits))?;
+        let enable_count = decode_field("SubscriptionDiagnosticsDataType", "enable_count", u32::decode(stream, decoding_limits))?;
+        let disable_count = decode_field("SubscriptionDiagnosticsDataType", "disable_count", u32::decode(stream, decoding_limits))?;
+        let republish_request_count = decode_field("SubscriptionDiagnosticsDataType", "republish_request_count", u32::decode(stream, decoding_limits))?;
+        let republish_message_request_count = decode_field("SubscriptionDiagnosticsDataType", "republish_message_request_count", u32::decode(stream, decoding_limits))?;
+        let republish_message_count = decode_field("SubscriptionDiagnosticsDataType", "republish_message_count", u32::decode(stream, decoding_limits))?;
+        let transfer_request_count = decode_field("SubscriptionDiagnosticsDataType", "transfer_request_count", u32::decode(stream, decoding_limits))?;
+        let transferred_to_alt_client_count = decode_field("SubscriptionDiagnosticsDataType", "transferred_to_alt_client_count", u32::decode(stream, decoding_limits))?;
+        let transferred_to_same_client_count = decode_field("SubscriptionDiagnosticsDataType", "transferred_to_same_client_count", u32::decode(stream, decoding_limits))?;
+        let publish_request_count = decode_field("SubscriptionDiagnosticsDataType", "publish_request_count", u32::decode(stream, decoding_limits))?;
+        let data_change_notifications_count = decode_field("SubscriptionDiagnosticsDataType", "data_change_notifications_count", u32::decode(stream, decoding_limits))?;
+        let event_notifications_count = decode_field("SubscriptionDiagnosticsDataType", "event_notifications_count", u32::decode(stream, decoding_limits))?;
+        let notifications_count = decode_field("SubscriptionDiagnosticsDataType", "notifications_count", u32::decode(stream, decoding_limits))?;
+        let late_publish_request_count = decode_field("SubscriptionDiagnosticsDataType", "late_publish_request_count", u32::decode(stream, decoding_limits))?;
+        let current_keep_alive_count = decode_field("SubscriptionDiagnosticsDataType", "current_keep_alive_count", u32::decode(stream, decoding_limits))?;
+        let current_lifetime_count = decode_field("SubscriptionDiagnosticsDataType", "current_lifetime_count", u32::decode(stream, decoding_limits))?;
+        let unacknowledged_message_count = decode_field("SubscriptionDiagnosticsDataType", "unacknowledged_message_count", u32::decode(stream, decoding_limits))?;
+        let discarded_message_count = decode_field("SubscriptionDiagnosticsDataType", "discarded_message_count", u32::decode(stream, decoding_limits))?;
+        let monitored_item_count = decode_field("SubscriptionDiagnosticsDataType", "monitored_item_count", u32::decode(stream, decoding_limits))?;
+        let disabled_monitored_item_count = decode_field("SubscriptionDiagnosticsDataType", "disabled_monitored_item_count", u32::decode(stream, decoding_limits))?;
+        let monitoring_queue_overflow_count = decode_field("SubscriptionDiagnosticsDataType", "monitoring_queue_overflow_count", u32::decode(stream, decoding_limits))?;
+        let next_sequence_number = decode_field("SubscriptionDiagnosticsDataType", "next_sequence_number", u32::decode(stream, decoding_limits))?;
+        let event_queue_over_flow_count = decode_field("SubscriptionDiagnosticsDataType", "event_queue_over_flow_count", u32::decode(stream, decoding_limits))?;
         Ok(SubscriptionDiagnosticsDataType {
             session_id,
             subscription_id,
@@ -36,7 +36,7 @@ impl BinaryEncoder<TargetVariablesDataType> for TargetVariablesDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let target_variables: Option<Vec<FieldTargetDataType>> = read_array(stream, decoding_limits)?;
+        let target_variables: Option<Vec<FieldTargetDataType>> = decode_field("TargetVariablesDataType", "target_variables", read_array(stream, decoding_limits))?;
         Ok(TargetVariablesDataType {
             target_variables,
         })
@@ -41,9 +41,9 @@ impl BinaryEncoder<ThreeDCartesianCoordinates> for ThreeDCartesianCoordinates {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let x = f64::decode(stream, decoding_limits)?;
-        let y = f64::decode(stream, decoding_limits)?;
-        let z = f64::decode(stream, decoding_limits)?;
+        let x = decode_field("ThreeDCartesianCoordinates", "x", f64::decode(stream, decoding_limits))?;
+        let y = decode_field("ThreeDCartesianCoordinates", "y", f64::decode(stream, decoding_limits))?;
+        let z = decode_field("ThreeDCartesianCoordinates", "z", f64::decode(stream, decoding_limits))?;
         Ok(ThreeDCartesianCoordinates {
             x,
             y,
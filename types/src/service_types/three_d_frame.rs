@@ -40,8 +40,8 @@ impl BinaryEncoder<ThreeDFrame> for ThreeDFrame {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let cartesian_coordinates = ThreeDCartesianCoordinates::decode(stream, decoding_limits)?;
-        let orientation = ThreeDOrientation::decode(stream, decoding_limits)?;
+        let cartesian_coordinates = decode_field("ThreeDFrame", "cartesian_coordinates", ThreeDCartesianCoordinates::decode(stream, decoding_limits))?;
+        let orientation = decode_field("ThreeDFrame", "orientation", ThreeDOrientation::decode(stream, decoding_limits))?;
         Ok(ThreeDFrame {
             cartesian_coordinates,
             orientation,
@@ -41,9 +41,9 @@ impl BinaryEncoder<ThreeDOrientation> for ThreeDOrientation {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let a = f64::decode(stream, decoding_limits)?;
-        let b = f64::decode(stream, decoding_limits)?;
-        let c = f64::decode(stream, decoding_limits)?;
+        let a = decode_field("ThreeDOrientation", "a", f64::decode(stream, decoding_limits))?;
+        let b = decode_field("ThreeDOrientation", "b", f64::decode(stream, decoding_limits))?;
+        let c = decode_field("ThreeDOrientation", "c", f64::decode(stream, decoding_limits))?;
         Ok(ThreeDOrientation {
             a,
             b,
@@ -41,9 +41,9 @@ impl BinaryEncoder<ThreeDVector> for ThreeDVector {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let x = f64::decode(stream, decoding_limits)?;
-        let y = f64::decode(stream, decoding_limits)?;
-        let z = f64::decode(stream, decoding_limits)?;
+        let x = decode_field("ThreeDVector", "x", f64::decode(stream, decoding_limits))?;
+        let y = decode_field("ThreeDVector", "y", f64::decode(stream, decoding_limits))?;
+        let z = decode_field("ThreeDVector", "z", f64::decode(stream, decoding_limits))?;
         Ok(ThreeDVector {
             x,
             y,
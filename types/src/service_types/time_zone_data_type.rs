@@ -46,8 +46,8 @@ impl BinaryEncoder<TimeZoneDataType> for TimeZoneDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let offset = i16::decode(stream, decoding_limits)?;
-        let daylight_saving_in_offset = bool::decode(stream, decoding_limits)?;
+        let offset = decode_field("TimeZoneDataType", "offset", i16::decode(stream, decoding_limits))?;
+        let daylight_saving_in_offset = decode_field("TimeZoneDataType", "daylight_saving_in_offset", bool::decode(stream, decoding_limits))?;
         Ok(TimeZoneDataType {
             offset,
             daylight_saving_in_offset,
@@ -47,8 +47,8 @@ impl BinaryEncoder<TransferResult> for TransferResult {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let status_code = StatusCode::decode(stream, decoding_limits)?;
-        let available_sequence_numbers: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
+        let status_code = decode_field("TransferResult", "status_code", StatusCode::decode(stream, decoding_limits))?;
+        let available_sequence_numbers: Option<Vec<u32>> = decode_field("TransferResult", "available_sequence_numbers", read_array(stream, decoding_limits))?;
         Ok(TransferResult {
             status_code,
             available_sequence_numbers,
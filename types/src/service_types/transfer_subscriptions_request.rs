@@ -50,9 +50,9 @@ impl BinaryEncoder<TransferSubscriptionsRequest> for TransferSubscriptionsReques
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let subscription_ids: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let send_initial_values = bool::decode(stream, decoding_limits)?;
+        let request_header = decode_field("TransferSubscriptionsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let subscription_ids: Option<Vec<u32>> = decode_field("TransferSubscriptionsRequest", "subscription_ids", read_array(stream, decoding_limits))?;
+        let send_initial_values = decode_field("TransferSubscriptionsRequest", "send_initial_values", bool::decode(stream, decoding_limits))?;
         Ok(TransferSubscriptionsRequest {
             request_header,
             subscription_ids,
@@ -52,9 +52,9 @@ impl BinaryEncoder<TransferSubscriptionsResponse> for TransferSubscriptionsRespo
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<TransferResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("TransferSubscriptionsResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<TransferResult>> = decode_field("TransferSubscriptionsResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("TransferSubscriptionsResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(TransferSubscriptionsResponse {
             response_header,
             results,
@@ -48,8 +48,8 @@ impl BinaryEncoder<TranslateBrowsePathsToNodeIdsRequest> for TranslateBrowsePath
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let browse_paths: Option<Vec<BrowsePath>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("TranslateBrowsePathsToNodeIdsRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let browse_paths: Option<Vec<BrowsePath>> = decode_field("TranslateBrowsePathsToNodeIdsRequest", "browse_paths", read_array(stream, decoding_limits))?;
         Ok(TranslateBrowsePathsToNodeIdsRequest {
             request_header,
             browse_paths,
@@ -52,9 +52,9 @@ impl BinaryEncoder<TranslateBrowsePathsToNodeIdsResponse> for TranslateBrowsePat
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
-        let results: Option<Vec<BrowsePathResult>> = read_array(stream, decoding_limits)?;
-        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        let response_header = decode_field("TranslateBrowsePathsToNodeIdsResponse", "response_header", ResponseHeader::decode(stream, decoding_limits))?;
+        let results: Option<Vec<BrowsePathResult>> = decode_field("TranslateBrowsePathsToNodeIdsResponse", "results", read_array(stream, decoding_limits))?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_field("TranslateBrowsePathsToNodeIdsResponse", "diagnostic_infos", read_array(stream, decoding_limits))?;
         Ok(TranslateBrowsePathsToNodeIdsResponse {
             response_header,
             results,
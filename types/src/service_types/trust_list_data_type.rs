@@ -56,11 +56,11 @@ impl BinaryEncoder<TrustListDataType> for TrustListDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_lists = u32::decode(stream, decoding_limits)?;
-        let trusted_certificates: Option<Vec<ByteString>> = read_array(stream, decoding_limits)?;
-        let trusted_crls: Option<Vec<ByteString>> = read_array(stream, decoding_limits)?;
-        let issuer_certificates: Option<Vec<ByteString>> = read_array(stream, decoding_limits)?;
-        let issuer_crls: Option<Vec<ByteString>> = read_array(stream, decoding_limits)?;
+        let specified_lists = decode_field("TrustListDataType", "specified_lists", u32::decode(stream, decoding_limits))?;
+        let trusted_certificates: Option<Vec<ByteString>> = decode_field("TrustListDataType", "trusted_certificates", read_array(stream, decoding_limits))?;
+        let trusted_crls: Option<Vec<ByteString>> = decode_field("TrustListDataType", "trusted_crls", read_array(stream, decoding_limits))?;
+        let issuer_certificates: Option<Vec<ByteString>> = decode_field("TrustListDataType", "issuer_certificates", read_array(stream, decoding_limits))?;
+        let issuer_crls: Option<Vec<ByteString>> = decode_field("TrustListDataType", "issuer_crls", read_array(stream, decoding_limits))?;
         Ok(TrustListDataType {
             specified_lists,
             trusted_certificates,
@@ -59,13 +59,13 @@ impl BinaryEncoder<UABinaryFileDataType> for UABinaryFileDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let namespaces: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let structure_data_types: Option<Vec<StructureDescription>> = read_array(stream, decoding_limits)?;
-        let enum_data_types: Option<Vec<EnumDescription>> = read_array(stream, decoding_limits)?;
-        let simple_data_types: Option<Vec<SimpleTypeDescription>> = read_array(stream, decoding_limits)?;
-        let schema_location = UAString::decode(stream, decoding_limits)?;
-        let file_header: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let body = Variant::decode(stream, decoding_limits)?;
+        let namespaces: Option<Vec<UAString>> = decode_field("UABinaryFileDataType", "namespaces", read_array(stream, decoding_limits))?;
+        let structure_data_types: Option<Vec<StructureDescription>> = decode_field("UABinaryFileDataType", "structure_data_types", read_array(stream, decoding_limits))?;
+        let enum_data_types: Option<Vec<EnumDescription>> = decode_field("UABinaryFileDataType", "enum_data_types", read_array(stream, decoding_limits))?;
+        let simple_data_types: Option<Vec<SimpleTypeDescription>> = decode_field("UABinaryFileDataType", "simple_data_types", read_array(stream, decoding_limits))?;
+        let schema_location = decode_field("UABinaryFileDataType", "schema_location", UAString::decode(stream, decoding_limits))?;
+        let file_header: Option<Vec<KeyValuePair>> = decode_field("UABinaryFileDataType", "file_header", read_array(stream, decoding_limits))?;
+        let body = decode_field("UABinaryFileDataType", "body", Variant::decode(stream, decoding_limits))?;
         Ok(UABinaryFileDataType {
             namespaces,
             structure_data_types,
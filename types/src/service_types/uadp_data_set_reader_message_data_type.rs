@@ -62,15 +62,15 @@ impl BinaryEncoder<UadpDataSetReaderMessageDataType> for UadpDataSetReaderMessag
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let group_version = u32::decode(stream, decoding_limits)?;
-        let network_message_number = u16::decode(stream, decoding_limits)?;
-        let data_set_offset = u16::decode(stream, decoding_limits)?;
-        let data_set_class_id = Guid::decode(stream, decoding_limits)?;
-        let network_message_content_mask = UadpNetworkMessageContentMask::decode(stream, decoding_limits)?;
-        let data_set_message_content_mask = UadpDataSetMessageContentMask::decode(stream, decoding_limits)?;
-        let publishing_interval = f64::decode(stream, decoding_limits)?;
-        let receive_offset = f64::decode(stream, decoding_limits)?;
-        let processing_offset = f64::decode(stream, decoding_limits)?;
+        let group_version = decode_field("UadpDataSetReaderMessageDataType", "group_version", u32::decode(stream, decoding_limits))?;
+        let network_message_number = decode_field("UadpDataSetReaderMessageDataType", "network_message_number", u16::decode(stream, decoding_limits))?;
+        let data_set_offset = decode_field("UadpDataSetReaderMessageDataType", "data_set_offset", u16::decode(stream, decoding_limits))?;
+        let data_set_class_id = decode_field("UadpDataSetReaderMessageDataType", "data_set_class_id", Guid::decode(stream, decoding_limits))?;
+        let network_message_content_mask = decode_field("UadpDataSetReaderMessageDataType", "network_message_content_mask", UadpNetworkMessageContentMask::decode(stream, decoding_limits))?;
+        let data_set_message_content_mask = decode_field("UadpDataSetReaderMessageDataType", "data_set_message_content_mask", UadpDataSetMessageContentMask::decode(stream, decoding_limits))?;
+        let publishing_interval = decode_field("UadpDataSetReaderMessageDataType", "publishing_interval", f64::decode(stream, decoding_limits))?;
+        let receive_offset = decode_field("UadpDataSetReaderMessageDataType", "receive_offset", f64::decode(stream, decoding_limits))?;
+        let processing_offset = decode_field("UadpDataSetReaderMessageDataType", "processing_offset", f64::decode(stream, decoding_limits))?;
         Ok(UadpDataSetReaderMessageDataType {
             group_version,
             network_message_number,
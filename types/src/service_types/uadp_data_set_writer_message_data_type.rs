@@ -45,10 +45,10 @@ impl BinaryEncoder<UadpDataSetWriterMessageDataType> for UadpDataSetWriterMessag
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let data_set_message_content_mask = UadpDataSetMessageContentMask::decode(stream, decoding_limits)?;
-        let configured_size = u16::decode(stream, decoding_limits)?;
-        let network_message_number = u16::decode(stream, decoding_limits)?;
-        let data_set_offset = u16::decode(stream, decoding_limits)?;
+        let data_set_message_content_mask = decode_field("UadpDataSetWriterMessageDataType", "data_set_message_content_mask", UadpDataSetMessageContentMask::decode(stream, decoding_limits))?;
+        let configured_size = decode_field("UadpDataSetWriterMessageDataType", "configured_size", u16::decode(stream, decoding_limits))?;
+        let network_message_number = decode_field("UadpDataSetWriterMessageDataType", "network_message_number", u16::decode(stream, decoding_limits))?;
+        let data_set_offset = decode_field("UadpDataSetWriterMessageDataType", "data_set_offset", u16::decode(stream, decoding_limits))?;
         Ok(UadpDataSetWriterMessageDataType {
             data_set_message_content_mask,
             configured_size,
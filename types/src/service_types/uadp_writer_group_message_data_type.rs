@@ -49,11 +49,11 @@ impl BinaryEncoder<UadpWriterGroupMessageDataType> for UadpWriterGroupMessageDat
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let group_version = u32::decode(stream, decoding_limits)?;
-        let data_set_ordering = DataSetOrderingType::decode(stream, decoding_limits)?;
-        let network_message_content_mask = UadpNetworkMessageContentMask::decode(stream, decoding_limits)?;
-        let sampling_offset = f64::decode(stream, decoding_limits)?;
-        let publishing_offset: Option<Vec<f64>> = read_array(stream, decoding_limits)?;
+        let group_version = decode_field("UadpWriterGroupMessageDataType", "group_version", u32::decode(stream, decoding_limits))?;
+        let data_set_ordering = decode_field("UadpWriterGroupMessageDataType", "data_set_ordering", DataSetOrderingType::decode(stream, decoding_limits))?;
+        let network_message_content_mask = decode_field("UadpWriterGroupMessageDataType", "network_message_content_mask", UadpNetworkMessageContentMask::decode(stream, decoding_limits))?;
+        let sampling_offset = decode_field("UadpWriterGroupMessageDataType", "sampling_offset", f64::decode(stream, decoding_limits))?;
+        let publishing_offset: Option<Vec<f64>> = decode_field("UadpWriterGroupMessageDataType", "publishing_offset", read_array(stream, decoding_limits))?;
         Ok(UadpWriterGroupMessageDataType {
             group_version,
             data_set_ordering,
@@ -48,8 +48,8 @@ impl BinaryEncoder<UnregisterNodesRequest> for UnregisterNodesRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let nodes_to_unregister: Option<Vec<NodeId>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("UnregisterNodesRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let nodes_to_unregister: Option<Vec<NodeId>> = decode_field("UnregisterNodesRequest", "nodes_to_unregister", read_array(stream, decoding_limits))?;
         Ok(UnregisterNodesRequest {
             request_header,
             nodes_to_unregister,
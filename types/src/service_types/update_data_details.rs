@@ -44,9 +44,9 @@ impl BinaryEncoder<UpdateDataDetails> for UpdateDataDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let perform_insert_replace = PerformUpdateType::decode(stream, decoding_limits)?;
-        let update_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
+        let node_id = decode_field("UpdateDataDetails", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let perform_insert_replace = decode_field("UpdateDataDetails", "perform_insert_replace", PerformUpdateType::decode(stream, decoding_limits))?;
+        let update_values: Option<Vec<DataValue>> = decode_field("UpdateDataDetails", "update_values", read_array(stream, decoding_limits))?;
         Ok(UpdateDataDetails {
             node_id,
             perform_insert_replace,
@@ -48,10 +48,10 @@ impl BinaryEncoder<UpdateEventDetails> for UpdateEventDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let perform_insert_replace = PerformUpdateType::decode(stream, decoding_limits)?;
-        let filter = EventFilter::decode(stream, decoding_limits)?;
-        let event_data: Option<Vec<HistoryEventFieldList>> = read_array(stream, decoding_limits)?;
+        let node_id = decode_field("UpdateEventDetails", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let perform_insert_replace = decode_field("UpdateEventDetails", "perform_insert_replace", PerformUpdateType::decode(stream, decoding_limits))?;
+        let filter = decode_field("UpdateEventDetails", "filter", EventFilter::decode(stream, decoding_limits))?;
+        let event_data: Option<Vec<HistoryEventFieldList>> = decode_field("UpdateEventDetails", "event_data", read_array(stream, decoding_limits))?;
         Ok(UpdateEventDetails {
             node_id,
             perform_insert_replace,
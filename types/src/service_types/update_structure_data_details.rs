@@ -44,9 +44,9 @@ impl BinaryEncoder<UpdateStructureDataDetails> for UpdateStructureDataDetails {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let perform_insert_replace = PerformUpdateType::decode(stream, decoding_limits)?;
-        let update_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
+        let node_id = decode_field("UpdateStructureDataDetails", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let perform_insert_replace = decode_field("UpdateStructureDataDetails", "perform_insert_replace", PerformUpdateType::decode(stream, decoding_limits))?;
+        let update_values: Option<Vec<DataValue>> = decode_field("UpdateStructureDataDetails", "update_values", read_array(stream, decoding_limits))?;
         Ok(UpdateStructureDataDetails {
             node_id,
             perform_insert_replace,
@@ -44,7 +44,7 @@ impl BinaryEncoder<UserIdentityToken> for UserIdentityToken {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let policy_id = UAString::decode(stream, decoding_limits)?;
+        let policy_id = decode_field("UserIdentityToken", "policy_id", UAString::decode(stream, decoding_limits))?;
         Ok(UserIdentityToken {
             policy_id,
         })
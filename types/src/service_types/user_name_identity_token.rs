@@ -46,10 +46,10 @@ impl BinaryEncoder<UserNameIdentityToken> for UserNameIdentityToken {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let policy_id = UAString::decode(stream, decoding_limits)?;
-        let user_name = UAString::decode(stream, decoding_limits)?;
-        let password = ByteString::decode(stream, decoding_limits)?;
-        let encryption_algorithm = UAString::decode(stream, decoding_limits)?;
+        let policy_id = decode_field("UserNameIdentityToken", "policy_id", UAString::decode(stream, decoding_limits))?;
+        let user_name = decode_field("UserNameIdentityToken", "user_name", UAString::decode(stream, decoding_limits))?;
+        let password = decode_field("UserNameIdentityToken", "password", ByteString::decode(stream, decoding_limits))?;
+        let encryption_algorithm = decode_field("UserNameIdentityToken", "encryption_algorithm", UAString::decode(stream, decoding_limits))?;
         Ok(UserNameIdentityToken {
             policy_id,
             user_name,
@@ -57,11 +57,11 @@ impl BinaryEncoder<UserTokenPolicy> for UserTokenPolicy {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let policy_id = UAString::decode(stream, decoding_limits)?;
-        let token_type = UserTokenType::decode(stream, decoding_limits)?;
-        let issued_token_type = UAString::decode(stream, decoding_limits)?;
-        let issuer_endpoint_url = UAString::decode(stream, decoding_limits)?;
-        let security_policy_uri = UAString::decode(stream, decoding_limits)?;
+        let policy_id = decode_field("UserTokenPolicy", "policy_id", UAString::decode(stream, decoding_limits))?;
+        let token_type = decode_field("UserTokenPolicy", "token_type", UserTokenType::decode(stream, decoding_limits))?;
+        let issued_token_type = decode_field("UserTokenPolicy", "issued_token_type", UAString::decode(stream, decoding_limits))?;
+        let issuer_endpoint_url = decode_field("UserTokenPolicy", "issuer_endpoint_url", UAString::decode(stream, decoding_limits))?;
+        let security_policy_uri = decode_field("UserTokenPolicy", "security_policy_uri", UAString::decode(stream, decoding_limits))?;
         Ok(UserTokenPolicy {
             policy_id,
             token_type,
@@ -74,19 +74,19 @@ impl BinaryEncoder<VariableAttributes> for VariableAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let value = Variant::decode(stream, decoding_limits)?;
-        let data_type = NodeId::decode(stream, decoding_limits)?;
-        let value_rank = i32::decode(stream, decoding_limits)?;
-        let array_dimensions: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let access_level = u8::decode(stream, decoding_limits)?;
-        let user_access_level = u8::decode(stream, decoding_limits)?;
-        let minimum_sampling_interval = f64::decode(stream, decoding_limits)?;
-        let historizing = bool::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("VariableAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("VariableAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("VariableAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("VariableAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("VariableAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let value = decode_field("VariableAttributes", "value", Variant::decode(stream, decoding_limits))?;
+        let data_type = decode_field("VariableAttributes", "data_type", NodeId::decode(stream, decoding_limits))?;
+        let value_rank = decode_field("VariableAttributes", "value_rank", i32::decode(stream, decoding_limits))?;
+        let array_dimensions: Option<Vec<u32>> = decode_field("VariableAttributes", "array_dimensions", read_array(stream, decoding_limits))?;
+        let access_level = decode_field("VariableAttributes", "access_level", u8::decode(stream, decoding_limits))?;
+        let user_access_level = decode_field("VariableAttributes", "user_access_level", u8::decode(stream, decoding_limits))?;
+        let minimum_sampling_interval = decode_field("VariableAttributes", "minimum_sampling_interval", f64::decode(stream, decoding_limits))?;
+        let historizing = decode_field("VariableAttributes", "historizing", bool::decode(stream, decoding_limits))?;
         Ok(VariableAttributes {
             specified_attributes,
             display_name,
@@ -65,16 +65,16 @@ impl BinaryEncoder<VariableTypeAttributes> for VariableTypeAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let value = Variant::decode(stream, decoding_limits)?;
-        let data_type = NodeId::decode(stream, decoding_limits)?;
-        let value_rank = i32::decode(stream, decoding_limits)?;
-        let array_dimensions: Option<Vec<u32>> = read_array(stream, decoding_limits)?;
-        let is_abstract = bool::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("VariableTypeAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("VariableTypeAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("VariableTypeAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("VariableTypeAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("VariableTypeAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let value = decode_field("VariableTypeAttributes", "value", Variant::decode(stream, decoding_limits))?;
+        let data_type = decode_field("VariableTypeAttributes", "data_type", NodeId::decode(stream, decoding_limits))?;
+        let value_rank = decode_field("VariableTypeAttributes", "value_rank", i32::decode(stream, decoding_limits))?;
+        let array_dimensions: Option<Vec<u32>> = decode_field("VariableTypeAttributes", "array_dimensions", read_array(stream, decoding_limits))?;
+        let is_abstract = decode_field("VariableTypeAttributes", "is_abstract", bool::decode(stream, decoding_limits))?;
         Ok(VariableTypeAttributes {
             specified_attributes,
             display_name,
@@ -54,13 +54,13 @@ impl BinaryEncoder<ViewAttributes> for ViewAttributes {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let specified_attributes = u32::decode(stream, decoding_limits)?;
-        let display_name = LocalizedText::decode(stream, decoding_limits)?;
-        let description = LocalizedText::decode(stream, decoding_limits)?;
-        let write_mask = u32::decode(stream, decoding_limits)?;
-        let user_write_mask = u32::decode(stream, decoding_limits)?;
-        let contains_no_loops = bool::decode(stream, decoding_limits)?;
-        let event_notifier = u8::decode(stream, decoding_limits)?;
+        let specified_attributes = decode_field("ViewAttributes", "specified_attributes", u32::decode(stream, decoding_limits))?;
+        let display_name = decode_field("ViewAttributes", "display_name", LocalizedText::decode(stream, decoding_limits))?;
+        let description = decode_field("ViewAttributes", "description", LocalizedText::decode(stream, decoding_limits))?;
+        let write_mask = decode_field("ViewAttributes", "write_mask", u32::decode(stream, decoding_limits))?;
+        let user_write_mask = decode_field("ViewAttributes", "user_write_mask", u32::decode(stream, decoding_limits))?;
+        let contains_no_loops = decode_field("ViewAttributes", "contains_no_loops", bool::decode(stream, decoding_limits))?;
+        let event_notifier = decode_field("ViewAttributes", "event_notifier", u8::decode(stream, decoding_limits))?;
         Ok(ViewAttributes {
             specified_attributes,
             display_name,
@@ -51,9 +51,9 @@ impl BinaryEncoder<ViewDescription> for ViewDescription {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let view_id = NodeId::decode(stream, decoding_limits)?;
-        let timestamp = DateTime::decode(stream, decoding_limits)?;
-        let view_version = u32::decode(stream, decoding_limits)?;
+        let view_id = decode_field("ViewDescription", "view_id", NodeId::decode(stream, decoding_limits))?;
+        let timestamp = decode_field("ViewDescription", "timestamp", DateTime::decode(stream, decoding_limits))?;
+        let view_version = decode_field("ViewDescription", "view_version", u32::decode(stream, decoding_limits))?;
         Ok(ViewDescription {
             view_id,
             timestamp,
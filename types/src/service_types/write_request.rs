@@ -48,8 +48,8 @@ impl BinaryEncoder<WriteRequest> for WriteRequest {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let request_header = RequestHeader::decode(stream, decoding_limits)?;
-        let nodes_to_write: Option<Vec<WriteValue>> = read_array(stream, decoding_limits)?;
+        let request_header = decode_field("WriteRequest", "request_header", RequestHeader::decode(stream, decoding_limits))?;
+        let nodes_to_write: Option<Vec<WriteValue>> = decode_field("WriteRequest", "nodes_to_write", read_array(stream, decoding_limits))?;
         Ok(WriteRequest {
             request_header,
             nodes_to_write,
@@ -55,10 +55,10 @@ impl BinaryEncoder<WriteValue> for WriteValue {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let node_id = NodeId::decode(stream, decoding_limits)?;
-        let attribute_id = u32::decode(stream, decoding_limits)?;
-        let index_range = UAString::decode(stream, decoding_limits)?;
-        let value = DataValue::decode(stream, decoding_limits)?;
+        let node_id = decode_field("WriteValue", "node_id", NodeId::decode(stream, decoding_limits))?;
+        let attribute_id = decode_field("WriteValue", "attribute_id", u32::decode(stream, decoding_limits))?;
+        let index_range = decode_field("WriteValue", "index_range", UAString::decode(stream, decoding_limits))?;
+        let value = decode_field("WriteValue", "value", DataValue::decode(stream, decoding_limits))?;
         Ok(WriteValue {
             node_id,
             attribute_id,
@@ -86,22 +86,22 @@ impl BinaryEncoder<WriterGroupDataType> for WriterGroupDataType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let name = UAString::decode(stream, decoding_limits)?;
-        let enabled = bool::decode(stream, decoding_limits)?;
-        let security_mode = MessageSecurityMode::decode(stream, decoding_limits)?;
-        let security_group_id = UAString::decode(stream, decoding_limits)?;
-        let security_key_services: Option<Vec<EndpointDescription>> = read_array(stream, decoding_limits)?;
-        let max_network_message_size = u32::decode(stream, decoding_limits)?;
-        let group_properties: Option<Vec<KeyValuePair>> = read_array(stream, decoding_limits)?;
-        let writer_group_id = u16::decode(stream, decoding_limits)?;
-        let publishing_interval = f64::decode(stream, decoding_limits)?;
-        let keep_alive_time = f64::decode(stream, decoding_limits)?;
-        let priority = u8::decode(stream, decoding_limits)?;
-        let locale_ids: Option<Vec<UAString>> = read_array(stream, decoding_limits)?;
-        let header_layout_uri = UAString::decode(stream, decoding_limits)?;
-        let transport_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let message_settings = ExtensionObject::decode(stream, decoding_limits)?;
-        let data_set_writers: Option<Vec<DataSetWriterDataType>> = read_array(stream, decoding_limits)?;
+        let name = decode_field("WriterGroupDataType", "name", UAString::decode(stream, decoding_limits))?;
+        let enabled = decode_field("WriterGroupDataType", "enabled", bool::decode(stream, decoding_limits))?;
+        let security_mode = decode_field("WriterGroupDataType", "security_mode", MessageSecurityMode::decode(stream, decoding_limits))?;
+        let security_group_id = decode_field("WriterGroupDataType", "security_group_id", UAString::decode(stream, decoding_limits))?;
+        let security_key_services: Option<Vec<EndpointDescription>> = decode_field("WriterGroupDataType", "security_key_services", read_array(stream, decoding_limits))?;
+        let max_network_message_size = decode_field("WriterGroupDataType", "max_network_message_size", u32::decode(stream, decoding_limits))?;
+        let group_properties: Option<Vec<KeyValuePair>> = decode_field("WriterGroupDataType", "group_properties", read_array(stream, decoding_limits))?;
+        let writer_group_id = decode_field("WriterGroupDataType", "writer_group_id", u16::decode(stream, decoding_limits))?;
+        let publishing_interval = decode_field("WriterGroupDataType", "publishing_interval", f64::decode(stream, decoding_limits))?;
+        let keep_alive_time = decode_field("WriterGroupDataType", "keep_alive_time", f64::decode(stream, decoding_limits))?;
+        let priority = decode_field("WriterGroupDataType", "priority", u8::decode(stream, decoding_limits))?;
+        let locale_ids: Option<Vec<UAString>> = decode_field("WriterGroupDataType", "locale_ids", read_array(stream, decoding_limits))?;
+        let header_layout_uri = decode_field("WriterGroupDataType", "header_layout_uri", UAString::decode(stream, decoding_limits))?;
+        let transport_settings = decode_field("WriterGroupDataType", "transport_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let message_settings = decode_field("WriterGroupDataType", "message_settings", ExtensionObject::decode(stream, decoding_limits))?;
+        let data_set_writers: Option<Vec<DataSetWriterDataType>> = decode_field("WriterGroupDataType", "data_set_writers", read_array(stream, decoding_limits))?;
         Ok(WriterGroupDataType {
             name,
             enabled,
@@ -46,8 +46,8 @@ impl BinaryEncoder<XVType> for XVType {
 
     #[allow(unused_variables)]
     fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
-        let x = f64::decode(stream, decoding_limits)?;
-        let value = f32::decode(stream, decoding_limits)?;
+        let x = decode_field("XVType", "x", f64::decode(stream, decoding_limits))?;
+        let value = decode_field("XVType", "value", f32::decode(stream, decoding_limits))?;
         Ok(XVType {
             x,
             value,
@@ -72,8 +72,67 @@ impl StatusCode {
     pub fn is_good(&self) -> bool {
         !self.is_bad() && !self.is_uncertain()
     }
+
+    /// Takes a raw status code, e.g. one received from a peer, and returns the `StatusCode`
+    /// including any bit flags. If the code is recognized, its `name()` and `description()` will
+    /// return the corresponding text, otherwise they return "Unrecognized status code".
+    pub fn from_code(code: u32) -> StatusCode {
+        StatusCode::from_bits_truncate(code)
+    }
+
+    /// Tests if the status portion of the code is one of the known OPC UA status codes
+    pub fn is_known(&self) -> bool {
+        self.name() != UNRECOGNIZED_STATUS_CODE
+    }
+
+    /// Returns the info type held in bits 10:11, which says how the rest of the info bits are to
+    /// be interpreted
+    pub fn info_type(&self) -> InfoType {
+        match self.bits() & INFO_TYPE_MASK {
+            0 => InfoType::NotUsed,
+            INFO_TYPE_DATA_VALUE => InfoType::DataValue,
+            _ => InfoType::Reserved,
+        }
+    }
+
+    /// Tests if the overflow bit is set. The bit is only meaningful when the info type is
+    /// `DataValue`, and means that values were dropped from a monitored item's queue.
+    pub fn has_overflow(&self) -> bool {
+        self.info_type() == InfoType::DataValue && self.contains(StatusCode::OVERFLOW)
+    }
+
+    /// Sets or clears the overflow bit. Setting it also sets the info type to `DataValue` so the
+    /// bit will be interpreted correctly by the receiver.
+    pub fn set_overflow(&mut self, overflow: bool) {
+        if overflow {
+            *self = StatusCode::from_bits_truncate(
+                (self.bits() & !INFO_TYPE_MASK) | INFO_TYPE_DATA_VALUE,
+            ) | StatusCode::OVERFLOW;
+        } else {
+            self.remove(StatusCode::OVERFLOW);
+        }
+    }
+}
+
+/// Mask for the info type bits 10:11 of a status code
+const INFO_TYPE_MASK: u32 = 0x0000_0c00;
+/// Info type value for a status code whose info bits describe a data value
+const INFO_TYPE_DATA_VALUE: u32 = 0x0000_0400;
+
+/// The info type of a status code, which determines the meaning of its info bits
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InfoType {
+    /// The info bits are not used and must be 0
+    NotUsed,
+    /// The info bits hold the limit, overflow and historian bits of a data value
+    DataValue,
+    /// The info type is reserved for future use
+    Reserved,
 }
 
+/// Text returned by `name()` and `description()` for a code that is not recognized
+const UNRECOGNIZED_STATUS_CODE: &str = "Unrecognized status code";
+
 // It would be very nice to be able to override the default implementation in bitflags! macro
 // of this fmt::Debug because it breaks on StatusCode
 /*
@@ -158,3 +217,50 @@ fn status_code() {
         StatusCode::HISTORICAL_CALCULATED
     );
 }
+
+#[test]
+fn status_code_from_code() {
+    let status_code = StatusCode::from_code(0x8074_0000);
+    assert_eq!(status_code, StatusCode::BadTypeMismatch);
+    assert!(status_code.is_known());
+    assert_eq!(status_code.name(), "BadTypeMismatch");
+    assert_eq!(
+        status_code.description(),
+        "The value supplied for the attribute is not of the same type as the attribute's value."
+    );
+
+    // Bit flags are preserved
+    let status_code = StatusCode::from_code(0x8074_0000 | 0x0000_0080);
+    assert_eq!(status_code.status(), StatusCode::BadTypeMismatch);
+    assert_eq!(status_code.bitflags(), StatusCode::OVERFLOW);
+    assert!(status_code.is_known());
+
+    // Unknown code
+    let status_code = StatusCode::from_code(0x80FF_0000);
+    assert_eq!(status_code.bits(), 0x80FF_0000);
+    assert!(!status_code.is_known());
+    assert!(status_code.is_bad());
+    assert_eq!(status_code.description(), "Unrecognized status code");
+}
+
+#[test]
+fn status_code_overflow() {
+    let mut status_code = StatusCode::GoodClamped;
+    assert_eq!(status_code.info_type(), InfoType::NotUsed);
+    assert!(!status_code.has_overflow());
+
+    status_code.set_overflow(true);
+    assert!(status_code.has_overflow());
+    assert_eq!(status_code.info_type(), InfoType::DataValue);
+    assert_eq!(status_code.status(), StatusCode::GoodClamped);
+    assert_eq!(status_code.bits(), 0x0030_0480);
+    assert!(status_code.is_good());
+
+    // The overflow bit means nothing without the data value info type
+    assert!(!StatusCode::from_code(0x0030_0080).has_overflow());
+
+    status_code.set_overflow(false);
+    assert!(!status_code.has_overflow());
+    assert_eq!(status_code.info_type(), InfoType::DataValue);
+    assert_eq!(status_code.status(), StatusCode::GoodClamped);
+}
@@ -11,7 +11,7 @@ use std::{
 
 use crate::{
     encoding::{
-        process_decode_io_result, process_encode_io_result, write_i32, BinaryEncoder,
+        encoded_length, process_decode_io_result, write_byte_slice, write_i32, BinaryEncoder,
         DecodingLimits, EncodingResult,
     },
     status_codes::StatusCode,
@@ -23,7 +23,7 @@ use crate::{
 /// A string contains UTF-8 encoded characters or a null value. A null value is distinct from
 /// being an empty string so internally, the code maintains that distinction by holding the value
 /// as an `Option<String>`.
-#[derive(Eq, PartialEq, Debug, Clone, Hash, Serialize, Deserialize)]
+#[derive(Eq, PartialEq, PartialOrd, Ord, Debug, Clone, Hash, Serialize, Deserialize)]
 pub struct UAString {
     value: Option<String>,
 }
@@ -55,9 +55,8 @@ impl BinaryEncoder<UAString> for UAString {
         } else {
             let value = self.value.as_ref().unwrap();
             let mut size: usize = 0;
-            size += write_i32(stream, value.len() as i32)?;
-            let buf = value.as_bytes();
-            size += process_encode_io_result(stream.write(&buf))?;
+            size += write_i32(stream, self.encoded_len()?)?;
+            size += write_byte_slice(stream, value.as_bytes())?;
             assert_eq!(size, self.byte_len());
             Ok(size)
         }
@@ -168,6 +167,16 @@ impl UAString {
         }
     }
 
+    /// Returns the length that prefixes the string when it is encoded, i.e. the length in bytes
+    /// or -1 for null. Fails with `BadEncodingLimitsExceeded` if the string is too long for its
+    /// length to be encoded as an `Int32`.
+    pub fn encoded_len(&self) -> EncodingResult<i32> {
+        match self.value {
+            None => Ok(-1),
+            Some(ref value) => encoded_length(value.len()),
+        }
+    }
+
     /// Create a null string (not the same as an empty string).
     pub fn null() -> UAString {
         UAString { value: None }
@@ -1,4 +1,7 @@
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use crate::*;
 
@@ -57,3 +60,86 @@ fn string() {
     // so this code may have to change to compare an interval delta
     assert_eq!(now, now2);
 }
+
+/// Ticks between the OPC UA epoch of 1601-01-01 and the Unix epoch of 1970-01-01
+const UNIX_EPOCH_TICKS: i64 = 116_444_736_000_000_000;
+
+#[test]
+fn system_time() {
+    // The unix epoch is a well known FILETIME
+    let unix_epoch = DateTime::from(UNIX_EPOCH);
+    assert_eq!(unix_epoch.ticks(), UNIX_EPOCH_TICKS);
+    assert_eq!(unix_epoch, DateTime::ymd(1970, 1, 1));
+    assert_eq!(SystemTime::from(unix_epoch), UNIX_EPOCH);
+
+    // Round trip a time with sub-second precision in whole ticks
+    let system_time = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_700);
+    let date_time = DateTime::from(system_time);
+    assert_eq!(
+        date_time.ticks(),
+        UNIX_EPOCH_TICKS + 1_600_000_000 * 10_000_000 + 1_234_567
+    );
+    assert_eq!(SystemTime::from(date_time), system_time);
+
+    // Precision below a tick is lost
+    let system_time = UNIX_EPOCH + Duration::new(0, 150);
+    assert_eq!(DateTime::from(system_time).ticks(), UNIX_EPOCH_TICKS + 1);
+}
+
+#[test]
+fn encoding_sentinels() {
+    let decoding_limits = DecodingLimits::default();
+
+    // The unix epoch encodes as its FILETIME
+    let unix_epoch = DateTime::from(UNIX_EPOCH);
+    let bytes = unix_epoch.encode_to_vec();
+    assert_eq!(bytes, UNIX_EPOCH_TICKS.to_le_bytes().to_vec());
+    let decoded = DateTime::decode(&mut std::io::Cursor::new(bytes), &decoding_limits).unwrap();
+    assert_eq!(decoded, unix_epoch);
+
+    // 0 is null
+    let bytes = DateTime::null().encode_to_vec();
+    assert_eq!(bytes, vec![0u8; 8]);
+    let decoded = DateTime::decode(&mut std::io::Cursor::new(bytes), &decoding_limits).unwrap();
+    assert!(decoded.is_null());
+
+    // i64::MAX is the end of time
+    let bytes = DateTime::endtimes().encode_to_vec();
+    assert_eq!(bytes, i64::max_value().to_le_bytes().to_vec());
+    let decoded = DateTime::decode(&mut std::io::Cursor::new(bytes), &decoding_limits).unwrap();
+    assert_eq!(decoded, DateTime::endtimes());
+    assert_eq!(decoded.checked_ticks(), i64::max_value());
+}
+
+#[test]
+fn chrono_conversions() {
+    use chrono::{TimeZone, Utc};
+
+    // A known time round trips
+    let chrono_time = Utc.ymd(2020, 9, 13).and_hms_nano(12, 26, 40, 123_456_700);
+    let date_time = DateTime::from(chrono_time);
+    assert_eq!(
+        date_time.ticks(),
+        UNIX_EPOCH_TICKS + 1_600_000_000 * 10_000_000 + 1_234_567
+    );
+    assert_eq!(DateTimeUtc::from(date_time.clone()), chrono_time);
+    let into: DateTimeUtc = date_time.clone().into();
+    assert_eq!(into, chrono_time);
+    assert_eq!(date_time.as_chrono_opt(), Some(chrono_time));
+
+    // Null has no chrono time
+    assert!(DateTime::null().as_chrono_opt().is_none());
+    let before_epoch = Utc.ymd(1500, 1, 1).and_hms(0, 0, 0);
+    assert!(DateTime::from(before_epoch).is_null());
+    assert!(DateTime::from(before_epoch).as_chrono_opt().is_none());
+
+    // Beyond the end of time saturates to endtimes
+    let far_future = Utc.ymd(20000, 1, 1).and_hms(0, 0, 0);
+    let date_time = DateTime::from(far_future);
+    assert_eq!(date_time, DateTime::endtimes());
+    assert_eq!(date_time.checked_ticks(), i64::max_value());
+    assert_eq!(
+        date_time.as_chrono_opt(),
+        Some(Utc.ymd(9999, 12, 31).and_hms(23, 59, 59))
+    );
+}
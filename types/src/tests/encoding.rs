@@ -88,6 +88,29 @@ fn encoding_string() {
     serialize_test(UAString::from("This is a test"));
 }
 
+#[test]
+fn encoding_byte_string() {
+    // Null is encoded as a -1 length, empty as a 0 length
+    serialize_and_compare(ByteString::null(), &[0xff, 0xff, 0xff, 0xff]);
+    serialize_and_compare(ByteString::from(vec![]), &[0x00, 0x00, 0x00, 0x00]);
+    serialize_and_compare(
+        ByteString::from(vec![0x01, 0x02]),
+        &[0x02, 0x00, 0x00, 0x00, 0x01, 0x02],
+    );
+
+    // The distinction survives a round trip
+    let v = serialize_test_and_return(ByteString::null());
+    assert!(v.is_null());
+    assert!(!v.is_empty());
+    let v = serialize_test_and_return(ByteString::from(vec![]));
+    assert!(!v.is_null());
+    assert!(v.is_empty());
+    let v = serialize_test_and_return(ByteString::from(vec![0x01, 0x02]));
+    assert!(!v.is_null());
+    assert!(!v.is_empty());
+    assert_ne!(ByteString::null(), ByteString::from(vec![]));
+}
+
 #[test]
 fn encode_string_part_6_5224() {
     // Sample from OPCUA Part 6 - 5.2.2.4
@@ -144,6 +167,26 @@ fn encoding_guid() {
     serialize_test(guid);
 }
 
+#[test]
+fn encoded_length_prefix() {
+    assert_eq!(UAString::null().encoded_len(), Ok(-1));
+    assert_eq!(UAString::from("").encoded_len(), Ok(0));
+    assert_eq!(UAString::from("Hot水").encoded_len(), Ok(6));
+
+    // A string longer than i32::MAX bytes can't be allocated in a test, so the length check
+    // is exercised directly
+    assert_eq!(encoded_length(0), Ok(0));
+    assert_eq!(encoded_length(i32::MAX as usize), Ok(i32::MAX));
+    assert_eq!(
+        encoded_length(i32::MAX as usize + 1),
+        Err(StatusCode::BadEncodingLimitsExceeded)
+    );
+    assert_eq!(
+        encoded_length(u32::MAX as usize),
+        Err(StatusCode::BadEncodingLimitsExceeded)
+    );
+}
+
 #[test]
 fn encode_guid_5226() {
     // Sample from OPCUA Part 6 - 5.2.2.6
@@ -151,10 +194,39 @@ fn encode_guid_5226() {
         0x91, 0x2B, 0x96, 0x72, 0x75, 0xFA, 0xE6, 0x4A, 0x8D, 0x28, 0xB4, 0x04, 0xDC, 0x7D, 0xAF,
         0x63,
     ];
-    let guid = Guid::from_str("912b9672-75fa-e64a-8D28-B404DC7DAF63").unwrap();
+    let guid = Guid::from_str("72962B91-FA75-4ae6-8D28-B404DC7DAF63").unwrap();
     serialize_and_compare(guid, &expected_bytes);
 }
 
+#[test]
+fn guid_bytes_le() {
+    // The canonical string prints Data1, Data2 and Data3 big endian, while the encoded form
+    // follows the Microsoft GUID layout with those fields little endian
+    let guid = Guid::from_str("72962B91-FA75-4AE6-8D28-B404DC7DAF63").unwrap();
+    let bytes_le = [
+        0x91, 0x2B, 0x96, 0x72, 0x75, 0xFA, 0xE6, 0x4A, 0x8D, 0x28, 0xB4, 0x04, 0xDC, 0x7D, 0xAF,
+        0x63,
+    ];
+    assert_eq!(guid.to_bytes_le(), bytes_le);
+    assert_eq!(
+        guid.as_bytes(),
+        &[
+            0x72, 0x96, 0x2B, 0x91, 0xFA, 0x75, 0x4A, 0xE6, 0x8D, 0x28, 0xB4, 0x04, 0xDC, 0x7D,
+            0xAF, 0x63,
+        ]
+    );
+    assert_eq!(Guid::from_bytes_le(&bytes_le), guid);
+
+    // Reading the encoded bytes back through from_bytes_le gives the canonical string
+    let encoded = guid.encode_to_vec();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&encoded);
+    assert_eq!(
+        Guid::from_bytes_le(&bytes).to_string(),
+        "72962b91-fa75-4ae6-8d28-b404dc7daf63"
+    );
+}
+
 #[test]
 fn node_id_2byte_numeric() {
     // Sample from OPCUA Part 6 - 5.2.2.9
@@ -201,6 +273,50 @@ fn node_id_large_id() {
     serialize_test(node_id);
 }
 
+#[test]
+fn node_id_compact_form_boundaries() {
+    // Encode picks the most compact form that can hold the namespace and value
+    let encoding_byte = |node_id: NodeId| node_id.encode_to_vec()[0];
+    assert_eq!(encoding_byte(NodeId::new(0, 255)), 0x0);
+    assert_eq!(encoding_byte(NodeId::new(0, 256)), 0x1);
+    assert_eq!(encoding_byte(NodeId::new(1, 0)), 0x1);
+    assert_eq!(encoding_byte(NodeId::new(255, 65535)), 0x1);
+    assert_eq!(encoding_byte(NodeId::new(256, 0)), 0x2);
+    assert_eq!(encoding_byte(NodeId::new(0, 65536)), 0x2);
+    assert_eq!(encoding_byte(NodeId::new(0, "")), 0x3);
+
+    [
+        NodeId::new(0, 255),
+        NodeId::new(0, 256),
+        NodeId::new(255, 65535),
+        NodeId::new(256, 0),
+        NodeId::new(0, 65536),
+    ]
+    .iter()
+    .for_each(|node_id| serialize_test(node_id.clone()));
+}
+
+#[test]
+fn node_id_decode_non_compact_forms() {
+    // Other implementations may use a larger form than necessary and it must still decode
+    let decoding_limits = DecodingLimits::default();
+    let decode = |bytes: &[u8]| NodeId::decode(&mut Cursor::new(bytes.to_vec()), &decoding_limits);
+
+    // Four byte and full numeric forms of a two byte id
+    let expected = NodeId::new(0, 0x72);
+    assert_eq!(decode(&[0x1, 0x0, 0x72, 0x0]).unwrap(), expected);
+    assert_eq!(
+        decode(&[0x2, 0x0, 0x0, 0x72, 0x0, 0x0, 0x0]).unwrap(),
+        expected
+    );
+    // Re-encoding compacts it
+    assert_eq!(expected.encode_to_vec(), vec![0x0, 0x72]);
+
+    // An unknown encoding or a truncated id is an error
+    assert_eq!(decode(&[0x6, 0x0, 0x0]), Err(StatusCode::BadDecodingError));
+    assert!(decode(&[0x1, 0x5, 0x1]).is_err());
+}
+
 #[test]
 fn node_id_string_part_6_5229() {
     // Sample from OPCUA Part 6 - 5.2.2.9
@@ -249,6 +365,152 @@ fn extension_object() {
     serialize_test(eo);
 }
 
+#[test]
+fn extension_object_none_body() {
+    // A body of None is still written with its 0x00 encoding byte after the node id
+    let eo = ExtensionObject::null();
+    assert_eq!(eo.byte_len(), 3);
+    assert_eq!(eo.encode_to_vec(), vec![0x00, 0x00, 0x00]);
+
+    let eo = ExtensionObject {
+        node_id: ObjectId::CreateSessionResponse_Encoding_DefaultBinary.into(),
+        body: ExtensionObjectEncoding::None,
+    };
+    let bytes = eo.encode_to_vec();
+    assert_eq!(bytes.len(), eo.byte_len());
+    assert_eq!(bytes.last(), Some(&0x00));
+    let eo = serialize_test_and_return(eo);
+    assert_eq!(eo.body, ExtensionObjectEncoding::None);
+}
+
+#[test]
+fn extension_object_null_vs_empty_byte_string() {
+    // A null byte string body is not the same as an empty one
+    let null_body = ExtensionObject {
+        node_id: ObjectId::CreateSessionResponse_Encoding_DefaultBinary.into(),
+        body: ExtensionObjectEncoding::ByteString(ByteString::null()),
+    };
+    let empty_body = ExtensionObject {
+        node_id: ObjectId::CreateSessionResponse_Encoding_DefaultBinary.into(),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![])),
+    };
+    assert_ne!(null_body, empty_body);
+    assert_ne!(null_body.encode_to_vec(), empty_body.encode_to_vec());
+
+    let eo = serialize_test_and_return(null_body);
+    match eo.body {
+        ExtensionObjectEncoding::ByteString(v) => assert!(v.is_null()),
+        _ => panic!("Expected a byte string body"),
+    }
+    let eo = serialize_test_and_return(empty_body);
+    match eo.body {
+        ExtensionObjectEncoding::ByteString(v) => {
+            assert!(!v.is_null());
+            assert!(v.is_empty());
+        }
+        _ => panic!("Expected a byte string body"),
+    }
+}
+
+#[test]
+fn extension_object_registry() {
+    let decoding_limits = DecodingLimits::default();
+    let mut registry = ExtensionObjectRegistry::new();
+    registry.register::<Range, _>(ObjectId::Range_Encoding_DefaultBinary);
+
+    // A wrapper encoding whose body is another extension object, resolved through the registry
+    let wrapper_id = NodeId::new(2, "Wrapper");
+    registry.register_with(wrapper_id.clone(), |registry, body, decoding_limits| {
+        let inner = ExtensionObject::decode(&mut Cursor::new(body), decoding_limits)?;
+        Ok(Box::new(registry.decode(&inner, decoding_limits)?))
+    });
+
+    // A data value holding a known structure resolves to the typed value
+    let range = Range {
+        low: -1.5,
+        high: 100.0,
+    };
+    let data_value = DataValue::new_now(Variant::from(ExtensionObject::from_message(&range)));
+    let data_value = serialize_test_and_return(data_value);
+    let resolved = registry
+        .resolve_data_value(&data_value, &decoding_limits)
+        .unwrap()
+        .unwrap();
+    assert_eq!(resolved.downcast_ref::<Range>(), Some(&range));
+    assert!(resolved.downcast_ref::<EUInformation>().is_none());
+
+    // An unknown encoding stays as it is with its node id intact
+    let unknown = ExtensionObject::from_encodable(NodeId::new(2, 999), &range);
+    match registry.decode(&unknown, &decoding_limits).unwrap() {
+        ResolvedValue::Unresolved(Variant::ExtensionObject(eo)) => assert_eq!(*eo, unknown),
+        resolved => panic!(
+            "Expected an unresolved extension object, got {:?}",
+            resolved
+        ),
+    }
+
+    // Nested extension objects resolve recursively, including within arrays
+    let wrapper =
+        ExtensionObject::from_encodable(wrapper_id, &ExtensionObject::from_message(&range));
+    let variant = Variant::from(vec![
+        Variant::from(wrapper),
+        Variant::from(unknown),
+        Variant::from(1i32),
+    ]);
+    match registry.resolve(&variant, &decoding_limits).unwrap() {
+        ResolvedValue::Array(values) => {
+            assert_eq!(values.len(), 3);
+            let inner = values[0].downcast_ref::<ResolvedValue>().unwrap();
+            assert_eq!(inner.downcast_ref::<Range>(), Some(&range));
+            assert!(!values[1].is_typed());
+            assert!(!values[2].is_typed());
+        }
+        resolved => panic!("Expected an array, got {:?}", resolved),
+    }
+
+    // A known encoding whose body is malformed is an error
+    let malformed = ExtensionObject {
+        node_id: ObjectId::Range_Encoding_DefaultBinary.into(),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(vec![1u8, 2, 3])),
+    };
+    assert_eq!(
+        registry.decode(&malformed, &decoding_limits).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn extension_object_message() {
+    let response = SessionlessInvokeResponseType {
+        namespace_uris: Some(vec![UAString::from("http://opcfoundation.org/UA/")]),
+        server_uris: None,
+        service_id: 428,
+    };
+    let eo = ExtensionObject::from_message(&response);
+    assert_eq!(
+        eo.node_id,
+        ObjectId::SessionlessInvokeResponseType_Encoding_DefaultBinary.into()
+    );
+
+    // Round trip through the extension object's own encoding and back out
+    let eo = serialize_test_and_return(eo);
+    let decoding_limits = DecodingLimits::default();
+    let decoded = eo
+        .decode_message::<SessionlessInvokeResponseType>(&decoding_limits)
+        .unwrap();
+    assert_eq!(decoded, response);
+
+    // A node id for some other type is rejected
+    let eo = ExtensionObject {
+        node_id: ObjectId::CreateSessionResponse_Encoding_DefaultBinary.into(),
+        body: eo.body,
+    };
+    assert_eq!(
+        eo.decode_message::<SessionlessInvokeResponseType>(&decoding_limits),
+        Err(StatusCode::BadDecodingError)
+    );
+}
+
 #[test]
 fn localized_text() {
     let t = LocalizedText {
@@ -276,6 +538,64 @@ fn localized_text() {
     serialize_test(t);
 }
 
+#[test]
+fn localized_text_encoding_mask() {
+    let t = LocalizedText::text_only("Hi");
+    assert_eq!(t.encoding_mask(), 0x02);
+    assert_eq!(t.encode_to_vec()[0], 0x02);
+    serialize_test(t);
+
+    let t = LocalizedText::new("en", "Hi");
+    assert_eq!(t.encoding_mask(), 0x03);
+    assert_eq!(t.encode_to_vec()[0], 0x03);
+    serialize_test(t);
+
+    // Empty fields are omitted so they do not contribute to the mask
+    assert_eq!(LocalizedText::new("", "Hi").encoding_mask(), 0x02);
+    assert_eq!(LocalizedText::new("en", "").encoding_mask(), 0x01);
+    assert_eq!(LocalizedText::null().encoding_mask(), 0x00);
+}
+
+#[test]
+fn localized_text_locale_selection() {
+    let options = [
+        LocalizedText::text_only("Colour"),
+        LocalizedText::new("en-US", "Color"),
+        LocalizedText::new("de", "Farbe"),
+    ];
+
+    // Exact, case insensitive
+    assert!(options[1].matches_locale(&["en-us"]));
+    assert_eq!(select_localized_text(&options, &["de"]), Some(&options[2]));
+    assert_eq!(
+        select_localized_text(&options, &["fr", "EN-US"]),
+        Some(&options[1])
+    );
+
+    // Language fallback in either direction
+    assert!(options[1].matches_locale(&["en"]));
+    assert!(options[2].matches_locale(&["de-AT"]));
+    assert_eq!(
+        select_localized_text(&options, &["en-GB"]),
+        Some(&options[1])
+    );
+    assert_eq!(
+        select_localized_text(&options, &["de-CH", "en-US"]),
+        Some(&options[2])
+    );
+
+    // No match falls back to the text without a locale, then the first text
+    assert!(!options[1].matches_locale(&["fr", "es"]));
+    assert!(!options[0].matches_locale(&["en"]));
+    assert_eq!(select_localized_text(&options, &["fr"]), Some(&options[0]));
+    assert_eq!(select_localized_text(&options, &[]), Some(&options[0]));
+    assert_eq!(
+        select_localized_text(&options[1..], &["fr"]),
+        Some(&options[1])
+    );
+    assert_eq!(select_localized_text(&[], &["en"]), None);
+}
+
 #[test]
 fn expanded_node_id() {
     let node_id = ExpandedNodeId::new(NodeId::new(200, 2000));
@@ -296,6 +616,55 @@ fn expanded_node_id() {
     serialize_test(node_id);
 }
 
+#[test]
+fn expanded_node_id_flags() {
+    // Plain, the encoding byte is the same as the node id's
+    let node_id = ExpandedNodeId::new(NodeId::new(0, 0x72));
+    serialize_and_compare(node_id, &[0x0, 0x72]);
+
+    // The namespace uri sets 0x80 and follows the node id
+    let mut node_id = ExpandedNodeId::new(NodeId::new(0, 0x72));
+    node_id.namespace_uri = UAString::from("ab");
+    serialize_and_compare(node_id, &[0x80, 0x72, 0x2, 0x0, 0x0, 0x0, b'a', b'b']);
+
+    // The server index sets 0x40 and follows the node id
+    let mut node_id = ExpandedNodeId::new(NodeId::new(0, 0x72));
+    node_id.server_index = 5;
+    serialize_and_compare(node_id, &[0x40, 0x72, 0x5, 0x0, 0x0, 0x0]);
+
+    // Both, combined with a string id
+    let mut node_id = ExpandedNodeId::new(NodeId::new(1, "x"));
+    node_id.namespace_uri = UAString::from("ab");
+    node_id.server_index = 5;
+    serialize_and_compare(
+        node_id,
+        &[
+            0xc3, 0x1, 0x0, 0x1, 0x0, 0x0, 0x0, b'x', 0x2, 0x0, 0x0, 0x0, b'a', b'b', 0x5, 0x0,
+            0x0, 0x0,
+        ],
+    );
+}
+
+#[test]
+fn expanded_node_id_in_reference_description() {
+    // The optional fields must be read back exactly or the fields after them are out of step
+    let mut node_id = ExpandedNodeId::new(NodeId::new(2, "Remote"));
+    node_id.namespace_uri = UAString::from("urn:remote");
+    node_id.server_index = 3;
+    let mut type_definition = ExpandedNodeId::new(NodeId::new(0, 58));
+    type_definition.server_index = 1;
+    let reference_description = ReferenceDescription {
+        reference_type_id: ReferenceTypeId::Organizes.into(),
+        is_forward: true,
+        node_id,
+        browse_name: QualifiedName::new(2, "Remote"),
+        display_name: LocalizedText::new("en", "Remote"),
+        node_class: NodeClass::Object,
+        type_definition,
+    };
+    serialize_test(reference_description);
+}
+
 #[test]
 fn qualified_name() {
     let qname = QualifiedName {
@@ -305,6 +674,55 @@ fn qualified_name() {
     serialize_test(qname);
 }
 
+#[test]
+fn qualified_name_validate_namespace() {
+    let qname = QualifiedName::new(2, "Name");
+    assert!(qname.validate_namespace(3).is_ok());
+    assert!(QualifiedName::new(0, "Name").validate_namespace(1).is_ok());
+
+    assert_eq!(
+        qname.validate_namespace(2).unwrap_err(),
+        StatusCode::BadBrowseNameInvalid
+    );
+    assert_eq!(
+        QualifiedName::new(0, "Name")
+            .validate_namespace(0)
+            .unwrap_err(),
+        StatusCode::BadBrowseNameInvalid
+    );
+}
+
+#[test]
+fn qualified_name_oversized() {
+    let qname = QualifiedName {
+        namespace_index: 100,
+        name: UAString::from("this is a qualified name"),
+    };
+    let bytes = qname.encode_to_vec();
+
+    // Name is within the limit
+    let decoding_limits = DecodingLimits {
+        max_string_length: 24,
+        ..Default::default()
+    };
+    let mut stream = Cursor::new(bytes.clone());
+    assert_eq!(
+        QualifiedName::decode(&mut stream, &decoding_limits).unwrap(),
+        qname
+    );
+
+    // Name exceeds the limit
+    let decoding_limits = DecodingLimits {
+        max_string_length: 23,
+        ..Default::default()
+    };
+    let mut stream = Cursor::new(bytes);
+    assert_eq!(
+        QualifiedName::decode(&mut stream, &decoding_limits).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
 #[test]
 fn variant() {
     use std::mem;
@@ -409,6 +827,16 @@ fn variant_single_dimension_array() {
     serialize_test(v);
 }
 
+#[test]
+fn variant_status_code() {
+    // A status code carried as a value, e.g. the quality of an event field
+    let v = Variant::from(StatusCode::BadTimeout);
+    assert_eq!(v.type_id(), VariantTypeId::StatusCode);
+    let v = serialize_test_and_return(v);
+    assert_eq!(v, Variant::StatusCode(StatusCode::BadTimeout));
+    assert_eq!(v.as_status_code(), Some(StatusCode::BadTimeout));
+}
+
 #[test]
 fn variant_multi_dimension_array() {
     let values = vec![
@@ -424,6 +852,179 @@ fn variant_multi_dimension_array() {
     serialize_test(v);
 }
 
+/// One value of every type that a variant can hold, other than arrays
+fn sample_scalar_variants() -> Vec<Variant> {
+    vec![
+        Variant::Boolean(true),
+        Variant::SByte(-44),
+        Variant::Byte(255),
+        Variant::Int16(-20000),
+        Variant::UInt16(55778),
+        Variant::Int32(-9999999),
+        Variant::UInt32(24424244),
+        Variant::Int64(-384747424424244),
+        Variant::UInt64(9384747424422314244),
+        Variant::Float(77.33f32),
+        Variant::Double(99.123f64),
+        Variant::from(DateTime::now()),
+        Variant::from(Guid::new()),
+        Variant::from(UAString::from("Hello Everybody")),
+        Variant::from(ByteString::from(b"Everything or nothing")),
+        Variant::XmlElement(XmlElement::from("<a>The world wonders</a>")),
+        Variant::from(NodeId::new(99, "hello everyone")),
+        Variant::from(ExpandedNodeId::new(NodeId::new(1, 100))),
+        Variant::from(StatusCode::BadTcpMessageTypeInvalid),
+        Variant::from(QualifiedName::new(100, "this is a qualified name")),
+        Variant::from(LocalizedText::new("en", "This text is localized")),
+        Variant::from(ExtensionObject::null()),
+    ]
+}
+
+#[test]
+fn variant_round_trip() {
+    sample_scalar_variants().into_iter().for_each(|v| {
+        // As a scalar
+        serialize_test(v.clone());
+
+        // As arrays of 1 and 3 of the same value
+        serialize_test(Variant::from(vec![v.clone()]));
+        serialize_test(Variant::from(vec![v.clone(), v.clone(), v.clone()]));
+
+        // As a multi dimensional array
+        serialize_test(Variant::from((
+            vec![v.clone(), v.clone()],
+            vec![1u32, 2u32],
+        )));
+    });
+}
+
+#[test]
+fn variant_empty_array() {
+    // An empty array carries its element type through the encoding
+    let v = Variant::empty_array(VariantTypeId::Double);
+    assert_eq!(v.array_data_type(), Some(DataTypeId::Double.into()));
+    let bytes = v.encode_to_vec();
+    assert_eq!(bytes, vec![DataTypeId::Double as u8 | 0x80, 0, 0, 0, 0]);
+    let decoded = serialize_test_and_return(v.clone());
+    assert_eq!(decoded, v);
+    if let Variant::Array(array) = decoded {
+        assert!(array.values.is_empty());
+        assert_eq!(array.value_type(), VariantTypeId::Double);
+    } else {
+        panic!("Expected an array");
+    }
+
+    // An untyped empty array has no data type
+    let v = Variant::from(Vec::<Variant>::new());
+    assert!(v.array_data_type().is_none());
+    serialize_test(v);
+
+    // Empty arrays of an unknown element type are rejected
+    let mut stream = Cursor::new(vec![30 | 0x80, 0, 0, 0, 0]);
+    assert_eq!(
+        Variant::decode(&mut stream, &DecodingLimits::default()).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn variant_null_array() {
+    // A null array is encoded with the array bit and a length of -1, keeping its element type
+    let v = Variant::null_array(VariantTypeId::Int32);
+    let bytes = v.encode_to_vec();
+    assert_eq!(
+        bytes,
+        vec![DataTypeId::Int32 as u8 | 0x80, 0xff, 0xff, 0xff, 0xff]
+    );
+    let decoded = serialize_test_and_return(v.clone());
+    assert_eq!(decoded, v);
+    if let Variant::Array(ref array) = decoded {
+        assert!(array.is_null());
+        assert!(array.values.is_empty());
+        assert_eq!(array.value_type(), VariantTypeId::Int32);
+    } else {
+        panic!("Expected an array");
+    }
+    assert_eq!(decoded.encode_to_vec(), bytes);
+
+    // A null scalar is only the encoding byte and stays distinct from a null array
+    let null_scalar = serialize_test_and_return(Variant::Empty);
+    assert_eq!(null_scalar.encode_to_vec(), vec![0]);
+    assert_ne!(null_scalar, decoded);
+    assert!(!null_scalar.is_array());
+    assert!(decoded.is_array());
+
+    // Nor is a null array the same as an empty one
+    assert_ne!(decoded, Variant::empty_array(VariantTypeId::Int32));
+
+    // A null array which is given values is encoded as an ordinary array
+    let mut array = Array::new_null(VariantTypeId::Int32);
+    array.values.push(Variant::Int32(1));
+    assert!(!array.is_null());
+    let v = Variant::from(array);
+    assert_eq!(
+        v.encode_to_vec(),
+        vec![DataTypeId::Int32 as u8 | 0x80, 1, 0, 0, 0, 1, 0, 0, 0]
+    );
+    serialize_test(v);
+
+    // Dimensions make no sense on a null array
+    let mut stream = Cursor::new(vec![DataTypeId::Int32 as u8 | 0xc0, 0xff, 0xff, 0xff, 0xff]);
+    assert_eq!(
+        Variant::decode(&mut stream, &DecodingLimits::default()).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn variant_truncated_array() {
+    // Every truncation of an encoded array must be a clean error, never a partial array
+    let decoding_limits = DecodingLimits::default();
+    sample_scalar_variants().into_iter().for_each(|v| {
+        let array = Variant::from((vec![v.clone(), v.clone()], vec![2u32]));
+        let bytes = array.encode_to_vec();
+        (0..bytes.len()).for_each(|len| {
+            let mut stream = Cursor::new(&bytes[..len]);
+            let result = Variant::decode(&mut stream, &decoding_limits);
+            assert_eq!(
+                result,
+                Err(StatusCode::BadDecodingError),
+                "array {:?} truncated to {} bytes",
+                array,
+                len
+            );
+        });
+    });
+}
+
+#[test]
+fn variant_array_invalid_element_type() {
+    let decoding_limits = DecodingLimits::default();
+
+    // An array of 2 nulls is not decodable
+    let bytes = [0x80u8, 2, 0, 0, 0];
+    assert_eq!(
+        Variant::decode(&mut Cursor::new(&bytes[..]), &decoding_limits),
+        Err(StatusCode::BadDecodingError)
+    );
+
+    // An array of an unknown type, followed by what would be a string
+    let bytes = [0x80u8 | 30, 1, 0, 0, 0, 1, 0, 0, 0, b'A'];
+    assert_eq!(
+        Variant::decode(&mut Cursor::new(&bytes[..]), &decoding_limits),
+        Err(StatusCode::BadDecodingError)
+    );
+
+    // An array of DataValue / Variant / DiagnosticInfo cannot be framed
+    for element_type in 23..=25 {
+        let bytes = [0x80u8 | element_type, 1, 0, 0, 0, 0];
+        assert_eq!(
+            Variant::decode(&mut Cursor::new(&bytes[..]), &decoding_limits),
+            Err(StatusCode::BadDecodingError)
+        );
+    }
+}
+
 #[test]
 fn diagnostic_info() {
     let mut d = DiagnosticInfo {
@@ -471,6 +1072,126 @@ fn diagnostic_info() {
     serialize_test(d.clone());
 }
 
+#[test]
+fn diagnostic_info_encode_does_not_allocate() {
+    // Nest a few levels of diagnostics
+    let mut d = DiagnosticInfo {
+        symbolic_id: Some(1),
+        namespace_uri: None,
+        locale: None,
+        localized_text: None,
+        additional_info: Some(UAString::from("Innermost diagnostic")),
+        inner_status_code: Some(StatusCode::BadUnexpectedError),
+        inner_diagnostic_info: None,
+    };
+    for i in 0..5 {
+        d = DiagnosticInfo {
+            symbolic_id: Some(i),
+            namespace_uri: Some(i),
+            locale: None,
+            localized_text: None,
+            additional_info: Some(UAString::from("Diagnostic")),
+            inner_status_code: Some(StatusCode::BadUnexpectedError),
+            inner_diagnostic_info: Some(Box::new(d)),
+        };
+    }
+
+    let byte_len = d.byte_len();
+    let mut stream = Cursor::new(Vec::with_capacity(byte_len));
+    let allocations = allocations_during(|| {
+        assert_eq!(d.encode(&mut stream).unwrap(), byte_len);
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(stream.into_inner().len(), byte_len);
+}
+
+#[test]
+fn diagnostic_info_nesting_depth() {
+    let decoding_limits = DecodingLimits::default();
+
+    // Every level has only an inner diagnostic info, the innermost is empty
+    let nested = |depth: usize| {
+        let mut bytes = vec![DiagnosticInfoMask::HAS_INNER_DIAGNOSTIC_INFO.bits(); depth];
+        bytes.push(0);
+        bytes
+    };
+
+    let max_depth = decoding_limits.max_nesting_depth;
+    let d = DiagnosticInfo::decode(&mut Cursor::new(nested(max_depth)), &decoding_limits);
+    assert!(d.is_ok());
+    let d = DiagnosticInfo::decode(&mut Cursor::new(nested(max_depth + 1)), &decoding_limits);
+    assert_eq!(d.unwrap_err(), StatusCode::BadDecodingError);
+
+    // Far deeper nesting is rejected rather than overflowing the stack
+    let d = DiagnosticInfo::decode(&mut Cursor::new(nested(1_000_000)), &decoding_limits);
+    assert_eq!(d.unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn nesting_depth_is_shared() {
+    // A service fault whose response header holds a diagnostic info with 3 inner levels
+    let mut diagnostic_info = DiagnosticInfo::null();
+    for _ in 0..3 {
+        diagnostic_info = DiagnosticInfo {
+            inner_diagnostic_info: Some(Box::new(diagnostic_info)),
+            ..DiagnosticInfo::null()
+        };
+    }
+    let mut response_header = ResponseHeader::null();
+    response_header.service_diagnostics = diagnostic_info.clone();
+    let service_fault = ServiceFault { response_header };
+    let extension_object = ExtensionObject::from_encodable(
+        ObjectId::ServiceFault_Encoding_DefaultBinary,
+        &service_fault,
+    );
+
+    let limits = |max_nesting_depth| DecodingLimits {
+        max_nesting_depth,
+        ..Default::default()
+    };
+
+    // On its own the diagnostic info is 3 levels deep
+    let bytes = diagnostic_info.encode_to_vec();
+    assert!(DiagnosticInfo::decode(&mut Cursor::new(&bytes), &limits(3)).is_ok());
+    assert_eq!(
+        DiagnosticInfo::decode(&mut Cursor::new(&bytes), &limits(2)).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+
+    // The body of the extension object adds a level to the same count
+    assert_eq!(
+        extension_object
+            .decode_inner::<ServiceFault>(&limits(4))
+            .unwrap(),
+        service_fault
+    );
+    assert_eq!(
+        extension_object
+            .decode_inner::<ServiceFault>(&limits(3))
+            .unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+
+    // An extension object decoded inside the body of another adds a further level
+    let wrapper_id = NodeId::new(1, "Wrapper");
+    let outer = ExtensionObject::from_encodable(wrapper_id.clone(), &extension_object);
+
+    let mut registry = ExtensionObjectRegistry::new();
+    registry.register::<ServiceFault, _>(ObjectId::ServiceFault_Encoding_DefaultBinary);
+    registry.register_with(wrapper_id, |registry, body, decoding_limits| {
+        let inner = ExtensionObject::decode(&mut Cursor::new(body), decoding_limits)?;
+        match registry.decode(&inner, decoding_limits)? {
+            ResolvedValue::Typed(_, value) => Ok(value),
+            _ => Err(StatusCode::BadDecodingError),
+        }
+    });
+    assert!(registry.decode(&outer, &limits(5)).is_ok());
+    assert_eq!(
+        registry.decode(&outer, &limits(4)).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
 #[test]
 fn argument() {
     serialize_test(Argument {
@@ -481,3 +1202,258 @@ fn argument() {
         description: LocalizedText::new("foo", "bar"),
     });
 }
+
+#[test]
+fn write_repeated_bytes() {
+    // Crosses the internal block size so the remainder is written too
+    let mut stream = Cursor::new(Vec::new());
+    let size = write_bytes(&mut stream, 0x5a, 150).unwrap();
+    assert_eq!(size, 150);
+    let bytes = stream.into_inner();
+    assert_eq!(bytes.len(), 150);
+    assert!(bytes.iter().all(|b| *b == 0x5a));
+
+    let mut stream = Cursor::new(Vec::new());
+    assert_eq!(write_bytes(&mut stream, 0x5a, 0).unwrap(), 0);
+    assert!(stream.into_inner().is_empty());
+}
+
+#[test]
+fn decode_truncated_names_field() {
+    use crate::{BrowseNextRequest, BrowseRequest, ByteString, RequestHeader, ViewDescription};
+
+    let request = BrowseNextRequest {
+        request_header: RequestHeader::dummy(),
+        release_continuation_points: false,
+        continuation_points: Some(vec![
+            ByteString::from(b"continuation1"),
+            ByteString::from(b"continuation2"),
+        ]),
+    };
+    let bytes = request.encode_to_vec();
+
+    // Cut the stream off in the middle of the second continuation point
+    let truncated = &bytes[..bytes.len() - 4];
+    let err = decode_with_context::<BrowseNextRequest, _>(
+        &mut Cursor::new(truncated),
+        &DecodingLimits::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.status_code, StatusCode::BadDecodingError);
+    assert_eq!(err.fields, vec!["BrowseNextRequest.continuation_points"]);
+    assert_eq!(
+        err.to_string(),
+        "decoding BrowseNextRequest.continuation_points: BadDecodingError"
+    );
+
+    // A failure in a nested structure names every field from the outermost type inwards
+    let browse = BrowseRequest {
+        request_header: RequestHeader::dummy(),
+        view: ViewDescription {
+            view_id: NodeId::null(),
+            timestamp: DateTime::null(),
+            view_version: 1,
+        },
+        requested_max_references_per_node: 0,
+        nodes_to_browse: None,
+    };
+    let browse_bytes = browse.encode_to_vec();
+    // Cut the stream off in the middle of the view's timestamp
+    let truncated = &browse_bytes[..browse.request_header.byte_len() + 2 + 4];
+    let err = decode_with_context::<BrowseRequest, _>(
+        &mut Cursor::new(truncated),
+        &DecodingLimits::default(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "decoding BrowseRequest.view: decoding ViewDescription.timestamp: BadDecodingError"
+    );
+
+    // A successful decode is the same as decode()
+    let decoded = decode_with_context::<BrowseNextRequest, _>(
+        &mut Cursor::new(&bytes),
+        &DecodingLimits::default(),
+    )
+    .unwrap();
+    assert_eq!(decoded, request);
+}
+
+#[test]
+fn decode_array_length() {
+    use crate::{BrowseNextRequest, RequestHeader};
+
+    let request = BrowseNextRequest {
+        request_header: RequestHeader::dummy(),
+        release_continuation_points: false,
+        continuation_points: None,
+    };
+    // The continuation points length is the last field of the request
+    let bytes = request.encode_to_vec();
+    let prefix = &bytes[..bytes.len() - 4];
+    assert_eq!(&bytes[prefix.len()..], &(-1i32).to_le_bytes());
+
+    let decode_with_len = |len: i32| {
+        let mut bytes = prefix.to_vec();
+        bytes.extend_from_slice(&len.to_le_bytes());
+        BrowseNextRequest::decode(&mut Cursor::new(bytes), &DecodingLimits::default())
+    };
+
+    // -1 is a null array
+    let decoded = decode_with_len(-1).unwrap();
+    assert!(decoded.continuation_points.is_none());
+    assert_eq!(decoded.encode_to_vec(), bytes);
+
+    // 0 is an empty array, which is not the same as null
+    let decoded = decode_with_len(0).unwrap();
+    assert_eq!(decoded.continuation_points, Some(Vec::new()));
+    assert_ne!(decoded.encode_to_vec(), bytes);
+
+    // Any other negative length is invalid
+    assert_eq!(
+        decode_with_len(-2).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+    assert_eq!(
+        decode_with_len(i32::min_value()).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn encoding_vec() {
+    let values: Vec<i32> = vec![1, -2, i32::MAX];
+    let bytes = values.encode_to_vec();
+    assert_eq!(bytes.len(), 4 + 3 * 4);
+    assert_eq!(&bytes[..4], &3i32.to_le_bytes());
+    serialize_test(values);
+
+    // An empty vec has a length of 0
+    let values: Vec<i32> = Vec::new();
+    assert_eq!(values.encode_to_vec(), 0i32.to_le_bytes().to_vec());
+    serialize_test(values);
+
+    // A null array decodes as an empty vec
+    let decoded = Vec::<i32>::decode(
+        &mut Cursor::new((-1i32).to_le_bytes().to_vec()),
+        &DecodingLimits::default(),
+    )
+    .unwrap();
+    assert!(decoded.is_empty());
+}
+
+#[test]
+fn encoding_optional_vec() {
+    serialize_test(Some(vec![1i32, 2, 3]));
+    serialize_test(Some(Vec::<i32>::new()));
+
+    // A null array has a length of -1 and stays distinct from an empty array
+    let values: Option<Vec<i32>> = None;
+    assert_eq!(values.encode_to_vec(), (-1i32).to_le_bytes().to_vec());
+    serialize_test(values);
+
+    // The array length is subject to the decoding limits
+    let decoding_limits = DecodingLimits {
+        max_array_length: 2,
+        ..Default::default()
+    };
+    let bytes = Some(vec![1i32, 2, 3]).encode_to_vec();
+    assert_eq!(
+        Option::<Vec<i32>>::decode(&mut Cursor::new(bytes), &decoding_limits).unwrap_err(),
+        StatusCode::BadDecodingError
+    );
+}
+
+#[test]
+fn encoded_len_matches_byte_len() {
+    assert_encoded_len(&true);
+    assert_encoded_len(&-1i8);
+    assert_encoded_len(&1u16);
+    assert_encoded_len(&1i64);
+    assert_encoded_len(&1.5f64);
+    assert_encoded_len(&StatusCode::BadDecodingError);
+    assert_encoded_len(&DateTime::now());
+    assert_encoded_len(&Guid::new());
+
+    assert_encoded_len(&UAString::null());
+    assert_encoded_len(&UAString::from(""));
+    assert_encoded_len(&UAString::from("Hello world"));
+    assert_encoded_len(&ByteString::null());
+    assert_encoded_len(&ByteString::from(b"hello"));
+
+    assert_encoded_len(&QualifiedName::null());
+    assert_encoded_len(&QualifiedName::new(2, "Name"));
+
+    assert_encoded_len(&LocalizedText::null());
+    assert_encoded_len(&LocalizedText::text_only("Hi"));
+    assert_encoded_len(&LocalizedText::new("en", "Hi"));
+
+    assert_encoded_len(&NodeId::new(0, 1));
+    assert_encoded_len(&NodeId::new(1, 1000));
+    assert_encoded_len(&NodeId::new(300, 100_000));
+    assert_encoded_len(&NodeId::new(1, "Text"));
+    assert_encoded_len(&NodeId::new(1, Guid::new()));
+    assert_encoded_len(&NodeId::new(1, ByteString::from(b"bytes")));
+    let mut expanded_node_id = ExpandedNodeId::new(NodeId::new(200, 2000));
+    expanded_node_id.namespace_uri = UAString::from("urn:test");
+    expanded_node_id.server_index = 5;
+    assert_encoded_len(&expanded_node_id);
+
+    let empty_diagnostic_info = DiagnosticInfo::default();
+    assert_encoded_len(&empty_diagnostic_info);
+    let diagnostic_info = DiagnosticInfo {
+        symbolic_id: Some(1),
+        namespace_uri: Some(2),
+        locale: Some(3),
+        localized_text: Some(4),
+        additional_info: Some(UAString::from("Additional")),
+        inner_status_code: Some(StatusCode::BadUnexpectedError),
+        inner_diagnostic_info: Some(Box::new(DiagnosticInfo {
+            symbolic_id: Some(5),
+            ..Default::default()
+        })),
+    };
+    assert_encoded_len(&diagnostic_info);
+
+    assert_encoded_len(&ExtensionObject::null());
+    assert_encoded_len(&ExtensionObject {
+        node_id: ObjectId::CreateSessionResponse_Encoding_DefaultBinary.into(),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(b"hello world")),
+    });
+    assert_encoded_len(&ExtensionObject {
+        node_id: ObjectId::CreateSessionResponse_Encoding_DefaultBinary.into(),
+        body: ExtensionObjectEncoding::XmlElement(XmlElement::from("<hello/>")),
+    });
+
+    assert_encoded_len(&Variant::Empty);
+    assert_encoded_len(&Variant::from(LocalizedText::new("en", "Hi")));
+    assert_encoded_len(&Variant::from(vec![1u32, 2, 3]));
+    assert_encoded_len(&DataValue::new_now(100i32));
+    assert_encoded_len(&DataValue::default());
+}
+
+#[test]
+fn decode_from_slice_consumed() {
+    use crate::{encoding, BrowseNextRequest, ByteString, RequestHeader};
+
+    let request = BrowseNextRequest {
+        request_header: RequestHeader::dummy(),
+        release_continuation_points: true,
+        continuation_points: Some(vec![ByteString::from(b"point")]),
+    };
+    let mut bytes = encoding::encode_to_vec(&request);
+    assert_eq!(bytes.len(), request.byte_len());
+
+    // Trailing bytes are not consumed
+    bytes.extend_from_slice(&[1, 2, 3]);
+    let (decoded, consumed) =
+        decode_from_slice::<BrowseNextRequest>(&bytes, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, request);
+    assert_eq!(consumed, request.byte_len());
+    assert_eq!(&bytes[consumed..], &[1, 2, 3]);
+
+    // A truncated slice is an error
+    let result =
+        decode_from_slice::<BrowseNextRequest>(&bytes[..consumed - 1], &DecodingLimits::default());
+    assert!(result.is_err());
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+
+use crate::{
+    status_code::StatusCode,
+    variant::{Variant, VariantTypeId},
+    ByteString, Guid, LocalizedText, NodeId, QualifiedName, UAString,
+};
+
+fn json_round_trip(variant: Variant, type_hint: VariantTypeId, expected: Value) {
+    let value = variant.to_json();
+    assert_eq!(value, expected);
+    assert_eq!(Variant::from_json(&value, type_hint).unwrap(), variant);
+}
+
+#[test]
+fn json_int32() {
+    json_round_trip(Variant::Int32(-42), VariantTypeId::Int32, json!(-42));
+    assert_eq!(
+        Variant::from_json(&json!(1i64 << 40), VariantTypeId::Int32),
+        Err(StatusCode::BadOutOfRange)
+    );
+    assert_eq!(
+        Variant::from_json(&json!("42"), VariantTypeId::Int32),
+        Err(StatusCode::BadTypeMismatch)
+    );
+}
+
+#[test]
+fn json_uint64() {
+    // Written as a string so no precision is lost, but a number is accepted when read
+    json_round_trip(
+        Variant::UInt64(u64::max_value()),
+        VariantTypeId::UInt64,
+        json!("18446744073709551615"),
+    );
+    json_round_trip(Variant::Int64(-5), VariantTypeId::Int64, json!("-5"));
+    assert_eq!(
+        Variant::from_json(&json!(7), VariantTypeId::UInt64).unwrap(),
+        Variant::UInt64(7)
+    );
+}
+
+#[test]
+fn json_64bit_precision() {
+    // 2^53 + 1 is the first integer that an f64 cannot hold, so it only survives as a string
+    let big = 9007199254740993u64;
+    assert_ne!(big as f64 as u64, big);
+
+    let round_trip = |variant: Variant, type_hint| {
+        let text = serde_json::to_string(&variant.to_json()).unwrap();
+        let value: Value = serde_json::from_str(&text).unwrap();
+        (text, Variant::from_json(&value, type_hint).unwrap())
+    };
+    let (text, variant) = round_trip(Variant::UInt64(big), VariantTypeId::UInt64);
+    assert_eq!(text, "\"9007199254740993\"");
+    assert_eq!(variant, Variant::UInt64(big));
+    let (text, variant) = round_trip(Variant::Int64(-(big as i64)), VariantTypeId::Int64);
+    assert_eq!(text, "\"-9007199254740993\"");
+    assert_eq!(variant, Variant::Int64(-(big as i64)));
+
+    // A JSON number that has already been through a double is not silently accepted
+    assert_eq!(
+        Variant::from_json(&json!(9007199254740992.0), VariantTypeId::UInt64),
+        Err(StatusCode::BadTypeMismatch)
+    );
+}
+
+#[test]
+fn json_byte_string() {
+    json_round_trip(
+        Variant::from(ByteString::from(b"hello world")),
+        VariantTypeId::ByteString,
+        json!("aGVsbG8gd29ybGQ="),
+    );
+    json_round_trip(
+        Variant::from(ByteString::null()),
+        VariantTypeId::ByteString,
+        Value::Null,
+    );
+    assert_eq!(
+        Variant::from_json(&json!("!not base64!"), VariantTypeId::ByteString),
+        Err(StatusCode::BadTypeMismatch)
+    );
+}
+
+#[test]
+fn json_other_scalars() {
+    json_round_trip(Variant::Boolean(true), VariantTypeId::Boolean, json!(true));
+    json_round_trip(Variant::Double(1.5), VariantTypeId::Double, json!(1.5));
+    json_round_trip(
+        Variant::Double(std::f64::INFINITY),
+        VariantTypeId::Double,
+        json!("Infinity"),
+    );
+    json_round_trip(
+        Variant::from("Hello"),
+        VariantTypeId::String,
+        json!("Hello"),
+    );
+    json_round_trip(
+        Variant::String(UAString::null()),
+        VariantTypeId::String,
+        Value::Null,
+    );
+    let guid = Guid::from_str("f9e561f3-351c-47a2-b969-b8d6d7226fee").unwrap();
+    json_round_trip(
+        Variant::from(guid),
+        VariantTypeId::Guid,
+        json!("f9e561f3-351c-47a2-b969-b8d6d7226fee"),
+    );
+    json_round_trip(
+        Variant::from(NodeId::new(2, "Temperature")),
+        VariantTypeId::NodeId,
+        json!("ns=2;s=Temperature"),
+    );
+    json_round_trip(
+        Variant::from(QualifiedName::new(2, "Name")),
+        VariantTypeId::QualifiedName,
+        json!({"Name": "Name", "Uri": 2}),
+    );
+    json_round_trip(
+        Variant::from(LocalizedText::new("en", "Text")),
+        VariantTypeId::LocalizedText,
+        json!({"Locale": "en", "Text": "Text"}),
+    );
+    json_round_trip(
+        Variant::StatusCode(StatusCode::BadTypeMismatch),
+        VariantTypeId::StatusCode,
+        json!(StatusCode::BadTypeMismatch.bits()),
+    );
+}
+
+#[test]
+fn json_array() {
+    json_round_trip(
+        Variant::from(vec![1u16, 2, 3]),
+        VariantTypeId::UInt16,
+        json!([1, 2, 3]),
+    );
+    json_round_trip(
+        Variant::empty_array(VariantTypeId::Int32),
+        VariantTypeId::Int32,
+        json!([]),
+    );
+    // Arrays of arrays are not a single dimension array
+    assert_eq!(
+        Variant::from_json(&json!([[1], [2]]), VariantTypeId::Int32),
+        Err(StatusCode::BadTypeMismatch)
+    );
+}
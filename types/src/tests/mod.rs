@@ -1,9 +1,14 @@
 mod date_time;
 mod encoding;
+#[cfg(feature = "json")]
+mod json;
 mod node_id;
 mod serde;
+mod service_types;
 mod variant;
 
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
 use std::cmp::PartialEq;
 use std::fmt::Debug;
 use std::io::Cursor;
@@ -12,6 +17,38 @@ use crate::argument::Argument;
 use crate::status_codes::StatusCode;
 use crate::*;
 
+/// Allocator that counts the allocations made by each thread so a test can assert that some
+/// operation does not allocate.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = Cell::new(0);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|a| a.set(a.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made on this thread while calling the function
+pub fn allocations_during<F>(f: F) -> usize
+where
+    F: FnOnce(),
+{
+    let before = ALLOCATIONS.with(|a| a.get());
+    f();
+    ALLOCATIONS.with(|a| a.get()) - before
+}
+
 pub fn serialize_test_and_return<T>(value: T) -> T
 where
     T: BinaryEncoder<T> + Debug + PartialEq + Clone,
@@ -58,6 +95,29 @@ where
     let _ = serialize_test_and_return(value);
 }
 
+/// Encodes the value to a vec and asserts that the number of bytes written, both as reported by
+/// `encode` and as found in the vec, equals the value's `byte_len()`.
+pub fn assert_encoded_len<T>(value: &T)
+where
+    T: BinaryEncoder<T> + Debug,
+{
+    let byte_len = value.byte_len();
+    let mut stream = Cursor::new(Vec::new());
+    let size = value.encode(&mut stream).unwrap();
+    let actual = stream.into_inner();
+    assert_eq!(
+        size, byte_len,
+        "encode() size disagrees with byte_len() for {:?}",
+        value
+    );
+    assert_eq!(
+        actual.len(),
+        byte_len,
+        "bytes written disagree with byte_len() for {:?}",
+        value
+    );
+}
+
 pub fn serialize_test_expected<T>(value: T, expected_value: T)
 where
     T: BinaryEncoder<T> + Debug + PartialEq,
@@ -62,6 +62,30 @@ fn parse_node_id_guid() {
     );
 }
 
+#[test]
+fn null_guid() {
+    let guid = Guid::null();
+    assert!(guid.is_null());
+    assert_eq!(guid.as_bytes(), &[0u8; 16]);
+    assert_eq!(Guid::from_bytes([0u8; 16]), guid);
+    assert_eq!(Guid::default(), guid);
+
+    let guid = Guid::from_str("72962B91-FA75-4ae6-8D28-B404DC7DAF63").unwrap();
+    assert!(!guid.is_null());
+    let mut bytes = [0u8; 16];
+    bytes[15] = 1;
+    assert!(!Guid::from_bytes(bytes).is_null());
+
+    // A null guid identifier is a null node id, but only in namespace 0
+    assert!(NodeId::null().is_null());
+    assert!(NodeId::new(0, Guid::null()).is_null());
+    assert!(!NodeId::new(1, Guid::null()).is_null());
+    assert!(!NodeId::new(0, guid).is_null());
+    assert!(NodeId::from_str("g=00000000-0000-0000-0000-000000000000")
+        .unwrap()
+        .is_null());
+}
+
 #[test]
 fn parse_node_id_byte_string() {
     // ByteString (sample bytes comes from OPC UA spec)
@@ -120,3 +144,60 @@ fn expanded_node_id() {
         node_id
     );
 }
+
+#[test]
+fn namespace_table() {
+    let mut table = NamespaceTable::new();
+    assert_eq!(table.len(), 1);
+    assert_eq!(table.uri_at(0), Some("http://opcfoundation.org/UA/"));
+    assert_eq!(table.index_of("http://opcfoundation.org/UA/"), Some(0));
+
+    // Adding a uri returns its index, adding it again returns the same index
+    assert_eq!(table.add("urn:a"), 1);
+    assert_eq!(table.add("urn:b"), 2);
+    assert_eq!(table.add("urn:a"), 1);
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.index_of("urn:b"), Some(2));
+    assert_eq!(table.uri_at(2), Some("urn:b"));
+    assert!(table.index_of("urn:c").is_none());
+    assert!(table.uri_at(3).is_none());
+}
+
+#[test]
+fn namespace_table_remap() {
+    let mut server = NamespaceTable::new();
+    server.add("urn:a");
+    server.add("urn:b");
+    // The client knows the same uris at different indices and lacks one
+    let client = NamespaceTable::from(vec![
+        "http://opcfoundation.org/UA/".to_string(),
+        "urn:b".to_string(),
+    ]);
+
+    let node_id = NodeId::new(2, "Temperature");
+    assert_eq!(
+        server.remap_node_id(&node_id, &client),
+        Some(NodeId::new(1, "Temperature"))
+    );
+    assert_eq!(
+        client.remap_node_id(&NodeId::new(1, 100), &server),
+        Some(NodeId::new(2, 100))
+    );
+    assert!(server
+        .remap_node_id(&NodeId::new(1, 100), &client)
+        .is_none());
+    assert!(server
+        .remap_node_id(&NodeId::new(7, 100), &client)
+        .is_none());
+
+    // The OPC UA namespace is always 0
+    assert_eq!(
+        server.remap_node_id(&ObjectId::RootFolder.into(), &client),
+        Some(ObjectId::RootFolder.into())
+    );
+
+    assert_eq!(
+        server.remap_qualified_name(&QualifiedName::new(2, "Name"), &client),
+        Some(QualifiedName::new(1, "Name"))
+    );
+}
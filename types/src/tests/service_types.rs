@@ -0,0 +1,166 @@
+//! Round trip tests for the generated service types. Each sample is encoded, its length checked
+//! against `byte_len()` and then decoded back and compared to the original, so a generator bug
+//! such as a field written out of order or a mishandled array shows up here.
+
+use crate::{tests::*, *};
+
+/// Defines a round trip test for each named sample value
+macro_rules! round_trip_tests {
+    ($($name: ident => $value: expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                serialize_test($value);
+            }
+        )*
+    };
+}
+
+fn request_header() -> RequestHeader {
+    RequestHeader::new(&NodeId::new(1, "token"), &DateTime::ymd(2020, 1, 2), 99)
+}
+
+fn response_header() -> ResponseHeader {
+    ResponseHeader {
+        timestamp: DateTime::ymd(2020, 1, 2),
+        request_handle: 99,
+        service_result: StatusCode::BadTimeout,
+        service_diagnostics: DiagnosticInfo::default(),
+        string_table: Some(vec![UAString::from("a"), UAString::null()]),
+        additional_header: ExtensionObject::null(),
+    }
+}
+
+round_trip_tests! {
+    browse_next_request => BrowseNextRequest {
+        request_header: request_header(),
+        release_continuation_points: true,
+        continuation_points: Some(vec![ByteString::from(vec![1u8, 2, 3]), ByteString::null()]),
+    };
+    browse_next_request_null_array => BrowseNextRequest {
+        request_header: request_header(),
+        release_continuation_points: false,
+        continuation_points: None,
+    };
+    browse_next_request_empty_array => BrowseNextRequest {
+        request_header: request_header(),
+        release_continuation_points: false,
+        continuation_points: Some(Vec::new()),
+    };
+    sessionless_invoke_response_type => SessionlessInvokeResponseType {
+        namespace_uris: Some(vec![UAString::from("urn:a"), UAString::from("urn:b")]),
+        server_uris: None,
+        service_id: 0x1234_5678,
+    };
+    read_request => ReadRequest {
+        request_header: request_header(),
+        max_age: 500.5,
+        timestamps_to_return: TimestampsToReturn::Both,
+        nodes_to_read: Some(vec![ReadValueId {
+            node_id: NodeId::new(2, 100),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::from("1:2"),
+            data_encoding: QualifiedName::new(0, "Default Binary"),
+        }]),
+    };
+    write_request => WriteRequest {
+        request_header: request_header(),
+        nodes_to_write: Some(vec![WriteValue {
+            node_id: NodeId::new(2, "v1"),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::null(),
+            value: DataValue::new_now(Variant::from(vec![1i32, 2, 3])),
+        }]),
+    };
+    browse_response => BrowseResponse {
+        response_header: response_header(),
+        results: Some(vec![BrowseResult {
+            status_code: StatusCode::Good,
+            continuation_point: ByteString::null(),
+            references: Some(vec![ReferenceDescription {
+                reference_type_id: ReferenceTypeId::Organizes.into(),
+                is_forward: false,
+                node_id: ExpandedNodeId::new(NodeId::new(3, Guid::new())),
+                browse_name: QualifiedName::new(3, "Name"),
+                display_name: LocalizedText::new("en", "Name"),
+                node_class: NodeClass::Variable,
+                type_definition: ExpandedNodeId::new(VariableTypeId::BaseDataVariableType),
+            }]),
+        }]),
+        diagnostic_infos: None,
+    };
+    call_method_request => CallMethodRequest {
+        object_id: ObjectId::Server.into(),
+        method_id: MethodId::Server_GetMonitoredItems.into(),
+        input_arguments: Some(vec![Variant::from(1u32), Variant::from("two"), Variant::Empty]),
+    };
+    service_fault => ServiceFault {
+        response_header: response_header(),
+    };
+}
+
+#[test]
+fn message_security_mode_encoding() {
+    // The security mode is encoded as the Int32 value of the OPC UA enumeration
+    serialize_and_compare(MessageSecurityMode::Invalid, &[0, 0, 0, 0]);
+    serialize_and_compare(MessageSecurityMode::None, &[1, 0, 0, 0]);
+    serialize_and_compare(MessageSecurityMode::Sign, &[2, 0, 0, 0]);
+    serialize_and_compare(MessageSecurityMode::SignAndEncrypt, &[3, 0, 0, 0]);
+    for mode in &[
+        MessageSecurityMode::None,
+        MessageSecurityMode::Sign,
+        MessageSecurityMode::SignAndEncrypt,
+    ] {
+        serialize_test(*mode);
+    }
+}
+
+#[test]
+fn browse_next_request_pretty_print() {
+    let request = BrowseNextRequest {
+        request_header: request_header(),
+        release_continuation_points: false,
+        continuation_points: Some(vec![ByteString::from(&[0xde, 0xad]), ByteString::null()]),
+    };
+    let expected = r#"  BrowseNextRequest {
+      request_header: RequestHeader {
+          authentication_token: ns=1;s=token,
+          timestamp: 2020-01-02T00:00:00+00:00,
+          request_handle: 99,
+          return_diagnostics: (empty),
+          audit_entry_id: [null],
+          timeout_hint: 0,
+          additional_header: ExtensionObject {
+              node_id: i=0,
+              body: None,
+          },
+      },
+      release_continuation_points: false,
+      continuation_points: Some(
+          [
+              dead,
+              [null],
+          ],
+      ),
+  }"#;
+    assert_eq!(request.pretty_print(2), expected);
+
+    // Built-in types print the same way on their own
+    assert_eq!(NodeId::new(1, "token").pretty_print(0), "ns=1;s=token");
+    assert_eq!(
+        NodeId::new(2, "a \"b\"\n").pretty_print(0),
+        "ns=2;s=a \"b\"\n"
+    );
+    assert_eq!(
+        NodeId::new(0, ByteString::from(&[1, 2])).pretty_print(0),
+        "b=AQI="
+    );
+    assert_eq!(
+        Variant::from(UAString::null()).pretty_print(2),
+        "  String(\n      [null],\n  )"
+    );
+
+    // The debug forms are unchanged
+    assert!(format!("{:?}", request).contains("NodeId { namespace: 1, identifier: String("));
+    assert!(format!("{:#?}", request).contains("authentication_token: NodeId {"));
+}
@@ -2,8 +2,11 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 
 use crate::{
+    array::Array,
+    encoding::BinaryEncoder,
     numeric_range::NumericRange,
     status_code::StatusCode,
+    tests::serialize_test,
     variant::{Variant, VariantTypeId},
     ByteString, DataTypeId, DateTime, ExpandedNodeId, Guid, LocalizedText, NodeId, QualifiedName,
     UAString,
@@ -26,6 +29,177 @@ fn is_numeric() {
     assert_eq!(Variant::from(true).is_numeric(), false);
 }
 
+#[test]
+fn numeric_eq() {
+    // Same value, different widths
+    assert_eq!(Variant::Int32(5).numeric_eq(&Variant::Int16(5)), Some(true));
+    assert_eq!(Variant::Byte(5).numeric_eq(&Variant::UInt64(5)), Some(true));
+    assert_eq!(
+        Variant::Int64(-5).numeric_eq(&Variant::SByte(-5)),
+        Some(true)
+    );
+    assert_eq!(
+        Variant::Double(5.0).numeric_eq(&Variant::Int32(5)),
+        Some(true)
+    );
+
+    // Different values
+    assert_eq!(
+        Variant::Int32(5).numeric_eq(&Variant::Int16(6)),
+        Some(false)
+    );
+    assert_eq!(
+        Variant::Float(5.5).numeric_eq(&Variant::Int32(5)),
+        Some(false)
+    );
+
+    // Non-numeric
+    assert_eq!(Variant::from("5").numeric_eq(&Variant::Int32(5)), None);
+    assert_eq!(Variant::Int32(5).numeric_eq(&Variant::Boolean(true)), None);
+    assert_eq!(Variant::Empty.numeric_eq(&Variant::Empty), None);
+}
+
+#[test]
+fn numeric_eq_nan() {
+    // NaN -> NaN is not a change
+    assert_eq!(
+        Variant::Double(f64::NAN).numeric_eq(&Variant::Double(f64::NAN)),
+        Some(true)
+    );
+    assert_eq!(
+        Variant::Float(f32::NAN).numeric_eq(&Variant::Double(f64::NAN)),
+        Some(true)
+    );
+    // NaN -> 1.0 and 1.0 -> NaN are
+    assert_eq!(
+        Variant::Double(f64::NAN).numeric_eq(&Variant::Double(1.0)),
+        Some(false)
+    );
+    assert_eq!(
+        Variant::Double(1.0).numeric_eq(&Variant::Double(f64::NAN)),
+        Some(false)
+    );
+    assert!(Variant::Float(f32::NAN).is_nan());
+    assert!(!Variant::Double(1.0).is_nan());
+    assert!(!Variant::Int32(1).is_nan());
+}
+
+#[test]
+fn scalar_accessors() {
+    // Matching arms
+    assert_eq!(Variant::Boolean(true).as_bool(), Some(true));
+    assert_eq!(Variant::SByte(-1).as_i8(), Some(-1));
+    assert_eq!(Variant::Byte(1).as_u8(), Some(1));
+    assert_eq!(Variant::Int16(-2).as_i16(), Some(-2));
+    assert_eq!(Variant::UInt16(2).as_u16(), Some(2));
+    assert_eq!(Variant::Int32(-3).as_i32(), Some(-3));
+    assert_eq!(Variant::UInt32(3).as_u32(), Some(3));
+    assert_eq!(Variant::Int64(-4).as_i64(), Some(-4));
+    assert_eq!(Variant::UInt64(4).as_u64(), Some(4));
+    assert_eq!(Variant::Float(5.5).as_f32(), Some(5.5));
+    assert_eq!(Variant::Double(6.5).as_f64(), Some(6.5));
+    assert_eq!(
+        Variant::StatusCode(StatusCode::BadTimeout).as_status_code(),
+        Some(StatusCode::BadTimeout)
+    );
+    assert_eq!(Variant::from("foo").as_string(), Some("foo"));
+    let node_id = NodeId::new(1, "foo");
+    assert_eq!(Variant::from(node_id.clone()).as_node_id(), Some(&node_id));
+    let now = DateTime::now();
+    assert_eq!(Variant::from(now.clone()).as_date_time(), Some(&now));
+
+    // Non-matching arms are not coerced, even when the value would fit
+    assert_eq!(Variant::Int16(3).as_i32(), None);
+    assert_eq!(Variant::Int32(3).as_i64(), None);
+    assert_eq!(Variant::UInt32(3).as_i32(), None);
+    assert_eq!(Variant::Int32(3).as_f64(), None);
+    assert_eq!(Variant::Float(5.5).as_f64(), None);
+    assert_eq!(Variant::Int32(1).as_bool(), None);
+    assert_eq!(Variant::from("true").as_bool(), None);
+    assert_eq!(Variant::Int32(3).as_string(), None);
+    assert_eq!(Variant::from(NodeId::new(1, "foo")).as_string(), None);
+    assert_eq!(Variant::Empty.as_i32(), None);
+    assert_eq!(Variant::from(vec![1i32, 2]).as_i32(), None);
+
+    // A null string has no value
+    assert_eq!(Variant::String(UAString::null()).as_string(), None);
+
+    // to_f64 converts any numeric type
+    assert_eq!(Variant::Int32(3).to_f64(), Some(3.0));
+    assert_eq!(Variant::Float(5.5).to_f64(), Some(5.5));
+    assert_eq!(Variant::from("3").to_f64(), None);
+}
+
+#[test]
+fn is_array_and_array_len() {
+    // Scalar
+    let v = Variant::from(5i32);
+    assert!(!v.is_array());
+    assert_eq!(v.array_len(), None);
+
+    // Null variant
+    assert!(!Variant::Empty.is_array());
+    assert_eq!(Variant::Empty.array_len(), None);
+
+    // 1-D array
+    let v = Variant::from(vec![1i32, 2, 3]);
+    assert!(v.is_array());
+    assert_eq!(v.array_len(), Some(3));
+
+    // Empty array
+    let v = Variant::from(Vec::<i32>::new());
+    assert!(v.is_array());
+    assert_eq!(v.array_len(), Some(0));
+
+    // Multi dimensional array counts every value
+    let values: Vec<Variant> = (0..6).map(|i| Variant::from(i as i32)).collect();
+    let v = Variant::from(Array::new_multi(values, vec![2u32, 3u32]));
+    assert!(v.is_array());
+    assert_eq!(v.array_len(), Some(6));
+}
+
+#[test]
+fn coerce_to_boolean() {
+    // Strings are case insensitive
+    assert_eq!(Variant::from("TRUE").coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::from("true").coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::from("False").coerce_to_boolean(), Ok(false));
+    assert_eq!(Variant::from("1").coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::from("0").coerce_to_boolean(), Ok(false));
+    assert_eq!(
+        Variant::from("yes").coerce_to_boolean(),
+        Err(StatusCode::BadTypeMismatch)
+    );
+    assert_eq!(
+        Variant::from(UAString::null()).coerce_to_boolean(),
+        Err(StatusCode::BadTypeMismatch)
+    );
+
+    // Numbers are true when non-zero
+    assert_eq!(Variant::Boolean(true).coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::Int32(5).coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::Int32(-1).coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::Int32(0).coerce_to_boolean(), Ok(false));
+    assert_eq!(Variant::Byte(0).coerce_to_boolean(), Ok(false));
+    assert_eq!(Variant::UInt64(2).coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::Double(0.5).coerce_to_boolean(), Ok(true));
+    assert_eq!(Variant::Float(0.0).coerce_to_boolean(), Ok(false));
+
+    // Everything else is a mismatch
+    assert_eq!(
+        Variant::from(Guid::new()).coerce_to_boolean(),
+        Err(StatusCode::BadTypeMismatch)
+    );
+    assert_eq!(
+        Variant::Empty.coerce_to_boolean(),
+        Err(StatusCode::BadTypeMismatch)
+    );
+    assert_eq!(
+        Variant::from(vec![true]).coerce_to_boolean(),
+        Err(StatusCode::BadTypeMismatch)
+    );
+}
+
 #[test]
 fn size() {
     // Test that the variant is boxing enough data to keep the stack size down to some manageable
@@ -36,6 +210,72 @@ fn size() {
     assert!(vsize <= 32);
 }
 
+#[test]
+fn variant_from_scalars() {
+    assert_eq!(Variant::from(-5i8), Variant::SByte(-5));
+    assert_eq!(Variant::from(5u8), Variant::Byte(5));
+    assert_eq!(Variant::from(-500i16), Variant::Int16(-500));
+    assert_eq!(Variant::from(500u16), Variant::UInt16(500));
+    assert_eq!(Variant::from(-50000i32), Variant::Int32(-50000));
+    assert_eq!(Variant::from(50000u32), Variant::UInt32(50000));
+    assert_eq!(
+        Variant::from(i64::min_value()),
+        Variant::Int64(i64::min_value())
+    );
+    assert_eq!(
+        Variant::from(u64::max_value()),
+        Variant::UInt64(u64::max_value())
+    );
+    assert_eq!(Variant::from(1.5f32), Variant::Float(1.5));
+    assert_eq!(Variant::from(-1.5f64), Variant::Double(-1.5));
+    assert_eq!(
+        Variant::from(UAString::from("hello")),
+        Variant::String(UAString::from("hello"))
+    );
+
+    let guid = Guid::new();
+    assert_eq!(Variant::from(guid.clone()), Variant::Guid(Box::new(guid)));
+
+    let byte_string = ByteString::from(vec![1u8, 2, 3]);
+    assert_eq!(
+        Variant::from(byte_string.clone()),
+        Variant::ByteString(byte_string)
+    );
+
+    let qualified_name = QualifiedName::new(2, "Name");
+    assert_eq!(
+        Variant::from(qualified_name.clone()),
+        Variant::QualifiedName(Box::new(qualified_name))
+    );
+
+    let date_time = DateTime::now();
+    assert_eq!(
+        Variant::from(date_time.clone()),
+        Variant::DateTime(Box::new(date_time))
+    );
+
+    assert_eq!(
+        Variant::from(StatusCode::BadNodeIdUnknown),
+        Variant::StatusCode(StatusCode::BadNodeIdUnknown)
+    );
+}
+
+#[test]
+fn variant_from_64bit_arrays() {
+    let v = Variant::from(vec![-1i64, i64::max_value()]);
+    assert_eq!(v.array_len(), Some(2));
+    assert_eq!(
+        v,
+        Variant::from(vec![Variant::Int64(-1), Variant::Int64(i64::max_value())])
+    );
+
+    let v = Variant::from(&[1u64, u64::max_value()][..]);
+    assert_eq!(
+        v,
+        Variant::from(vec![Variant::UInt64(1), Variant::UInt64(u64::max_value())])
+    );
+}
+
 #[test]
 fn variant_type_id() {
     use crate::status_codes::StatusCode;
@@ -251,6 +491,89 @@ fn index_of_string() {
     assert_eq!(r, StatusCode::BadIndexRangeNoData);
 }
 
+#[test]
+fn index_of_multi_dimension_array() {
+    // 3x4 matrix holding 0..12
+    let values: Vec<Variant> = (0..12).map(|i| Variant::from(i as i32)).collect();
+    let v = Variant::from(Array::new_multi(values, vec![3u32, 4u32]));
+
+    // 2x2 block from rows 1..2, columns 0..1
+    let r = v.range_of("1:2,0:1".parse().unwrap()).unwrap();
+    match r {
+        Variant::Array(array) => {
+            assert_eq!(array.dimensions, vec![2u32, 2u32]);
+            assert_eq!(
+                array.values,
+                vec![
+                    Variant::Int32(4),
+                    Variant::Int32(5),
+                    Variant::Int32(8),
+                    Variant::Int32(9)
+                ]
+            );
+        }
+        _ => panic!(),
+    }
+
+    // Single element
+    let r = v.range_of("2,3".parse().unwrap()).unwrap();
+    match r {
+        Variant::Array(array) => {
+            assert_eq!(array.dimensions, vec![1u32, 1u32]);
+            assert_eq!(array.values, vec![Variant::Int32(11)]);
+        }
+        _ => panic!(),
+    }
+
+    // Max is clamped to the dimension
+    let r = v.range_of("0,2:100".parse().unwrap()).unwrap();
+    match r {
+        Variant::Array(array) => {
+            assert_eq!(array.dimensions, vec![1u32, 2u32]);
+            assert_eq!(array.values, vec![Variant::Int32(2), Variant::Int32(3)]);
+        }
+        _ => panic!(),
+    }
+
+    // Out of bounds and wrong number of dimensions
+    assert_eq!(
+        v.range_of("3,0".parse().unwrap()).unwrap_err(),
+        StatusCode::BadIndexRangeNoData
+    );
+    assert_eq!(
+        v.range_of("0,0,0".parse().unwrap()).unwrap_err(),
+        StatusCode::BadIndexRangeNoData
+    );
+}
+
+#[test]
+fn numeric_range_apply() {
+    let v = Variant::from(vec![10i32, 20, 30, 40]);
+
+    // Single index
+    let range = NumericRange::from_str("2").unwrap();
+    assert_eq!(range.apply(&v).unwrap(), Variant::from(vec![30i32]));
+
+    // Range
+    let range = NumericRange::from_str("1:3").unwrap();
+    assert_eq!(range.apply(&v).unwrap(), Variant::from(vec![20i32, 30, 40]));
+
+    // Out of bounds
+    let range = NumericRange::from_str("4:6").unwrap();
+    assert_eq!(
+        range.apply(&v).unwrap_err(),
+        StatusCode::BadIndexRangeNoData
+    );
+
+    // Malformed
+    assert!(NumericRange::from_str("3:1").is_err());
+    let range = NumericRange::Range(3, 1);
+    assert_eq!(
+        range.apply(&v).unwrap_err(),
+        StatusCode::BadIndexRangeInvalid
+    );
+}
+
 fn ensure_conversion_fails(v: &Variant, convert_to: &[VariantTypeId]) {
     convert_to
         .iter()
@@ -1458,3 +1781,83 @@ fn variant_bytestring_to_bytearray() {
 }
 
 // TODO arrays
+
+#[test]
+fn variant_display() {
+    assert_eq!(Variant::Double(1.5).to_string(), "1.5");
+    assert_eq!(
+        Variant::from("Hello \"World\"").to_string(),
+        "\"Hello \\\"World\\\"\""
+    );
+    assert_eq!(Variant::String(UAString::null()).to_string(), "null");
+    assert_eq!(Variant::Boolean(true).to_string(), "true");
+    assert_eq!(Variant::Boolean(false).to_string(), "false");
+    assert_eq!(Variant::Empty.to_string(), "null");
+
+    let guid = Guid::from_str("f9e561f3-351c-47a2-b969-b8d6d7226fee").unwrap();
+    assert_eq!(
+        Variant::from(guid).to_string(),
+        "f9e561f3-351c-47a2-b969-b8d6d7226fee"
+    );
+
+    let v = Variant::from(vec![1i32, -2, 3]);
+    assert_eq!(v.to_string(), "[1, -2, 3]");
+    let v = Variant::from(Vec::<i32>::new());
+    assert_eq!(v.to_string(), "[]");
+}
+
+#[test]
+fn variant_type_id_encoding_byte() {
+    // Both directions for a few types
+    for (type_id, encoding_byte) in &[
+        (VariantTypeId::Empty, 0u8),
+        (VariantTypeId::Boolean, 1),
+        (VariantTypeId::Int32, 6),
+        (VariantTypeId::Double, 11),
+        (VariantTypeId::String, 12),
+        (VariantTypeId::LocalizedText, 21),
+        (VariantTypeId::ExtensionObject, 22),
+    ] {
+        assert_eq!(type_id.to_encoding_byte(), *encoding_byte);
+        assert_eq!(
+            VariantTypeId::from_encoding_byte(*encoding_byte),
+            Some(*type_id)
+        );
+    }
+    assert_eq!(VariantTypeId::Array.to_encoding_byte(), 0);
+
+    // The encoding byte of a scalar is the same as its data type id
+    assert_eq!(
+        VariantTypeId::Guid.to_encoding_byte() as u32,
+        DataTypeId::Guid as u32
+    );
+    let value = Variant::from(LocalizedText::new("en", "text"));
+    assert_eq!(value.encode_to_vec()[0], value.type_id().to_encoding_byte());
+
+    // DataValue, Variant and DiagnosticInfo cannot be held by a variant
+    assert_eq!(VariantTypeId::from_encoding_byte(23), None);
+    assert_eq!(VariantTypeId::from_encoding_byte(24), None);
+    assert_eq!(VariantTypeId::from_encoding_byte(25), None);
+    // Reserved and out of range values
+    assert_eq!(VariantTypeId::from_encoding_byte(26), None);
+    assert_eq!(VariantTypeId::from_encoding_byte(63), None);
+    assert_eq!(VariantTypeId::from_encoding_byte(0x80 | 6), None);
+    assert_eq!(VariantTypeId::from_encoding_byte(255), None);
+}
+
+#[test]
+fn sample_round_trip() {
+    // Every type id, i.e. empty, the scalars and array
+    let type_ids = (0..=u8::MAX)
+        .filter_map(VariantTypeId::from_encoding_byte)
+        .chain(std::iter::once(VariantTypeId::Array))
+        .collect::<Vec<_>>();
+    assert_eq!(type_ids.len(), 24);
+    for type_id in type_ids {
+        let sample = Variant::sample(type_id);
+        assert_eq!(sample.type_id(), type_id);
+        // Samples are the same every time
+        assert_eq!(sample, Variant::sample(type_id));
+        serialize_test(sample);
+    }
+}
@@ -86,7 +86,7 @@ pub enum Variant {
 }
 
 /// The variant type id is the type of the variant but without its payload.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum VariantTypeId {
     // Null / Empty
     Empty,
@@ -116,6 +116,80 @@ pub enum VariantTypeId {
     Array,
 }
 
+impl VariantTypeId {
+    /// The scalar types, i.e. everything which may be the value type of an array
+    const SCALAR_TYPES: [VariantTypeId; 22] = [
+        VariantTypeId::Boolean,
+        VariantTypeId::SByte,
+        VariantTypeId::Byte,
+        VariantTypeId::Int16,
+        VariantTypeId::UInt16,
+        VariantTypeId::Int32,
+        VariantTypeId::UInt32,
+        VariantTypeId::Int64,
+        VariantTypeId::UInt64,
+        VariantTypeId::Float,
+        VariantTypeId::Double,
+        VariantTypeId::String,
+        VariantTypeId::DateTime,
+        VariantTypeId::Guid,
+        VariantTypeId::StatusCode,
+        VariantTypeId::ByteString,
+        VariantTypeId::XmlElement,
+        VariantTypeId::QualifiedName,
+        VariantTypeId::LocalizedText,
+        VariantTypeId::NodeId,
+        VariantTypeId::ExpandedNodeId,
+        VariantTypeId::ExtensionObject,
+    ];
+
+    /// Returns the encoding byte of a value of this type, i.e. its built-in type id from 1 for
+    /// `Boolean` to 22 for `ExtensionObject`. Empty and array have no type of their own and
+    /// return 0, which is the encoding of a null variant.
+    pub fn to_encoding_byte(&self) -> u8 {
+        match self {
+            VariantTypeId::Empty | VariantTypeId::Array => 0,
+            VariantTypeId::Boolean => DataTypeId::Boolean as u8,
+            VariantTypeId::SByte => DataTypeId::SByte as u8,
+            VariantTypeId::Byte => DataTypeId::Byte as u8,
+            VariantTypeId::Int16 => DataTypeId::Int16 as u8,
+            VariantTypeId::UInt16 => DataTypeId::UInt16 as u8,
+            VariantTypeId::Int32 => DataTypeId::Int32 as u8,
+            VariantTypeId::UInt32 => DataTypeId::UInt32 as u8,
+            VariantTypeId::Int64 => DataTypeId::Int64 as u8,
+            VariantTypeId::UInt64 => DataTypeId::UInt64 as u8,
+            VariantTypeId::Float => DataTypeId::Float as u8,
+            VariantTypeId::Double => DataTypeId::Double as u8,
+            VariantTypeId::String => DataTypeId::String as u8,
+            VariantTypeId::DateTime => DataTypeId::DateTime as u8,
+            VariantTypeId::Guid => DataTypeId::Guid as u8,
+            VariantTypeId::StatusCode => DataTypeId::StatusCode as u8,
+            VariantTypeId::ByteString => DataTypeId::ByteString as u8,
+            VariantTypeId::XmlElement => DataTypeId::XmlElement as u8,
+            VariantTypeId::QualifiedName => DataTypeId::QualifiedName as u8,
+            VariantTypeId::LocalizedText => DataTypeId::LocalizedText as u8,
+            VariantTypeId::NodeId => DataTypeId::NodeId as u8,
+            VariantTypeId::ExpandedNodeId => DataTypeId::ExpandedNodeId as u8,
+            // The built-in id of ExtensionObject is shared with the Structure data type
+            VariantTypeId::ExtensionObject => DataTypeId::Structure as u8,
+        }
+    }
+
+    /// Returns the type with the encoding byte, the reverse of `to_encoding_byte()`. 0 is
+    /// `Empty`. The built-in types a variant cannot hold (`DataValue`, `Variant` and
+    /// `DiagnosticInfo`), the reserved ids and any byte with the array bits set return `None`.
+    pub fn from_encoding_byte(encoding_byte: u8) -> Option<VariantTypeId> {
+        if encoding_byte == 0 {
+            Some(VariantTypeId::Empty)
+        } else {
+            Self::SCALAR_TYPES
+                .iter()
+                .find(|t| t.to_encoding_byte() == encoding_byte)
+                .copied()
+        }
+    }
+}
+
 impl TryFrom<&NodeId> for VariantTypeId {
     type Error = ();
     fn try_from(value: &NodeId) -> Result<Self, Self::Error> {
@@ -453,6 +527,8 @@ from_array_to_variant_impl!(i16);
 from_array_to_variant_impl!(u16);
 from_array_to_variant_impl!(i32);
 from_array_to_variant_impl!(u32);
+from_array_to_variant_impl!(i64);
+from_array_to_variant_impl!(u64);
 from_array_to_variant_impl!(f32);
 from_array_to_variant_impl!(f64);
 
@@ -585,6 +661,7 @@ impl BinaryEncoder<Variant> for Variant {
             Variant::QualifiedName(value) => value.encode(stream)?,
             Variant::LocalizedText(value) => value.encode(stream)?,
             Variant::ExtensionObject(value) => value.encode(stream)?,
+            Variant::Array(array) if array.is_null() => write_i32(stream, -1)?,
             Variant::Array(array) => {
                 let mut size = write_i32(stream, array.values.len() as i32)?;
                 for value in array.values.iter() {
@@ -615,7 +692,23 @@ impl BinaryEncoder<Variant> for Variant {
         // Read array length
         let array_length = if encoding_mask & ARRAY_VALUES_BIT != 0 {
             let array_length = i32::decode(stream, decoding_limits)?;
-            if array_length <= 0 {
+            if array_length == -1 {
+                // A null array, which like an empty array keeps the element type
+                return if encoding_mask & ARRAY_DIMENSIONS_BIT != 0 {
+                    error!("Array dimensions bit specified on a null array");
+                    Err(StatusCode::BadDecodingError)
+                } else if let Some(value_type) =
+                    VariantTypeId::from_encoding_byte(element_encoding_mask)
+                {
+                    Ok(Variant::null_array(value_type))
+                } else {
+                    error!(
+                        "Variant array has an invalid element type {}",
+                        element_encoding_mask
+                    );
+                    Err(StatusCode::BadDecodingError)
+                };
+            } else if array_length < 0 {
                 error!("Invalid array_length {}", array_length);
                 return Err(StatusCode::BadDecodingError);
             }
@@ -625,12 +718,39 @@ impl BinaryEncoder<Variant> for Variant {
         };
 
         // Read the value(s). If array length was specified, we assume a single or multi dimension array
-        if array_length > 0 {
+        if array_length == 0 {
+            // An empty array keeps the element type from the encoding mask
+            if encoding_mask & ARRAY_DIMENSIONS_BIT != 0 {
+                error!("Array dimensions bit specified without any values");
+                Err(StatusCode::BadDecodingError)
+            } else if let Some(value_type) =
+                VariantTypeId::from_encoding_byte(element_encoding_mask)
+            {
+                Ok(Variant::empty_array(value_type))
+            } else {
+                error!(
+                    "Variant array has an invalid element type {}",
+                    element_encoding_mask
+                );
+                Err(StatusCode::BadDecodingError)
+            }
+        } else if array_length > 0 {
             // Array length in total cannot exceed max array length
             if array_length > decoding_limits.max_array_length as i32 {
                 return Err(StatusCode::BadEncodingLimitsExceeded);
             }
 
+            // Every element shares the type in the encoding mask, which must be a type that
+            // can be decoded or the elements cannot be framed
+            let element_type = VariantTypeId::from_encoding_byte(element_encoding_mask);
+            if element_type.is_none() || element_type == Some(VariantTypeId::Empty) {
+                error!(
+                    "Variant array has an invalid element type {}",
+                    element_encoding_mask
+                );
+                return Err(StatusCode::BadDecodingError);
+            }
+
             let mut values: Vec<Variant> = Vec::with_capacity(array_length as usize);
             for _ in 0..array_length {
                 values.push(Variant::decode_variant_value(
@@ -639,6 +759,16 @@ impl BinaryEncoder<Variant> for Variant {
                     decoding_limits,
                 )?);
             }
+            if values
+                .iter()
+                .any(|v| v.encoding_mask() != element_encoding_mask)
+            {
+                error!(
+                    "Variant array contains values which are not of type {}",
+                    element_encoding_mask
+                );
+                return Err(StatusCode::BadDecodingError);
+            }
             if encoding_mask & ARRAY_DIMENSIONS_BIT != 0 {
                 if let Some(dimensions) = read_array(stream, decoding_limits)? {
                     if let Some(_) = dimensions.iter().find(|d| **d <= 0) {
@@ -681,10 +811,12 @@ impl Default for Variant {
 }
 
 /// This implementation is mainly for debugging / convenience purposes, to eliminate some of the
-/// noise in common types from using the Debug trait.
+/// noise in common types from using the Debug trait. Scalars are rendered naturally with strings
+/// quoted, null values as `null` and arrays as `[a, b, c]`.
 impl fmt::Display for Variant {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Variant::Empty => write!(f, "null"),
             Variant::SByte(v) => write!(f, "{}", v),
             Variant::Byte(v) => write!(f, "{}", v),
             Variant::Int16(v) => write!(f, "{}", v),
@@ -696,14 +828,46 @@ impl fmt::Display for Variant {
             Variant::Float(v) => write!(f, "{}", v),
             Variant::Double(v) => write!(f, "{}", v),
             Variant::Boolean(v) => write!(f, "{}", v),
-            Variant::String(ref v) => write!(f, "{}", v.to_string()),
-            Variant::Guid(ref v) => write!(f, "{}", v.to_string()),
-            Variant::DateTime(ref v) => write!(f, "{}", v.to_string()),
+            Variant::String(ref v) => match v.value() {
+                Some(ref v) => write!(f, "{:?}", v),
+                None => write!(f, "null"),
+            },
+            Variant::Guid(ref v) => write!(f, "{}", v),
+            Variant::DateTime(ref v) => write!(f, "{}", v),
+            Variant::StatusCode(ref v) => write!(f, "{}", v),
+            Variant::ByteString(ref v) => write!(f, "{}", v),
+            Variant::NodeId(ref v) => write!(f, "{}", v),
+            Variant::ExpandedNodeId(ref v) => write!(f, "{}", v),
+            Variant::LocalizedText(ref v) => write!(f, "{}", v),
+            Variant::Array(ref v) if v.is_null() => write!(f, "null"),
+            Variant::Array(ref v) => {
+                write!(f, "[")?;
+                for (i, value) in v.values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
             value => write!(f, "{:?}", value),
         }
     }
 }
 
+macro_rules! scalar_accessor_impl {
+    ($name: ident, $rtype: ident, $vtype: ident) => {
+        /// Returns the value if the variant is a scalar of exactly the matching type, or `None`
+        /// for any other variant. No conversion is attempted.
+        pub fn $name(&self) -> Option<$rtype> {
+            match self {
+                Variant::$vtype(v) => Some(*v),
+                _ => None,
+            }
+        }
+    };
+}
+
 impl Variant {
     /// Test the flag (convenience method)
     pub fn test_encoding_flag(encoding_mask: u8, data_type_id: DataTypeId) -> bool {
@@ -782,55 +946,45 @@ impl Variant {
         encoding_mask: u8,
         decoding_limits: &DecodingLimits,
     ) -> EncodingResult<Self> {
-        let result = if encoding_mask == 0 {
-            Variant::Empty
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Boolean) {
-            Self::from(bool::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::SByte) {
-            Self::from(i8::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Byte) {
-            Self::from(u8::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Int16) {
-            Self::from(i16::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::UInt16) {
-            Self::from(u16::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Int32) {
-            Self::from(i32::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::UInt32) {
-            Self::from(u32::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Int64) {
-            Self::from(i64::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::UInt64) {
-            Self::from(u64::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Float) {
-            Self::from(f32::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Double) {
-            Self::from(f64::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::String) {
-            Self::from(UAString::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::DateTime) {
-            Self::from(DateTime::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Guid) {
-            Self::from(Guid::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::ByteString) {
-            Self::from(ByteString::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::XmlElement) {
-            // Force the type to be XmlElement since its typedef'd to UAString
-            Variant::XmlElement(XmlElement::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::NodeId) {
-            Self::from(NodeId::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::ExpandedNodeId) {
-            Self::from(ExpandedNodeId::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::StatusCode) {
-            Self::from(StatusCode::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::QualifiedName) {
-            Self::from(QualifiedName::decode(stream, decoding_limits)?)
-        } else if Self::test_encoding_flag(encoding_mask, DataTypeId::LocalizedText) {
-            Self::from(LocalizedText::decode(stream, decoding_limits)?)
-        } else if encoding_mask == 22 {
-            Self::from(ExtensionObject::decode(stream, decoding_limits)?)
-        } else {
-            Variant::Empty
+        let result = match VariantTypeId::from_encoding_byte(encoding_mask) {
+            Some(VariantTypeId::Boolean) => Self::from(bool::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::SByte) => Self::from(i8::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Byte) => Self::from(u8::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Int16) => Self::from(i16::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::UInt16) => Self::from(u16::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Int32) => Self::from(i32::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::UInt32) => Self::from(u32::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Int64) => Self::from(i64::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::UInt64) => Self::from(u64::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Float) => Self::from(f32::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Double) => Self::from(f64::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::String) => Self::from(UAString::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::DateTime) => Self::from(DateTime::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::Guid) => Self::from(Guid::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::ByteString) => {
+                Self::from(ByteString::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::XmlElement) => {
+                // Force the type to be XmlElement since its typedef'd to UAString
+                Variant::XmlElement(XmlElement::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::NodeId) => Self::from(NodeId::decode(stream, decoding_limits)?),
+            Some(VariantTypeId::ExpandedNodeId) => {
+                Self::from(ExpandedNodeId::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::StatusCode) => {
+                Self::from(StatusCode::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::QualifiedName) => {
+                Self::from(QualifiedName::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::LocalizedText) => {
+                Self::from(LocalizedText::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::ExtensionObject) => {
+                Self::from(ExtensionObject::decode(stream, decoding_limits)?)
+            }
+            Some(VariantTypeId::Empty) | Some(VariantTypeId::Array) | None => Variant::Empty,
         };
         Ok(result)
     }
@@ -1337,6 +1491,46 @@ impl Variant {
         }
     }
 
+    /// Tests and returns true if the variant holds a `Double` or `Float` that is not a number
+    pub fn is_nan(&self) -> bool {
+        match self {
+            Variant::Double(v) => v.is_nan(),
+            Variant::Float(v) => v.is_nan(),
+            _ => false,
+        }
+    }
+
+    /// Coerces the variant to a boolean following the implicit conversion rules of Part 6. A
+    /// boolean is returned as is, a number is true if it is non-zero and a string is true or
+    /// false if it is "true" / "1" or "false" / "0", ignoring case. Anything else is
+    /// `BadTypeMismatch`.
+    pub fn coerce_to_boolean(&self) -> Result<bool, StatusCode> {
+        match self {
+            Variant::Boolean(v) => Ok(*v),
+            Variant::SByte(v) => Ok(*v != 0),
+            Variant::Byte(v) => Ok(*v != 0),
+            Variant::Int16(v) => Ok(*v != 0),
+            Variant::UInt16(v) => Ok(*v != 0),
+            Variant::Int32(v) => Ok(*v != 0),
+            Variant::UInt32(v) => Ok(*v != 0),
+            Variant::Int64(v) => Ok(*v != 0),
+            Variant::UInt64(v) => Ok(*v != 0),
+            Variant::Float(v) => Ok(*v != 0.0),
+            Variant::Double(v) => Ok(*v != 0.0),
+            Variant::String(v) if !v.is_null() => {
+                let v = v.as_ref();
+                if v.eq_ignore_ascii_case("true") || v == "1" {
+                    Ok(true)
+                } else if v.eq_ignore_ascii_case("false") || v == "0" {
+                    Ok(false)
+                } else {
+                    Err(StatusCode::BadTypeMismatch)
+                }
+            }
+            _ => Err(StatusCode::BadTypeMismatch),
+        }
+    }
+
     /// Test if the variant holds an array
     pub fn is_array(&self) -> bool {
         match self {
@@ -1345,6 +1539,15 @@ impl Variant {
         }
     }
 
+    /// Returns the number of values held by an array variant, or `None` if the variant is not an
+    /// array. For a multi dimensional array this is the total number of values in every dimension.
+    pub fn array_len(&self) -> Option<usize> {
+        match self {
+            Variant::Array(array) => Some(array.values.len()),
+            _ => None,
+        }
+    }
+
     pub fn is_array_of_type(&self, variant_type: VariantTypeId) -> bool {
         // A non-numeric value in the array means it is not numeric
         match self {
@@ -1363,8 +1566,9 @@ impl Variant {
         }
     }
 
-    /// Converts the numeric type to a double or returns None
-    pub fn as_f64(&self) -> Option<f64> {
+    /// Converts the numeric type to a double or returns None. Unlike `as_f64()` this accepts a
+    /// value of any numeric type.
+    pub fn to_f64(&self) -> Option<f64> {
         match *self {
             Variant::SByte(value) => Some(value as f64),
             Variant::Byte(value) => Some(value as f64),
@@ -1386,14 +1590,80 @@ impl Variant {
         }
     }
 
+    scalar_accessor_impl!(as_bool, bool, Boolean);
+    scalar_accessor_impl!(as_i8, i8, SByte);
+    scalar_accessor_impl!(as_u8, u8, Byte);
+    scalar_accessor_impl!(as_i16, i16, Int16);
+    scalar_accessor_impl!(as_u16, u16, UInt16);
+    scalar_accessor_impl!(as_i32, i32, Int32);
+    scalar_accessor_impl!(as_u32, u32, UInt32);
+    scalar_accessor_impl!(as_i64, i64, Int64);
+    scalar_accessor_impl!(as_u64, u64, UInt64);
+    scalar_accessor_impl!(as_f32, f32, Float);
+    scalar_accessor_impl!(as_f64, f64, Double);
+    scalar_accessor_impl!(as_status_code, StatusCode, StatusCode);
+
+    /// Returns the string if the variant is a non-null `String`, or `None` for any other variant
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Variant::String(v) => v.value().as_ref().map(|v| v.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the node id if the variant is a `NodeId`, or `None` for any other variant
+    pub fn as_node_id(&self) -> Option<&NodeId> {
+        match self {
+            Variant::NodeId(v) => Some(v.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the date time if the variant is a `DateTime`, or `None` for any other variant
+    pub fn as_date_time(&self) -> Option<&DateTime> {
+        match self {
+            Variant::DateTime(v) => Some(v.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Compares two numeric values regardless of the width of their representation, e.g. so that
+    /// `Int16(5)` and `Int32(5)` are equal. Both values are compared as `f64` within epsilon.
+    /// Unlike IEEE comparison, two NaNs are equal so a value that stays NaN is not seen as a
+    /// change, while NaN and a number are unequal. Returns `None` if either value is not numeric.
+    pub fn numeric_eq(&self, other: &Variant) -> Option<bool> {
+        match (self.to_f64(), other.to_f64()) {
+            (Some(v1), Some(v2)) if v1.is_nan() || v2.is_nan() => Some(v1.is_nan() && v2.is_nan()),
+            (Some(v1), Some(v2)) => Some((v1 - v2).abs() <= std::f64::EPSILON),
+            _ => None,
+        }
+    }
+
+    /// Tests if the two values are the same. This is the same as `==` except that NaN equals NaN,
+    /// so a value which stays NaN is not mistaken for a change in value.
+    pub fn value_eq(&self, other: &Variant) -> bool {
+        if self.is_nan() || other.is_nan() {
+            self.numeric_eq(other).unwrap_or(false)
+        } else {
+            self == other
+        }
+    }
+
     // Returns the data type of elements in array. Returns None if this is not an array or type
     // cannot be determined
     pub fn array_data_type(&self) -> Option<NodeId> {
         match self {
             Variant::Array(array) => {
                 if array.values.is_empty() {
-                    error!("Cannot get the data type of an empty array");
-                    None
+                    // An empty array only has the type it was created with. Built-in type ids are
+                    // the same as their data type ids except for ExtensionObject.
+                    match array.value_type {
+                        VariantTypeId::Empty | VariantTypeId::ExtensionObject => {
+                            error!("Cannot get the data type of an untyped empty array");
+                            None
+                        }
+                        value_type => Some(NodeId::new(0, value_type.to_encoding_byte() as u32)),
+                    }
                 } else {
                     array.values[0].scalar_data_type()
                 }
@@ -1402,6 +1672,18 @@ impl Variant {
         }
     }
 
+    /// Creates an array with no elements which is still of the supplied element type, so its
+    /// type survives encoding and decoding.
+    pub fn empty_array(type_id: VariantTypeId) -> Variant {
+        Variant::from(Array::new_empty(type_id))
+    }
+
+    /// Creates a null array of the supplied element type. It is encoded with a length of -1 so
+    /// it stays distinct from an empty array and from a null scalar, i.e. `Variant::Empty`.
+    pub fn null_array(type_id: VariantTypeId) -> Variant {
+        Variant::from(Array::new_null(type_id))
+    }
+
     // Returns the scalar data type. Returns None for arrays
     pub fn scalar_data_type(&self) -> Option<NodeId> {
         match self {
@@ -1433,41 +1715,8 @@ impl Variant {
     // Gets the encoding mask to write the variant to disk
     pub(crate) fn encoding_mask(&self) -> u8 {
         match self {
-            Variant::Empty => 0,
-            Variant::Boolean(_) => DataTypeId::Boolean as u8,
-            Variant::SByte(_) => DataTypeId::SByte as u8,
-            Variant::Byte(_) => DataTypeId::Byte as u8,
-            Variant::Int16(_) => DataTypeId::Int16 as u8,
-            Variant::UInt16(_) => DataTypeId::UInt16 as u8,
-            Variant::Int32(_) => DataTypeId::Int32 as u8,
-            Variant::UInt32(_) => DataTypeId::UInt32 as u8,
-            Variant::Int64(_) => DataTypeId::Int64 as u8,
-            Variant::UInt64(_) => DataTypeId::UInt64 as u8,
-            Variant::Float(_) => DataTypeId::Float as u8,
-            Variant::Double(_) => DataTypeId::Double as u8,
-            Variant::String(_) => DataTypeId::String as u8,
-            Variant::DateTime(_) => DataTypeId::DateTime as u8,
-            Variant::Guid(_) => DataTypeId::Guid as u8,
-            Variant::ByteString(_) => DataTypeId::ByteString as u8,
-            Variant::XmlElement(_) => DataTypeId::XmlElement as u8,
-            Variant::NodeId(_) => DataTypeId::NodeId as u8,
-            Variant::ExpandedNodeId(_) => DataTypeId::ExpandedNodeId as u8,
-            Variant::StatusCode(_) => DataTypeId::StatusCode as u8,
-            Variant::QualifiedName(_) => DataTypeId::QualifiedName as u8,
-            Variant::LocalizedText(_) => DataTypeId::LocalizedText as u8,
-            Variant::ExtensionObject(_) => 22, // DataTypeId::ExtensionObject as u8,
-            Variant::Array(array) => {
-                let mut encoding_mask = if array.values.is_empty() {
-                    0u8
-                } else {
-                    array.values[0].encoding_mask()
-                };
-                encoding_mask |= ARRAY_VALUES_BIT;
-                if array.has_dimensions() {
-                    encoding_mask |= ARRAY_DIMENSIONS_BIT;
-                }
-                encoding_mask
-            }
+            Variant::Array(array) => array.encoding_mask(),
+            value => value.type_id().to_encoding_byte(),
         }
     }
 
@@ -1627,11 +1876,128 @@ impl Variant {
                     _ => Err(StatusCode::BadIndexRangeNoData),
                 }
             }
-            NumericRange::MultipleRanges(_ranges) => {
-                // Not yet supported
-                error!("Multiple ranges not supported");
-                Err(StatusCode::BadIndexRangeNoData)
+            NumericRange::MultipleRanges(ranges) => match self {
+                Variant::Array(array) => Self::multi_dimension_range_of(array, &ranges),
+                _ => {
+                    error!("Multiple ranges are only supported on arrays");
+                    Err(StatusCode::BadIndexRangeNoData)
+                }
+            },
+        }
+    }
+
+    /// Returns the block of a multi dimensional array selected by a range for each dimension.
+    fn multi_dimension_range_of(
+        array: &Array,
+        ranges: &[NumericRange],
+    ) -> Result<Variant, StatusCode> {
+        // A single dimension array may omit its dimensions
+        let dimensions = if array.dimensions.is_empty() {
+            vec![array.values.len() as u32]
+        } else {
+            array.dimensions.clone()
+        };
+        if ranges.len() != dimensions.len() {
+            error!(
+                "Range has {} dimensions but array has {}",
+                ranges.len(),
+                dimensions.len()
+            );
+            return Err(StatusCode::BadIndexRangeNoData);
+        }
+
+        // Turn each range into an inclusive min / max, clamping max to the dimension's length
+        let mut bounds = Vec::with_capacity(ranges.len());
+        for (range, dimension) in ranges.iter().zip(dimensions.iter()) {
+            let (min, max) = match *range {
+                NumericRange::Index(idx) => (idx, idx),
+                NumericRange::Range(min, max) => (min, max),
+                _ => return Err(StatusCode::BadIndexRangeInvalid),
+            };
+            if min >= *dimension {
+                return Err(StatusCode::BadIndexRangeNoData);
+            }
+            bounds.push((min as usize, max.min(*dimension - 1) as usize));
+        }
+
+        // Values are stored with the last dimension varying fastest
+        let mut strides = vec![1usize; dimensions.len()];
+        for i in (0..dimensions.len() - 1).rev() {
+            strides[i] = strides[i + 1] * dimensions[i + 1] as usize;
+        }
+
+        // Walk every index inside the bounds, in storage order
+        let mut values = Vec::new();
+        let mut index: Vec<usize> = bounds.iter().map(|(min, _)| *min).collect();
+        'outer: loop {
+            let offset: usize = index.iter().zip(strides.iter()).map(|(i, s)| i * s).sum();
+            if let Some(value) = array.values.get(offset) {
+                values.push(value.clone());
+            } else {
+                return Err(StatusCode::BadIndexRangeNoData);
+            }
+            for d in (0..index.len()).rev() {
+                if index[d] < bounds[d].1 {
+                    index[d] += 1;
+                    continue 'outer;
+                }
+                index[d] = bounds[d].0;
+            }
+            break;
+        }
+
+        let dimensions: Vec<u32> = bounds
+            .iter()
+            .map(|(min, max)| (max - min + 1) as u32)
+            .collect();
+        Ok(Variant::from(Array::new_multi(values, dimensions)))
+    }
+}
+
+#[cfg(test)]
+impl Variant {
+    /// Returns a representative value of the type for tests, e.g. a non-empty string, a non-zero
+    /// guid or a multi-element array, so that encoding tests exercise real payloads rather than
+    /// defaults. The value is the same every time.
+    pub fn sample(type_id: VariantTypeId) -> Variant {
+        match type_id {
+            VariantTypeId::Empty => Variant::Empty,
+            VariantTypeId::Boolean => Variant::Boolean(true),
+            VariantTypeId::SByte => Variant::SByte(-7),
+            VariantTypeId::Byte => Variant::Byte(200),
+            VariantTypeId::Int16 => Variant::Int16(-1234),
+            VariantTypeId::UInt16 => Variant::UInt16(54321),
+            VariantTypeId::Int32 => Variant::Int32(-123456),
+            VariantTypeId::UInt32 => Variant::UInt32(3_000_000_000),
+            VariantTypeId::Int64 => Variant::Int64(-1_234_567_890_123),
+            VariantTypeId::UInt64 => Variant::UInt64(12_345_678_901_234_567_890),
+            VariantTypeId::Float => Variant::Float(1.5),
+            VariantTypeId::Double => Variant::Double(-2.25e10),
+            VariantTypeId::String => Variant::from("sample"),
+            VariantTypeId::DateTime => Variant::from(DateTime::ymd_hms(2020, 5, 17, 12, 30, 45)),
+            VariantTypeId::Guid => {
+                Variant::from(Guid::from_str("72962B91-FA75-4AE6-8D28-B404DC7DAF63").unwrap())
             }
+            VariantTypeId::StatusCode => Variant::StatusCode(StatusCode::BadNodeIdUnknown),
+            VariantTypeId::ByteString => Variant::from(ByteString::from(vec![1u8, 2, 3, 4])),
+            VariantTypeId::XmlElement => Variant::XmlElement(XmlElement::from("<a>sample</a>")),
+            VariantTypeId::QualifiedName => Variant::from(QualifiedName::new(2, "sample")),
+            VariantTypeId::LocalizedText => Variant::from(LocalizedText::new("en", "sample")),
+            VariantTypeId::NodeId => Variant::from(NodeId::new(2, "sample")),
+            VariantTypeId::ExpandedNodeId => Variant::from(ExpandedNodeId {
+                node_id: NodeId::new(2, 1234),
+                namespace_uri: UAString::from("urn:sample"),
+                server_index: 3,
+            }),
+            VariantTypeId::ExtensionObject => Variant::from(ExtensionObject::from_encodable(
+                NodeId::new(2, 100),
+                &UAString::from("sample"),
+            )),
+            VariantTypeId::Array => Variant::from(Array::new_single(vec![
+                Variant::Int32(1),
+                Variant::Int32(2),
+                Variant::Int32(3),
+            ])),
         }
     }
 }
@@ -0,0 +1,273 @@
+// OPCUA for Rust
+// SPDX-License-Identifier: MPL-2.0
+// Copyright (C) 2017-2020 Adam Lock
+
+//! Contains the conversion of a `Variant` to and from a `serde_json::Value`. The mapping follows
+//! the spirit of the OPC UA JSON encoding for a value whose type is known to the reader, i.e. the
+//! type is not written into the JSON and must be supplied as a hint when reading it back.
+
+use std::{convert::TryFrom, str::FromStr};
+
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    array::Array,
+    byte_string::ByteString,
+    date_time::DateTime,
+    extension_object::ExtensionObject,
+    guid::Guid,
+    localized_text::LocalizedText,
+    node_id::{ExpandedNodeId, NodeId},
+    qualified_name::QualifiedName,
+    status_code::StatusCode,
+    string::{UAString, XmlElement},
+    variant::{Variant, VariantTypeId},
+};
+
+fn string_to_json(value: &UAString) -> Value {
+    value
+        .value()
+        .as_ref()
+        .map_or(Value::Null, |v| Value::String(v.clone()))
+}
+
+fn float_to_json(value: f64) -> Value {
+    // JSON has no representation of these so they are written as strings
+    if value.is_nan() {
+        Value::String("NaN".into())
+    } else if value.is_infinite() && value.is_sign_positive() {
+        Value::String("Infinity".into())
+    } else if value.is_infinite() {
+        Value::String("-Infinity".into())
+    } else {
+        Number::from_f64(value).map_or(Value::Null, Value::Number)
+    }
+}
+
+fn type_mismatch(value: &Value, type_hint: VariantTypeId) -> StatusCode {
+    error!("JSON value {} cannot be read as a {:?}", value, type_hint);
+    StatusCode::BadTypeMismatch
+}
+
+fn json_to_i64(value: &Value, type_hint: VariantTypeId) -> Result<i64, StatusCode> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        // Only 64-bit integers are written as strings
+        Value::String(s) if type_hint == VariantTypeId::Int64 => i64::from_str(s).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| type_mismatch(value, type_hint))
+}
+
+fn json_to_u64(value: &Value, type_hint: VariantTypeId) -> Result<u64, StatusCode> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) if type_hint == VariantTypeId::UInt64 => u64::from_str(s).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| type_mismatch(value, type_hint))
+}
+
+fn json_to_f64(value: &Value, type_hint: VariantTypeId) -> Result<f64, StatusCode> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) if s == "NaN" => Some(f64::NAN),
+        Value::String(s) if s == "Infinity" => Some(f64::INFINITY),
+        Value::String(s) if s == "-Infinity" => Some(f64::NEG_INFINITY),
+        _ => None,
+    }
+    .ok_or_else(|| type_mismatch(value, type_hint))
+}
+
+fn json_to_str(value: &Value, type_hint: VariantTypeId) -> Result<&str, StatusCode> {
+    value
+        .as_str()
+        .ok_or_else(|| type_mismatch(value, type_hint))
+}
+
+/// Narrows an integer to the type of the hint, failing with `BadOutOfRange` if it does not fit
+fn narrow<T, V>(value: V, type_hint: VariantTypeId) -> Result<T, StatusCode>
+where
+    T: TryFrom<V>,
+    V: Copy + std::fmt::Display,
+{
+    T::try_from(value).map_err(|_| {
+        error!("JSON value {} is out of range for a {:?}", value, type_hint);
+        StatusCode::BadOutOfRange
+    })
+}
+
+impl Variant {
+    /// Converts the variant to a JSON value. Numbers are written as numbers except for `Int64`
+    /// and `UInt64` which are written as strings so they do not lose precision, and NaN and
+    /// infinities which are written as `"NaN"`, `"Infinity"` and `"-Infinity"`. Byte strings are
+    /// written as base64, guids, node ids and date times as their canonical strings and arrays as
+    /// a JSON array of their values. A null string, byte string or an empty variant is JSON null.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Variant::Empty => Value::Null,
+            Variant::Boolean(v) => Value::Bool(*v),
+            Variant::SByte(v) => Value::from(*v),
+            Variant::Byte(v) => Value::from(*v),
+            Variant::Int16(v) => Value::from(*v),
+            Variant::UInt16(v) => Value::from(*v),
+            Variant::Int32(v) => Value::from(*v),
+            Variant::UInt32(v) => Value::from(*v),
+            Variant::Int64(v) => Value::String(v.to_string()),
+            Variant::UInt64(v) => Value::String(v.to_string()),
+            Variant::Float(v) => float_to_json(f64::from(*v)),
+            Variant::Double(v) => float_to_json(*v),
+            Variant::String(v) => string_to_json(v),
+            Variant::XmlElement(v) => string_to_json(v),
+            Variant::DateTime(v) => Value::String(v.to_string()),
+            Variant::Guid(v) => Value::String(v.to_string()),
+            Variant::StatusCode(v) => Value::from(v.bits()),
+            Variant::ByteString(v) => {
+                if v.is_null() {
+                    Value::Null
+                } else {
+                    Value::String(v.as_base64())
+                }
+            }
+            Variant::QualifiedName(v) => {
+                let mut map = Map::new();
+                map.insert("Name".into(), string_to_json(&v.name));
+                if v.namespace_index != 0 {
+                    map.insert("Uri".into(), Value::from(v.namespace_index));
+                }
+                Value::Object(map)
+            }
+            Variant::LocalizedText(v) => {
+                let mut map = Map::new();
+                if !v.locale.is_empty() {
+                    map.insert("Locale".into(), string_to_json(&v.locale));
+                }
+                map.insert("Text".into(), string_to_json(&v.text));
+                Value::Object(map)
+            }
+            Variant::NodeId(v) => Value::String(v.to_string()),
+            Variant::ExpandedNodeId(v) => Value::String(v.to_string()),
+            Variant::ExtensionObject(v) => serde_json::to_value(v).unwrap_or(Value::Null),
+            Variant::Array(array) => {
+                Value::Array(array.values.iter().map(|v| v.to_json()).collect())
+            }
+        }
+    }
+
+    /// Converts a JSON value to a variant of the hinted type, the reverse of `to_json()`. A JSON
+    /// array becomes an array of the hinted type, and JSON null becomes a null string, byte string
+    /// or xml element for those types and an empty variant for any other. `Int64` and `UInt64`
+    /// may be supplied either as strings or as numbers. A value of the wrong kind for the hint
+    /// returns `BadTypeMismatch` and a number which does not fit the hint returns `BadOutOfRange`.
+    pub fn from_json(value: &Value, type_hint: VariantTypeId) -> Result<Variant, StatusCode> {
+        let variant = match value {
+            Value::Array(values) => {
+                let values = values
+                    .iter()
+                    .map(|v| match v {
+                        Value::Array(_) => Err(type_mismatch(v, type_hint)),
+                        v => Variant::from_json(v, type_hint),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if values.is_empty() {
+                    Variant::empty_array(type_hint)
+                } else {
+                    Variant::from(Array::new_single(values))
+                }
+            }
+            Value::Null => match type_hint {
+                VariantTypeId::String => Variant::String(UAString::null()),
+                VariantTypeId::XmlElement => Variant::XmlElement(XmlElement::null()),
+                VariantTypeId::ByteString => Variant::ByteString(ByteString::null()),
+                _ => Variant::Empty,
+            },
+            value => match type_hint {
+                VariantTypeId::Boolean => Variant::Boolean(
+                    value
+                        .as_bool()
+                        .ok_or_else(|| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::SByte => {
+                    Variant::SByte(narrow(json_to_i64(value, type_hint)?, type_hint)?)
+                }
+                VariantTypeId::Byte => {
+                    Variant::Byte(narrow(json_to_u64(value, type_hint)?, type_hint)?)
+                }
+                VariantTypeId::Int16 => {
+                    Variant::Int16(narrow(json_to_i64(value, type_hint)?, type_hint)?)
+                }
+                VariantTypeId::UInt16 => {
+                    Variant::UInt16(narrow(json_to_u64(value, type_hint)?, type_hint)?)
+                }
+                VariantTypeId::Int32 => {
+                    Variant::Int32(narrow(json_to_i64(value, type_hint)?, type_hint)?)
+                }
+                VariantTypeId::UInt32 => {
+                    Variant::UInt32(narrow(json_to_u64(value, type_hint)?, type_hint)?)
+                }
+                VariantTypeId::Int64 => Variant::Int64(json_to_i64(value, type_hint)?),
+                VariantTypeId::UInt64 => Variant::UInt64(json_to_u64(value, type_hint)?),
+                VariantTypeId::Float => Variant::Float(json_to_f64(value, type_hint)? as f32),
+                VariantTypeId::Double => Variant::Double(json_to_f64(value, type_hint)?),
+                VariantTypeId::String => Variant::from(json_to_str(value, type_hint)?),
+                VariantTypeId::XmlElement => {
+                    Variant::XmlElement(XmlElement::from(json_to_str(value, type_hint)?))
+                }
+                VariantTypeId::DateTime => Variant::from(
+                    DateTime::from_str(json_to_str(value, type_hint)?)
+                        .map_err(|_| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::Guid => Variant::from(
+                    Guid::from_str(json_to_str(value, type_hint)?)
+                        .map_err(|_| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::StatusCode => {
+                    let bits = narrow(json_to_u64(value, type_hint)?, type_hint)?;
+                    Variant::StatusCode(StatusCode::from_u32(bits).ok_or_else(|| {
+                        error!("JSON value {} is not a status code", value);
+                        StatusCode::BadOutOfRange
+                    })?)
+                }
+                VariantTypeId::ByteString => Variant::ByteString(
+                    ByteString::from_base64(json_to_str(value, type_hint)?)
+                        .ok_or_else(|| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::QualifiedName => {
+                    let name = value
+                        .get("Name")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| type_mismatch(value, type_hint))?;
+                    let namespace_index = match value.get("Uri") {
+                        Some(uri) => narrow(json_to_u64(uri, type_hint)?, type_hint)?,
+                        None => 0,
+                    };
+                    Variant::from(QualifiedName::new(namespace_index, name))
+                }
+                VariantTypeId::LocalizedText => {
+                    let text = value
+                        .get("Text")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| type_mismatch(value, type_hint))?;
+                    let locale = value.get("Locale").and_then(|v| v.as_str()).unwrap_or("");
+                    Variant::from(LocalizedText::new(locale, text))
+                }
+                VariantTypeId::NodeId => Variant::from(
+                    NodeId::from_str(json_to_str(value, type_hint)?)
+                        .map_err(|_| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::ExpandedNodeId => Variant::from(
+                    ExpandedNodeId::from_str(json_to_str(value, type_hint)?)
+                        .map_err(|_| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::ExtensionObject => Variant::from(
+                    serde_json::from_value::<ExtensionObject>(value.clone())
+                        .map_err(|_| type_mismatch(value, type_hint))?,
+                ),
+                VariantTypeId::Empty | VariantTypeId::Array => {
+                    return Err(type_mismatch(value, type_hint));
+                }
+            },
+        };
+        Ok(variant)
+    }
+}